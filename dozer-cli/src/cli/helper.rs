@@ -3,7 +3,7 @@ use crate::errors::CliError;
 use crate::errors::CliError::{ConfigurationFilePathNotProvided, FailedToFindConfigurationFiles};
 use crate::errors::ConfigCombineError::CannotReadConfig;
 use crate::errors::OrchestrationError;
-use crate::simple::SimpleOrchestrator as Dozer;
+use crate::simple::{MultiAppOrchestrator, SimpleOrchestrator as Dozer};
 
 use camino::Utf8PathBuf;
 use dozer_tracing::DozerMonitorContext;
@@ -46,6 +46,35 @@ pub fn init_dozer(
     Ok(Dozer::new(base_directory, config, runtime, labels))
 }
 
+/// Loads the app in every directory of `app_dirs`, with `config_paths` relative to that directory.
+pub async fn init_multi_app(
+    runtime: Arc<Runtime>,
+    app_dirs: &[String],
+    config_paths: &[String],
+    config_overrides: Vec<(String, serde_json::Value)>,
+    enable_progress: bool,
+) -> Result<MultiAppOrchestrator, OrchestrationError> {
+    let current_dir = get_base_dir()?;
+    let mut orchestrator = MultiAppOrchestrator::new(runtime.clone());
+    for app_dir in app_dirs {
+        let base_directory = current_dir.join(app_dir);
+        let app_config_paths = config_paths
+            .iter()
+            .map(|path| base_directory.join(path).to_string())
+            .collect();
+        let (config, loaded_files) =
+            init_config(app_config_paths, None, config_overrides.clone(), true).await?;
+        info!("Loaded config from: {}", loaded_files.join(", "));
+        let labels = DozerMonitorContext::new(
+            config.id.clone(),
+            config.company_id.clone(),
+            enable_progress,
+        );
+        orchestrator.add_app(Dozer::new(base_directory, config, runtime.clone(), labels))?;
+    }
+    Ok(orchestrator)
+}
+
 pub async fn list_sources(
     runtime: Arc<Runtime>,
     config_paths: Vec<String>,
@@ -88,6 +88,7 @@ pub fn generate_connection(connection_name: &str) -> Connection {
             let connection: Connection = Connection {
                 name: "snowflake".to_owned(),
                 config: ConnectionConfig::Snowflake(snowflake_config),
+                retry: None,
             };
             connection
         }
@@ -108,6 +109,7 @@ pub fn generate_connection(connection_name: &str) -> Connection {
             let connection: Connection = Connection {
                 name: "ethereum".to_owned(),
                 config: ConnectionConfig::Ethereum(ethereum_config),
+                retry: None,
             };
             connection
         }
@@ -115,10 +117,13 @@ pub fn generate_connection(connection_name: &str) -> Connection {
             let mysql_config = MySQLConfig {
                 url: "mysql://<user>:<password>@localhost:3306/<database>".to_owned(),
                 server_id: Some((1).to_owned()),
+                ssh_tunnel: None,
+                tls: None,
             };
             let connection: Connection = Connection {
                 name: "mysql".to_owned(),
                 config: ConnectionConfig::MySQL(mysql_config),
+                retry: None,
             };
             connection
         }
@@ -136,6 +141,7 @@ pub fn generate_connection(connection_name: &str) -> Connection {
             let connection: Connection = Connection {
                 name: "s3".to_owned(),
                 config: ConnectionConfig::S3Storage(s3_config),
+                retry: None,
             };
             connection
         }
@@ -147,6 +153,7 @@ pub fn generate_connection(connection_name: &str) -> Connection {
             let connection: Connection = Connection {
                 name: "mongodb".to_owned(),
                 config: ConnectionConfig::MongoDB(mongo_config),
+                retry: None,
             };
             connection
         }
@@ -154,6 +161,7 @@ pub fn generate_connection(connection_name: &str) -> Connection {
             let postgres_config = PostgresConfig {
                 user: Some("postgres".to_owned()),
                 password: Some("postgres".to_owned()),
+                password_file: None,
                 host: Some("localhost".to_owned()),
                 port: Some(5432),
                 database: Some("users".to_owned()),
@@ -161,10 +169,14 @@ pub fn generate_connection(connection_name: &str) -> Connection {
                 connection_url: None,
                 schema: None,
                 batch_size: None,
+                snapshot_connections: None,
+                ssh_tunnel: None,
+                tls: None,
             };
             let connection: Connection = Connection {
                 name: "postgres".to_owned(),
                 config: ConnectionConfig::Postgres(postgres_config),
+                retry: None,
             };
             connection
         }
@@ -2,6 +2,7 @@ mod helper;
 mod init;
 pub mod types;
 pub use helper::{
-    get_base_dir, init_config, init_dozer, list_sources, load_config_from_file, LOGO,
+    get_base_dir, init_config, init_dozer, init_multi_app, list_sources, load_config_from_file,
+    LOGO,
 };
 pub use init::{generate_config_repl, generate_connection};
@@ -55,6 +55,12 @@ pub enum Commands {
     Build(Build),
     #[command(about = "Run a replication instance with the provided configuration")]
     Run,
+    #[command(
+        about = "Run several apps in one process",
+        long_about = "Run several apps in one process. Each app is loaded from its own directory, \
+            using the config paths relative to that directory"
+    )]
+    RunMany(RunMany),
     #[command(about = "Run UI server")]
     UI(UI),
 }
@@ -81,6 +87,14 @@ pub struct Live {
     pub disable_live_ui: bool,
 }
 
+#[derive(Debug, Args)]
+pub struct RunMany {
+    #[arg(required = true, help = "Directories of the apps")]
+    pub app_dirs: Vec<String>,
+    #[arg(help = format!("Require that the {LOCK_FILE} of every app is up-to-date"), long = "locked")]
+    pub locked: bool,
+}
+
 #[derive(Debug, Args)]
 #[command(args_conflicts_with_subcommands = true)]
 pub struct Build {
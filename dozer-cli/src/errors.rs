@@ -88,6 +88,12 @@ pub enum OrchestrationError {
     LockedNoLockFile,
     #[error("Command was aborted")]
     Aborted,
+    #[error("App {0} is defined more than once")]
+    DuplicateApp(String),
+    #[error("App {0} not found")]
+    AppNotFound(String),
+    #[error("Apps failed: {0}")]
+    AppsFailed(String),
 }
 
 #[derive(Error, Debug)]
@@ -1,6 +1,7 @@
 use clap::Parser;
 use dozer_cli::cli::init_config;
 use dozer_cli::cli::init_dozer;
+use dozer_cli::cli::init_multi_app;
 use dozer_cli::cli::types::{Cli, Commands, UICommands};
 use dozer_cli::errors::{CliError, CloudError, OrchestrationError};
 use dozer_cli::ui;
@@ -69,6 +70,23 @@ fn run() -> Result<(), OrchestrationError> {
         return Ok(());
     }
 
+    // every app loads its own config
+    if let Commands::RunMany(run_many) = &cli.cmd {
+        return runtime.block_on(async {
+            let orchestrator = init_multi_app(
+                runtime.clone(),
+                &run_many.app_dirs,
+                &cli.config_paths,
+                cli.config_overrides.clone(),
+                cli.enable_progress,
+            )
+            .await?;
+            orchestrator
+                .run_all(shutdown_receiver, run_many.locked)
+                .await
+        });
+    }
+
     let (config, config_files) = config_res?;
     info!("Loaded config from: {}", config_files.join(", "));
 
@@ -96,7 +114,7 @@ fn run() -> Result<(), OrchestrationError> {
                 .block_on(dozer.build(force, shutdown_receiver, build.locked))
         }
         Commands::Clean => dozer.clean(),
-        Commands::UI(_) => {
+        Commands::UI(_) | Commands::RunMany(_) => {
             panic!("This should not happen as it is handled earlier");
         }
     })
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use dozer_core::{
+    epoch::Epoch,
+    event::EventHub,
+    node::{PortHandle, Sink, SinkFactory},
+};
+use dozer_types::{
+    errors::internal::BoxedError, node::OpIdentifier, types::Schema, types::TableOperation,
+};
+
+use crate::async_trait::async_trait;
+
+/// Wraps a sink factory and overrides when the sink flushes its batches, whichever of the two thresholds is hit first.
+#[derive(Debug)]
+pub struct BatchingSinkFactory {
+    inner: Box<dyn SinkFactory>,
+    max_commit_interval_ms: Option<u64>,
+    max_commit_batch: Option<u64>,
+}
+
+impl BatchingSinkFactory {
+    pub fn new(
+        inner: Box<dyn SinkFactory>,
+        max_commit_interval_ms: Option<u64>,
+        max_commit_batch: Option<u64>,
+    ) -> Self {
+        Self {
+            inner,
+            max_commit_interval_ms,
+            max_commit_batch,
+        }
+    }
+}
+
+#[async_trait]
+impl SinkFactory for BatchingSinkFactory {
+    fn get_input_ports(&self) -> Vec<PortHandle> {
+        self.inner.get_input_ports()
+    }
+
+    fn get_input_port_name(&self, port: &PortHandle) -> String {
+        self.inner.get_input_port_name(port)
+    }
+
+    fn prepare(&self, input_schemas: HashMap<PortHandle, Schema>) -> Result<(), BoxedError> {
+        self.inner.prepare(input_schemas)
+    }
+
+    async fn build(
+        &self,
+        input_schemas: HashMap<PortHandle, Schema>,
+        event_hub: EventHub,
+    ) -> Result<Box<dyn Sink>, BoxedError> {
+        let inner = self.inner.build(input_schemas, event_hub).await?;
+        Ok(Box::new(BatchingSink {
+            inner,
+            max_commit_interval_ms: self.max_commit_interval_ms,
+            max_commit_batch: self.max_commit_batch,
+        }))
+    }
+
+    fn type_name(&self) -> String {
+        self.inner.type_name()
+    }
+}
+
+#[derive(Debug)]
+struct BatchingSink {
+    inner: Box<dyn Sink>,
+    max_commit_interval_ms: Option<u64>,
+    max_commit_batch: Option<u64>,
+}
+
+impl Sink for BatchingSink {
+    fn commit(&mut self, epoch_details: &Epoch) -> Result<(), BoxedError> {
+        self.inner.commit(epoch_details)
+    }
+
+    fn process(&mut self, op: TableOperation) -> Result<(), BoxedError> {
+        self.inner.process(op)
+    }
+
+    fn on_source_snapshotting_started(
+        &mut self,
+        connection_name: String,
+    ) -> Result<(), BoxedError> {
+        self.inner.on_source_snapshotting_started(connection_name)
+    }
+
+    fn on_source_snapshotting_done(
+        &mut self,
+        connection_name: String,
+        id: Option<OpIdentifier>,
+    ) -> Result<(), BoxedError> {
+        self.inner.on_source_snapshotting_done(connection_name, id)
+    }
+
+    fn set_source_state(&mut self, source_state: &[u8]) -> Result<(), BoxedError> {
+        self.inner.set_source_state(source_state)
+    }
+
+    fn get_source_state(&mut self) -> Result<Option<Vec<u8>>, BoxedError> {
+        self.inner.get_source_state()
+    }
+
+    fn get_latest_op_id(&mut self) -> Result<Option<OpIdentifier>, BoxedError> {
+        self.inner.get_latest_op_id()
+    }
+
+    fn preferred_batch_size(&self) -> Option<u64> {
+        self.max_commit_batch
+            .or_else(|| self.inner.preferred_batch_size())
+    }
+
+    fn max_batch_duration_ms(&self) -> Option<u64> {
+        self.max_commit_interval_ms
+            .or_else(|| self.inner.max_batch_duration_ms())
+    }
+
+    fn flush_batch(&mut self) -> Result<(), BoxedError> {
+        self.inner.flush_batch()
+    }
+}
@@ -17,17 +17,22 @@ use dozer_types::models::connection::ConnectionConfig;
 use dozer_types::models::flags::Flags;
 use dozer_types::models::sink::Sink;
 use dozer_types::models::sink::SinkConfig;
+use dozer_types::models::sink::SinkDeletePolicy;
 use dozer_types::models::source::Source;
 use dozer_types::models::udf_config::UdfConfig;
 use dozer_types::types::PortHandle;
 use std::hash::Hash;
 use tokio::runtime::Runtime;
 
+use crate::pipeline::batching_sink::BatchingSinkFactory;
+use crate::pipeline::delete_policy_sink::DeletePolicySinkFactory;
 use crate::pipeline::dummy_sink::DummySinkFactory;
+use crate::pipeline::validation_sink::ValidationSinkFactory;
 use dozer_sink_aerospike::AerospikeSinkFactory;
 use dozer_sink_clickhouse::ClickhouseSinkFactory;
 use dozer_sink_oracle::OracleSinkFactory;
 
+use super::quota::QuotaEnforcer;
 use super::source_builder::SourceBuilder;
 use crate::errors::OrchestrationError;
 
@@ -57,6 +62,7 @@ pub struct PipelineBuilder<'a> {
     labels: DozerMonitorContext,
     flags: Flags,
     udfs: &'a [UdfConfig],
+    quota: Option<Arc<QuotaEnforcer>>,
 }
 
 impl<'a> PipelineBuilder<'a> {
@@ -77,9 +83,16 @@ impl<'a> PipelineBuilder<'a> {
             labels,
             flags,
             udfs,
+            quota: None,
         }
     }
 
+    /// Enforces the app's quotas on the operations ingested by its sources.
+    pub fn with_quota(mut self, quota: Arc<QuotaEnforcer>) -> Self {
+        self.quota = Some(quota);
+        self
+    }
+
     // Based on used_sources, map it to the connection name and create sources
     // For not breaking current functionality, current format is to be still supported.
     pub async fn get_grouped_tables(
@@ -244,6 +257,8 @@ impl<'a> PipelineBuilder<'a> {
                     &mut pipeline,
                     Box::new(DummySinkFactory),
                     id,
+                    sink,
+                    &self.labels,
                     vec![(get_table_info(&config.table_name)?, DEFAULT_PORT_HANDLE)],
                 ),
                 SinkConfig::Aerospike(config) => {
@@ -254,6 +269,7 @@ impl<'a> PipelineBuilder<'a> {
                             Connection {
                                 config: ConnectionConfig::Aerospike(conn_config),
                                 name,
+                                ..
                             } if name == &config.connection => Some(conn_config),
                             _ => None,
                         })
@@ -273,16 +289,25 @@ impl<'a> PipelineBuilder<'a> {
                             Ok((table_info, port as PortHandle))
                         })
                         .collect::<Result<Vec<_>, OrchestrationError>>()?;
-                    add_sink_to_pipeline(&mut pipeline, sink_factory, id, table_infos);
+                    add_sink_to_pipeline(
+                        &mut pipeline,
+                        sink_factory,
+                        id,
+                        sink,
+                        &self.labels,
+                        table_infos,
+                    );
                 }
                 SinkConfig::Clickhouse(config) => {
-                    let sink =
+                    let sink_factory =
                         Box::new(ClickhouseSinkFactory::new(config.clone(), runtime.clone()));
                     let table_info = get_table_info(&config.source_table_name)?;
                     add_sink_to_pipeline(
                         &mut pipeline,
-                        sink,
+                        sink_factory,
                         id,
+                        sink,
+                        &self.labels,
                         vec![(table_info, DEFAULT_PORT_HANDLE)],
                     );
                 }
@@ -294,18 +319,22 @@ impl<'a> PipelineBuilder<'a> {
                             Connection {
                                 config: ConnectionConfig::Oracle(conn_config),
                                 name,
+                                ..
                             } if name == &config.connection => Some(conn_config),
                             _ => None,
                         })
                         .ok_or_else(|| {
                             OrchestrationError::ConnectionNotFound(config.connection.clone())
                         })?;
-                    let sink = Box::new(OracleSinkFactory::new(connection.clone(), config.clone()));
+                    let sink_factory =
+                        Box::new(OracleSinkFactory::new(connection.clone(), config.clone()));
                     let table_info = get_table_info(&config.table_name)?;
                     add_sink_to_pipeline(
                         &mut pipeline,
-                        sink,
+                        sink_factory,
                         id,
+                        sink,
+                        &self.labels,
                         vec![(table_info, DEFAULT_PORT_HANDLE)],
                     );
                 }
@@ -314,7 +343,10 @@ impl<'a> PipelineBuilder<'a> {
 
         pipelines.push(pipeline);
 
-        let source_builder = SourceBuilder::new(grouped_connections, self.labels);
+        let mut source_builder = SourceBuilder::new(grouped_connections, self.labels);
+        if let Some(quota) = self.quota {
+            source_builder = source_builder.with_quota(quota);
+        }
         let asm = source_builder
             .build_source_manager(runtime, shutdown)
             .await?;
@@ -360,8 +392,36 @@ fn add_sink_to_pipeline(
     pipeline: &mut AppPipeline,
     sink: Box<dyn SinkFactory>,
     id: &str,
+    config: &Sink,
+    labels: &DozerMonitorContext,
     table_infos: Vec<(&OutputTableInfo, PortHandle)>,
 ) {
+    let sink: Box<dyn SinkFactory> = match config.on_delete {
+        SinkDeletePolicy::Propagate => sink,
+        policy => Box::new(DeletePolicySinkFactory::new(sink, policy)),
+    };
+    // Validation sees the records as they come from upstream, before the delete policy changes them.
+    let sink: Box<dyn SinkFactory> = if config.validation.is_empty() {
+        sink
+    } else {
+        Box::new(ValidationSinkFactory::new(
+            sink,
+            id.to_string(),
+            config.validation.clone(),
+            config.on_invalid.clone(),
+            labels.clone(),
+        ))
+    };
+    let sink: Box<dyn SinkFactory> =
+        if config.max_commit_interval.is_none() && config.max_commit_batch.is_none() {
+            sink
+        } else {
+            Box::new(BatchingSinkFactory::new(
+                sink,
+                config.max_commit_interval,
+                config.max_commit_batch,
+            ))
+        };
     pipeline.add_sink(sink, id.to_string());
 
     for (table_info, port) in table_infos {
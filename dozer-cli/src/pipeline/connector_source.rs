@@ -13,6 +13,7 @@ use dozer_tracing::{emit_event, DozerMonitorContext};
 use dozer_types::errors::internal::BoxedError;
 use dozer_types::models::connection::Connection;
 use dozer_types::models::ingestion_types::IngestionMessage;
+use dozer_types::models::source::{FieldMapping, KeyStrategy};
 use dozer_types::node::OpIdentifier;
 use dozer_types::thiserror::{self, Error};
 use dozer_types::tracing::info;
@@ -24,13 +25,19 @@ use tokio::runtime::Runtime;
 use tokio::sync::mpsc::Sender;
 use tonic::async_trait;
 
+use super::quota::QuotaEnforcer;
+use super::source_mapping::{SourceMappingError, TableMapping};
+
 #[derive(Debug)]
 struct Table {
     schema_name: Option<String>,
     name: String,
     columns: Vec<String>,
+    filter: Option<String>,
     schema: Schema,
     cdc_type: CdcType,
+    mapping: TableMapping,
+    event_time: Option<usize>,
     port: PortHandle,
 }
 
@@ -42,6 +49,25 @@ pub enum ConnectorSourceFactoryError {
     PortNotFoundInSource(PortHandle),
     #[error("Schema not initialized")]
     SchemaNotInitialized,
+    #[error("Invalid mapping for table {0}: {1}")]
+    Mapping(String, #[source] SourceMappingError),
+    #[error("Event time field {1} not found in table {0}")]
+    EventTimeFieldNotFound(String, String),
+    #[error("Connection {0} doesn't support filtering table {1}")]
+    FilterNotSupported(String, String),
+}
+
+/// A table of the connection to ingest, and the port its operations are sent to.
+#[derive(Debug, Clone)]
+pub struct SourceTable {
+    pub info: TableInfo,
+    /// How the fields of the table are mapped.
+    pub mappings: Vec<FieldMapping>,
+    /// How the records are keyed.
+    pub key: Option<KeyStrategy>,
+    /// Name of the field holding the event time of the records.
+    pub event_time: Option<String>,
+    pub port: PortHandle,
 }
 
 #[derive(Debug)]
@@ -51,6 +77,7 @@ pub struct ConnectorSourceFactory {
     tables: Vec<Table>,
     labels: DozerMonitorContext,
     shutdown: ShutdownReceiver,
+    quota: Option<Arc<QuotaEnforcer>>,
 }
 
 fn map_replication_type_to_output_port_type(_typ: &CdcType) -> OutputPortType {
@@ -59,34 +86,47 @@ fn map_replication_type_to_output_port_type(_typ: &CdcType) -> OutputPortType {
 
 impl ConnectorSourceFactory {
     pub async fn new(
-        mut table_and_ports: Vec<(TableInfo, PortHandle)>,
+        mut source_tables: Vec<SourceTable>,
         connection: Connection,
         runtime: Arc<Runtime>,
         labels: DozerMonitorContext,
         shutdown: ShutdownReceiver,
+        quota: Option<Arc<QuotaEnforcer>>,
     ) -> Result<Self, ConnectorSourceFactoryError> {
         let mut connector =
             get_connector(runtime.clone(), EventHub::new(1), connection.clone(), None)
                 .map_err(|e| ConnectorSourceFactoryError::Connector(e.into()))?;
 
+        if let Some(table) = source_tables
+            .iter()
+            .find(|table| table.info.filter.is_some())
+        {
+            if !connector.supports_filter() {
+                return Err(ConnectorSourceFactoryError::FilterNotSupported(
+                    connection.name.clone(),
+                    table.info.name.clone(),
+                ));
+            }
+        }
+
         // Fill column names if not provided.
-        let table_identifiers = table_and_ports
+        let table_identifiers = source_tables
             .iter()
-            .map(|(table, _)| TableIdentifier::new(table.schema.clone(), table.name.clone()))
+            .map(|table| TableIdentifier::new(table.info.schema.clone(), table.info.name.clone()))
             .collect();
         let all_columns = connector
             .list_columns(table_identifiers)
             .await
             .map_err(ConnectorSourceFactoryError::Connector)?;
-        for ((table, _), columns) in table_and_ports.iter_mut().zip(all_columns) {
-            if table.column_names.is_empty() {
-                table.column_names = columns.column_names;
+        for (table, columns) in source_tables.iter_mut().zip(all_columns) {
+            if table.info.column_names.is_empty() {
+                table.info.column_names = columns.column_names;
             }
         }
 
-        let tables: Vec<TableInfo> = table_and_ports
+        let tables: Vec<TableInfo> = source_tables
             .iter()
-            .map(|(table, _)| table.clone())
+            .map(|table| table.info.clone())
             .collect();
         let source_schemas = connector
             .get_schemas(&tables)
@@ -94,19 +134,49 @@ impl ConnectorSourceFactory {
             .map_err(ConnectorSourceFactoryError::Connector)?;
 
         let mut tables = vec![];
-        for ((table, port), source_schema) in table_and_ports.into_iter().zip(source_schemas) {
+        for (source_table, source_schema) in source_tables.into_iter().zip(source_schemas) {
+            let SourceTable {
+                info: table,
+                mappings,
+                key,
+                event_time,
+                port,
+            } = source_table;
             let name = table.name;
             let columns = table.column_names;
+            let filter = table.filter;
             let source_schema = source_schema.map_err(ConnectorSourceFactoryError::Connector)?;
-            let schema = source_schema.schema;
+            let mut schema = source_schema.schema;
             let cdc_type = source_schema.cdc_type;
+            let mapping = TableMapping::new(
+                &mut schema,
+                &mappings,
+                key.as_ref(),
+                cdc_type,
+                connector.sends_op_identifiers(),
+            )
+            .map_err(|e| ConnectorSourceFactoryError::Mapping(name.clone(), e))?;
+            let event_time = event_time
+                .map(|field| {
+                    schema
+                        .fields
+                        .iter()
+                        .position(|definition| definition.name == field)
+                        .ok_or_else(|| {
+                            ConnectorSourceFactoryError::EventTimeFieldNotFound(name.clone(), field)
+                        })
+                })
+                .transpose()?;
 
             let table = Table {
                 name,
                 schema_name: table.schema.clone(),
                 columns,
+                filter,
                 schema,
                 cdc_type,
+                mapping,
+                event_time,
                 port,
             };
 
@@ -119,6 +189,7 @@ impl ConnectorSourceFactory {
             tables,
             labels,
             shutdown,
+            quota,
         })
     }
 }
@@ -183,9 +254,20 @@ impl SourceFactory for ConnectorSourceFactory {
                 schema: table.schema_name.clone(),
                 name: table.name.clone(),
                 column_names: table.columns.clone(),
+                filter: table.filter.clone(),
             })
             .collect();
+        let mappings = self
+            .tables
+            .iter()
+            .map(|table| table.mapping.clone())
+            .collect();
         let ports = self.tables.iter().map(|table| table.port).collect();
+        let event_time_fields = self
+            .tables
+            .iter()
+            .filter_map(|table| table.event_time.map(|field| (table.port, field)))
+            .collect();
 
         let connector = get_connector(
             self.runtime.clone(),
@@ -196,12 +278,15 @@ impl SourceFactory for ConnectorSourceFactory {
 
         Ok(Box::new(ConnectorSource {
             tables,
+            mappings,
             ports,
+            event_time_fields,
             connector,
             connection_name: self.connection.name.clone(),
             labels: self.labels.clone(),
             shutdown: self.shutdown.clone(),
             ingestion_config: IngestionConfig::default(),
+            quota: self.quota.clone(),
         }))
     }
 }
@@ -209,12 +294,15 @@ impl SourceFactory for ConnectorSourceFactory {
 #[derive(Debug)]
 pub struct ConnectorSource {
     tables: Vec<TableInfo>,
+    mappings: Vec<TableMapping>,
     ports: Vec<PortHandle>,
+    event_time_fields: HashMap<PortHandle, usize>,
     connector: Box<dyn Connector>,
     connection_name: String,
     labels: DozerMonitorContext,
     shutdown: ShutdownReceiver,
     ingestion_config: IngestionConfig,
+    quota: Option<Arc<QuotaEnforcer>>,
 }
 
 #[async_trait]
@@ -231,15 +319,19 @@ impl Source for ConnectorSource {
         let (ingestor, iterator) = Ingestor::initialize_channel(self.ingestion_config.clone());
         let connection_name = self.connection_name.clone();
         let tables = self.tables.clone();
+        let mappings = self.mappings.clone();
         let ports = self.ports.clone();
         let labels = self.labels.clone();
+        let quota = self.quota.clone();
         let handle = tokio::spawn(forward_message_to_pipeline(
             iterator,
             sender,
             connection_name.clone(),
             tables,
+            mappings,
             ports,
             labels,
+            quota,
         ));
 
         let shutdown_future = self.shutdown.create_shutdown_future();
@@ -288,18 +380,23 @@ impl Source for ConnectorSource {
         drop(ingestor);
 
         // If we reach here, it means the connector has finished ingesting, so we wait for the forwarding task to finish.
-        if let Err(e) = handle.await {
-            emit_event(
-                &connection_name,
-                &ConnectorEntityType::Connector,
-                &labels,
-                "source_error",
-            );
-
-            std::panic::panic_any(e);
+        match handle.await {
+            Ok(result) => result,
+            Err(e) => {
+                emit_event(
+                    &connection_name,
+                    &ConnectorEntityType::Connector,
+                    &labels,
+                    "source_error",
+                );
+
+                std::panic::panic_any(e);
+            }
         }
+    }
 
-        Ok(())
+    fn event_time_fields(&self) -> HashMap<PortHandle, usize> {
+        self.event_time_fields.clone()
     }
 }
 
@@ -308,9 +405,11 @@ async fn forward_message_to_pipeline(
     sender: Sender<(PortHandle, IngestionMessage)>,
     connection_name: String,
     tables: Vec<TableInfo>,
+    mappings: Vec<TableMapping>,
     ports: Vec<PortHandle>,
     labels: DozerMonitorContext,
-) {
+    quota: Option<Arc<QuotaEnforcer>>,
+) -> Result<(), BoxedError> {
     let mut bars = vec![];
     for table in &tables {
         let pb = labels.create_progress_bar(table.name.clone());
@@ -325,7 +424,18 @@ async fn forward_message_to_pipeline(
         .init();
 
     let mut counter = vec![(0u64, 0u64); tables.len()];
-    while let Some(message) = iterator.receiver.recv().await {
+    while let Some(mut message) = iterator.receiver.recv().await {
+        if let IngestionMessage::OperationEvent {
+            table_index,
+            op,
+            id,
+        } = &mut message
+        {
+            mappings[*table_index].apply(op, *id).map_err(|e| {
+                ConnectorSourceFactoryError::Mapping(tables[*table_index].name.clone(), e)
+            })?;
+        }
+
         match &message {
             IngestionMessage::OperationEvent {
                 table_index, op, ..
@@ -361,6 +471,10 @@ async fn forward_message_to_pipeline(
 
                 source_counter.add(counter_number, &labels);
 
+                if let Some(quota) = &quota {
+                    quota.acquire(counter_number).await;
+                }
+
                 // Update counter
                 let counter = &mut counter[*table_index];
                 if let Operation::BatchInsert { new } = &op {
@@ -386,4 +500,5 @@ async fn forward_message_to_pipeline(
             }
         }
     }
+    Ok(())
 }
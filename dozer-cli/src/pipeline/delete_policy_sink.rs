@@ -0,0 +1,341 @@
+use std::collections::HashMap;
+
+use dozer_core::{
+    epoch::Epoch,
+    event::EventHub,
+    node::{PortHandle, Sink, SinkFactory},
+};
+use dozer_types::{
+    errors::internal::BoxedError,
+    models::sink::SinkDeletePolicy,
+    node::OpIdentifier,
+    types::{
+        Field, FieldDefinition, FieldType, Operation, Record, Schema, SourceDefinition,
+        TableOperation,
+    },
+};
+
+use crate::async_trait::async_trait;
+
+const DELETED_COLUMN_NAME: &str = "__deleted";
+
+/// Wraps a sink factory and applies a [`SinkDeletePolicy`] to the operations before they reach the sink.
+///
+/// When deleted rows stay in the sink, a later insert of the same primary key becomes an update of the row. The
+/// deleted rows are kept in memory for that, so after a restart the insert of a row deleted before it reaches the sink
+/// as an insert. Keyless tables have no key to match, so their inserts are always forwarded as inserts.
+#[derive(Debug)]
+pub struct DeletePolicySinkFactory {
+    inner: Box<dyn SinkFactory>,
+    policy: SinkDeletePolicy,
+}
+
+impl DeletePolicySinkFactory {
+    pub fn new(inner: Box<dyn SinkFactory>, policy: SinkDeletePolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    fn transform_schemas(
+        &self,
+        mut input_schemas: HashMap<PortHandle, Schema>,
+    ) -> Result<HashMap<PortHandle, Schema>, BoxedError> {
+        if self.policy == SinkDeletePolicy::MarkDeleted {
+            for schema in input_schemas.values_mut() {
+                if schema.get_field_index(DELETED_COLUMN_NAME).is_ok() {
+                    return Err(format!(
+                        "Column {DELETED_COLUMN_NAME} is reserved when deletes are marked"
+                    )
+                    .into());
+                }
+                schema.field(
+                    FieldDefinition::new(
+                        DELETED_COLUMN_NAME.to_string(),
+                        FieldType::Boolean,
+                        false,
+                        SourceDefinition::Dynamic,
+                    ),
+                    false,
+                );
+            }
+        }
+        Ok(input_schemas)
+    }
+}
+
+#[async_trait]
+impl SinkFactory for DeletePolicySinkFactory {
+    fn get_input_ports(&self) -> Vec<PortHandle> {
+        self.inner.get_input_ports()
+    }
+
+    fn get_input_port_name(&self, port: &PortHandle) -> String {
+        self.inner.get_input_port_name(port)
+    }
+
+    fn prepare(&self, input_schemas: HashMap<PortHandle, Schema>) -> Result<(), BoxedError> {
+        self.inner.prepare(self.transform_schemas(input_schemas)?)
+    }
+
+    async fn build(
+        &self,
+        input_schemas: HashMap<PortHandle, Schema>,
+        event_hub: EventHub,
+    ) -> Result<Box<dyn Sink>, BoxedError> {
+        let ports = input_schemas
+            .iter()
+            .map(|(port, schema)| (*port, DeletedRows::new(self.policy, schema.clone())))
+            .collect();
+        let inner = self
+            .inner
+            .build(self.transform_schemas(input_schemas)?, event_hub)
+            .await?;
+        Ok(Box::new(DeletePolicySink { inner, ports }))
+    }
+
+    fn type_name(&self) -> String {
+        self.inner.type_name()
+    }
+}
+
+#[derive(Debug)]
+struct DeletePolicySink {
+    inner: Box<dyn Sink>,
+    ports: HashMap<PortHandle, DeletedRows>,
+}
+
+fn mark(mut record: Record, deleted: bool) -> Record {
+    record.push_value(Field::Boolean(deleted));
+    record
+}
+
+/// Applies the delete policy to the operations of a port, remembering the rows that were deleted but are still in the sink.
+#[derive(Debug)]
+struct DeletedRows {
+    policy: SinkDeletePolicy,
+    schema: Schema,
+    /// The deleted rows, by primary key.
+    rows: HashMap<Vec<Field>, Record>,
+}
+
+impl DeletedRows {
+    fn new(policy: SinkDeletePolicy, schema: Schema) -> Self {
+        Self {
+            policy,
+            schema,
+            rows: HashMap::new(),
+        }
+    }
+
+    /// Whether deleted rows stay in the sink and can be matched by key.
+    fn tracks_rows(&self) -> bool {
+        self.policy != SinkDeletePolicy::Propagate && !self.schema.primary_index.is_empty()
+    }
+
+    fn delete(&mut self, old: Record) -> Option<Operation> {
+        if self.tracks_rows() {
+            self.rows
+                .insert(old.get_key_fields(&self.schema), old.clone());
+        }
+        match self.policy {
+            SinkDeletePolicy::Propagate => Some(Operation::Delete { old }),
+            SinkDeletePolicy::Ignore => None,
+            SinkDeletePolicy::MarkDeleted => Some(Operation::Update {
+                old: mark(old.clone(), false),
+                new: mark(old, true),
+            }),
+        }
+    }
+
+    /// Inserts `new`, or updates the deleted row with its key.
+    fn insert(&mut self, new: Record) -> Operation {
+        let deleted = if self.tracks_rows() {
+            self.rows.remove(&new.get_key_fields(&self.schema))
+        } else {
+            None
+        };
+        match (self.policy, deleted) {
+            (SinkDeletePolicy::MarkDeleted, Some(old)) => Operation::Update {
+                old: mark(old, true),
+                new: mark(new, false),
+            },
+            (SinkDeletePolicy::MarkDeleted, None) => Operation::Insert {
+                new: mark(new, false),
+            },
+            (_, Some(old)) => Operation::Update { old, new },
+            (_, None) => Operation::Insert { new },
+        }
+    }
+
+    fn apply(&mut self, op: Operation) -> Vec<Operation> {
+        if self.policy == SinkDeletePolicy::Propagate {
+            return vec![op];
+        }
+        match op {
+            Operation::Delete { old } => self.delete(old).into_iter().collect(),
+            Operation::Insert { new } => vec![self.insert(new)],
+            Operation::Update { old, new } => vec![match self.policy {
+                SinkDeletePolicy::MarkDeleted => Operation::Update {
+                    old: mark(old, false),
+                    new: mark(new, false),
+                },
+                _ => Operation::Update { old, new },
+            }],
+            Operation::BatchInsert { new } => {
+                // The inserts of deleted rows become updates, the others stay in the batch.
+                let mut updates = vec![];
+                let mut inserts = vec![];
+                for record in new {
+                    match self.insert(record) {
+                        Operation::Insert { new } => inserts.push(new),
+                        update => updates.push(update),
+                    }
+                }
+                if !inserts.is_empty() {
+                    updates.push(Operation::BatchInsert { new: inserts });
+                }
+                updates
+            }
+        }
+    }
+}
+
+impl Sink for DeletePolicySink {
+    fn commit(&mut self, epoch_details: &Epoch) -> Result<(), BoxedError> {
+        self.inner.commit(epoch_details)
+    }
+
+    fn process(&mut self, op: TableOperation) -> Result<(), BoxedError> {
+        let TableOperation { id, op, port } = op;
+        let Some(deleted_rows) = self.ports.get_mut(&port) else {
+            return self.inner.process(TableOperation { id, op, port });
+        };
+        for op in deleted_rows.apply(op) {
+            self.inner.process(TableOperation { id, op, port })?;
+        }
+        Ok(())
+    }
+
+    fn on_source_snapshotting_started(
+        &mut self,
+        connection_name: String,
+    ) -> Result<(), BoxedError> {
+        self.inner.on_source_snapshotting_started(connection_name)
+    }
+
+    fn on_source_snapshotting_done(
+        &mut self,
+        connection_name: String,
+        id: Option<OpIdentifier>,
+    ) -> Result<(), BoxedError> {
+        self.inner.on_source_snapshotting_done(connection_name, id)
+    }
+
+    fn set_source_state(&mut self, source_state: &[u8]) -> Result<(), BoxedError> {
+        self.inner.set_source_state(source_state)
+    }
+
+    fn get_source_state(&mut self) -> Result<Option<Vec<u8>>, BoxedError> {
+        self.inner.get_source_state()
+    }
+
+    fn get_latest_op_id(&mut self) -> Result<Option<OpIdentifier>, BoxedError> {
+        self.inner.get_latest_op_id()
+    }
+
+    fn preferred_batch_size(&self) -> Option<u64> {
+        self.inner.preferred_batch_size()
+    }
+
+    fn max_batch_duration_ms(&self) -> Option<u64> {
+        self.inner.max_batch_duration_ms()
+    }
+
+    fn flush_batch(&mut self) -> Result<(), BoxedError> {
+        self.inner.flush_batch()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(id: i64) -> Record {
+        Record::new(vec![Field::Int(id)])
+    }
+
+    fn deleted_rows(policy: SinkDeletePolicy) -> DeletedRows {
+        let mut schema = Schema::new();
+        schema.field(
+            FieldDefinition::new(
+                "id".to_string(),
+                FieldType::Int,
+                false,
+                SourceDefinition::Dynamic,
+            ),
+            true,
+        );
+        DeletedRows::new(policy, schema)
+    }
+
+    #[test]
+    fn ignore_drops_deletes() {
+        let mut rows = deleted_rows(SinkDeletePolicy::Ignore);
+        let op = Operation::Delete { old: record(1) };
+        assert_eq!(rows.apply(op), vec![]);
+
+        let op = Operation::Insert { new: record(2) };
+        assert_eq!(rows.apply(op.clone()), vec![op]);
+    }
+
+    #[test]
+    fn mark_deleted_turns_delete_into_update() {
+        let mut rows = deleted_rows(SinkDeletePolicy::MarkDeleted);
+        let op = Operation::Delete { old: record(1) };
+        assert_eq!(
+            rows.apply(op),
+            vec![Operation::Update {
+                old: Record::new(vec![Field::Int(1), Field::Boolean(false)]),
+                new: Record::new(vec![Field::Int(1), Field::Boolean(true)]),
+            }]
+        );
+
+        let op = Operation::Insert { new: record(2) };
+        assert_eq!(
+            rows.apply(op),
+            vec![Operation::Insert {
+                new: Record::new(vec![Field::Int(2), Field::Boolean(false)]),
+            }]
+        );
+    }
+
+    #[test]
+    fn reinsert_of_deleted_row_becomes_update() {
+        let mut rows = deleted_rows(SinkDeletePolicy::Ignore);
+        rows.apply(Operation::Delete { old: record(1) });
+        assert_eq!(
+            rows.apply(Operation::BatchInsert {
+                new: vec![record(1), record(2)]
+            }),
+            vec![
+                Operation::Update {
+                    old: record(1),
+                    new: record(1)
+                },
+                Operation::BatchInsert {
+                    new: vec![record(2)]
+                },
+            ]
+        );
+        assert!(rows.rows.is_empty());
+
+        let mut rows = deleted_rows(SinkDeletePolicy::MarkDeleted);
+        rows.apply(Operation::Delete { old: record(1) });
+        assert_eq!(
+            rows.apply(Operation::Insert { new: record(1) }),
+            vec![Operation::Update {
+                old: Record::new(vec![Field::Int(1), Field::Boolean(true)]),
+                new: Record::new(vec![Field::Int(1), Field::Boolean(false)]),
+            }]
+        );
+    }
+}
@@ -1,7 +1,12 @@
+mod batching_sink;
 mod builder;
 pub mod connector_source;
+mod delete_policy_sink;
 mod dummy_sink;
+pub mod quota;
 pub mod source_builder;
+mod source_mapping;
+mod validation_sink;
 
 pub use builder::PipelineBuilder;
 
@@ -0,0 +1,286 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use camino::{Utf8Path, Utf8PathBuf};
+use dozer_core::shutdown::ShutdownReceiver;
+use dozer_types::models::app_config::{AppQuotas, QuotaAction};
+use dozer_types::parking_lot::Mutex;
+use dozer_types::tracing::{error, warn};
+
+/// How often the state size is measured.
+pub const STATE_SIZE_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+const RATE_WINDOW: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+struct RateWindow {
+    start: Instant,
+    count: u64,
+    alerted: bool,
+}
+
+/// Enforces the [`AppQuotas`] of an app. Shared by all its sources.
+#[derive(Debug)]
+pub struct QuotaEnforcer {
+    app_name: String,
+    quotas: AppQuotas,
+    window: Mutex<RateWindow>,
+    state_size: AtomicU64,
+    state_alerted: Mutex<Option<Instant>>,
+    paused: AtomicBool,
+}
+
+impl QuotaEnforcer {
+    pub fn new(app_name: String, quotas: AppQuotas) -> Self {
+        Self {
+            app_name,
+            quotas,
+            window: Mutex::new(RateWindow {
+                start: Instant::now(),
+                count: 0,
+                alerted: false,
+            }),
+            state_size: AtomicU64::new(0),
+            state_alerted: Mutex::new(None),
+            paused: AtomicBool::new(false),
+        }
+    }
+
+    pub fn quotas(&self) -> &AppQuotas {
+        &self.quotas
+    }
+
+    pub fn set_state_size(&self, size: u64) {
+        self.state_size.store(size, Ordering::Relaxed);
+    }
+
+    /// Stops ingestion for good, because `quota` was exceeded.
+    fn pause(&self, quota: &str) {
+        if !self.paused.swap(true, Ordering::Relaxed) {
+            error!(
+                "App {} exceeded its {quota} quota, ingestion is paused until the app is restarted",
+                self.app_name
+            );
+        }
+    }
+
+    fn state_exceeded(&self) -> bool {
+        self.quotas
+            .max_state_size
+            .map_or(false, |max| self.state_size.load(Ordering::Relaxed) > max)
+    }
+
+    /// Records `count` operations ingested at `now`, returning how long ingestion must wait
+    /// for them to fit in the rate quota.
+    fn record_operations(&self, now: Instant, count: u64) -> Option<Duration> {
+        let max = self.quotas.max_ingestion_rate?;
+        let mut window = self.window.lock();
+        if now.duration_since(window.start) >= RATE_WINDOW {
+            *window = RateWindow {
+                start: now,
+                count: 0,
+                alerted: false,
+            };
+        }
+        window.count += count;
+        if window.count <= max {
+            return None;
+        }
+        match self.quotas.on_exceeded {
+            QuotaAction::Throttle => {
+                // The operations are charged to the next window, which starts when the wait is over.
+                let wait = RATE_WINDOW.saturating_sub(now.duration_since(window.start));
+                *window = RateWindow {
+                    start: now + wait,
+                    count,
+                    alerted: false,
+                };
+                Some(wait)
+            }
+            QuotaAction::Alert => {
+                if !window.alerted {
+                    window.alerted = true;
+                    warn!(
+                        "App {} exceeded its ingestion rate quota of {max} operations per second",
+                        self.app_name
+                    );
+                }
+                None
+            }
+            QuotaAction::Pause => {
+                self.pause("ingestion rate");
+                None
+            }
+        }
+    }
+
+    /// Waits until `count` more operations can be ingested without exceeding the quotas.
+    pub async fn acquire(&self, count: u64) {
+        if self.state_exceeded() {
+            match self.quotas.on_exceeded {
+                QuotaAction::Alert => {
+                    let mut alerted = self.state_alerted.lock();
+                    if alerted.map_or(true, |at| at.elapsed() >= STATE_SIZE_CHECK_INTERVAL) {
+                        *alerted = Some(Instant::now());
+                        warn!(
+                            "App {} exceeded its state size quota: {} bytes",
+                            self.app_name,
+                            self.state_size.load(Ordering::Relaxed)
+                        );
+                    }
+                }
+                // Nothing frees the state while ingestion waits, so throttling would never end.
+                QuotaAction::Throttle | QuotaAction::Pause => self.pause("state size"),
+            }
+        }
+
+        if let Some(wait) = self.record_operations(Instant::now(), count) {
+            tokio::time::sleep(wait).await;
+        }
+        if self.paused.load(Ordering::Relaxed) {
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+/// Measures the size of `home_dir` for the state size quota, until `shutdown`.
+pub async fn monitor_state_size(
+    home_dir: Utf8PathBuf,
+    quota: Arc<QuotaEnforcer>,
+    shutdown: ShutdownReceiver,
+) {
+    let running = shutdown.get_running_flag();
+    while running.load(Ordering::Relaxed) {
+        let path = home_dir.clone();
+        match tokio::task::spawn_blocking(move || directory_size(&path)).await {
+            Ok(Ok(size)) => quota.set_state_size(size),
+            Ok(Err(e)) => warn!("Failed to measure the state size of {home_dir}: {e}"),
+            Err(e) => warn!("Failed to measure the state size of {home_dir}: {e}"),
+        }
+        tokio::time::sleep(STATE_SIZE_CHECK_INTERVAL).await;
+    }
+}
+
+/// Total size of the files under `path`. Missing directories have size 0.
+pub fn directory_size(path: &Utf8Path) -> std::io::Result<u64> {
+    let mut size = 0;
+    let entries = match std::fs::read_dir(path) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e),
+    };
+    for entry in entries {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if metadata.is_dir() {
+            let path = Utf8PathBuf::try_from(entry.path()).map_err(|e| e.into_io_error())?;
+            size += directory_size(&path)?;
+        } else {
+            size += metadata.len();
+        }
+    }
+    Ok(size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enforcer(on_exceeded: QuotaAction) -> QuotaEnforcer {
+        QuotaEnforcer::new(
+            "app".to_string(),
+            AppQuotas {
+                max_ingestion_rate: Some(10),
+                max_state_size: Some(100),
+                on_exceeded,
+            },
+        )
+    }
+
+    #[test]
+    fn throttle_waits_for_next_window() {
+        let enforcer = enforcer(QuotaAction::Throttle);
+        let start = enforcer.window.lock().start;
+        assert_eq!(enforcer.record_operations(start, 10), None);
+        let wait = enforcer
+            .record_operations(start + Duration::from_millis(400), 1)
+            .unwrap();
+        assert_eq!(wait, Duration::from_millis(600));
+        // The throttled operation counts towards the next window.
+        assert_eq!(enforcer.record_operations(start + RATE_WINDOW, 9), None);
+        assert!(enforcer.record_operations(start + RATE_WINDOW, 1).is_some());
+    }
+
+    #[test]
+    fn alert_never_waits() {
+        let enforcer = enforcer(QuotaAction::Alert);
+        let start = enforcer.window.lock().start;
+        assert_eq!(enforcer.record_operations(start, 100), None);
+        assert!(enforcer.window.lock().alerted);
+        assert_eq!(enforcer.record_operations(start + RATE_WINDOW, 1), None);
+        assert!(!enforcer.window.lock().alerted);
+    }
+
+    #[test]
+    fn pause_stops_ingestion() {
+        let rate = enforcer(QuotaAction::Pause);
+        let start = rate.window.lock().start;
+        assert_eq!(rate.record_operations(start, 10), None);
+        assert!(!rate.paused.load(Ordering::Relaxed));
+        assert_eq!(rate.record_operations(start, 1), None);
+        assert!(rate.paused.load(Ordering::Relaxed));
+        // Ingestion stays paused in the next windows.
+        assert_eq!(rate.record_operations(start + RATE_WINDOW, 1), None);
+        assert!(rate.paused.load(Ordering::Relaxed));
+
+        let state = enforcer(QuotaAction::Pause);
+        state.set_state_size(101);
+        let acquire = state.acquire(1);
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        assert!(runtime
+            .block_on(tokio::time::timeout(Duration::from_millis(10), acquire))
+            .is_err());
+        assert!(state.paused.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn state_size_quota() {
+        let enforcer = enforcer(QuotaAction::Throttle);
+        assert!(!enforcer.state_exceeded());
+        enforcer.set_state_size(101);
+        assert!(enforcer.state_exceeded());
+    }
+
+    #[test]
+    fn throttle_resumes() {
+        let enforcer = enforcer(QuotaAction::Throttle);
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        let start = Instant::now();
+        runtime.block_on(async {
+            enforcer.acquire(10).await;
+            // Over the rate quota, so this waits for the next window and then goes on.
+            tokio::time::timeout(RATE_WINDOW * 2, enforcer.acquire(1))
+                .await
+                .unwrap();
+        });
+        assert!(start.elapsed() >= RATE_WINDOW / 2);
+        assert!(!enforcer.paused.load(Ordering::Relaxed));
+
+        // The state size can't go down while ingestion waits, so exceeding it pauses.
+        enforcer.set_state_size(101);
+        assert!(runtime
+            .block_on(tokio::time::timeout(
+                Duration::from_millis(10),
+                enforcer.acquire(1)
+            ))
+            .is_err());
+        assert!(enforcer.paused.load(Ordering::Relaxed));
+    }
+}
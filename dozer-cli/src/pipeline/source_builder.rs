@@ -1,4 +1,5 @@
-use crate::pipeline::connector_source::ConnectorSourceFactory;
+use crate::pipeline::connector_source::{ConnectorSourceFactory, SourceTable};
+use crate::pipeline::quota::QuotaEnforcer;
 use crate::OrchestrationError;
 use dozer_core::appsource::{AppSourceManager, AppSourceMappings};
 use dozer_core::shutdown::ShutdownReceiver;
@@ -14,6 +15,7 @@ use tokio::runtime::Runtime;
 pub struct SourceBuilder {
     grouped_connections: HashMap<Connection, Vec<Source>>,
     labels: DozerMonitorContext,
+    quota: Option<Arc<QuotaEnforcer>>,
 }
 
 const SOURCE_PORTS_RANGE_START: u16 = 1000;
@@ -26,9 +28,15 @@ impl SourceBuilder {
         Self {
             grouped_connections,
             labels,
+            quota: None,
         }
     }
 
+    pub fn with_quota(mut self, quota: Arc<QuotaEnforcer>) -> Self {
+        self.quota = Some(quota);
+        self
+    }
+
     pub fn get_ports(&self) -> HashMap<(&str, &str), u16> {
         let mut port: u16 = SOURCE_PORTS_RANGE_START;
 
@@ -53,28 +61,33 @@ impl SourceBuilder {
 
         for (connection, sources_group) in &self.grouped_connections {
             let mut ports = HashMap::new();
-            let mut table_and_ports = vec![];
+            let mut source_tables = vec![];
             for source in sources_group {
                 ports.insert(source.name.clone(), port);
 
-                table_and_ports.push((
-                    TableInfo {
+                source_tables.push(SourceTable {
+                    info: TableInfo {
                         schema: source.schema.clone(),
                         name: source.table_name.clone(),
                         column_names: source.columns.clone(),
+                        filter: source.filter.clone(),
                     },
+                    mappings: source.mappings.clone(),
+                    key: source.key.clone(),
+                    event_time: source.event_time.clone(),
                     port,
-                ));
+                });
 
                 port += 1;
             }
 
             let source_factory = ConnectorSourceFactory::new(
-                table_and_ports,
+                source_tables,
                 connection.clone(),
                 runtime.clone(),
                 self.labels.clone(),
                 shutdown.clone(),
+                self.quota.clone(),
             )
             .await?;
 
@@ -0,0 +1,641 @@
+use dozer_ingestion::CdcType;
+use dozer_types::{
+    chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc},
+    models::source::{FieldCoercion, FieldMapping, KeyStrategy},
+    node::OpIdentifier,
+    ordered_float::OrderedFloat,
+    rust_decimal::Decimal,
+    thiserror::{self, Error},
+    types::{Field, FieldDefinition, FieldType, Operation, Record, Schema, SourceDefinition},
+};
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum SourceMappingError {
+    #[error("Mapped field {0} not found in source schema")]
+    FieldNotFound(String),
+    #[error("Field {0} is mapped more than once")]
+    DuplicateMapping(String),
+    #[error("Field name {0} is used more than once after renaming")]
+    DuplicateFieldName(String),
+    #[error("Cannot apply {coercion:?} to field {field} of type {typ}")]
+    UnsupportedType {
+        field: String,
+        coercion: FieldCoercion,
+        typ: FieldType,
+    },
+    #[error("Cannot apply {coercion:?} to value {value} of field {field}")]
+    InvalidValue {
+        field: String,
+        coercion: FieldCoercion,
+        value: Field,
+    },
+    #[error("Table already has a primary key, it cannot be generated with {0:?}")]
+    HasPrimaryKey(KeyStrategy),
+    #[error("Key field {0} not found in schema")]
+    KeyFieldNotFound(String),
+    #[error("Updates and deletes cannot be matched to records keyed with {0:?}")]
+    UnkeyedOperation(KeyStrategy),
+    #[error("Connector doesn't identify every operation, so records cannot be keyed with their source offset")]
+    NoSourceOffsets,
+    #[error("Operation has no source offset to key it with")]
+    MissingOffset,
+}
+
+/// The mappings of a source table, resolved against its schema.
+#[derive(Debug, Clone, Default)]
+pub struct TableMapping {
+    coercions: Vec<(usize, String, FieldCoercion)>,
+    key: Option<KeyGenerator>,
+}
+
+/// A resolved [`KeyStrategy`] whose key is generated for every record.
+#[derive(Debug, Clone)]
+enum KeyGenerator {
+    Uuid,
+    SourceOffset,
+}
+
+impl TableMapping {
+    /// Renames, retypes and annotates the fields of `schema` according to `mappings`,
+    /// and sets its primary key according to `key`, adding the fields of the generated key if any.
+    ///
+    /// Generated keys can only be used for append-only tables, as updates and deletes can't be matched to them.
+    pub fn new(
+        schema: &mut Schema,
+        mappings: &[FieldMapping],
+        key: Option<&KeyStrategy>,
+        cdc_type: CdcType,
+        sends_op_identifiers: bool,
+    ) -> Result<Self, SourceMappingError> {
+        let mut mapped = vec![];
+        let mut coercions = vec![];
+        for mapping in mappings {
+            let index = schema
+                .fields
+                .iter()
+                .position(|field| field.name == mapping.field)
+                .ok_or_else(|| SourceMappingError::FieldNotFound(mapping.field.clone()))?;
+            if mapped.iter().any(|(mapped, _)| *mapped == index) {
+                return Err(SourceMappingError::DuplicateMapping(mapping.field.clone()));
+            }
+            mapped.push((index, mapping));
+            schema.fields[index]
+                .annotations
+                .merge(&mapping.annotations());
+
+            if let Some(coercion) = &mapping.coerce {
+                let field = &mut schema.fields[index];
+                field.typ = coerced_type(&field.name, field.typ, coercion)?;
+                coercions.push((index, field.name.clone(), coercion.clone()));
+            }
+        }
+
+        // Renames are applied after all mappings are resolved, so that fields can be swapped.
+        for (index, mapping) in &mapped {
+            if let Some(rename) = &mapping.rename {
+                schema.fields[*index].name = rename.clone();
+            }
+        }
+        let key = match key {
+            Some(key) => add_key(schema, key, cdc_type, sends_op_identifiers)?,
+            None => None,
+        };
+        for (index, field) in schema.fields.iter().enumerate() {
+            if schema.fields[..index]
+                .iter()
+                .any(|other| other.name == field.name)
+            {
+                return Err(SourceMappingError::DuplicateFieldName(field.name.clone()));
+            }
+        }
+
+        Ok(Self { coercions, key })
+    }
+
+    /// Maps the records of `op`, which has identifier `id` in the source.
+    pub fn apply(
+        &self,
+        op: &mut Operation,
+        id: Option<OpIdentifier>,
+    ) -> Result<(), SourceMappingError> {
+        if self.coercions.is_empty() && self.key.is_none() {
+            return Ok(());
+        }
+        match op {
+            Operation::Insert { new } => self.apply_record(new, id, 0),
+            Operation::Delete { old } => self.apply_old_record(old),
+            Operation::Update { old, new } => {
+                self.apply_old_record(old)?;
+                self.apply_record(new, id, 0)
+            }
+            Operation::BatchInsert { new } => new
+                .iter_mut()
+                .enumerate()
+                .try_for_each(|(index, record)| self.apply_record(record, id, index as u64)),
+        }
+    }
+
+    fn apply_record(
+        &self,
+        record: &mut Record,
+        id: Option<OpIdentifier>,
+        index: u64,
+    ) -> Result<(), SourceMappingError> {
+        self.coerce_record(record)?;
+        match &self.key {
+            None => (),
+            Some(KeyGenerator::Uuid) => {
+                record.push_value(Field::String(Uuid::now_v7().to_string()))
+            }
+            Some(KeyGenerator::SourceOffset) => {
+                let id = id.ok_or(SourceMappingError::MissingOffset)?;
+                record.push_value(Field::UInt(id.txid));
+                record.push_value(Field::UInt(id.seq_in_tx));
+                record.push_value(Field::UInt(index));
+            }
+        }
+        Ok(())
+    }
+
+    /// Old records can't be keyed with a generated key.
+    fn apply_old_record(&self, record: &mut Record) -> Result<(), SourceMappingError> {
+        self.coerce_record(record)?;
+        match &self.key {
+            None => Ok(()),
+            Some(KeyGenerator::Uuid) => {
+                Err(SourceMappingError::UnkeyedOperation(KeyStrategy::Uuid))
+            }
+            Some(KeyGenerator::SourceOffset) => Err(SourceMappingError::UnkeyedOperation(
+                KeyStrategy::SourceOffset,
+            )),
+        }
+    }
+
+    fn coerce_record(&self, record: &mut Record) -> Result<(), SourceMappingError> {
+        for (index, name, coercion) in &self.coercions {
+            let value = std::mem::replace(&mut record.values[*index], Field::Null);
+            record.values[*index] =
+                coerce(value, coercion).map_err(|value| SourceMappingError::InvalidValue {
+                    field: name.clone(),
+                    coercion: coercion.clone(),
+                    value,
+                })?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the generator of the key, if it's generated rather than made of existing fields.
+fn add_key(
+    schema: &mut Schema,
+    key: &KeyStrategy,
+    cdc_type: CdcType,
+    sends_op_identifiers: bool,
+) -> Result<Option<KeyGenerator>, SourceMappingError> {
+    if !schema.primary_index.is_empty() {
+        return Err(SourceMappingError::HasPrimaryKey(key.clone()));
+    }
+    if !matches!(key, KeyStrategy::Fields { .. }) && cdc_type != CdcType::Nothing {
+        return Err(SourceMappingError::UnkeyedOperation(key.clone()));
+    }
+    let (generator, fields) = match key {
+        KeyStrategy::Fields { fields } => {
+            schema.primary_index = fields
+                .iter()
+                .map(|name| {
+                    schema
+                        .fields
+                        .iter()
+                        .position(|field| field.name == *name)
+                        .ok_or_else(|| SourceMappingError::KeyFieldNotFound(name.clone()))
+                })
+                .collect::<Result<_, _>>()?;
+            return Ok(None);
+        }
+        KeyStrategy::Uuid => (KeyGenerator::Uuid, vec![("__key", FieldType::String)]),
+        KeyStrategy::SourceOffset if !sends_op_identifiers => {
+            return Err(SourceMappingError::NoSourceOffsets)
+        }
+        KeyStrategy::SourceOffset => (
+            KeyGenerator::SourceOffset,
+            vec![
+                ("__txid", FieldType::UInt),
+                ("__seq_in_tx", FieldType::UInt),
+                ("__index", FieldType::UInt),
+            ],
+        ),
+    };
+    for (name, typ) in fields {
+        schema.field(
+            FieldDefinition::new(name.to_string(), typ, false, SourceDefinition::Dynamic),
+            true,
+        );
+    }
+    Ok(Some(generator))
+}
+
+fn coerced_type(
+    field: &str,
+    typ: FieldType,
+    coercion: &FieldCoercion,
+) -> Result<FieldType, SourceMappingError> {
+    let (inputs, output): (&[FieldType], _) = match coercion {
+        FieldCoercion::EpochMillisToTimestamp | FieldCoercion::EpochSecondsToTimestamp => {
+            (&[FieldType::Int, FieldType::UInt], FieldType::Timestamp)
+        }
+        FieldCoercion::StringToInt => (&[FieldType::String, FieldType::Text], FieldType::Int),
+        FieldCoercion::StringToFloat => (&[FieldType::String, FieldType::Text], FieldType::Float),
+        FieldCoercion::StringToDecimal { .. } => {
+            (&[FieldType::String, FieldType::Text], FieldType::Decimal)
+        }
+        FieldCoercion::StringToTimestamp { .. } => {
+            (&[FieldType::String, FieldType::Text], FieldType::Timestamp)
+        }
+        FieldCoercion::StringToDate { .. } => {
+            (&[FieldType::String, FieldType::Text], FieldType::Date)
+        }
+    };
+    if inputs.contains(&typ) {
+        Ok(output)
+    } else {
+        Err(SourceMappingError::UnsupportedType {
+            field: field.to_string(),
+            coercion: coercion.clone(),
+            typ,
+        })
+    }
+}
+
+/// Converts a value, returning it unchanged as the error if it can't be converted.
+fn coerce(value: Field, coercion: &FieldCoercion) -> Result<Field, Field> {
+    let converted = match (&value, coercion) {
+        (Field::Null, _) => return Ok(Field::Null),
+        (Field::Int(_) | Field::UInt(_), FieldCoercion::EpochMillisToTimestamp) => {
+            epoch_millis(&value, 1)
+        }
+        (Field::Int(_) | Field::UInt(_), FieldCoercion::EpochSecondsToTimestamp) => {
+            epoch_millis(&value, 1000)
+        }
+        (Field::String(s) | Field::Text(s), coercion) => parse(s.trim(), coercion),
+        _ => None,
+    };
+    converted.ok_or(value)
+}
+
+fn epoch_millis(value: &Field, multiplier: i64) -> Option<Field> {
+    let value = match value {
+        Field::Int(value) => *value,
+        Field::UInt(value) => i64::try_from(*value).ok()?,
+        _ => return None,
+    };
+    let millis = value.checked_mul(multiplier)?;
+    Some(Field::Timestamp(
+        Utc.timestamp_millis_opt(millis).single()?.fixed_offset(),
+    ))
+}
+
+fn parse(s: &str, coercion: &FieldCoercion) -> Option<Field> {
+    match coercion {
+        FieldCoercion::StringToInt => s.parse().ok().map(Field::Int),
+        FieldCoercion::StringToFloat => s.parse().ok().map(|f| Field::Float(OrderedFloat(f))),
+        FieldCoercion::StringToDecimal {
+            thousands_separator,
+            decimal_separator,
+        } => {
+            let mut s = s.to_string();
+            if let Some(separator) = thousands_separator {
+                s = s.replace(separator.as_str(), "");
+            }
+            if let Some(separator) = decimal_separator {
+                s = s.replace(separator.as_str(), ".");
+            }
+            s.parse::<Decimal>().ok().map(Field::Decimal)
+        }
+        FieldCoercion::StringToTimestamp { format } => DateTime::parse_from_str(s, format)
+            .ok()
+            .or_else(|| {
+                NaiveDateTime::parse_from_str(s, format)
+                    .ok()
+                    .map(|timestamp| timestamp.and_utc().fixed_offset())
+            })
+            .map(Field::Timestamp),
+        FieldCoercion::StringToDate { format } => {
+            NaiveDate::parse_from_str(s, format).ok().map(Field::Date)
+        }
+        FieldCoercion::EpochMillisToTimestamp | FieldCoercion::EpochSecondsToTimestamp => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dozer_types::types::{FieldAnnotations, PiiCategory};
+
+    use super::*;
+
+    fn schema() -> Schema {
+        let mut schema = Schema::new();
+        for (name, typ) in [
+            ("id", FieldType::Int),
+            ("created_at", FieldType::Int),
+            ("price", FieldType::String),
+        ] {
+            schema.field(
+                FieldDefinition::new(name.to_string(), typ, true, SourceDefinition::Dynamic),
+                name == "id",
+            );
+        }
+        schema
+    }
+
+    fn mapping(field: &str, rename: Option<&str>, coerce: Option<FieldCoercion>) -> FieldMapping {
+        FieldMapping {
+            field: field.to_string(),
+            rename: rename.map(ToString::to_string),
+            coerce,
+            description: None,
+            pii: None,
+            unit: None,
+        }
+    }
+
+    fn new_mapping(
+        schema: &mut Schema,
+        mappings: &[FieldMapping],
+        key: Option<&KeyStrategy>,
+    ) -> Result<TableMapping, SourceMappingError> {
+        TableMapping::new(schema, mappings, key, CdcType::FullChanges, false)
+    }
+
+    #[test]
+    fn mapping_renames_and_retypes_schema() {
+        let mut schema = schema();
+        TableMapping::new(
+            &mut schema,
+            &[
+                mapping(
+                    "created_at",
+                    Some("created"),
+                    Some(FieldCoercion::EpochMillisToTimestamp),
+                ),
+                mapping(
+                    "price",
+                    None,
+                    Some(FieldCoercion::StringToDecimal {
+                        thousands_separator: Some(".".to_string()),
+                        decimal_separator: Some(",".to_string()),
+                    }),
+                ),
+            ],
+            None,
+            CdcType::FullChanges,
+            false,
+        )
+        .unwrap();
+        assert_eq!(schema.fields[1].name, "created");
+        assert_eq!(schema.fields[1].typ, FieldType::Timestamp);
+        assert_eq!(schema.fields[2].typ, FieldType::Decimal);
+        assert_eq!(schema.primary_index, vec![0]);
+    }
+
+    #[test]
+    fn mapping_annotates_schema() {
+        let mut schema = schema();
+        TableMapping::new(
+            &mut schema,
+            &[FieldMapping {
+                description: Some("Price in cents".to_string()),
+                pii: Some(PiiCategory::Sensitive),
+                unit: Some("cents".to_string()),
+                ..mapping("price", None, None)
+            }],
+            None,
+            CdcType::FullChanges,
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            schema.fields[2].annotations,
+            FieldAnnotations {
+                description: Some("Price in cents".to_string()),
+                pii: Some(PiiCategory::Sensitive),
+                unit: Some("cents".to_string()),
+            }
+        );
+        assert!(schema.fields[0].annotations.is_empty());
+    }
+
+    #[test]
+    fn invalid_mappings_are_rejected() {
+        assert!(matches!(
+            new_mapping(&mut schema(), &[mapping("missing", Some("a"), None)], None),
+            Err(SourceMappingError::FieldNotFound(_))
+        ));
+        assert!(matches!(
+            new_mapping(&mut schema(), &[mapping("price", Some("id"), None)], None),
+            Err(SourceMappingError::DuplicateFieldName(name)) if name == "id"
+        ));
+        assert!(matches!(
+            new_mapping(
+                &mut schema(),
+                &[mapping("id", None, Some(FieldCoercion::StringToInt))],
+                None
+            ),
+            Err(SourceMappingError::UnsupportedType { .. })
+        ));
+        // Swapping names is allowed.
+        assert!(new_mapping(
+            &mut schema(),
+            &[
+                mapping("id", Some("price"), None),
+                mapping("price", Some("id"), None)
+            ],
+            None
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn mapping_coerces_records() {
+        let mapping = TableMapping::new(
+            &mut schema(),
+            &[
+                mapping(
+                    "created_at",
+                    None,
+                    Some(FieldCoercion::EpochSecondsToTimestamp),
+                ),
+                mapping(
+                    "price",
+                    None,
+                    Some(FieldCoercion::StringToDecimal {
+                        thousands_separator: Some(",".to_string()),
+                        decimal_separator: None,
+                    }),
+                ),
+            ],
+            None,
+            CdcType::FullChanges,
+            false,
+        )
+        .unwrap();
+
+        let mut op = Operation::Insert {
+            new: Record::new(vec![
+                Field::Int(1),
+                Field::Int(86400),
+                Field::String("1,234.50".to_string()),
+            ]),
+        };
+        mapping.apply(&mut op, None).unwrap();
+        assert_eq!(
+            op,
+            Operation::Insert {
+                new: Record::new(vec![
+                    Field::Int(1),
+                    Field::Timestamp(Utc.timestamp_opt(86400, 0).unwrap().fixed_offset()),
+                    Field::Decimal(Decimal::new(123450, 2)),
+                ]),
+            }
+        );
+
+        let mut op = Operation::Delete {
+            old: Record::new(vec![Field::Int(1), Field::Null, Field::String("x".into())]),
+        };
+        assert!(matches!(
+            mapping.apply(&mut op, None),
+            Err(SourceMappingError::InvalidValue { field, .. }) if field == "price"
+        ));
+    }
+
+    #[test]
+    fn string_coercions() {
+        let timestamp = FieldCoercion::StringToTimestamp {
+            format: "%Y-%m-%d %H:%M:%S".to_string(),
+        };
+        assert_eq!(
+            coerce(Field::String("2023-01-02 03:04:05".into()), &timestamp),
+            Ok(Field::Timestamp(
+                Utc.with_ymd_and_hms(2023, 1, 2, 3, 4, 5)
+                    .unwrap()
+                    .fixed_offset()
+            ))
+        );
+        let date = FieldCoercion::StringToDate {
+            format: "%d/%m/%Y".to_string(),
+        };
+        assert_eq!(
+            coerce(Field::Text("02/01/2023".into()), &date),
+            Ok(Field::Date(NaiveDate::from_ymd_opt(2023, 1, 2).unwrap()))
+        );
+        assert_eq!(
+            coerce(Field::String(" 42 ".into()), &FieldCoercion::StringToInt),
+            Ok(Field::Int(42))
+        );
+        assert_eq!(
+            coerce(Field::String("abc".into()), &FieldCoercion::StringToFloat),
+            Err(Field::String("abc".into()))
+        );
+    }
+
+    fn keyless_schema() -> Schema {
+        let mut schema = schema();
+        schema.primary_index.clear();
+        schema
+    }
+
+    #[test]
+    fn fields_key_becomes_primary_key() {
+        let mut schema = keyless_schema();
+        let key = KeyStrategy::Fields {
+            fields: vec!["price".to_string(), "id".to_string()],
+        };
+        new_mapping(&mut schema, &[], Some(&key)).unwrap();
+        assert_eq!(schema.fields.len(), 3);
+        assert_eq!(schema.primary_index, vec![2, 0]);
+
+        let key = KeyStrategy::Fields {
+            fields: vec!["missing".to_string()],
+        };
+        assert!(matches!(
+            new_mapping(&mut keyless_schema(), &[], Some(&key)),
+            Err(SourceMappingError::KeyFieldNotFound(name)) if name == "missing"
+        ));
+    }
+
+    #[test]
+    fn generated_keys() {
+        let mut schema = keyless_schema();
+        let mapping = TableMapping::new(
+            &mut schema,
+            &[],
+            Some(&KeyStrategy::SourceOffset),
+            CdcType::Nothing,
+            true,
+        )
+        .unwrap();
+        assert_eq!(schema.primary_index, vec![3, 4, 5]);
+        let mut op = Operation::BatchInsert {
+            new: vec![Record::new(vec![Field::Null; 3]); 2],
+        };
+        mapping
+            .apply(&mut op, Some(OpIdentifier::new(7, 1)))
+            .unwrap();
+        let Operation::BatchInsert { new } = &op else {
+            unreachable!()
+        };
+        assert_eq!(
+            new[1].values[3..],
+            [Field::UInt(7), Field::UInt(1), Field::UInt(1)]
+        );
+        assert!(matches!(
+            mapping.apply(&mut op, None),
+            Err(SourceMappingError::MissingOffset)
+        ));
+
+        let mut schema = keyless_schema();
+        let mapping = TableMapping::new(
+            &mut schema,
+            &[],
+            Some(&KeyStrategy::Uuid),
+            CdcType::Nothing,
+            false,
+        )
+        .unwrap();
+        assert_eq!(schema.fields[3].name, "__key");
+        let mut op = Operation::Insert {
+            new: Record::new(vec![Field::Null; 3]),
+        };
+        mapping.apply(&mut op, None).unwrap();
+        let Operation::Insert { new } = op else {
+            unreachable!()
+        };
+        assert!(matches!(&new.values[3], Field::String(_)));
+    }
+
+    #[test]
+    fn generated_keys_are_rejected_for_tables_with_changes() {
+        assert!(matches!(
+            new_mapping(&mut schema(), &[], Some(&KeyStrategy::Uuid)),
+            Err(SourceMappingError::HasPrimaryKey(_))
+        ));
+        for cdc_type in [CdcType::FullChanges, CdcType::OnlyPK] {
+            for key in [KeyStrategy::Uuid, KeyStrategy::SourceOffset] {
+                assert!(matches!(
+                    TableMapping::new(&mut keyless_schema(), &[], Some(&key), cdc_type, true),
+                    Err(SourceMappingError::UnkeyedOperation(_))
+                ));
+            }
+        }
+        assert!(matches!(
+            TableMapping::new(
+                &mut keyless_schema(),
+                &[],
+                Some(&KeyStrategy::SourceOffset),
+                CdcType::Nothing,
+                false
+            ),
+            Err(SourceMappingError::NoSourceOffsets)
+        ));
+    }
+}
@@ -20,6 +20,7 @@ fn get_default_config() -> Config {
             schemas: ConfigSchemas::Inline(schema_str.to_string()),
         }),
         name: "grpc_conn".to_string(),
+        retry: None,
     };
 
     Config {
@@ -36,6 +37,10 @@ fn get_default_config() -> Config {
                 connection: grpc_conn.name.clone(),
                 schema: None,
                 refresh_config: Default::default(),
+                mappings: vec![],
+                key: None,
+                event_time: None,
+                filter: None,
             },
             Source {
                 name: "grpc_conn_customers".to_string(),
@@ -44,6 +49,10 @@ fn get_default_config() -> Config {
                 connection: grpc_conn.name,
                 schema: None,
                 refresh_config: Default::default(),
+                mappings: vec![],
+                key: None,
+                event_time: None,
+                filter: None,
             },
         ],
         ..Default::default()
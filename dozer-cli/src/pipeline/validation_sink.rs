@@ -0,0 +1,678 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+
+use dozer_core::{
+    epoch::Epoch,
+    event::EventHub,
+    node::{PortHandle, Sink, SinkFactory},
+};
+use dozer_tracing::{
+    constants::{DOZER_METER_NAME, ENDPOINT_LABEL, SINK_INVALID_RECORD_COUNTER_NAME, TABLE_LABEL},
+    opentelemetry_metrics::Counter,
+    DozerMonitorContext, KeyValue,
+};
+use dozer_types::{
+    errors::internal::BoxedError,
+    json_record::record_to_json,
+    models::sink::{InvalidRecordPolicy, ValidationCheck, ValidationRule},
+    node::OpIdentifier,
+    serde_json::{self, Value},
+    tracing::warn,
+    types::{Field, FieldType, Operation, Record, Schema, TableOperation},
+};
+use regex::Regex;
+
+use crate::async_trait::async_trait;
+
+const VIOLATIONS_FIELD_NAME: &str = "__violations";
+
+/// Most keys of rejected rows a port remembers. The pipeline fails beyond that, rather than letting the updates and
+/// deletes of the rows it forgot reach the sink.
+const MAX_REJECTED_KEYS: usize = 1_000_000;
+
+/// Wraps a sink factory and checks the records against [`ValidationRule`]s before they reach the sink.
+///
+/// Rows stay consistent in the sink: an update to an invalid row deletes the old row, and the updates and deletes of
+/// rows that were rejected are dropped, or become inserts if the row becomes valid.
+///
+/// The rows of a keyless table are identified by all their values, so whether one was rejected is found by checking
+/// it again. For tables with a primary key, the keys of the rejected rows are kept in memory, up to
+/// [`MAX_REJECTED_KEYS`]. They are not part of the checkpoint, so after a restart the updates and deletes of rows
+/// rejected before it reach the sink unchanged.
+#[derive(Debug)]
+pub struct ValidationSinkFactory {
+    inner: Box<dyn SinkFactory>,
+    id: String,
+    rules: Vec<ValidationRule>,
+    on_invalid: InvalidRecordPolicy,
+    labels: DozerMonitorContext,
+}
+
+impl ValidationSinkFactory {
+    pub fn new(
+        inner: Box<dyn SinkFactory>,
+        id: String,
+        rules: Vec<ValidationRule>,
+        on_invalid: InvalidRecordPolicy,
+        labels: DozerMonitorContext,
+    ) -> Self {
+        Self {
+            inner,
+            id,
+            rules,
+            on_invalid,
+            labels,
+        }
+    }
+}
+
+#[async_trait]
+impl SinkFactory for ValidationSinkFactory {
+    fn get_input_ports(&self) -> Vec<PortHandle> {
+        self.inner.get_input_ports()
+    }
+
+    fn get_input_port_name(&self, port: &PortHandle) -> String {
+        self.inner.get_input_port_name(port)
+    }
+
+    fn prepare(&self, input_schemas: HashMap<PortHandle, Schema>) -> Result<(), BoxedError> {
+        for schema in input_schemas.values() {
+            compile_rules(&self.rules, schema)?;
+        }
+        self.inner.prepare(input_schemas)
+    }
+
+    async fn build(
+        &self,
+        input_schemas: HashMap<PortHandle, Schema>,
+        event_hub: EventHub,
+    ) -> Result<Box<dyn Sink>, BoxedError> {
+        let mut ports = HashMap::new();
+        for (port, schema) in &input_schemas {
+            let mut labels = self.labels.attrs();
+            labels.push(KeyValue::new(ENDPOINT_LABEL, self.id.clone()));
+            labels.push(KeyValue::new(
+                TABLE_LABEL,
+                self.inner.get_input_port_name(port),
+            ));
+            ports.insert(
+                *port,
+                PortRules {
+                    rules: compile_rules(&self.rules, schema)?,
+                    schema: schema.clone(),
+                    labels,
+                    skipped: HashSet::new(),
+                },
+            );
+        }
+        let dead_letter = match &self.on_invalid {
+            InvalidRecordPolicy::DeadLetter { path } => Some(BufWriter::new(
+                OpenOptions::new().create(true).append(true).open(path)?,
+            )),
+            _ => None,
+        };
+        let counter = dozer_tracing::global::meter(DOZER_METER_NAME)
+            .u64_counter(SINK_INVALID_RECORD_COUNTER_NAME)
+            .with_description("Number of records rejected by sink validation rules")
+            .init();
+
+        let inner = self.inner.build(input_schemas, event_hub).await?;
+        Ok(Box::new(ValidationSink {
+            inner,
+            id: self.id.clone(),
+            ports,
+            on_invalid: self.on_invalid.clone(),
+            dead_letter,
+            counter,
+        }))
+    }
+
+    fn type_name(&self) -> String {
+        self.inner.type_name()
+    }
+}
+
+#[derive(Debug)]
+enum Check {
+    NotNull,
+    NonNegative,
+    Regex(Regex),
+    OneOf(HashSet<Field>),
+    MaxLength(usize),
+}
+
+#[derive(Debug)]
+struct CompiledRule {
+    index: usize,
+    field: String,
+    check: Check,
+}
+
+impl CompiledRule {
+    /// Returns the reason `record` violates the rule, if it does.
+    fn violation(&self, record: &Record) -> Option<String> {
+        let value = &record.values[self.index];
+        let valid = match (&self.check, value) {
+            (Check::NotNull, value) => value != &Field::Null,
+            (_, Field::Null) => true,
+            (Check::NonNegative, value) => match value {
+                Field::Int(i) => *i >= 0,
+                Field::I128(i) => *i >= 0,
+                Field::Float(f) => f.0 >= 0.0,
+                Field::Decimal(d) => !d.is_sign_negative() || d.is_zero(),
+                _ => true,
+            },
+            (Check::Regex(regex), Field::String(s) | Field::Text(s)) => regex.is_match(s),
+            (Check::OneOf(values), value) => values.contains(value),
+            (Check::MaxLength(length), Field::String(s) | Field::Text(s)) => {
+                s.chars().count() <= *length
+            }
+            _ => true,
+        };
+        if valid {
+            return None;
+        }
+        let reason = match &self.check {
+            Check::NotNull => "must not be null".to_string(),
+            Check::NonNegative => "must not be negative".to_string(),
+            Check::Regex(regex) => format!("must match {}", regex.as_str()),
+            Check::OneOf(_) => "is not one of the allowed values".to_string(),
+            Check::MaxLength(length) => format!("must be at most {length} characters"),
+        };
+        Some(format!("field {} {reason}", self.field))
+    }
+}
+
+fn compile_rules(
+    rules: &[ValidationRule],
+    schema: &Schema,
+) -> Result<Vec<CompiledRule>, BoxedError> {
+    rules
+        .iter()
+        .map(|rule| {
+            let (index, definition) = schema
+                .get_field_index(&rule.field)
+                .map_err(|_| format!("Validated field {} not found", rule.field))?;
+            let is_string = matches!(definition.typ, FieldType::String | FieldType::Text);
+            let check = match &rule.check {
+                ValidationCheck::NotNull => Check::NotNull,
+                ValidationCheck::NonNegative => {
+                    if !matches!(
+                        definition.typ,
+                        FieldType::UInt
+                            | FieldType::U128
+                            | FieldType::Int
+                            | FieldType::I128
+                            | FieldType::Float
+                            | FieldType::Decimal
+                    ) {
+                        return Err(format!("Field {} is not a number", rule.field).into());
+                    }
+                    Check::NonNegative
+                }
+                ValidationCheck::Regex { pattern } => {
+                    if !is_string {
+                        return Err(format!("Field {} is not a string", rule.field).into());
+                    }
+                    Check::Regex(Regex::new(pattern)?)
+                }
+                ValidationCheck::OneOf { values } => Check::OneOf(
+                    values
+                        .iter()
+                        .map(|value| {
+                            Field::from_str(value, definition.typ, false).map_err(|e| {
+                                format!("Allowed value {value} of field {}: {e}", rule.field)
+                            })
+                        })
+                        .collect::<Result<_, _>>()?,
+                ),
+                ValidationCheck::MaxLength { length } => {
+                    if !is_string {
+                        return Err(format!("Field {} is not a string", rule.field).into());
+                    }
+                    Check::MaxLength(*length)
+                }
+            };
+            Ok(CompiledRule {
+                index,
+                field: rule.field.clone(),
+                check,
+            })
+        })
+        .collect()
+}
+
+fn violations(rules: &[CompiledRule], record: &Record) -> Vec<String> {
+    rules
+        .iter()
+        .filter_map(|rule| rule.violation(record))
+        .collect()
+}
+
+/// A record rejected by the rules, with its violations.
+type Rejected = (Record, Vec<String>);
+
+#[derive(Debug)]
+struct PortRules {
+    rules: Vec<CompiledRule>,
+    schema: Schema,
+    labels: Vec<KeyValue>,
+    /// Keys of the rejected rows that are not in the sink. Always empty for keyless tables.
+    skipped: HashSet<Vec<Field>>,
+}
+
+impl PortRules {
+    fn is_keyless(&self) -> bool {
+        self.schema.primary_index.is_empty()
+    }
+
+    /// Returns `record` if it's valid, otherwise adds it to `rejected`.
+    fn accept(&mut self, record: Record, rejected: &mut Vec<Rejected>) -> Option<Record> {
+        let found = violations(&self.rules, &record);
+        let key = (!self.is_keyless() && (!found.is_empty() || !self.skipped.is_empty()))
+            .then(|| record.get_key_fields(&self.schema));
+        if found.is_empty() {
+            if let Some(key) = key {
+                self.skipped.remove(&key);
+            }
+            return Some(record);
+        }
+        self.skipped.extend(key);
+        rejected.push((record, found));
+        None
+    }
+
+    /// Returns whether the row of `record` was rejected, and forgets it.
+    fn forget_skipped(&mut self, record: &Record) -> bool {
+        if self.is_keyless() {
+            return !violations(&self.rules, record).is_empty();
+        }
+        if self.skipped.is_empty() {
+            return false;
+        }
+        let key = record.get_key_fields(&self.schema);
+        self.skipped.remove(&key)
+    }
+
+    /// Adds the invalid records of `op` to `rejected`, returning what's left of it.
+    fn validate(&mut self, op: Operation, rejected: &mut Vec<Rejected>) -> Option<Operation> {
+        match op {
+            Operation::Insert { new } => self
+                .accept(new, rejected)
+                .map(|new| Operation::Insert { new }),
+            Operation::Update { old, new } => {
+                let old_skipped = self.forget_skipped(&old);
+                match (old_skipped, self.accept(new, rejected)) {
+                    (false, Some(new)) => Some(Operation::Update { old, new }),
+                    (true, Some(new)) => Some(Operation::Insert { new }),
+                    (false, None) => Some(Operation::Delete { old }),
+                    (true, None) => None,
+                }
+            }
+            Operation::Delete { old } => {
+                (!self.forget_skipped(&old)).then_some(Operation::Delete { old })
+            }
+            Operation::BatchInsert { new } => {
+                let new = new
+                    .into_iter()
+                    .filter_map(|record| self.accept(record, rejected))
+                    .collect::<Vec<_>>();
+                (!new.is_empty()).then_some(Operation::BatchInsert { new })
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ValidationSink {
+    inner: Box<dyn Sink>,
+    id: String,
+    ports: HashMap<PortHandle, PortRules>,
+    on_invalid: InvalidRecordPolicy,
+    dead_letter: Option<BufWriter<File>>,
+    counter: Counter<u64>,
+}
+
+impl ValidationSink {
+    fn reject(
+        &mut self,
+        port: PortHandle,
+        record: &Record,
+        violations: Vec<String>,
+    ) -> Result<(), BoxedError> {
+        let port_rules = &self.ports[&port];
+        self.counter.add(1, &port_rules.labels);
+        match &self.on_invalid {
+            InvalidRecordPolicy::Skip => {
+                warn!(
+                    "Sink {} skipped an invalid record: {}",
+                    self.id,
+                    violations.join("; ")
+                );
+                Ok(())
+            }
+            InvalidRecordPolicy::Fail => Err(format!(
+                "Sink {} received an invalid record: {}",
+                self.id,
+                violations.join("; ")
+            )
+            .into()),
+            InvalidRecordPolicy::DeadLetter { .. } => {
+                let mut object = record_to_json(record, &port_rules.schema);
+                object.insert(
+                    VIOLATIONS_FIELD_NAME.to_string(),
+                    Value::Array(violations.into_iter().map(Value::String).collect()),
+                );
+                let writer = self
+                    .dead_letter
+                    .as_mut()
+                    .expect("dead letter file is opened for the dead letter policy");
+                serde_json::to_writer(&mut *writer, &object)?;
+                writer.write_all(b"\n")?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Rejects the invalid records of `op`, returning what's left of it.
+    fn validate(
+        &mut self,
+        port: PortHandle,
+        op: Operation,
+    ) -> Result<Option<Operation>, BoxedError> {
+        let Some(port_rules) = self.ports.get_mut(&port) else {
+            return Ok(Some(op));
+        };
+        let mut rejected = vec![];
+        let op = port_rules.validate(op, &mut rejected);
+        if port_rules.skipped.len() > MAX_REJECTED_KEYS {
+            return Err(format!(
+                "Sink {} rejected more than {MAX_REJECTED_KEYS} rows that are still in the source",
+                self.id
+            )
+            .into());
+        }
+        for (record, found) in rejected {
+            self.reject(port, &record, found)?;
+        }
+        Ok(op)
+    }
+}
+
+impl Sink for ValidationSink {
+    fn commit(&mut self, epoch_details: &Epoch) -> Result<(), BoxedError> {
+        if let Some(writer) = &mut self.dead_letter {
+            writer.flush()?;
+        }
+        self.inner.commit(epoch_details)
+    }
+
+    fn process(&mut self, op: TableOperation) -> Result<(), BoxedError> {
+        let TableOperation { id, op, port } = op;
+        match self.validate(port, op)? {
+            Some(op) => self.inner.process(TableOperation { id, op, port }),
+            None => Ok(()),
+        }
+    }
+
+    fn on_source_snapshotting_started(
+        &mut self,
+        connection_name: String,
+    ) -> Result<(), BoxedError> {
+        self.inner.on_source_snapshotting_started(connection_name)
+    }
+
+    fn on_source_snapshotting_done(
+        &mut self,
+        connection_name: String,
+        id: Option<OpIdentifier>,
+    ) -> Result<(), BoxedError> {
+        self.inner.on_source_snapshotting_done(connection_name, id)
+    }
+
+    fn set_source_state(&mut self, source_state: &[u8]) -> Result<(), BoxedError> {
+        self.inner.set_source_state(source_state)
+    }
+
+    fn get_source_state(&mut self) -> Result<Option<Vec<u8>>, BoxedError> {
+        self.inner.get_source_state()
+    }
+
+    fn get_latest_op_id(&mut self) -> Result<Option<OpIdentifier>, BoxedError> {
+        self.inner.get_latest_op_id()
+    }
+
+    fn preferred_batch_size(&self) -> Option<u64> {
+        self.inner.preferred_batch_size()
+    }
+
+    fn max_batch_duration_ms(&self) -> Option<u64> {
+        self.inner.max_batch_duration_ms()
+    }
+
+    fn flush_batch(&mut self) -> Result<(), BoxedError> {
+        self.inner.flush_batch()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dozer_types::types::{FieldDefinition, SourceDefinition};
+
+    use super::*;
+
+    fn schema() -> Schema {
+        let mut schema = Schema::new();
+        for (name, typ) in [("amount", FieldType::Int), ("email", FieldType::String)] {
+            schema.field(
+                FieldDefinition::new(name.to_string(), typ, true, SourceDefinition::Dynamic),
+                false,
+            );
+        }
+        schema
+    }
+
+    fn rule(field: &str, check: ValidationCheck) -> ValidationRule {
+        ValidationRule {
+            field: field.to_string(),
+            check,
+        }
+    }
+
+    fn record(amount: Field, email: Field) -> Record {
+        Record::new(vec![amount, email])
+    }
+
+    #[test]
+    fn rules_report_violations() {
+        let rules = compile_rules(
+            &[
+                rule("amount", ValidationCheck::NonNegative),
+                rule(
+                    "email",
+                    ValidationCheck::Regex {
+                        pattern: "^[^@]+@[^@]+$".to_string(),
+                    },
+                ),
+                rule("email", ValidationCheck::MaxLength { length: 8 }),
+            ],
+            &schema(),
+        )
+        .unwrap();
+
+        let valid = record(Field::Int(0), Field::String("a@b.com".to_string()));
+        assert!(violations(&rules, &valid).is_empty());
+        // Checks other than `NotNull` accept nulls.
+        assert!(violations(&rules, &record(Field::Null, Field::Null)).is_empty());
+
+        let invalid = record(Field::Int(-1), Field::String("not an email".to_string()));
+        assert_eq!(
+            violations(&rules, &invalid),
+            vec![
+                "field amount must not be negative".to_string(),
+                "field email must match ^[^@]+@[^@]+$".to_string(),
+                "field email must be at most 8 characters".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn not_null_and_one_of() {
+        let rules = compile_rules(
+            &[
+                rule("amount", ValidationCheck::NotNull),
+                rule(
+                    "amount",
+                    ValidationCheck::OneOf {
+                        values: vec!["1".to_string(), "2".to_string()],
+                    },
+                ),
+            ],
+            &schema(),
+        )
+        .unwrap();
+        assert!(violations(&rules, &record(Field::Int(2), Field::Null)).is_empty());
+        assert_eq!(
+            violations(&rules, &record(Field::Null, Field::Null)),
+            vec!["field amount must not be null".to_string()]
+        );
+        assert_eq!(
+            violations(&rules, &record(Field::Int(3), Field::Null)).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn one_of_compares_typed_values() {
+        let rules = compile_rules(
+            &[rule(
+                "amount",
+                ValidationCheck::OneOf {
+                    values: vec!["01".to_string()],
+                },
+            )],
+            &schema(),
+        )
+        .unwrap();
+        assert!(violations(&rules, &record(Field::Int(1), Field::Null)).is_empty());
+        assert!(compile_rules(
+            &[rule(
+                "amount",
+                ValidationCheck::OneOf {
+                    values: vec!["one".to_string()],
+                },
+            )],
+            &schema()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn rejected_rows_stay_out_of_the_sink() {
+        let mut schema = schema();
+        schema.primary_index = vec![1];
+        let mut port_rules = PortRules {
+            rules: compile_rules(&[rule("amount", ValidationCheck::NonNegative)], &schema).unwrap(),
+            schema,
+            labels: vec![],
+            skipped: HashSet::new(),
+        };
+        let row = |amount| record(Field::Int(amount), Field::String("a".to_string()));
+        let mut validate = |op| {
+            let mut rejected = vec![];
+            let op = port_rules.validate(op, &mut rejected);
+            (op, rejected.len())
+        };
+
+        assert_eq!(
+            validate(Operation::Insert { new: row(1) }),
+            (Some(Operation::Insert { new: row(1) }), 0)
+        );
+        // The row becomes invalid, so it's deleted from the sink.
+        assert_eq!(
+            validate(Operation::Update {
+                old: row(1),
+                new: row(-1)
+            }),
+            (Some(Operation::Delete { old: row(1) }), 1)
+        );
+        assert_eq!(
+            validate(Operation::Update {
+                old: row(-1),
+                new: row(-2)
+            }),
+            (None, 1)
+        );
+        // The row becomes valid again, so it's inserted.
+        assert_eq!(
+            validate(Operation::Update {
+                old: row(-2),
+                new: row(2)
+            }),
+            (Some(Operation::Insert { new: row(2) }), 0)
+        );
+        assert_eq!(
+            validate(Operation::Delete { old: row(2) }),
+            (Some(Operation::Delete { old: row(2) }), 0)
+        );
+
+        assert_eq!(validate(Operation::Insert { new: row(-3) }), (None, 1));
+        assert_eq!(validate(Operation::Delete { old: row(-3) }), (None, 0));
+    }
+
+    #[test]
+    fn keyless_rows_are_checked_again() {
+        let schema = schema();
+        let mut port_rules = PortRules {
+            rules: compile_rules(&[rule("amount", ValidationCheck::NonNegative)], &schema).unwrap(),
+            schema,
+            labels: vec![],
+            skipped: HashSet::new(),
+        };
+        let row = |amount| record(Field::Int(amount), Field::Null);
+        let mut rejected = vec![];
+
+        assert_eq!(
+            port_rules.validate(Operation::Insert { new: row(-1) }, &mut rejected),
+            None
+        );
+        assert_eq!(
+            port_rules.validate(
+                Operation::Update {
+                    old: row(-1),
+                    new: row(1)
+                },
+                &mut rejected
+            ),
+            Some(Operation::Insert { new: row(1) })
+        );
+        assert_eq!(
+            port_rules.validate(Operation::Delete { old: row(-2) }, &mut rejected),
+            None
+        );
+        assert!(port_rules.skipped.is_empty());
+    }
+
+    #[test]
+    fn rules_are_checked_against_schema() {
+        assert!(compile_rules(&[rule("missing", ValidationCheck::NotNull)], &schema()).is_err());
+        assert!(compile_rules(&[rule("email", ValidationCheck::NonNegative)], &schema()).is_err());
+        assert!(compile_rules(
+            &[rule("amount", ValidationCheck::MaxLength { length: 1 })],
+            &schema()
+        )
+        .is_err());
+        assert!(compile_rules(
+            &[rule(
+                "email",
+                ValidationCheck::Regex {
+                    pattern: "(".to_string()
+                }
+            )],
+            &schema()
+        )
+        .is_err());
+    }
+}
@@ -9,6 +9,7 @@ use std::sync::Arc;
 use dozer_types::models::source::Source;
 use dozer_types::models::udf_config::UdfConfig;
 
+use crate::pipeline::quota::QuotaEnforcer;
 use crate::pipeline::PipelineBuilder;
 use dozer_core::executor::{DagExecutor, ExecutorOptions};
 
@@ -23,6 +24,7 @@ pub struct Executor<'a> {
     sinks: &'a [Sink],
     labels: DozerMonitorContext,
     udfs: &'a [UdfConfig],
+    quota: Option<Arc<QuotaEnforcer>>,
 }
 
 impl<'a> Executor<'a> {
@@ -36,6 +38,7 @@ impl<'a> Executor<'a> {
         sinks: &'a [Sink],
         labels: DozerMonitorContext,
         udfs: &'a [UdfConfig],
+        quota: Option<Arc<QuotaEnforcer>>,
     ) -> Result<Executor<'a>, OrchestrationError> {
         Ok(Executor {
             connections,
@@ -44,6 +47,7 @@ impl<'a> Executor<'a> {
             sinks,
             labels,
             udfs,
+            quota,
         })
     }
 
@@ -54,7 +58,7 @@ impl<'a> Executor<'a> {
         shutdown: ShutdownReceiver,
         flags: Flags,
     ) -> Result<DagExecutor, OrchestrationError> {
-        let builder = PipelineBuilder::new(
+        let mut builder = PipelineBuilder::new(
             self.connections,
             self.sources,
             self.sql,
@@ -63,6 +67,9 @@ impl<'a> Executor<'a> {
             flags,
             self.udfs,
         );
+        if let Some(quota) = self.quota {
+            builder = builder.with_quota(quota);
+        }
 
         let dag = builder.build(runtime, shutdown).await?;
         let exec = DagExecutor::new(dag, executor_options).await?;
@@ -1,5 +1,7 @@
 mod executor;
+pub mod multi_app;
 pub mod orchestrator;
+pub use multi_app::MultiAppOrchestrator;
 pub use orchestrator::SimpleOrchestrator;
 mod build;
 pub use build::{Contract, PipelineContract};
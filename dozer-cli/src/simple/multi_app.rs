@@ -0,0 +1,237 @@
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::sync::Arc;
+
+use dozer_core::shutdown::{self, ShutdownReceiver, ShutdownSender};
+use dozer_types::log::info;
+use dozer_types::tracing::error;
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+use tokio::runtime::Runtime;
+use tokio::task::JoinHandle;
+
+use super::SimpleOrchestrator;
+use crate::errors::OrchestrationError;
+use crate::flatten_join_handle;
+
+struct RunningApp {
+    shutdown: ShutdownSender,
+    handle: JoinHandle<Result<(), OrchestrationError>>,
+}
+
+/// Runs several independent apps in one process.
+///
+/// Every app keeps its own base directory, so its own home directory and lock file, and is labelled with its own app id in metrics.
+/// Apps are started and stopped independently, and an app failing doesn't stop the others.
+pub struct MultiAppOrchestrator {
+    runtime: Arc<Runtime>,
+    apps: BTreeMap<String, SimpleOrchestrator>,
+    running: HashMap<String, RunningApp>,
+}
+
+impl MultiAppOrchestrator {
+    pub fn new(runtime: Arc<Runtime>) -> Self {
+        Self {
+            runtime,
+            apps: BTreeMap::new(),
+            running: HashMap::new(),
+        }
+    }
+
+    pub fn add_app(&mut self, app: SimpleOrchestrator) -> Result<(), OrchestrationError> {
+        let name = app.config.app_name.clone();
+        if self.apps.contains_key(&name) {
+            return Err(OrchestrationError::DuplicateApp(name));
+        }
+        self.apps.insert(name, app);
+        Ok(())
+    }
+
+    pub fn app_names(&self) -> impl Iterator<Item = &str> {
+        self.apps.keys().map(String::as_str)
+    }
+
+    pub fn is_running(&self, name: &str) -> bool {
+        self.running
+            .get(name)
+            .map_or(false, |app| !app.handle.is_finished())
+    }
+
+    /// Builds and runs an app in the background. Does nothing if it's already running.
+    pub fn start(&mut self, name: &str, locked: bool) -> Result<(), OrchestrationError> {
+        let app = self
+            .apps
+            .get(name)
+            .ok_or_else(|| OrchestrationError::AppNotFound(name.to_string()))?
+            .clone();
+        if self.is_running(name) {
+            return Ok(());
+        }
+
+        info!("Starting app {name}");
+        // `SimpleOrchestrator::run_all` returns once the pipeline is ready, so the task must run the pipeline itself to
+        // finish only when the app stops.
+        self.spawn(name, move |shutdown| async move {
+            app.build(false, shutdown.clone(), locked).await?;
+            app.run_apps(shutdown, None).await
+        });
+        Ok(())
+    }
+
+    /// Runs `run` in the background as the app `name`, until it returns or the app is stopped.
+    fn spawn<F, Fut>(&mut self, name: &str, run: F)
+    where
+        F: FnOnce(ShutdownReceiver) -> Fut,
+        Fut: Future<Output = Result<(), OrchestrationError>> + Send + 'static,
+    {
+        let (shutdown_sender, shutdown_receiver) = shutdown::new(&self.runtime);
+        let handle = self.runtime.spawn(run(shutdown_receiver));
+        self.running.insert(
+            name.to_string(),
+            RunningApp {
+                shutdown: shutdown_sender,
+                handle,
+            },
+        );
+    }
+
+    /// Stops an app and waits for it to finish, returning the error it failed with, if any.
+    pub async fn stop(&mut self, name: &str) -> Result<(), OrchestrationError> {
+        let Some(app) = self.running.remove(name) else {
+            return Ok(());
+        };
+        info!("Stopping app {name}");
+        app.shutdown.shutdown();
+        flatten_join_handle(app.handle).await
+    }
+
+    /// Starts all the apps and waits until they all finish, or until `shutdown`, which stops them all.
+    pub async fn run_all(
+        mut self,
+        shutdown: ShutdownReceiver,
+        locked: bool,
+    ) -> Result<(), OrchestrationError> {
+        let names = self.apps.keys().cloned().collect::<Vec<_>>();
+        for name in &names {
+            self.start(name, locked)?;
+        }
+
+        let mut shutdown_senders = HashMap::new();
+        let mut apps = FuturesUnordered::new();
+        for (name, app) in self.running.drain() {
+            shutdown_senders.insert(name.clone(), app.shutdown);
+            apps.push(async move {
+                let result = flatten_join_handle(app.handle).await;
+                (name, result)
+            });
+        }
+
+        let shutdown_future = shutdown.create_shutdown_future();
+        tokio::pin!(shutdown_future);
+        let mut shutting_down = false;
+        let mut failed = vec![];
+        loop {
+            tokio::select! {
+                _ = &mut shutdown_future, if !shutting_down => {
+                    shutting_down = true;
+                    // Dropping the senders shuts the apps down.
+                    shutdown_senders.clear();
+                }
+                finished = apps.next() => {
+                    let Some((name, result)) = finished else {
+                        break;
+                    };
+                    // The app has stopped, so its sender is no longer needed. The others keep theirs until shutdown.
+                    shutdown_senders.remove(&name);
+                    if let Err(e) = result {
+                        error!("App {name} failed: {e}");
+                        failed.push(name);
+                    }
+                }
+            }
+        }
+
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            failed.sort();
+            Err(OrchestrationError::AppsFailed(failed.join(", ")))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use camino::Utf8PathBuf;
+    use dozer_types::models::config::Config;
+
+    use super::*;
+
+    fn app(runtime: &Arc<Runtime>, name: &str) -> SimpleOrchestrator {
+        SimpleOrchestrator::new(
+            Utf8PathBuf::from(name),
+            Config {
+                app_name: name.to_string(),
+                ..Default::default()
+            },
+            runtime.clone(),
+            Default::default(),
+        )
+    }
+
+    #[test]
+    fn app_names_must_be_unique() {
+        let runtime = Arc::new(Runtime::new().unwrap());
+        let mut orchestrator = MultiAppOrchestrator::new(runtime.clone());
+        orchestrator.add_app(app(&runtime, "a")).unwrap();
+        orchestrator.add_app(app(&runtime, "b")).unwrap();
+        assert!(matches!(
+            orchestrator.add_app(app(&runtime, "a")),
+            Err(OrchestrationError::DuplicateApp(name)) if name == "a"
+        ));
+        assert_eq!(orchestrator.app_names().collect::<Vec<_>>(), vec!["a", "b"]);
+        assert!(matches!(
+            orchestrator.start("c", false),
+            Err(OrchestrationError::AppNotFound(name)) if name == "c"
+        ));
+        assert!(!orchestrator.is_running("a"));
+    }
+
+    fn run_until_shutdown(
+        shutdown: ShutdownReceiver,
+    ) -> impl Future<Output = Result<(), OrchestrationError>> {
+        let shutdown = shutdown.create_shutdown_future();
+        async move {
+            shutdown.await;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn apps_keep_running_until_shutdown() {
+        let runtime = Arc::new(Runtime::new().unwrap());
+        let mut orchestrator = MultiAppOrchestrator::new(runtime.clone());
+        orchestrator.spawn("a", run_until_shutdown);
+        orchestrator.spawn("b", run_until_shutdown);
+
+        let (shutdown_sender, shutdown_receiver) = shutdown::new(&runtime);
+        let handle = runtime.spawn(orchestrator.run_all(shutdown_receiver, false));
+        runtime.block_on(tokio::time::sleep(std::time::Duration::from_millis(100)));
+        assert!(!handle.is_finished(), "apps must run until shutdown");
+
+        shutdown_sender.shutdown();
+        runtime.block_on(handle).unwrap().unwrap();
+    }
+
+    #[test]
+    fn stopped_app_is_no_longer_running() {
+        let runtime = Arc::new(Runtime::new().unwrap());
+        let mut orchestrator = MultiAppOrchestrator::new(runtime.clone());
+        orchestrator.spawn("a", run_until_shutdown);
+        runtime.block_on(tokio::time::sleep(std::time::Duration::from_millis(100)));
+        assert!(orchestrator.is_running("a"));
+
+        runtime.block_on(orchestrator.stop("a")).unwrap();
+        assert!(!orchestrator.is_running("a"));
+    }
+}
@@ -3,6 +3,7 @@ use super::Contract;
 use crate::errors::{BuildError, OrchestrationError};
 use crate::home_dir::{BuildId, HomeDir};
 use crate::pipeline::connector_source::ConnectorSourceFactoryError;
+use crate::pipeline::quota::{monitor_state_size, QuotaEnforcer};
 use crate::pipeline::PipelineBuilder;
 use crate::simple::build;
 use crate::simple::helper::validate_config;
@@ -79,6 +80,22 @@ impl SimpleOrchestrator {
         shutdown: ShutdownReceiver,
         api_notifier: Option<oneshot::Sender<()>>,
     ) -> Result<(), OrchestrationError> {
+        let quota = self
+            .config
+            .app
+            .quotas
+            .clone()
+            .map(|quotas| Arc::new(QuotaEnforcer::new(self.config.app_name.clone(), quotas)));
+        if let Some(quota) = &quota {
+            if quota.quotas().max_state_size.is_some() {
+                self.runtime.spawn(monitor_state_size(
+                    self.home_dir(),
+                    quota.clone(),
+                    shutdown.clone(),
+                ));
+            }
+        }
+
         let executor = Executor::new(
             &self.config.connections,
             &self.config.sources,
@@ -86,6 +103,7 @@ impl SimpleOrchestrator {
             &self.config.sinks,
             self.labels.clone(),
             &self.config.udfs,
+            quota,
         )
         .await?;
         let dag_executor = executor
@@ -1,4 +1,8 @@
-use dozer_types::models::flags::{EnableProbabilisticOptimizations, Flags};
+use std::collections::BTreeMap;
+
+use dozer_types::models::flags::{
+    AggregationEmission, DecimalRounding, EnableProbabilisticOptimizations, Flags, NumericOverflow,
+};
 use dozer_types::node::NodeHandle;
 
 use crate::appsource::{self, AppSourceManager};
@@ -93,16 +97,34 @@ impl AppPipeline {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PipelineFlags {
     pub enable_probabilistic_optimizations: EnableProbabilisticOptimizations,
+    pub aggregation_emission: AggregationEmission,
+    pub aggregation_emissions: BTreeMap<String, AggregationEmission>,
+    pub numeric_overflow: NumericOverflow,
+    pub decimal_rounding: Option<DecimalRounding>,
 }
 
 impl From<&Flags> for PipelineFlags {
     fn from(flags: &Flags) -> Self {
         Self {
             enable_probabilistic_optimizations: flags.enable_probabilistic_optimizations.clone(),
+            aggregation_emission: flags.aggregation_emission,
+            aggregation_emissions: flags.aggregation_emissions.clone(),
+            numeric_overflow: flags.numeric_overflow,
+            decimal_rounding: flags.decimal_rounding,
         }
     }
 }
 
+impl PipelineFlags {
+    /// The emission policy of the aggregation of the query outputting `table_name`.
+    pub fn aggregation_emission_of(&self, table_name: &str) -> AggregationEmission {
+        self.aggregation_emissions
+            .get(table_name)
+            .copied()
+            .unwrap_or(self.aggregation_emission)
+    }
+}
+
 impl From<Flags> for PipelineFlags {
     fn from(flags: Flags) -> Self {
         Self::from(&flags)
@@ -0,0 +1,135 @@
+use std::time::Duration;
+
+use dozer_types::log::warn;
+use dozer_types::models::app_config::{ChaosConfig, FaultSchedule};
+use dozer_types::node::NodeHandle;
+use dozer_types::thiserror::{self, Error};
+
+#[derive(Debug, Error)]
+#[error("Injected fault: {0}")]
+pub struct InjectedFault(pub String);
+
+/// Counts the events of a node and decides on which ones a fault is injected, following a [`FaultSchedule`].
+#[derive(Debug, Clone)]
+pub struct FaultInjector {
+    schedule: FaultSchedule,
+    events: u64,
+    injections: u64,
+}
+
+impl FaultInjector {
+    pub fn new(schedule: FaultSchedule) -> Self {
+        Self {
+            schedule,
+            events: 0,
+            injections: 0,
+        }
+    }
+
+    /// Counts an event, returning if a fault must be injected on it.
+    pub fn on_event(&mut self) -> bool {
+        self.events += 1;
+        let max_injections = match self.schedule.every {
+            Some(_) => self.schedule.times.unwrap_or(u64::MAX),
+            None => 1,
+        };
+        if self.injections >= max_injections || self.events <= self.schedule.after {
+            return false;
+        }
+        let since_first = self.events - self.schedule.after - 1;
+        let inject = match self.schedule.every {
+            Some(every) => since_first % every.max(1) == 0,
+            None => since_first == 0,
+        };
+        if inject {
+            self.injections += 1;
+        }
+        inject
+    }
+}
+
+fn targets(target: &Option<String>, node: &NodeHandle) -> bool {
+    target.as_ref().map_or(true, |target| *target == node.id)
+}
+
+/// Faults are only injected in builds with the `chaos` feature.
+fn enabled(chaos: Option<&ChaosConfig>) -> Option<&ChaosConfig> {
+    if cfg!(feature = "chaos") {
+        chaos
+    } else {
+        None
+    }
+}
+
+pub fn warn_if_disabled(chaos: Option<&ChaosConfig>) {
+    if chaos.is_some() && enabled(chaos).is_none() {
+        warn!("Fault injection is configured but dozer was built without the `chaos` feature, ignoring it");
+    }
+}
+
+pub fn connector_disconnect(
+    chaos: Option<&ChaosConfig>,
+    node: &NodeHandle,
+) -> Option<FaultInjector> {
+    enabled(chaos)?
+        .connector_disconnects
+        .iter()
+        .find(|fault| targets(&fault.connection, node))
+        .map(|fault| FaultInjector::new(fault.schedule))
+}
+
+pub fn slow_processor(
+    chaos: Option<&ChaosConfig>,
+    node: &NodeHandle,
+) -> Option<(FaultInjector, Duration)> {
+    enabled(chaos)?
+        .slow_processors
+        .iter()
+        .find(|fault| targets(&fault.processor, node))
+        .map(|fault| {
+            (
+                FaultInjector::new(fault.schedule),
+                Duration::from_millis(fault.delay_ms),
+            )
+        })
+}
+
+pub fn sink_commit_error(chaos: Option<&ChaosConfig>, node: &NodeHandle) -> Option<FaultInjector> {
+    enabled(chaos)?
+        .sink_commit_errors
+        .iter()
+        .find(|fault| targets(&fault.sink, node))
+        .map(|fault| FaultInjector::new(fault.schedule))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn injected_events(schedule: FaultSchedule, events: u64) -> Vec<u64> {
+        let mut injector = FaultInjector::new(schedule);
+        (1..=events).filter(|_| injector.on_event()).collect()
+    }
+
+    #[test]
+    fn test_fault_schedule() {
+        let once = FaultSchedule {
+            after: 2,
+            every: None,
+            times: None,
+        };
+        assert_eq!(injected_events(once, 10), vec![3]);
+
+        let every = FaultSchedule {
+            every: Some(3),
+            ..once
+        };
+        assert_eq!(injected_events(every, 10), vec![3, 6, 9]);
+
+        let limited = FaultSchedule {
+            times: Some(2),
+            ..every
+        };
+        assert_eq!(injected_events(limited, 10), vec![3, 6]);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::builder_dag::{BuilderDag, NodeKind};
+use crate::chaos;
 use crate::dag_schemas::DagSchemas;
 use crate::errors::ExecutionError;
 use crate::Dag;
@@ -6,6 +7,7 @@ use crate::Dag;
 use daggy::petgraph::visit::IntoNodeIdentifiers;
 
 use dozer_tracing::DozerMonitorContext;
+use dozer_types::models::app_config::{ChaosConfig, MergeOrdering};
 use futures::Future;
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -19,6 +21,10 @@ pub struct ExecutorOptions {
     pub channel_buffer_sz: usize,
     pub event_hub_capacity: usize,
     pub error_threshold: Option<u32>,
+    /// How the operations of the sources are merged.
+    pub merge_ordering: MergeOrdering,
+    /// Faults to inject, in builds with the `chaos` feature.
+    pub chaos: Option<ChaosConfig>,
 }
 
 impl Default for ExecutorOptions {
@@ -27,6 +33,8 @@ impl Default for ExecutorOptions {
             channel_buffer_sz: 20_000,
             event_hub_capacity: 100,
             error_threshold: Some(0),
+            merge_ordering: MergeOrdering::Arrival,
+            chaos: None,
         }
     }
 }
@@ -57,6 +65,7 @@ pub struct DagExecutorJoinHandle {
 
 impl DagExecutor {
     pub async fn new(dag: Dag, options: ExecutorOptions) -> Result<Self, ExecutionError> {
+        chaos::warn_if_disabled(options.chaos.as_ref());
         let dag_schemas = DagSchemas::new(dag).await?;
 
         let builder_dag = BuilderDag::new(dag_schemas, options.event_hub_capacity).await?;
@@ -98,11 +107,17 @@ impl DagExecutor {
             match node {
                 NodeKind::Source { .. } => unreachable!("We already started the source node"),
                 NodeKind::Processor(_) => {
-                    let processor_node = ProcessorNode::new(&mut execution_dag, node_index).await;
+                    let processor_node = ProcessorNode::new(
+                        &mut execution_dag,
+                        node_index,
+                        self.options.chaos.as_ref(),
+                    )
+                    .await;
                     join_handles.push(start_processor(processor_node)?);
                 }
                 NodeKind::Sink(_) => {
-                    let sink_node = SinkNode::new(&mut execution_dag, node_index);
+                    let sink_node =
+                        SinkNode::new(&mut execution_dag, node_index, self.options.chaos.as_ref());
                     join_handles.push(start_sink(sink_node)?);
                 }
             }
@@ -1,11 +1,14 @@
 use std::sync::Arc;
+use std::time::Duration;
 use std::{borrow::Cow, mem::swap};
 
 use crossbeam::channel::Receiver;
 use daggy::NodeIndex;
+use dozer_types::models::app_config::ChaosConfig;
 use dozer_types::node::{NodeHandle, OpIdentifier};
 use dozer_types::types::TableOperation;
 
+use crate::chaos::{self, FaultInjector};
 use crate::epoch::Epoch;
 use crate::error_manager::ErrorManager;
 use crate::executor_operation::ExecutorOperation;
@@ -32,10 +35,16 @@ pub struct ProcessorNode {
     channel_manager: ChannelManager,
     /// The error manager, for reporting non-fatal errors.
     error_manager: Arc<ErrorManager>,
+    /// When to sleep before processing an operation, and for how long.
+    slow: Option<(FaultInjector, Duration)>,
 }
 
 impl ProcessorNode {
-    pub async fn new(dag: &mut ExecutionDag, node_index: NodeIndex) -> Self {
+    pub async fn new(
+        dag: &mut ExecutionDag,
+        node_index: NodeIndex,
+        chaos: Option<&ChaosConfig>,
+    ) -> Self {
         let node = dag.node_weight_mut(node_index);
         let Some(kind) = node.kind.take() else {
             panic!("Must pass in a node")
@@ -57,12 +66,14 @@ impl ProcessorNode {
             dag.error_manager().clone(),
         );
 
+        let slow = chaos::slow_processor(chaos, &node_handle);
         Self {
             node_handle,
             initial_epoch_id: dag.initial_epoch_id(),
             node_handles,
             receivers,
             processor,
+            slow,
             channel_manager,
             error_manager: dag.error_manager().clone(),
         }
@@ -95,6 +106,11 @@ impl ReceiverLoop for ProcessorNode {
     }
 
     fn on_op(&mut self, _index: usize, op: TableOperation) -> Result<(), ExecutionError> {
+        if let Some((injector, delay)) = &mut self.slow {
+            if injector.on_event() {
+                std::thread::sleep(*delay);
+            }
+        }
         if let Err(e) = self.processor.process(op, &mut self.channel_manager) {
             self.error_manager.report(e);
         }
@@ -102,6 +118,9 @@ impl ReceiverLoop for ProcessorNode {
     }
 
     fn on_commit(&mut self, epoch: Epoch) -> Result<(), ExecutionError> {
+        if let Err(e) = self.processor.flush(&mut self.channel_manager) {
+            self.error_manager.report(e);
+        }
         if let Err(e) = self.processor.commit(&epoch) {
             self.error_manager.report(e);
         }
@@ -113,6 +132,17 @@ impl ReceiverLoop for ProcessorNode {
         self.channel_manager.send_terminate()
     }
 
+    fn timeout(&self) -> Option<Duration> {
+        self.processor.flush_interval()
+    }
+
+    fn on_timeout(&mut self) -> Result<(), ExecutionError> {
+        if let Err(e) = self.processor.flush(&mut self.channel_manager) {
+            self.error_manager.report(e);
+        }
+        Ok(())
+    }
+
     fn on_snapshotting_started(&mut self, connection_name: String) -> Result<(), ExecutionError> {
         self.channel_manager
             .send_snapshotting_started(connection_name)
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::time::Duration;
 
 use crossbeam::channel::{Receiver, Select};
 use dozer_types::{log::debug, node::OpIdentifier, types::TableOperation};
@@ -23,6 +24,14 @@ pub trait ReceiverLoop: Name {
     fn on_commit(&mut self, epoch: Epoch) -> Result<(), ExecutionError>;
     /// Responds to `terminate`.
     fn on_terminate(&mut self) -> Result<(), ExecutionError>;
+    /// How long to wait for input before calling [`on_timeout`].
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
+    /// Responds to no input arriving for [`timeout`].
+    fn on_timeout(&mut self) -> Result<(), ExecutionError> {
+        Ok(())
+    }
     /// Responds to `SnapshottingStarted`.
     fn on_snapshotting_started(&mut self, connection_name: String) -> Result<(), ExecutionError>;
     /// Responds to `SnapshottingDone`.
@@ -48,8 +57,18 @@ pub trait ReceiverLoop: Name {
         let mut epoch_id = initial_epoch_id;
 
         let mut sel = init_select(&receivers);
+        let timeout = self.timeout();
         loop {
-            let index = sel.ready();
+            let index = match timeout {
+                Some(timeout) => match sel.ready_timeout(timeout) {
+                    Ok(index) => index,
+                    Err(_) => {
+                        self.on_timeout()?;
+                        continue;
+                    }
+                },
+                None => sel.ready(),
+            };
             let op = receivers[index]
                 .recv()
                 .map_err(|_| ExecutionError::CannotReceiveFromChannel)?;
@@ -3,7 +3,8 @@ use daggy::NodeIndex;
 use dozer_tracing::{
     constants::{
         ConnectorEntityType, DOZER_METER_NAME, ENDPOINT_LABEL, OPERATION_TYPE_LABEL,
-        PIPELINE_LATENCY_GAUGE_NAME, SINK_OPERATION_COUNTER_NAME, TABLE_LABEL,
+        PIPELINE_LATENCY_GAUGE_NAME, SINK_FLUSH_LATENCY_GAUGE_NAME, SINK_OPERATION_COUNTER_NAME,
+        TABLE_LABEL,
     },
     emit_event,
     opentelemetry_metrics::{Counter, Gauge},
@@ -11,6 +12,7 @@ use dozer_tracing::{
 };
 use dozer_types::{
     log::debug,
+    models::app_config::ChaosConfig,
     node::{NodeHandle, OpIdentifier},
     types::{Operation, TableOperation},
 };
@@ -24,8 +26,14 @@ use std::{
 use tokio::sync::broadcast;
 
 use crate::{
-    builder_dag::NodeKind, epoch::Epoch, error_manager::ErrorManager, errors::ExecutionError,
-    event::Event, executor_operation::ExecutorOperation, node::Sink,
+    builder_dag::NodeKind,
+    chaos::{self, FaultInjector, InjectedFault},
+    epoch::Epoch,
+    error_manager::ErrorManager,
+    errors::ExecutionError,
+    event::Event,
+    executor_operation::ExecutorOperation,
+    node::Sink,
 };
 
 use super::execution_dag::ExecutionDag;
@@ -107,6 +115,8 @@ pub struct SinkNode {
     max_flush_interval: Duration,
 
     ops_since_flush: u64,
+    /// When the first operation since the last flush was received.
+    first_op_since_flush: Option<Instant>,
     last_op_if_commit: Option<Epoch>,
     flush_scheduled_on_next_commit: bool,
     flush_scheduler_sender: Sender<Duration>,
@@ -114,6 +124,8 @@ pub struct SinkNode {
 
     event_sender: broadcast::Sender<Event>,
     metrics: SinkMetrics,
+    /// When to fail a commit.
+    commit_error: Option<FaultInjector>,
 }
 
 #[derive(Debug)]
@@ -121,10 +133,11 @@ pub struct SinkNode {
 pub struct SinkMetrics {
     sink_counter: Counter<u64>,
     latency_gauge: Gauge<f64>,
+    flush_latency_gauge: Gauge<f64>,
 }
 
 impl SinkNode {
-    pub fn new(dag: &mut ExecutionDag, node_index: NodeIndex) -> Self {
+    pub fn new(dag: &mut ExecutionDag, node_index: NodeIndex, chaos: Option<&ChaosConfig>) -> Self {
         let node = dag.node_weight_mut(node_index);
         let Some(kind) = node.kind.take() else {
             panic!("Must pass in a node")
@@ -145,6 +158,10 @@ impl SinkNode {
             .f64_gauge(PIPELINE_LATENCY_GAUGE_NAME)
             .with_description("Mesasures latency between commits")
             .init();
+        let flush_latency_gauge = meter
+            .f64_gauge(SINK_FLUSH_LATENCY_GAUGE_NAME)
+            .with_description("Measures how long operations wait in the sink before being flushed")
+            .init();
 
         let max_flush_interval = sink
             .max_batch_duration_ms()
@@ -161,6 +178,7 @@ impl SinkNode {
 
         std::thread::spawn(move || scheduler.run());
 
+        let commit_error = chaos::sink_commit_error(chaos, &node_handle);
         Self {
             node_handle,
             initial_epoch_id: dag.initial_epoch_id(),
@@ -176,10 +194,13 @@ impl SinkNode {
             event_sender: dag.event_hub().sender.clone(),
             max_flush_interval,
             ops_since_flush: 0,
+            first_op_since_flush: None,
             metrics: SinkMetrics {
                 sink_counter,
                 latency_gauge,
+                flush_latency_gauge,
             },
+            commit_error,
         }
     }
 
@@ -192,6 +213,16 @@ impl SinkNode {
             self.error_manager.report(e);
         }
         self.ops_since_flush = 0;
+        if let Some(first_op) = self.first_op_since_flush.take() {
+            let mut labels = self.labels.attrs();
+            labels.push(dozer_tracing::KeyValue::new(
+                ENDPOINT_LABEL,
+                self.node_handle.id.clone(),
+            ));
+            self.metrics
+                .flush_latency_gauge
+                .record(first_op.elapsed().as_secs_f64(), &labels);
+        }
         self.flush_scheduler_sender
             .send(self.max_flush_interval)
             .unwrap();
@@ -388,6 +419,7 @@ impl ReceiverLoop for SinkNode {
             _ => 1,
         };
         self.ops_since_flush += counter_number;
+        self.first_op_since_flush.get_or_insert_with(Instant::now);
 
         if let Err(e) = self.sink.process(op) {
             self.error_manager.report(e);
@@ -399,7 +431,16 @@ impl ReceiverLoop for SinkNode {
 
     fn on_commit(&mut self, epoch: Epoch) -> Result<(), ExecutionError> {
         // debug!("[{}] Checkpointing - {}", self.node_handle, epoch);
-        if let Err(e) = self.sink.commit(&epoch) {
+        if self
+            .commit_error
+            .as_mut()
+            .is_some_and(|injector| injector.on_event())
+        {
+            self.error_manager.report(Box::new(InjectedFault(format!(
+                "commit of {} in sink {}",
+                epoch.common_info.id, self.node_handle
+            ))));
+        } else if let Err(e) = self.sink.commit(&epoch) {
             self.error_manager.report(e);
         }
         self.last_op_if_commit = Some(epoch.clone());
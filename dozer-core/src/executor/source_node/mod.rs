@@ -1,18 +1,32 @@
-use std::{fmt::Debug, future::Future, pin::pin, sync::Arc, time::SystemTime};
+use std::{
+    fmt::Debug,
+    future::Future,
+    pin::pin,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime},
+};
 
 use daggy::petgraph::visit::IntoNodeIdentifiers;
 use dozer_types::{
     log::debug, models::ingestion_types::TransactionInfo, node::OpIdentifier, types::TableOperation,
 };
-use dozer_types::{models::ingestion_types::IngestionMessage, node::SourceState};
-use futures::{future::Either, StreamExt};
+use dozer_types::{
+    models::{
+        app_config::{default_event_time_idle_timeout_ms, MergeOrdering},
+        ingestion_types::IngestionMessage,
+    },
+    node::SourceState,
+};
+use futures::future::Either;
 use tokio::{
     runtime::Runtime,
     sync::mpsc::{channel, Receiver, Sender},
+    time::timeout_at,
 };
 
 use crate::{
     builder_dag::NodeKind,
+    chaos::{self, FaultInjector, InjectedFault},
     epoch::Epoch,
     errors::ExecutionError,
     executor_operation::ExecutorOperation,
@@ -20,6 +34,8 @@ use crate::{
     node::{PortHandle, Source},
 };
 
+use self::{ordering::EventTimeMerger, stream::Receivers};
+
 use super::{execution_dag::ExecutionDag, node::Node, ExecutorOptions};
 
 /// The source operation collector.
@@ -37,6 +53,8 @@ pub struct SourceNode<F> {
     shutdown: F,
     /// The runtime to run the source in.
     runtime: Arc<Runtime>,
+    /// Orders the messages of the sources by event time, if configured.
+    merger: Option<EventTimeMerger>,
 }
 
 impl<F: Future + Unpin> Node for SourceNode<F> {
@@ -52,24 +70,55 @@ impl<F: Future + Unpin> Node for SourceNode<F> {
         }
         let mut num_running_sources = handles.len();
 
-        let mut stream = pin!(stream::receivers_stream(self.receivers));
+        let mut receivers = Receivers::new(self.receivers);
         loop {
-            let next = stream.next();
-            let next = pin!(next);
-            match self
-                .runtime
-                .block_on(futures::future::select(self.shutdown, next))
-            {
-                Either::Left((_, _)) => {
+            let next = {
+                let merger = &self.merger;
+                // Sources with too many messages held back are not received from until the merger passes some on.
+                let receive = receivers.receive(move |index| {
+                    merger.as_ref().is_some_and(|merger| merger.is_full(index))
+                });
+                let deadline = merger
+                    .as_ref()
+                    .and_then(|merger| merger.next_idle_deadline(Instant::now()));
+                // `None` if a source that holds back messages has become idle.
+                let next = async move {
+                    match deadline {
+                        Some(deadline) => timeout_at(deadline.into(), receive).await.ok(),
+                        None => Some(receive.await),
+                    }
+                };
+                let next = pin!(next);
+                match self
+                    .runtime
+                    .block_on(futures::future::select(self.shutdown, next))
+                {
+                    Either::Left(_) => None,
+                    Either::Right((next, shutdown)) => {
+                        self.shutdown = shutdown;
+                        Some(next)
+                    }
+                }
+            };
+            match next {
+                None => {
                     send_to_all_nodes(&self.sources, ExecutorOperation::Terminate)?;
                     return Ok(());
                 }
-                Either::Right((next, shutdown)) => {
-                    let next = next.expect("We return just when the stream ends");
-                    self.shutdown = shutdown;
-                    let index = next.0;
-                    let Some((port, message)) = next.1 else {
+                Some(None) => {
+                    let merger = self
+                        .merger
+                        .as_mut()
+                        .expect("Only the merger sets a deadline");
+                    forward_merged(merger, &mut self.sources, &mut self.epoch_id)?;
+                }
+                Some(Some((index, next))) => {
+                    let Some((port, message)) = next else {
                         debug!("[{}] quit", self.sources[index].channel_manager.owner().id);
+                        if let Some(merger) = &mut self.merger {
+                            merger.quit(index);
+                            forward_merged(merger, &mut self.sources, &mut self.epoch_id)?;
+                        }
                         match self.runtime.block_on(
                             handles[index]
                                 .take()
@@ -89,53 +138,18 @@ impl<F: Future + Unpin> Node for SourceNode<F> {
                             }
                         }
                     };
-                    let source = &mut self.sources[index];
-                    match message {
-                        IngestionMessage::OperationEvent { op, id, .. } => {
-                            source.state = SourceState::NonRestartable;
-                            source
-                                .channel_manager
-                                .send_op(TableOperation { op, id, port })?;
+                    match &mut self.merger {
+                        Some(merger) => {
+                            merger.push(index, port, message, Instant::now());
+                            forward_merged(merger, &mut self.sources, &mut self.epoch_id)?;
                         }
-                        IngestionMessage::TransactionInfo(info) => match info {
-                            TransactionInfo::Commit { id } => {
-                                if let Some(id) = id {
-                                    source.state = SourceState::Restartable(id);
-                                } else {
-                                    source.state = SourceState::NonRestartable;
-                                }
-
-                                let source_states = Arc::new(
-                                    self.sources
-                                        .iter()
-                                        .map(|source| {
-                                            (
-                                                source.channel_manager.owner().clone(),
-                                                source.state.clone(),
-                                            )
-                                        })
-                                        .collect(),
-                                );
-                                let epoch =
-                                    Epoch::new(self.epoch_id, source_states, SystemTime::now());
-                                send_to_all_nodes(
-                                    &self.sources,
-                                    ExecutorOperation::Commit { epoch },
-                                )?;
-                                self.epoch_id += 1;
-                            }
-                            TransactionInfo::SnapshottingStarted => {
-                                source.channel_manager.send_snapshotting_started(
-                                    source.channel_manager.owner().id.clone(),
-                                )?;
-                            }
-                            TransactionInfo::SnapshottingDone { id } => {
-                                source.channel_manager.send_snapshotting_done(
-                                    source.channel_manager.owner().id.clone(),
-                                    id,
-                                )?;
-                            }
-                        },
+                        None => handle_message(
+                            &mut self.sources,
+                            &mut self.epoch_id,
+                            index,
+                            port,
+                            message,
+                        )?,
                     }
                 }
             }
@@ -143,10 +157,84 @@ impl<F: Future + Unpin> Node for SourceNode<F> {
     }
 }
 
+/// Forwards the messages of the sources that `merger` has ordered so far.
+fn forward_merged(
+    merger: &mut EventTimeMerger,
+    sources: &mut [RunningSource],
+    epoch_id: &mut u64,
+) -> Result<(), ExecutionError> {
+    while let Some((index, port, message)) = merger.pop(Instant::now()) {
+        handle_message(sources, epoch_id, index, port, message)?;
+    }
+    Ok(())
+}
+
+fn handle_message(
+    sources: &mut [RunningSource],
+    epoch_id: &mut u64,
+    index: usize,
+    port: PortHandle,
+    message: IngestionMessage,
+) -> Result<(), ExecutionError> {
+    let source = &mut sources[index];
+    match message {
+        IngestionMessage::OperationEvent { op, id, .. } => {
+            if source
+                .disconnect
+                .as_mut()
+                .is_some_and(|injector| injector.on_event())
+            {
+                return Err(ExecutionError::Source(Box::new(InjectedFault(format!(
+                    "disconnect of source {}",
+                    source.channel_manager.owner()
+                )))));
+            }
+            source.state = SourceState::NonRestartable;
+            source
+                .channel_manager
+                .send_op(TableOperation { op, id, port })?;
+        }
+        IngestionMessage::TransactionInfo(info) => match info {
+            TransactionInfo::Commit { id } => {
+                if let Some(id) = id {
+                    source.state = SourceState::Restartable(id);
+                } else {
+                    source.state = SourceState::NonRestartable;
+                }
+
+                let source_states = Arc::new(
+                    sources
+                        .iter()
+                        .map(|source| {
+                            (source.channel_manager.owner().clone(), source.state.clone())
+                        })
+                        .collect(),
+                );
+                let epoch = Epoch::new(*epoch_id, source_states, SystemTime::now());
+                send_to_all_nodes(sources, ExecutorOperation::Commit { epoch })?;
+                *epoch_id += 1;
+            }
+            TransactionInfo::SnapshottingStarted => {
+                source
+                    .channel_manager
+                    .send_snapshotting_started(source.channel_manager.owner().id.clone())?;
+            }
+            TransactionInfo::SnapshottingDone { id } => {
+                source
+                    .channel_manager
+                    .send_snapshotting_done(source.channel_manager.owner().id.clone(), id)?;
+            }
+        },
+    }
+    Ok(())
+}
+
 #[derive(Debug)]
 struct RunningSource {
     channel_manager: ChannelManager,
     state: SourceState,
+    /// When to fail as if the connector lost its connection.
+    disconnect: Option<FaultInjector>,
 }
 
 #[derive(Debug)]
@@ -176,6 +264,7 @@ pub async fn create_source_node<F>(
     let mut sources = vec![];
     let mut source_runners = vec![];
     let mut receivers = vec![];
+    let mut event_time_fields = vec![];
 
     let node_indices = dag.graph().node_identifiers().collect::<Vec<_>>();
     for node_index in node_indices {
@@ -196,6 +285,7 @@ pub async fn create_source_node<F>(
             continue;
         };
 
+        let disconnect = chaos::connector_disconnect(options.chaos.as_ref(), &node_handle);
         let senders = dag.collect_senders(node_index);
         let record_writers = dag.collect_record_writers(node_index).await;
         let channel_manager = ChannelManager::new(
@@ -207,8 +297,10 @@ pub async fn create_source_node<F>(
         sources.push(RunningSource {
             channel_manager,
             state: SourceState::NotStarted,
+            disconnect,
         });
 
+        event_time_fields.push(source.event_time_fields());
         let (sender, receiver) = channel(options.channel_buffer_sz);
         // let (sender, receiver) = channel(1);
         source_runners.push(SourceRunner {
@@ -219,6 +311,22 @@ pub async fn create_source_node<F>(
         receivers.push(receiver);
     }
 
+    let merger = match options.merge_ordering {
+        MergeOrdering::Arrival => None,
+        MergeOrdering::EventTime {
+            watermark_ms,
+            idle_timeout_ms,
+        } => Some(EventTimeMerger::new(
+            watermark_ms,
+            Duration::from_millis(
+                idle_timeout_ms.unwrap_or_else(default_event_time_idle_timeout_ms),
+            ),
+            options.channel_buffer_sz,
+            event_time_fields,
+            Instant::now(),
+        )),
+    };
+
     SourceNode {
         sources,
         source_runners,
@@ -226,7 +334,9 @@ pub async fn create_source_node<F>(
         epoch_id: dag.initial_epoch_id(),
         shutdown,
         runtime,
+        merger,
     }
 }
 
+mod ordering;
 mod stream;
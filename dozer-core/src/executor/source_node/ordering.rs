@@ -0,0 +1,268 @@
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use dozer_types::models::ingestion_types::IngestionMessage;
+use dozer_types::types::{Field, Operation, Record};
+
+use crate::node::PortHandle;
+
+/// Merges the messages of the sources in the order of their event time, so the merge doesn't depend on arrival order.
+///
+/// Each source must send its messages in the order of their event time. Sources without event time fields are not ordered,
+/// and their messages are passed on as they arrive. A source that hasn't sent anything for `idle_timeout` doesn't hold back
+/// the others, and a source with `max_buffered` messages held back shouldn't be received from until some are passed on.
+#[derive(Debug)]
+pub struct EventTimeMerger {
+    /// In milliseconds.
+    watermark: i64,
+    idle_timeout: Duration,
+    max_buffered: usize,
+    sources: Vec<MergedSource>,
+    /// The latest event time received from any source.
+    max_event_time: i64,
+}
+
+#[derive(Debug)]
+struct MergedSource {
+    event_time_fields: HashMap<PortHandle, usize>,
+    /// The messages received but not passed on yet, with the event time of the source when they were received.
+    queue: VecDeque<(i64, PortHandle, IngestionMessage)>,
+    /// The latest event time received from this source.
+    event_time: i64,
+    /// When this source last sent a message.
+    last_received: Instant,
+    running: bool,
+}
+
+impl MergedSource {
+    fn is_ordered(&self) -> bool {
+        !self.event_time_fields.is_empty()
+    }
+}
+
+impl EventTimeMerger {
+    pub fn new(
+        watermark_ms: u64,
+        idle_timeout: Duration,
+        max_buffered: usize,
+        event_time_fields: Vec<HashMap<PortHandle, usize>>,
+        now: Instant,
+    ) -> Self {
+        Self {
+            watermark: watermark_ms.try_into().unwrap_or(i64::MAX),
+            idle_timeout,
+            max_buffered: max_buffered.max(1),
+            sources: event_time_fields
+                .into_iter()
+                .map(|event_time_fields| MergedSource {
+                    event_time_fields,
+                    queue: VecDeque::new(),
+                    event_time: i64::MIN,
+                    last_received: now,
+                    running: true,
+                })
+                .collect(),
+            max_event_time: i64::MIN,
+        }
+    }
+
+    pub fn push(
+        &mut self,
+        index: usize,
+        port: PortHandle,
+        message: IngestionMessage,
+        now: Instant,
+    ) {
+        let source = &mut self.sources[index];
+        source.last_received = now;
+        if let IngestionMessage::OperationEvent { op, .. } = &message {
+            if let Some(time) = source
+                .event_time_fields
+                .get(&port)
+                .and_then(|field| operation_event_time(op, *field))
+            {
+                source.event_time = source.event_time.max(time);
+                self.max_event_time = self.max_event_time.max(time);
+            }
+        }
+        source.queue.push_back((source.event_time, port, message));
+    }
+
+    /// The source at `index` won't send any more messages, so it no longer holds back the others.
+    pub fn quit(&mut self, index: usize) {
+        self.sources[index].running = false;
+    }
+
+    /// Whether the source at `index` has as many messages held back as allowed.
+    pub fn is_full(&self, index: usize) -> bool {
+        self.sources[index].queue.len() >= self.max_buffered
+    }
+
+    /// When a source that may be holding back messages becomes idle, if any messages are held back.
+    pub fn next_idle_deadline(&self, now: Instant) -> Option<Instant> {
+        if self.sources.iter().all(|source| source.queue.is_empty()) {
+            return None;
+        }
+        self.sources
+            .iter()
+            .filter(|source| source.is_ordered() && source.running && source.queue.is_empty())
+            .map(|source| source.last_received + self.idle_timeout)
+            .filter(|deadline| *deadline > now)
+            .min()
+    }
+
+    /// Returns the next message to pass on, or `None` if the next one may still come from a source that hasn't sent it yet.
+    pub fn pop(&mut self, now: Instant) -> Option<(usize, PortHandle, IngestionMessage)> {
+        let index = match self
+            .sources
+            .iter()
+            .position(|source| !source.is_ordered() && !source.queue.is_empty())
+        {
+            Some(index) => index,
+            None => {
+                // Ties are broken by source index.
+                let (index, time) = self
+                    .sources
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, source)| {
+                        source.queue.front().map(|(time, ..)| (index, *time))
+                    })
+                    .min_by_key(|(index, time)| (*time, *index))?;
+                let held_back = self.sources.iter().enumerate().any(|(other, source)| {
+                    source.is_ordered()
+                        && source.running
+                        && source.queue.is_empty()
+                        && now.duration_since(source.last_received) < self.idle_timeout
+                        && (source.event_time, other) < (time, index)
+                });
+                if held_back && time > self.max_event_time.saturating_sub(self.watermark) {
+                    return None;
+                }
+                index
+            }
+        };
+        let (_, port, message) = self.sources[index].queue.pop_front()?;
+        Some((index, port, message))
+    }
+}
+
+/// Deletes don't carry the time of the deletion, so they are at the latest event time of their source.
+fn operation_event_time(op: &Operation, field: usize) -> Option<i64> {
+    match op {
+        Operation::Insert { new } | Operation::Update { new, .. } => record_event_time(new, field),
+        Operation::Delete { .. } => None,
+        Operation::BatchInsert { new } => new
+            .iter()
+            .filter_map(|record| record_event_time(record, field))
+            .min(),
+    }
+}
+
+fn record_event_time(record: &Record, field: usize) -> Option<i64> {
+    match record.values.get(field)? {
+        Field::Timestamp(time) => Some(time.timestamp_millis()),
+        Field::Int(millis) => Some(*millis),
+        Field::UInt(millis) => (*millis).try_into().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn insert(time: i64) -> IngestionMessage {
+        IngestionMessage::OperationEvent {
+            table_index: 0,
+            op: Operation::Insert {
+                new: Record::new(vec![Field::Int(time)]),
+            },
+            id: None,
+        }
+    }
+
+    fn merger(fields: Vec<HashMap<PortHandle, usize>>, now: Instant) -> EventTimeMerger {
+        EventTimeMerger::new(100, Duration::from_secs(1), 2, fields, now)
+    }
+
+    fn popped_times(merger: &mut EventTimeMerger, now: Instant) -> Vec<(usize, i64)> {
+        std::iter::from_fn(|| merger.pop(now))
+            .map(|(index, _, message)| {
+                let IngestionMessage::OperationEvent {
+                    op: Operation::Insert { new },
+                    ..
+                } = message
+                else {
+                    panic!("Unexpected message {message:?}");
+                };
+                (index, record_event_time(&new, 0).unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_event_time_merge() {
+        let now = Instant::now();
+        let fields = HashMap::from([(0, 0)]);
+        let mut merger = merger(vec![fields.clone(), fields], now);
+
+        merger.push(0, 0, insert(10), now);
+        merger.push(0, 0, insert(30), now);
+        // Source 1 may still send an earlier operation.
+        assert_eq!(popped_times(&mut merger, now), vec![]);
+
+        merger.push(1, 0, insert(20), now);
+        assert_eq!(popped_times(&mut merger, now), vec![(0, 10), (1, 20)]);
+
+        // Source 1 is more than the watermark behind.
+        merger.push(0, 0, insert(150), now);
+        assert_eq!(popped_times(&mut merger, now), vec![(0, 30)]);
+
+        merger.quit(1);
+        assert_eq!(popped_times(&mut merger, now), vec![(0, 150)]);
+    }
+
+    #[test]
+    fn test_unordered_sources_keep_arrival_order() {
+        let now = Instant::now();
+        let mut merger = merger(vec![HashMap::from([(0, 0)]), HashMap::new()], now);
+
+        merger.push(1, 0, insert(20), now);
+        merger.push(1, 0, insert(10), now);
+        assert_eq!(popped_times(&mut merger, now), vec![(1, 20), (1, 10)]);
+    }
+
+    #[test]
+    fn test_idle_source_does_not_hold_back() {
+        let start = Instant::now();
+        let fields = HashMap::from([(0, 0)]);
+        let mut merger = merger(vec![fields.clone(), fields], start);
+
+        merger.push(0, 0, insert(10), start);
+        assert_eq!(popped_times(&mut merger, start), vec![]);
+        let deadline = start + Duration::from_secs(1);
+        assert_eq!(merger.next_idle_deadline(start), Some(deadline));
+
+        // Source 1 hasn't sent anything for the idle timeout.
+        assert_eq!(popped_times(&mut merger, deadline), vec![(0, 10)]);
+        assert_eq!(merger.next_idle_deadline(deadline), None);
+    }
+
+    #[test]
+    fn test_full_source() {
+        let now = Instant::now();
+        let fields = HashMap::from([(0, 0)]);
+        let mut merger = merger(vec![fields.clone(), fields], now);
+
+        merger.push(0, 0, insert(10), now);
+        assert!(!merger.is_full(0));
+        merger.push(0, 0, insert(20), now);
+        assert!(merger.is_full(0));
+        assert!(!merger.is_full(1));
+
+        merger.push(1, 0, insert(15), now);
+        assert_eq!(popped_times(&mut merger, now), vec![(0, 10), (1, 15)]);
+        assert!(!merger.is_full(0));
+    }
+}
@@ -1,37 +1,86 @@
-use async_stream::stream;
-use futures::{future::select_all, Stream};
+use std::{future::poll_fn, task::Poll};
+
 use tokio::sync::mpsc::Receiver;
 
-/// A convenient way of getting a self-referential struct.
-async fn receive_or_drop<T>(
-    index: usize,
-    mut receiver: Receiver<T>,
-) -> (usize, Option<(Receiver<T>, T)>) {
-    (index, receiver.recv().await.map(|item| (receiver, item)))
+/// The receivers of the sources, received from in turn so a source that always has messages doesn't starve the others.
+#[derive(Debug)]
+pub struct Receivers<T> {
+    receivers: Vec<Option<Receiver<T>>>,
+    /// The receiver polled first by the next `receive`.
+    next: usize,
 }
 
-/// This is not simply the merge of `ReceiverStream` because we need to know if the source has quit.
-pub fn receivers_stream<T>(receivers: Vec<Receiver<T>>) -> impl Stream<Item = (usize, Option<T>)> {
-    let mut futures = receivers
-        .into_iter()
-        .enumerate()
-        .map(|(index, receiver)| Box::pin(receive_or_drop(index, receiver)))
-        .collect::<Vec<_>>();
-
-    stream! {
-        while !futures.is_empty() {
-            match select_all(futures).await {
-                ((index, Some((receiver, item))), _, remaining) => {
-                    yield (index, Some(item));
-                    futures = remaining;
-                    // Can we somehow remove the allocation here?
-                    futures.push(Box::pin(receive_or_drop(index, receiver)));
+impl<T> Receivers<T> {
+    pub fn new(receivers: Vec<Receiver<T>>) -> Self {
+        Self {
+            receivers: receivers.into_iter().map(Some).collect(),
+            next: 0,
+        }
+    }
+
+    /// Receives the next item from any of the receivers that are not `paused`, returning `None` once if the source has quit.
+    ///
+    /// Waits forever if no receiver can be received from.
+    pub async fn receive(&mut self, paused: impl Fn(usize) -> bool) -> (usize, Option<T>) {
+        poll_fn(|cx| {
+            let len = self.receivers.len();
+            for offset in 0..len {
+                let index = (self.next + offset) % len;
+                if paused(index) {
+                    continue;
                 }
-                ((index, None), _, remaining) => {
-                    yield (index, None);
-                    futures = remaining;
+                let Some(receiver) = &mut self.receivers[index] else {
+                    continue;
+                };
+                if let Poll::Ready(item) = receiver.poll_recv(cx) {
+                    if item.is_none() {
+                        self.receivers[index] = None;
+                    }
+                    self.next = index + 1;
+                    return Poll::Ready((index, item));
                 }
             }
+            Poll::Pending
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc::channel;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_busy_receiver_does_not_starve_others() {
+        let (busy_sender, busy) = channel(100);
+        let (quiet_sender, quiet) = channel(100);
+        for i in 0..100 {
+            busy_sender.send(i).await.unwrap();
         }
+        let mut receivers = Receivers::new(vec![busy, quiet]);
+
+        assert_eq!(receivers.receive(|_| false).await, (0, Some(0)));
+        quiet_sender.send(-1).await.unwrap();
+        assert_eq!(receivers.receive(|_| false).await, (1, Some(-1)));
+        assert_eq!(receivers.receive(|_| false).await, (0, Some(1)));
+
+        drop(quiet_sender);
+        assert_eq!(receivers.receive(|_| false).await, (1, None));
+        assert_eq!(receivers.receive(|_| false).await, (0, Some(2)));
+        assert_eq!(receivers.receive(|_| false).await, (0, Some(3)));
+    }
+
+    #[tokio::test]
+    async fn test_paused_receiver_is_skipped() {
+        let (first_sender, first) = channel(1);
+        let (second_sender, second) = channel(1);
+        first_sender.send(0).await.unwrap();
+        second_sender.send(1).await.unwrap();
+        let mut receivers = Receivers::new(vec![first, second]);
+
+        assert_eq!(receivers.receive(|index| index == 0).await, (1, Some(1)));
+        assert_eq!(receivers.receive(|_| false).await, (0, Some(0)));
     }
 }
@@ -10,6 +10,7 @@ use dozer_types::tonic::async_trait;
 use dozer_types::types::{Schema, TableOperation};
 use std::collections::HashMap;
 use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
 use tokio::sync::mpsc::Sender;
 
 pub use dozer_types::types::PortHandle;
@@ -65,6 +66,11 @@ pub trait Source: Send + Sync + Debug {
         sender: Sender<(PortHandle, IngestionMessage)>,
         last_checkpoint: Option<OpIdentifier>,
     ) -> Result<(), BoxedError>;
+
+    /// For each output port, the index of the field holding the time of the source event, if there is one.
+    fn event_time_fields(&self) -> HashMap<PortHandle, usize> {
+        HashMap::new()
+    }
 }
 
 #[async_trait]
@@ -93,6 +99,16 @@ pub trait Processor: Send + Sync + Debug {
         op: TableOperation,
         fw: &mut dyn ProcessorChannelForwarder,
     ) -> Result<(), BoxedError>;
+
+    /// How long the processor waits for input before [`Processor::flush`] is called, if it holds back operations.
+    fn flush_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Emits the held back operations that are due. Called before every commit, and after `flush_interval` without input.
+    fn flush(&mut self, _fw: &mut dyn ProcessorChannelForwarder) -> Result<(), BoxedError> {
+        Ok(())
+    }
 }
 
 #[async_trait]
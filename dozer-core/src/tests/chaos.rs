@@ -0,0 +1,110 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use dozer_types::models::app_config::{
+    ChaosConfig, ConnectorDisconnect, FaultSchedule, SinkCommitError,
+};
+use dozer_types::node::NodeHandle;
+
+use crate::chaos::InjectedFault;
+use crate::errors::ExecutionError;
+use crate::executor::ExecutorOptions;
+use crate::tests::dag_base_run::NoopProcessorFactory;
+use crate::tests::sinks::{CountingSinkFactory, COUNTING_SINK_INPUT_PORT};
+use crate::tests::sources::{GeneratorSourceFactory, GENERATOR_SOURCE_OUTPUT_PORT};
+use crate::{Dag, Endpoint, DEFAULT_PORT_HANDLE};
+
+use super::run_dag_with_options;
+
+const COUNT: u64 = 1_000;
+
+/// A generator source feeding a counting sink, which stops the source once it has counted all the operations.
+fn generator_dag() -> (Dag, Arc<AtomicBool>) {
+    let mut dag = Dag::new();
+    let latch = Arc::new(AtomicBool::new(true));
+
+    let source_handle = NodeHandle::new(None, "generator".to_string());
+    let proc_handle = NodeHandle::new(Some(1), 1.to_string());
+    let sink_handle = NodeHandle::new(Some(1), 2.to_string());
+
+    dag.add_source(
+        source_handle.clone(),
+        Box::new(GeneratorSourceFactory::new(COUNT, latch.clone(), false)),
+    );
+    dag.add_processor(proc_handle.clone(), Box::new(NoopProcessorFactory {}));
+    dag.add_sink(
+        sink_handle.clone(),
+        Box::new(CountingSinkFactory::new(COUNT, latch.clone())),
+    );
+
+    dag.connect(
+        Endpoint::new(source_handle, GENERATOR_SOURCE_OUTPUT_PORT),
+        Endpoint::new(proc_handle.clone(), DEFAULT_PORT_HANDLE),
+    )
+    .unwrap();
+    dag.connect(
+        Endpoint::new(proc_handle, DEFAULT_PORT_HANDLE),
+        Endpoint::new(sink_handle, COUNTING_SINK_INPUT_PORT),
+    )
+    .unwrap();
+
+    (dag, latch)
+}
+
+fn options(chaos: ChaosConfig) -> ExecutorOptions {
+    ExecutorOptions {
+        error_threshold: None,
+        chaos: Some(chaos),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_run_dag_after_injected_connector_disconnect() {
+    let chaos = ChaosConfig {
+        connector_disconnects: vec![ConnectorDisconnect {
+            connection: Some("generator".to_string()),
+            schedule: FaultSchedule {
+                after: COUNT / 2,
+                every: None,
+                times: None,
+            },
+        }],
+        ..Default::default()
+    };
+    let (dag, latch) = generator_dag();
+    let Err(ExecutionError::Source(e)) = run_dag_with_options(dag, options(chaos)) else {
+        panic!("the pipeline must stop on a disconnect");
+    };
+    assert!(e.downcast_ref::<InjectedFault>().is_some());
+    assert!(
+        latch.load(Ordering::Relaxed),
+        "the sink didn't get all operations"
+    );
+
+    // The pipeline restarted after the disconnect runs to completion.
+    let (dag, latch) = generator_dag();
+    run_dag_with_options(dag, Default::default()).unwrap();
+    assert!(!latch.load(Ordering::Relaxed));
+}
+
+#[test]
+fn test_run_dag_through_injected_sink_commit_errors() {
+    let chaos = ChaosConfig {
+        sink_commit_errors: vec![SinkCommitError {
+            sink: None,
+            schedule: FaultSchedule {
+                after: 0,
+                every: Some(2),
+                times: None,
+            },
+        }],
+        ..Default::default()
+    };
+    let (dag, latch) = generator_dag();
+    run_dag_with_options(dag, options(chaos)).unwrap();
+    assert!(
+        !latch.load(Ordering::Relaxed),
+        "the sink got all operations despite the failed commits"
+    );
+}
@@ -3,9 +3,15 @@ use std::sync::Arc;
 use futures::future::pending;
 use tokio::runtime::{self, Runtime};
 
-use crate::{errors::ExecutionError, executor::DagExecutor, Dag};
+use crate::{
+    errors::ExecutionError,
+    executor::{DagExecutor, ExecutorOptions},
+    Dag,
+};
 
 mod app;
+#[cfg(feature = "chaos")]
+mod chaos;
 mod checkpoint_ns;
 mod dag_base_create_errors;
 mod dag_base_errors;
@@ -26,10 +32,14 @@ fn create_test_runtime() -> Arc<Runtime> {
 }
 
 fn run_dag(dag: Dag) -> Result<(), ExecutionError> {
+    run_dag_with_options(dag, Default::default())
+}
+
+fn run_dag_with_options(dag: Dag, options: ExecutorOptions) -> Result<(), ExecutionError> {
     let runtime = create_test_runtime();
     let runtime_clone = runtime.clone();
     let handle = runtime.block_on(async move {
-        DagExecutor::new(dag, Default::default())
+        DagExecutor::new(dag, options)
             .await?
             .start(pending::<()>(), Default::default(), runtime_clone)
             .await
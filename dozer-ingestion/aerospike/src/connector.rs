@@ -1,5 +1,7 @@
 use dozer_ingestion_connector::dozer_types::errors::internal::BoxedError;
+use dozer_ingestion_connector::dozer_types::errors::types::TypeError;
 use dozer_ingestion_connector::dozer_types::event::Event;
+use dozer_ingestion_connector::dozer_types::json_value_to_field;
 use dozer_ingestion_connector::dozer_types::log::{debug, error, info, trace, warn};
 use dozer_ingestion_connector::dozer_types::models::connection::AerospikeConnection;
 use dozer_ingestion_connector::dozer_types::models::ingestion_types::{
@@ -12,15 +14,13 @@ use dozer_ingestion_connector::tokio::sync::broadcast::error::RecvError;
 use dozer_ingestion_connector::tokio::sync::broadcast::Receiver;
 use dozer_ingestion_connector::tokio::sync::{mpsc, oneshot};
 use dozer_ingestion_connector::{
-    async_trait, dozer_types, tokio, Connector, Ingestor, SourceSchema, SourceSchemaResult,
-    TableIdentifier, TableInfo,
+    async_trait, dozer_types, retry::RetryPolicy, tokio, Connector, Ingestor, SourceSchema,
+    SourceSchemaResult, TableIdentifier, TableInfo,
 };
 use std::collections::HashMap;
 use std::ffi::{CStr, CString};
 use std::num::TryFromIntError;
 
-use std::time::Duration;
-
 use dozer_ingestion_connector::dozer_types::serde::Deserialize;
 
 use actix_web::dev::Server;
@@ -110,11 +110,14 @@ pub enum AerospikeConnectorError {
     #[error("Schema not found: {0}")]
     SchemaNotFound(String),
 
-    #[error("Failed parsing timestamp: {0}")]
-    TimestampParsingError(#[from] dozer_ingestion_connector::dozer_types::chrono::ParseError),
+    #[error("Invalid value: {0}")]
+    InvalidValue(#[from] TypeError),
 
     #[error("Key is neither string or int")]
     KeyNotSupported(Value),
+
+    #[error("Failed to rewind the replication of namespace {0} to datacenter {1}")]
+    RewindFailed(String, String),
 }
 
 #[derive(Deserialize, Debug)]
@@ -141,6 +144,7 @@ pub struct AerospikeConnector {
     pub config: AerospikeConnection,
     node_handle: NodeHandle,
     event_receiver: Receiver<Event>,
+    retry_policy: RetryPolicy,
 }
 
 impl AerospikeConnector {
@@ -153,9 +157,15 @@ impl AerospikeConnector {
             config,
             node_handle,
             event_receiver,
+            retry_policy: RetryPolicy::default(),
         }
     }
 
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
     fn start_server(&self, server_state: ServerState) -> Result<Server, AerospikeConnectorError> {
         let address = format!(
             "{}:{}",
@@ -498,6 +508,7 @@ impl Connector for AerospikeConnector {
                                         },
                                         nullable: true,
                                         source: Default::default(),
+                                        annotations: Default::default(),
                                     })
                                     .collect(),
                                 primary_index,
@@ -595,13 +606,17 @@ impl Connector for AerospikeConnector {
                 client.info(&request, &mut response).map_err(Box::new)?;
             }
 
+            let mut backoff = self.retry_policy.backoff();
             loop {
                 if self.rewind(&client, &dc_name, &namespace).await? {
                     info!("Aerospike replication configuration set successfully");
                     break;
                 } else {
-                    warn!("Aerospike replication configuration set failed");
-                    tokio::time::sleep(Duration::from_secs(3)).await;
+                    let delay = backoff.next_delay().ok_or_else(|| {
+                        AerospikeConnectorError::RewindFailed(namespace.clone(), dc_name.clone())
+                    })?;
+                    warn!("Aerospike replication configuration set failed, retrying in {delay:?}");
+                    tokio::time::sleep(delay).await;
                 }
             }
         }
@@ -823,37 +838,28 @@ pub(crate) fn map_value_to_field(
                         .ok_or(AerospikeConnectorError::InvalidDate(days))?;
                     Ok(Field::Date(date))
                 }
-                typ => Err(AerospikeConnectorError::UnsupportedType(typ)),
+                typ => Ok(json_value_to_field(Value::Number(v), typ, true)?),
             }
         }
-        Value::String(s) => {
-            match typ {
-                FieldType::UInt => Ok(Field::UInt(s.as_str().parse()?)),
-                FieldType::U128 => Ok(Field::U128(s.as_str().parse()?)),
-                FieldType::Int => Ok(Field::Int(s.as_str().parse()?)),
-                FieldType::I128 => Ok(Field::I128(s.as_str().parse()?)),
-                FieldType::Float => Ok(Field::Float(OrderedFloat(s.parse()?))),
-                FieldType::Boolean => Ok(Field::Boolean(s == "true" || s == "1")),
-                FieldType::String => Ok(Field::String(s)),
-                FieldType::Text => Ok(Field::Text(s)),
-                FieldType::Timestamp => Ok(Field::Timestamp(DateTime::parse_from_rfc3339(&s)?)),
-                FieldType::Date => {
-                    // TODO: decide on the format of the date
-
-                    Err(AerospikeConnectorError::UnsupportedType(typ))
-                }
-                FieldType::Binary => {
-                    let bytes = BASE64_STANDARD.decode(s.as_bytes())?;
-                    Ok(Field::Binary(bytes))
-                }
-                typ => Err(AerospikeConnectorError::UnsupportedType(typ)),
+        Value::String(s) => match typ {
+            FieldType::UInt
+            | FieldType::U128
+            | FieldType::Int
+            | FieldType::I128
+            | FieldType::Float => Ok(Field::from_str(&s, typ, false)?),
+            FieldType::Boolean => Ok(Field::Boolean(s == "true" || s == "1")),
+            FieldType::Binary => {
+                let bytes = BASE64_STANDARD.decode(s.as_bytes())?;
+                Ok(Field::Binary(bytes))
             }
-        }
-        Value::Object(_) | Value::Array(_) => {
+            typ => Ok(json_value_to_field(Value::String(s), typ, true)?),
+        },
+        Value::Object(_) | Value::Array(_) if typ != FieldType::Json => {
             Err(AerospikeConnectorError::UnsupportedTypeForFieldType {
                 bin_type: bin_type.to_string(),
                 field_type: typ,
             })
         }
+        value => Ok(json_value_to_field(value, typ, true)?),
     }
 }
@@ -1,6 +1,8 @@
 use crate::connector::map_value_to_field;
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
+use dozer_ingestion_connector::dozer_types::chrono::NaiveDate;
+use dozer_ingestion_connector::dozer_types::json_types::json_from_str;
 use dozer_ingestion_connector::dozer_types::ordered_float::OrderedFloat;
 use dozer_ingestion_connector::dozer_types::rust_decimal::Decimal;
 use dozer_ingestion_connector::dozer_types::serde_json::{json, Value};
@@ -133,4 +135,24 @@ pub fn test_type_conversion() {
         FieldType::Binary,
         Field::Binary(vec![52, 57])
     );
+    test_conversion!(
+        "str",
+        json!("2024-02-29"),
+        FieldType::Date,
+        Field::Date(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())
+    );
+    test_conversion!(
+        "number",
+        json!(50),
+        FieldType::Decimal,
+        Field::Decimal(Decimal::from(50))
+    );
+    test_conversion!(
+        "map",
+        json!({"a": 51}),
+        FieldType::Json,
+        Field::Json(json_from_str(r#"{"a": 51}"#).unwrap())
+    );
+    assert!(map_value_to_field("str", json!(""), FieldType::Int).is_err());
+    assert!(map_value_to_field("map", json!({"a": 52}), FieldType::String).is_err());
 }
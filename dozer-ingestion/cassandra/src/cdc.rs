@@ -0,0 +1,390 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    time::Duration,
+};
+
+use dozer_ingestion_connector::{
+    dozer_types::{
+        log::debug,
+        models::ingestion_types::{IngestionMessage, TransactionInfo},
+        types::{Field, FieldType, Operation, Record},
+    },
+    futures::{stream, StreamExt, TryStreamExt},
+    tokio::time::sleep,
+    Ingestor,
+};
+use scylla::{frame::response::result::CqlValue, Session};
+use uuid::Uuid;
+
+use crate::{conversion::cql_to_field, schema::quote, CassandraError};
+
+/// Values of the `cdc$operation` column of Scylla CDC log tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CdcOperation {
+    PreImage,
+    Update,
+    Insert,
+    RowDelete,
+    PartitionDelete,
+    RangeDelete,
+    PostImage,
+}
+
+impl CdcOperation {
+    pub fn from_i8(operation: i8) -> Option<Self> {
+        match operation {
+            0 => Some(CdcOperation::PreImage),
+            1 => Some(CdcOperation::Update),
+            2 => Some(CdcOperation::Insert),
+            3 => Some(CdcOperation::RowDelete),
+            4 => Some(CdcOperation::PartitionDelete),
+            5..=8 => Some(CdcOperation::RangeDelete),
+            9 => Some(CdcOperation::PostImage),
+            _ => None,
+        }
+    }
+}
+
+/// A change read from a CDC log.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    Operation(Operation),
+    /// The deletion of all rows with this partition key.
+    PartitionDelete(Vec<Field>),
+}
+
+/// A record with only the primary key values set.
+fn pk_only(primary_index: &[usize], record: &Record) -> Record {
+    let mut values = vec![Field::Null; record.values.len()];
+    for index in primary_index {
+        values[*index] = record.values[*index].clone();
+    }
+    Record::new(values)
+}
+
+/// Turns the rows of a CDC log stream into changes.
+///
+/// Inserts and updates only carry the written columns, so they are remembered until the
+/// post-image of the same row, written with the same `cdc$time`, provides the complete record.
+#[derive(Debug)]
+pub struct ChangeAssembler {
+    primary_index: Vec<usize>,
+    partition_key_len: usize,
+    time: Option<Uuid>,
+    pending: HashMap<Vec<Field>, CdcOperation>,
+}
+
+impl ChangeAssembler {
+    pub fn new(primary_index: Vec<usize>, partition_key_len: usize) -> Self {
+        Self {
+            primary_index,
+            partition_key_len,
+            time: None,
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Range deletes can't be mapped to rows, so the reader must reject them.
+    pub fn process(
+        &mut self,
+        time: Uuid,
+        operation: CdcOperation,
+        record: Record,
+    ) -> Option<Change> {
+        if self.time != Some(time) {
+            self.time = Some(time);
+            self.pending.clear();
+        }
+        let mut key = record.get_fields_by_indexes(&self.primary_index);
+        let operation = match operation {
+            CdcOperation::Insert | CdcOperation::Update => {
+                self.pending.insert(key, operation);
+                return None;
+            }
+            CdcOperation::PostImage => match self.pending.remove(&key)? {
+                CdcOperation::Insert => Operation::Insert { new: record },
+                _ => Operation::Update {
+                    old: pk_only(&self.primary_index, &record),
+                    new: record,
+                },
+            },
+            CdcOperation::RowDelete => Operation::Delete { old: record },
+            CdcOperation::PartitionDelete => {
+                key.truncate(self.partition_key_len);
+                return Some(Change::PartitionDelete(key));
+            }
+            CdcOperation::RangeDelete | CdcOperation::PreImage => return None,
+        };
+        Some(Change::Operation(operation))
+    }
+}
+
+/// The primary keys of the rows of a table that have been ingested.
+///
+/// Cassandra writes are upserts, and the changes made while the snapshot runs are read again from the
+/// CDC log, so a write is only an insert if its key is new. Deletes of unknown rows are dropped, and
+/// partition deletes become deletes of the known rows of the partition.
+#[derive(Debug, Default)]
+pub struct KnownKeys {
+    /// Primary keys start with the partition key, so the rows of a partition are contiguous.
+    keys: BTreeSet<Vec<Field>>,
+}
+
+impl KnownKeys {
+    pub fn insert(&mut self, key: Vec<Field>) {
+        self.keys.insert(key);
+    }
+
+    pub fn apply(&mut self, table: &CdcTable, change: Change) -> Vec<Operation> {
+        let primary_index = table.primary_index.as_slice();
+        match change {
+            Change::Operation(Operation::Insert { new } | Operation::Update { new, .. }) => {
+                if self.keys.insert(new.get_fields_by_indexes(primary_index)) {
+                    vec![Operation::Insert { new }]
+                } else {
+                    vec![Operation::Update {
+                        old: pk_only(primary_index, &new),
+                        new,
+                    }]
+                }
+            }
+            Change::Operation(Operation::Delete { old }) => {
+                if self.keys.remove(&old.get_fields_by_indexes(primary_index)) {
+                    vec![Operation::Delete { old }]
+                } else {
+                    vec![]
+                }
+            }
+            Change::Operation(Operation::BatchInsert { new }) => new
+                .into_iter()
+                .flat_map(|new| self.apply(table, Change::Operation(Operation::Insert { new })))
+                .collect(),
+            Change::PartitionDelete(partition_key) => {
+                let keys = self
+                    .keys
+                    .range(partition_key.clone()..)
+                    .take_while(|key| key.starts_with(&partition_key))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                keys.into_iter()
+                    .map(|key| {
+                        self.keys.remove(&key);
+                        let mut values = vec![Field::Null; table.columns.len()];
+                        for (index, value) in primary_index.iter().zip(key) {
+                            values[*index] = value;
+                        }
+                        Operation::Delete {
+                            old: Record::new(values),
+                        }
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// A table whose CDC log is polled.
+#[derive(Debug, Clone)]
+pub struct CdcTable {
+    pub table_index: usize,
+    pub cdc_log_name: String,
+    pub columns: Vec<String>,
+    pub types: Vec<FieldType>,
+    pub primary_index: Vec<usize>,
+    pub partition_key_len: usize,
+}
+
+impl CdcTable {
+    fn query(&self) -> String {
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| quote(column))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "SELECT \"cdc$time\", \"cdc$operation\", {columns} FROM {} \
+             WHERE \"cdc$stream_id\" = ? AND \"cdc$time\" > ?",
+            self.cdc_log_name
+        )
+    }
+}
+
+/// The server's current time, as a timeuuid. Used as the starting point of CDC.
+pub async fn now(session: &Session) -> Result<Uuid, CassandraError> {
+    let (now,) = session
+        .query_iter("SELECT now() FROM system.local", ())
+        .await?
+        .into_typed::<(Uuid,)>()
+        .try_next()
+        .await?
+        .ok_or(CassandraError::UnexpectedCdcValue("now()"))?;
+    Ok(now)
+}
+
+/// The stream ids of the latest CDC generation.
+async fn current_streams(session: &Session) -> Result<Vec<Vec<u8>>, CassandraError> {
+    let mut latest = None;
+    let mut timestamps = session
+        .query_iter(
+            "SELECT time FROM system_distributed.cdc_generation_timestamps WHERE key = 'timestamps'",
+            (),
+        )
+        .await?;
+    while let Some(row) = timestamps.try_next().await? {
+        match row.columns.into_iter().next().flatten() {
+            Some(CqlValue::Timestamp(time)) => {
+                latest = latest.max(Some(time.num_milliseconds()));
+            }
+            _ => return Err(CassandraError::UnexpectedCdcValue("generation timestamp")),
+        }
+    }
+    let Some(latest) = latest else {
+        return Ok(vec![]);
+    };
+
+    let mut streams = vec![];
+    // Timestamps are bound as literals, which avoids depending on the driver's timestamp type.
+    let mut descriptions = session
+        .query_iter(
+            format!("SELECT streams FROM system_distributed.cdc_streams_descriptions_v2 WHERE time = {latest}"),
+            (),
+        )
+        .await?;
+    while let Some(row) = descriptions.try_next().await? {
+        match row.columns.into_iter().next().flatten() {
+            Some(CqlValue::Set(ids)) | Some(CqlValue::List(ids)) => {
+                for id in ids {
+                    match id {
+                        CqlValue::Blob(id) => streams.push(id),
+                        _ => return Err(CassandraError::UnexpectedCdcValue("stream id")),
+                    }
+                }
+            }
+            _ => return Err(CassandraError::UnexpectedCdcValue("stream ids")),
+        }
+    }
+    Ok(streams)
+}
+
+/// Reads the changes of one stream written after `after`.
+/// Returns the changes and the `cdc$time` of the last row read.
+async fn read_stream(
+    session: &Session,
+    table: &CdcTable,
+    stream_id: &[u8],
+    after: Uuid,
+) -> Result<(Vec<Change>, Option<Uuid>), CassandraError> {
+    let mut rows = session
+        .query_iter(table.query(), (stream_id.to_vec(), after))
+        .await?;
+    let mut assembler = ChangeAssembler::new(table.primary_index.clone(), table.partition_key_len);
+    let mut changes = vec![];
+    let mut last_time = None;
+    while let Some(row) = rows.try_next().await? {
+        let mut columns = row.columns.into_iter();
+        let Some(Some(CqlValue::Timeuuid(time))) = columns.next() else {
+            return Err(CassandraError::UnexpectedCdcValue("cdc$time"));
+        };
+        let Some(Some(CqlValue::TinyInt(operation))) = columns.next() else {
+            return Err(CassandraError::UnexpectedCdcValue("cdc$operation"));
+        };
+        last_time = Some(time);
+        let Some(operation) = CdcOperation::from_i8(operation) else {
+            debug!("Ignoring unknown CDC operation {operation}");
+            continue;
+        };
+        if operation == CdcOperation::RangeDelete {
+            return Err(CassandraError::RangeDelete(table.cdc_log_name.clone()));
+        }
+        let values = columns
+            .zip(&table.types)
+            .map(|(value, typ)| cql_to_field(value, *typ))
+            .collect::<Result<Vec<_>, _>>()?;
+        changes.extend(assembler.process(time, operation, Record::new(values)));
+    }
+    Ok((changes, last_time))
+}
+
+/// Polls the CDC log tables of `tables` for changes written after `start`, forever.
+///
+/// `known_keys` holds the keys of the snapshotted rows of each table, by table index.
+pub async fn poll(
+    session: &Session,
+    tables: &[CdcTable],
+    mut known_keys: HashMap<usize, KnownKeys>,
+    start: Uuid,
+    interval: Duration,
+    parallelism: u32,
+    ingestor: &Ingestor,
+) -> Result<(), CassandraError> {
+    let mut positions = HashMap::<(usize, Vec<u8>), Uuid>::new();
+    let mut streams = current_streams(session).await?;
+    loop {
+        let latest_streams = current_streams(session).await?;
+        // On a generation change, the previous streams are read one last time before switching.
+        let mut polled_streams = streams.clone();
+        if latest_streams != streams {
+            polled_streams.extend(latest_streams.iter().cloned());
+            streams = latest_streams;
+        }
+
+        let reads = tables.iter().enumerate().flat_map(|(index, table)| {
+            polled_streams
+                .iter()
+                .map(move |stream_id| (index, table, stream_id))
+        });
+        let results = stream::iter(reads)
+            .map(|(index, table, stream_id)| {
+                let after = positions
+                    .get(&(index, stream_id.clone()))
+                    .copied()
+                    .unwrap_or(start);
+                async move {
+                    read_stream(session, table, stream_id, after)
+                        .await
+                        .map(|result| (index, table, stream_id, result))
+                }
+            })
+            .buffer_unordered(parallelism.max(1) as usize)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        for (index, table, stream_id, (changes, last_time)) in results {
+            if let Some(last_time) = last_time {
+                positions.insert((index, stream_id.clone()), last_time);
+            }
+            let keys = known_keys.entry(table.table_index).or_default();
+            let ops = changes
+                .into_iter()
+                .flat_map(|change| keys.apply(table, change))
+                .collect::<Vec<_>>();
+            for op in ops {
+                if ingestor
+                    .handle_message(IngestionMessage::OperationEvent {
+                        table_index: table.table_index,
+                        op,
+                        id: None,
+                    })
+                    .await
+                    .is_err()
+                {
+                    // If the ingestor is closed, we should stop
+                    return Ok(());
+                }
+            }
+        }
+        positions.retain(|(_, stream_id), _| streams.contains(stream_id));
+
+        if ingestor
+            .handle_message(IngestionMessage::TransactionInfo(TransactionInfo::Commit {
+                id: None,
+            }))
+            .await
+            .is_err()
+        {
+            return Ok(());
+        }
+        sleep(interval).await;
+    }
+}
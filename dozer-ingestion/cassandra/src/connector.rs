@@ -0,0 +1,299 @@
+use dozer_ingestion_connector::{
+    async_trait,
+    dozer_types::{
+        errors::internal::BoxedError,
+        log::info,
+        models::ingestion_types::{
+            default_cassandra_cdc_poll_interval, default_cassandra_snapshot_parallelism,
+            CassandraConfig, IngestionMessage, TransactionInfo,
+        },
+        node::OpIdentifier,
+        types::FieldType,
+    },
+    utils::TableNotFound,
+    Connector, Ingestor, SourceSchemaResult, TableIdentifier, TableInfo,
+};
+use scylla::{Session, SessionBuilder};
+
+use crate::{
+    cdc::{self, CdcTable},
+    schema::{self, map_type, TableMetadata},
+    snapshot::{snapshot, SnapshotTable},
+    CassandraError,
+};
+
+#[derive(Debug)]
+pub struct CassandraConnector {
+    name: String,
+    config: CassandraConfig,
+}
+
+impl CassandraConnector {
+    pub fn new(name: String, config: CassandraConfig) -> Self {
+        Self { name, config }
+    }
+
+    async fn session(&self) -> Result<Session, CassandraError> {
+        let mut builder = SessionBuilder::new().known_nodes(&self.config.nodes);
+        if let Some(user) = &self.config.user {
+            builder = builder.user(user, self.config.password.as_deref().unwrap_or_default());
+        }
+        Ok(builder.build().await?)
+    }
+
+    /// Reads the metadata of the tables, failing if any of them doesn't exist.
+    async fn describe_tables<'a>(
+        &self,
+        session: &Session,
+        tables: impl Iterator<Item = (&'a Option<String>, &'a str)> + Clone,
+    ) -> Result<Vec<TableMetadata>, BoxedError> {
+        let names = tables
+            .clone()
+            .map(|(_, name)| name.to_string())
+            .collect::<Vec<_>>();
+        let metadata = schema::describe_tables(session, &self.config.keyspace, &names).await?;
+        let mut result = vec![];
+        for (schema, name) in tables {
+            let table = schema
+                .as_ref()
+                .map_or(true, |schema| *schema == self.config.keyspace)
+                .then(|| metadata.get(name).cloned())
+                .flatten()
+                .ok_or_else(|| TableNotFound {
+                    schema: schema.clone(),
+                    name: name.to_string(),
+                })?;
+            result.push(table);
+        }
+        Ok(result)
+    }
+}
+
+fn identifier_refs(
+    tables: &[TableIdentifier],
+) -> impl Iterator<Item = (&Option<String>, &str)> + Clone {
+    tables
+        .iter()
+        .map(|table| (&table.schema, table.name.as_str()))
+}
+
+fn info_refs(tables: &[TableInfo]) -> impl Iterator<Item = (&Option<String>, &str)> + Clone {
+    tables
+        .iter()
+        .map(|table| (&table.schema, table.name.as_str()))
+}
+
+#[async_trait]
+impl Connector for CassandraConnector {
+    fn types_mapping() -> Vec<(String, Option<FieldType>)>
+    where
+        Self: Sized,
+    {
+        [
+            "ascii",
+            "text",
+            "varchar",
+            "inet",
+            "uuid",
+            "timeuuid",
+            "boolean",
+            "tinyint",
+            "smallint",
+            "int",
+            "bigint",
+            "counter",
+            "varint",
+            "float",
+            "double",
+            "decimal",
+            "timestamp",
+            "date",
+            "time",
+            "duration",
+            "blob",
+            "list",
+            "set",
+            "map",
+            "tuple",
+        ]
+        .into_iter()
+        .map(|cql_type| (cql_type.to_string(), Some(map_type(cql_type))))
+        .collect()
+    }
+
+    async fn validate_connection(&mut self) -> Result<(), BoxedError> {
+        self.session().await?;
+        Ok(())
+    }
+
+    async fn list_tables(&mut self) -> Result<Vec<TableIdentifier>, BoxedError> {
+        let session = self.session().await?;
+        Ok(schema::list_tables(&session, &self.config.keyspace)
+            .await?
+            .into_iter()
+            .map(TableIdentifier::from_table_name)
+            .collect())
+    }
+
+    async fn validate_tables(&mut self, tables: &[TableIdentifier]) -> Result<(), BoxedError> {
+        let session = self.session().await?;
+        self.describe_tables(&session, identifier_refs(tables))
+            .await?;
+        Ok(())
+    }
+
+    async fn list_columns(
+        &mut self,
+        tables: Vec<TableIdentifier>,
+    ) -> Result<Vec<TableInfo>, BoxedError> {
+        let session = self.session().await?;
+        let metadata = self
+            .describe_tables(&session, identifier_refs(&tables))
+            .await?;
+        Ok(tables
+            .into_iter()
+            .zip(metadata)
+            .map(|(table, metadata)| TableInfo {
+                schema: table.schema,
+                name: table.name,
+                column_names: metadata
+                    .columns
+                    .into_iter()
+                    .map(|column| column.name)
+                    .collect(),
+                filter: None,
+            })
+            .collect())
+    }
+
+    async fn get_schemas(
+        &mut self,
+        table_infos: &[TableInfo],
+    ) -> Result<Vec<SourceSchemaResult>, BoxedError> {
+        let session = self.session().await?;
+        let metadata = self
+            .describe_tables(&session, info_refs(table_infos))
+            .await?;
+        Ok(table_infos
+            .iter()
+            .zip(metadata)
+            .map(|(table, metadata)| Ok(metadata.source_schema(&table.column_names)?))
+            .collect())
+    }
+
+    async fn serialize_state(&self) -> Result<Vec<u8>, BoxedError> {
+        Ok(vec![])
+    }
+
+    async fn start(
+        &mut self,
+        ingestor: &Ingestor,
+        tables: Vec<TableInfo>,
+        _last_checkpoint: Option<OpIdentifier>,
+    ) -> Result<(), BoxedError> {
+        let session = self.session().await?;
+        let metadata = self.describe_tables(&session, info_refs(&tables)).await?;
+
+        let mut snapshot_tables = vec![];
+        let mut cdc_tables = vec![];
+        for (table_index, (table, metadata)) in tables.iter().zip(metadata).enumerate() {
+            let types = table
+                .column_names
+                .iter()
+                .map(|name| Ok(map_type(&metadata.column(name)?.cql_type)))
+                .collect::<Result<Vec<_>, CassandraError>>()?;
+            let primary_index = metadata
+                .primary_key()
+                .map(|column| {
+                    table
+                        .column_names
+                        .iter()
+                        .position(|name| *name == column.name)
+                        .ok_or_else(|| {
+                            CassandraError::PrimaryKeyColumnNotSelected(
+                                metadata.name.clone(),
+                                column.name.clone(),
+                            )
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            snapshot_tables.push(SnapshotTable {
+                table_index,
+                qualified_name: metadata.qualified_name(),
+                partition_key: metadata
+                    .partition_key()
+                    .map(|column| column.name.clone())
+                    .collect(),
+                columns: table.column_names.clone(),
+                types: types.clone(),
+                primary_index: metadata.has_cdc_log.then(|| primary_index.clone()),
+            });
+            if metadata.has_cdc_log {
+                cdc_tables.push(CdcTable {
+                    table_index,
+                    cdc_log_name: metadata.cdc_log_name(),
+                    columns: table.column_names.clone(),
+                    types,
+                    primary_index,
+                    partition_key_len: metadata.partition_key().count(),
+                });
+            } else {
+                info!(
+                    "[{}] Table {} has no CDC log, only the snapshot will be ingested",
+                    self.name, metadata.name
+                );
+            }
+        }
+
+        let parallelism = self
+            .config
+            .snapshot_parallelism
+            .unwrap_or_else(default_cassandra_snapshot_parallelism);
+
+        // Changes are read from the time the snapshot starts, so that none is missed.
+        // Those that the snapshot already contains become updates, because their keys are known.
+        let cdc_start = cdc::now(&session).await?;
+        if ingestor
+            .handle_message(IngestionMessage::TransactionInfo(
+                TransactionInfo::SnapshottingStarted,
+            ))
+            .await
+            .is_err()
+        {
+            // If the ingestor is closed, we should stop
+            return Ok(());
+        }
+        let Some(known_keys) = snapshot(&session, &snapshot_tables, parallelism, ingestor).await?
+        else {
+            return Ok(());
+        };
+        if ingestor
+            .handle_message(IngestionMessage::TransactionInfo(
+                TransactionInfo::SnapshottingDone { id: None },
+            ))
+            .await
+            .is_err()
+        {
+            return Ok(());
+        }
+
+        if cdc_tables.is_empty() {
+            return Ok(());
+        }
+        let interval = self
+            .config
+            .cdc_poll_interval_seconds
+            .unwrap_or_else(default_cassandra_cdc_poll_interval);
+        cdc::poll(
+            &session,
+            &cdc_tables,
+            known_keys,
+            cdc_start,
+            interval,
+            parallelism,
+            ingestor,
+        )
+        .await
+        .map_err(Into::into)
+    }
+}
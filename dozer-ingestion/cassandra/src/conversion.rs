@@ -0,0 +1,158 @@
+use std::{str::FromStr, time::Duration};
+
+use dozer_ingestion_connector::dozer_types::{
+    chrono::{Days, NaiveDate, TimeZone, Utc},
+    json_types::serde_json_to_json_value,
+    ordered_float::OrderedFloat,
+    rust_decimal::Decimal,
+    serde_json::{self, Map, Value},
+    types::{DozerDuration, Field, FieldType, TimeUnit},
+};
+use scylla::frame::response::result::CqlValue;
+
+use crate::CassandraError;
+
+/// CQL `date`s are days since the epoch, shifted by 2^31 so that they are unsigned.
+const DATE_EPOCH_OFFSET: i64 = 1 << 31;
+const NANOS_PER_DAY: u64 = 86_400_000_000_000;
+
+pub fn cql_to_field(value: Option<CqlValue>, typ: FieldType) -> Result<Field, CassandraError> {
+    let Some(value) = value else {
+        return Ok(Field::Null);
+    };
+    if typ == FieldType::Json {
+        return serde_json_to_json_value(cql_to_json(value))
+            .map(Field::Json)
+            .map_err(|e| conversion_error(typ, e));
+    }
+    let field = match value {
+        CqlValue::Ascii(s) | CqlValue::Text(s) => Field::String(s),
+        CqlValue::Inet(ip) => Field::String(ip.to_string()),
+        CqlValue::Uuid(uuid) | CqlValue::Timeuuid(uuid) => Field::String(uuid.to_string()),
+        CqlValue::Boolean(b) => Field::Boolean(b),
+        CqlValue::TinyInt(i) => Field::Int(i.into()),
+        CqlValue::SmallInt(i) => Field::Int(i.into()),
+        CqlValue::Int(i) => Field::Int(i.into()),
+        CqlValue::BigInt(i) => Field::Int(i),
+        CqlValue::Counter(counter) => Field::Int(counter.0),
+        CqlValue::Varint(i) => {
+            Field::I128(i128::from_str(&i.to_string()).map_err(|_| conversion_error(typ, i))?)
+        }
+        CqlValue::Float(f) => Field::Float(OrderedFloat(f.into())),
+        CqlValue::Double(f) => Field::Float(OrderedFloat(f)),
+        CqlValue::Decimal(d) => Field::Decimal(
+            Decimal::from_str_exact(&d.to_string()).map_err(|_| conversion_error(typ, d))?,
+        ),
+        CqlValue::Timestamp(millis) => Field::Timestamp(
+            Utc.timestamp_millis_opt(millis.num_milliseconds())
+                .single()
+                .ok_or_else(|| conversion_error(typ, millis))?
+                .into(),
+        ),
+        CqlValue::Date(days) => {
+            Field::Date(date_from_cql(days).ok_or_else(|| conversion_error(typ, days))?)
+        }
+        CqlValue::Time(nanos) => Field::Duration(DozerDuration(
+            Duration::from_nanos(
+                nanos
+                    .num_nanoseconds()
+                    .and_then(|nanos| u64::try_from(nanos).ok())
+                    .ok_or_else(|| conversion_error(typ, nanos))?,
+            ),
+            TimeUnit::Nanoseconds,
+        )),
+        CqlValue::Duration(duration) => {
+            // Months have no fixed length, so only durations made of days and nanoseconds are supported.
+            let nanos = u64::try_from(duration.days)
+                .ok()
+                .filter(|_| duration.months == 0)
+                .and_then(|days| days.checked_mul(NANOS_PER_DAY))
+                .zip(u64::try_from(duration.nanoseconds).ok())
+                .and_then(|(days, nanos)| days.checked_add(nanos))
+                .ok_or_else(|| conversion_error(typ, format!("{duration:?}")))?;
+            Field::Duration(DozerDuration(
+                Duration::from_nanos(nanos),
+                TimeUnit::Nanoseconds,
+            ))
+        }
+        CqlValue::Blob(bytes) => Field::Binary(bytes),
+        CqlValue::Empty => Field::Null,
+        other => return Err(conversion_error(typ, format!("{other:?}"))),
+    };
+    Ok(field)
+}
+
+fn date_from_cql(days: u32) -> Option<NaiveDate> {
+    let days = days as i64 - DATE_EPOCH_OFFSET;
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)?;
+    if days >= 0 {
+        epoch.checked_add_days(Days::new(days as u64))
+    } else {
+        epoch.checked_sub_days(Days::new(days.unsigned_abs()))
+    }
+}
+
+fn conversion_error(typ: FieldType, value: impl ToString) -> CassandraError {
+    CassandraError::Conversion {
+        typ: typ.to_string(),
+        value: value.to_string(),
+    }
+}
+
+/// Converts any CQL value to JSON. Map keys are converted to their string representation.
+pub fn cql_to_json(value: CqlValue) -> Value {
+    match value {
+        CqlValue::Ascii(s) | CqlValue::Text(s) => Value::String(s),
+        CqlValue::Inet(ip) => Value::String(ip.to_string()),
+        CqlValue::Uuid(uuid) | CqlValue::Timeuuid(uuid) => Value::String(uuid.to_string()),
+        CqlValue::Boolean(b) => Value::Bool(b),
+        CqlValue::TinyInt(i) => i.into(),
+        CqlValue::SmallInt(i) => i.into(),
+        CqlValue::Int(i) => i.into(),
+        CqlValue::BigInt(i) => i.into(),
+        CqlValue::Counter(counter) => counter.0.into(),
+        CqlValue::Float(f) => f.into(),
+        CqlValue::Double(f) => f.into(),
+        CqlValue::Varint(i) => Value::String(i.to_string()),
+        CqlValue::Decimal(d) => Value::String(d.to_string()),
+        CqlValue::Timestamp(millis) => millis.num_milliseconds().into(),
+        CqlValue::Date(days) => {
+            date_from_cql(days).map_or(Value::Null, |date| Value::String(date.to_string()))
+        }
+        CqlValue::Time(nanos) => nanos.num_nanoseconds().into(),
+        CqlValue::Duration(duration) => serde_json::json!({
+            "months": duration.months,
+            "days": duration.days,
+            "nanoseconds": duration.nanoseconds,
+        }),
+        CqlValue::Blob(bytes) => bytes.into(),
+        CqlValue::Empty => Value::Null,
+        CqlValue::List(values) | CqlValue::Set(values) => {
+            Value::Array(values.into_iter().map(cql_to_json).collect())
+        }
+        CqlValue::Tuple(values) => Value::Array(
+            values
+                .into_iter()
+                .map(|value| value.map_or(Value::Null, cql_to_json))
+                .collect(),
+        ),
+        CqlValue::Map(entries) => Value::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| {
+                    let key = match cql_to_json(key) {
+                        Value::String(key) => key,
+                        key => key.to_string(),
+                    };
+                    (key, cql_to_json(value))
+                })
+                .collect::<Map<_, _>>(),
+        ),
+        CqlValue::UserDefinedType { fields, .. } => Value::Object(
+            fields
+                .into_iter()
+                .map(|(name, value)| (name, value.map_or(Value::Null, cql_to_json)))
+                .collect(),
+        ),
+    }
+}
@@ -0,0 +1,45 @@
+use dozer_ingestion_connector::dozer_types::thiserror::{self, Error};
+use scylla::transport::{
+    errors::{NewSessionError, QueryError},
+    iterator::NextRowError,
+};
+
+mod cdc;
+pub mod connector;
+mod conversion;
+mod schema;
+mod snapshot;
+#[cfg(test)]
+mod tests;
+
+pub use connector::CassandraConnector;
+
+#[derive(Error, Debug)]
+pub enum CassandraError {
+    #[error("cannot connect to cluster: {0}")]
+    NewSession(#[from] NewSessionError),
+
+    #[error("query failed: {0}")]
+    Query(#[from] QueryError),
+
+    #[error("cannot read row: {0}")]
+    NextRow(#[from] NextRowError),
+
+    #[error("column {1} not found in table {0}")]
+    ColumnNotFound(String, String),
+
+    #[error("primary key column {1} of table {0} must be selected")]
+    PrimaryKeyColumnNotSelected(String, String),
+
+    #[error("table {0} has CDC enabled without post-images. Enable them with `ALTER TABLE {0} WITH cdc = {{'enabled': true, 'postimage': true}}`")]
+    CdcPostimageDisabled(String),
+
+    #[error("cannot convert {value} to {typ}")]
+    Conversion { typ: String, value: String },
+
+    #[error("range delete in CDC log {0} can't be mapped to rows")]
+    RangeDelete(String),
+
+    #[error("unexpected {0} value in CDC log")]
+    UnexpectedCdcValue(&'static str),
+}
@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+
+use dozer_ingestion_connector::{
+    dozer_types::types::{FieldDefinition, FieldType, Schema, SourceDefinition},
+    futures::TryStreamExt,
+    CdcType, SourceSchema,
+};
+use scylla::Session;
+
+use crate::CassandraError;
+
+/// Scylla stores the changes of table `t` in table `t_scylla_cdc_log` of the same keyspace.
+pub const CDC_LOG_SUFFIX: &str = "_scylla_cdc_log";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColumnKind {
+    PartitionKey,
+    Clustering,
+    Regular,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnMetadata {
+    pub name: String,
+    pub kind: ColumnKind,
+    pub position: i32,
+    pub cql_type: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct TableMetadata {
+    pub keyspace: String,
+    pub name: String,
+    /// Partition key columns, then clustering columns, then the other columns.
+    pub columns: Vec<ColumnMetadata>,
+    /// Whether the table has a Scylla CDC log table.
+    pub has_cdc_log: bool,
+}
+
+impl TableMetadata {
+    pub fn column(&self, name: &str) -> Result<&ColumnMetadata, CassandraError> {
+        self.columns
+            .iter()
+            .find(|column| column.name == name)
+            .ok_or_else(|| CassandraError::ColumnNotFound(self.name.clone(), name.to_string()))
+    }
+
+    pub fn partition_key(&self) -> impl Iterator<Item = &ColumnMetadata> {
+        self.columns
+            .iter()
+            .filter(|column| column.kind == ColumnKind::PartitionKey)
+    }
+
+    /// The dozer primary key, made of the partition key and the clustering columns.
+    pub fn primary_key(&self) -> impl Iterator<Item = &ColumnMetadata> {
+        self.columns
+            .iter()
+            .filter(|column| column.kind != ColumnKind::Regular)
+    }
+
+    pub fn qualified_name(&self) -> String {
+        format!("{}.{}", quote(&self.keyspace), quote(&self.name))
+    }
+
+    pub fn cdc_log_name(&self) -> String {
+        format!(
+            "{}.{}",
+            quote(&self.keyspace),
+            quote(&format!("{}{CDC_LOG_SUFFIX}", self.name))
+        )
+    }
+
+    /// Maps the selected columns to a dozer schema, checking that the whole primary key is selected.
+    pub fn source_schema(&self, column_names: &[String]) -> Result<SourceSchema, CassandraError> {
+        let mut schema = Schema::new();
+        for name in column_names {
+            let column = self.column(name)?;
+            let is_primary_key = column.kind != ColumnKind::Regular;
+            schema.field(
+                FieldDefinition::new(
+                    column.name.clone(),
+                    map_type(&column.cql_type),
+                    !is_primary_key,
+                    SourceDefinition::Dynamic,
+                ),
+                is_primary_key,
+            );
+        }
+        if let Some(missing) = self
+            .primary_key()
+            .find(|column| !column_names.contains(&column.name))
+        {
+            return Err(CassandraError::PrimaryKeyColumnNotSelected(
+                self.name.clone(),
+                missing.name.clone(),
+            ));
+        }
+        let cdc_type = if self.has_cdc_log {
+            CdcType::OnlyPK
+        } else {
+            CdcType::Nothing
+        };
+        Ok(SourceSchema::new(schema, cdc_type))
+    }
+}
+
+/// Quotes an identifier so that case and special characters are preserved.
+pub fn quote(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+/// Maps a CQL type, as written in `system_schema.columns`, to a dozer field type.
+/// Collections, tuples and user defined types are ingested as JSON.
+pub fn map_type(cql_type: &str) -> FieldType {
+    match cql_type {
+        "ascii" | "text" | "varchar" | "inet" | "uuid" | "timeuuid" => FieldType::String,
+        "boolean" => FieldType::Boolean,
+        "tinyint" | "smallint" | "int" | "bigint" | "counter" => FieldType::Int,
+        "varint" => FieldType::I128,
+        "float" | "double" => FieldType::Float,
+        "decimal" => FieldType::Decimal,
+        "timestamp" => FieldType::Timestamp,
+        "date" => FieldType::Date,
+        "time" | "duration" => FieldType::Duration,
+        "blob" => FieldType::Binary,
+        _ => FieldType::Json,
+    }
+}
+
+/// All tables of the keyspace, including the CDC log tables.
+async fn keyspace_tables(session: &Session, keyspace: &str) -> Result<Vec<String>, CassandraError> {
+    Ok(session
+        .query_iter(
+            "SELECT table_name FROM system_schema.tables WHERE keyspace_name = ?",
+            (keyspace,),
+        )
+        .await?
+        .into_typed::<(String,)>()
+        .map_ok(|(name,)| name)
+        .try_collect()
+        .await?)
+}
+
+pub async fn list_tables(session: &Session, keyspace: &str) -> Result<Vec<String>, CassandraError> {
+    Ok(keyspace_tables(session, keyspace)
+        .await?
+        .into_iter()
+        .filter(|name| !name.ends_with(CDC_LOG_SUFFIX))
+        .collect())
+}
+
+/// Reads the metadata of `tables`. Tables that don't exist are missing from the result.
+pub async fn describe_tables(
+    session: &Session,
+    keyspace: &str,
+    tables: &[String],
+) -> Result<HashMap<String, TableMetadata>, CassandraError> {
+    let existing = keyspace_tables(session, keyspace).await?;
+
+    let mut result = HashMap::new();
+    for table in tables {
+        if !existing.contains(table) {
+            continue;
+        }
+        let mut columns = session
+            .query_iter(
+                "SELECT column_name, kind, position, type FROM system_schema.columns \
+                 WHERE keyspace_name = ? AND table_name = ?",
+                (keyspace, table.as_str()),
+            )
+            .await?
+            .into_typed::<(String, String, i32, String)>()
+            .map_ok(|(name, kind, position, cql_type)| ColumnMetadata {
+                name,
+                kind: match kind.as_str() {
+                    "partition_key" => ColumnKind::PartitionKey,
+                    "clustering" => ColumnKind::Clustering,
+                    _ => ColumnKind::Regular,
+                },
+                position,
+                cql_type,
+            })
+            .try_collect::<Vec<_>>()
+            .await?;
+        sort_columns(&mut columns);
+
+        let has_cdc_log = existing.contains(&format!("{table}{CDC_LOG_SUFFIX}"));
+        if has_cdc_log && !has_postimage(session, keyspace, table).await? {
+            return Err(CassandraError::CdcPostimageDisabled(format!(
+                "{keyspace}.{table}"
+            )));
+        }
+
+        result.insert(
+            table.clone(),
+            TableMetadata {
+                keyspace: keyspace.to_string(),
+                name: table.clone(),
+                columns,
+                has_cdc_log,
+            },
+        );
+    }
+    Ok(result)
+}
+
+pub fn sort_columns(columns: &mut [ColumnMetadata]) {
+    columns.sort_by(|a, b| (a.kind, a.position, &a.name).cmp(&(b.kind, b.position, &b.name)));
+}
+
+/// Update events in the CDC log only contain the changed columns, so post-images are needed to
+/// build complete records.
+async fn has_postimage(
+    session: &Session,
+    keyspace: &str,
+    table: &str,
+) -> Result<bool, CassandraError> {
+    let options = session
+        .query_iter(
+            "SELECT cdc FROM system_schema.scylla_tables WHERE keyspace_name = ? AND table_name = ?",
+            (keyspace, table),
+        )
+        .await?
+        .into_typed::<(Option<HashMap<String, String>>,)>()
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(options.into_iter().any(|(options,)| {
+        options
+            .and_then(|options| options.get("postimage").cloned())
+            .as_deref()
+            == Some("true")
+    }))
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use dozer_ingestion_connector::{
+    dozer_types::{
+        models::ingestion_types::IngestionMessage,
+        types::{Field, FieldType, Operation, Record},
+    },
+    futures::{stream, StreamExt, TryStreamExt},
+    Ingestor,
+};
+use scylla::Session;
+
+use crate::{cdc::KnownKeys, conversion::cql_to_field, schema::quote, CassandraError};
+
+/// A table to snapshot, with the columns to read and their dozer types.
+#[derive(Debug, Clone)]
+pub struct SnapshotTable {
+    pub table_index: usize,
+    pub qualified_name: String,
+    pub partition_key: Vec<String>,
+    pub columns: Vec<String>,
+    pub types: Vec<FieldType>,
+    /// The positions of the primary key columns, if the keys of the rows must be known to read the CDC log.
+    pub primary_index: Option<Vec<usize>>,
+}
+
+impl SnapshotTable {
+    fn query(&self) -> String {
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| quote(column))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let token = format!(
+            "token({})",
+            self.partition_key
+                .iter()
+                .map(|column| quote(column))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        format!(
+            "SELECT {columns} FROM {} WHERE {token} >= ? AND {token} <= ?",
+            self.qualified_name
+        )
+    }
+}
+
+/// Splits the Murmur3 token ring into `count` contiguous, inclusive ranges.
+pub fn token_ranges(count: u32) -> Vec<(i64, i64)> {
+    let count = count.max(1) as i128;
+    let min = i64::MIN as i128;
+    let span = i64::MAX as i128 - min + 1;
+    (0..count)
+        .map(|i| {
+            let start = min + span * i / count;
+            let end = min + span * (i + 1) / count - 1;
+            (start as i64, end as i64)
+        })
+        .collect()
+}
+
+/// Reads all rows of `tables`, scanning up to `parallelism` token ranges at a time.
+///
+/// Returns the keys of the rows read of the tables with a `primary_index`, by table index,
+/// or `None` if the ingestor was closed before the snapshot finished.
+pub async fn snapshot(
+    session: &Session,
+    tables: &[SnapshotTable],
+    parallelism: u32,
+    ingestor: &Ingestor,
+) -> Result<Option<HashMap<usize, KnownKeys>>, CassandraError> {
+    let scans = tables.iter().flat_map(|table| {
+        token_ranges(parallelism)
+            .into_iter()
+            .map(move |range| (table, range))
+    });
+    let scanned = stream::iter(scans)
+        .map(|(table, range)| async move {
+            scan_range(session, table, range, ingestor)
+                .await
+                .map(|keys| keys.map(|keys| (table.table_index, keys)))
+        })
+        .buffer_unordered(parallelism.max(1) as usize)
+        .try_collect::<Vec<_>>()
+        .await?;
+    let mut known_keys = HashMap::<usize, KnownKeys>::new();
+    for scanned in scanned {
+        let Some((table_index, keys)) = scanned else {
+            return Ok(None);
+        };
+        let table_keys = known_keys.entry(table_index).or_default();
+        for key in keys {
+            table_keys.insert(key);
+        }
+    }
+    Ok(Some(known_keys))
+}
+
+/// Returns the keys of the rows read if `table` has a `primary_index`, or `None` if the ingestor was closed.
+async fn scan_range(
+    session: &Session,
+    table: &SnapshotTable,
+    (start, end): (i64, i64),
+    ingestor: &Ingestor,
+) -> Result<Option<Vec<Vec<Field>>>, CassandraError> {
+    let mut keys = vec![];
+    let mut rows = session.query_iter(table.query(), (start, end)).await?;
+    while let Some(row) = rows.try_next().await? {
+        let values = row
+            .columns
+            .into_iter()
+            .zip(&table.types)
+            .map(|(value, typ)| cql_to_field(value, *typ))
+            .collect::<Result<Vec<_>, _>>()?;
+        let record = Record::new(values);
+        if let Some(primary_index) = &table.primary_index {
+            keys.push(record.get_fields_by_indexes(primary_index));
+        }
+        if ingestor
+            .handle_message(IngestionMessage::OperationEvent {
+                table_index: table.table_index,
+                op: Operation::Insert { new: record },
+                id: None,
+            })
+            .await
+            .is_err()
+        {
+            // If the ingestor is closed, we should stop
+            return Ok(None);
+        }
+    }
+    Ok(Some(keys))
+}
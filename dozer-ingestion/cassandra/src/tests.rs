@@ -0,0 +1,228 @@
+use dozer_ingestion_connector::dozer_types::{
+    chrono::NaiveDate,
+    types::{Field, FieldType, Operation, Record},
+};
+use scylla::frame::response::result::CqlValue;
+use uuid::Uuid;
+
+use crate::{
+    cdc::{CdcOperation, CdcTable, Change, ChangeAssembler, KnownKeys},
+    conversion::cql_to_field,
+    schema::{map_type, sort_columns, ColumnKind, ColumnMetadata, TableMetadata},
+    snapshot::token_ranges,
+    CassandraError,
+};
+
+#[test]
+fn test_token_ranges_cover_the_ring() {
+    let ranges = token_ranges(3);
+    assert_eq!(ranges.len(), 3);
+    assert_eq!(ranges[0].0, i64::MIN);
+    assert_eq!(ranges[2].1, i64::MAX);
+    for window in ranges.windows(2) {
+        assert_eq!(window[0].1 + 1, window[1].0);
+    }
+    assert_eq!(token_ranges(0), vec![(i64::MIN, i64::MAX)]);
+}
+
+#[test]
+fn test_map_type() {
+    assert_eq!(map_type("text"), FieldType::String);
+    assert_eq!(map_type("bigint"), FieldType::Int);
+    assert_eq!(map_type("varint"), FieldType::I128);
+    assert_eq!(map_type("timestamp"), FieldType::Timestamp);
+    assert_eq!(map_type("list<int>"), FieldType::Json);
+    assert_eq!(map_type("frozen<address>"), FieldType::Json);
+}
+
+#[test]
+fn test_cql_to_field() {
+    assert_eq!(
+        cql_to_field(Some(CqlValue::Int(42)), FieldType::Int).unwrap(),
+        Field::Int(42)
+    );
+    assert_eq!(cql_to_field(None, FieldType::Int).unwrap(), Field::Null);
+    assert_eq!(
+        cql_to_field(Some(CqlValue::Date(1 << 31)), FieldType::Date).unwrap(),
+        Field::Date(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap())
+    );
+    assert_eq!(
+        cql_to_field(Some(CqlValue::Date((1 << 31) - 1)), FieldType::Date).unwrap(),
+        Field::Date(NaiveDate::from_ymd_opt(1969, 12, 31).unwrap())
+    );
+    assert!(matches!(
+        cql_to_field(
+            Some(CqlValue::List(vec![CqlValue::Int(1), CqlValue::Int(2)])),
+            FieldType::Json
+        )
+        .unwrap(),
+        Field::Json(_)
+    ));
+}
+
+fn column(name: &str, kind: ColumnKind, position: i32) -> ColumnMetadata {
+    ColumnMetadata {
+        name: name.to_string(),
+        kind,
+        position,
+        cql_type: "int".to_string(),
+    }
+}
+
+#[test]
+fn test_composite_primary_key() {
+    let mut columns = vec![
+        column("value", ColumnKind::Regular, -1),
+        column("ts", ColumnKind::Clustering, 1),
+        column("bucket", ColumnKind::Clustering, 0),
+        column("id", ColumnKind::PartitionKey, 0),
+    ];
+    sort_columns(&mut columns);
+    let table = TableMetadata {
+        keyspace: "ks".to_string(),
+        name: "events".to_string(),
+        columns,
+        has_cdc_log: true,
+    };
+    let names = table
+        .columns
+        .iter()
+        .map(|column| column.name.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["id", "bucket", "ts", "value"]);
+
+    let schema = table.source_schema(&names).unwrap();
+    assert_eq!(schema.schema.primary_index, vec![0, 1, 2]);
+
+    assert!(matches!(
+        table.source_schema(&["id".to_string(), "value".to_string()]),
+        Err(CassandraError::PrimaryKeyColumnNotSelected(_, column)) if column == "bucket"
+    ));
+}
+
+fn record(id: i64, value: Option<i64>) -> Record {
+    Record::new(vec![Field::Int(id), value.map_or(Field::Null, Field::Int)])
+}
+
+#[test]
+fn test_change_assembler() {
+    let mut assembler = ChangeAssembler::new(vec![0], 1);
+    let t1 = Uuid::from_u128(1);
+    let t2 = Uuid::from_u128(2);
+    let t3 = Uuid::from_u128(3);
+
+    assert_eq!(
+        assembler.process(t1, CdcOperation::Insert, record(1, Some(10))),
+        None
+    );
+    assert_eq!(
+        assembler.process(t1, CdcOperation::PostImage, record(1, Some(10))),
+        Some(Change::Operation(Operation::Insert {
+            new: record(1, Some(10))
+        }))
+    );
+
+    assert_eq!(
+        assembler.process(t2, CdcOperation::Update, record(1, Some(11))),
+        None
+    );
+    assert_eq!(
+        assembler.process(t2, CdcOperation::PostImage, record(1, Some(11))),
+        Some(Change::Operation(Operation::Update {
+            old: record(1, None),
+            new: record(1, Some(11))
+        }))
+    );
+
+    assert_eq!(
+        assembler.process(t3, CdcOperation::RowDelete, record(1, None)),
+        Some(Change::Operation(Operation::Delete {
+            old: record(1, None)
+        }))
+    );
+    assert_eq!(
+        assembler.process(t3, CdcOperation::PartitionDelete, record(2, None)),
+        Some(Change::PartitionDelete(vec![Field::Int(2)]))
+    );
+}
+
+#[test]
+fn test_known_keys() {
+    // Partition key `id`, clustering column `ts`.
+    let table = CdcTable {
+        table_index: 0,
+        cdc_log_name: "ks.events_scylla_cdc_log".to_string(),
+        columns: vec!["id".to_string(), "ts".to_string(), "value".to_string()],
+        types: vec![FieldType::Int; 3],
+        primary_index: vec![0, 1],
+        partition_key_len: 1,
+    };
+    let row = |id, ts, value: Option<i64>| {
+        Record::new(vec![
+            Field::Int(id),
+            Field::Int(ts),
+            value.map_or(Field::Null, Field::Int),
+        ])
+    };
+    let write = |record| Change::Operation(Operation::Insert { new: record });
+    let mut keys = KnownKeys::default();
+    // Snapshotted.
+    keys.insert(vec![Field::Int(1), Field::Int(1)]);
+
+    // A change the snapshot already contains is read again.
+    assert_eq!(
+        keys.apply(&table, write(row(1, 1, Some(10)))),
+        vec![Operation::Update {
+            old: row(1, 1, None),
+            new: row(1, 1, Some(10))
+        }]
+    );
+    assert_eq!(
+        keys.apply(&table, write(row(1, 2, Some(20)))),
+        vec![Operation::Insert {
+            new: row(1, 2, Some(20))
+        }]
+    );
+    assert_eq!(
+        keys.apply(&table, write(row(2, 1, Some(30)))),
+        vec![Operation::Insert {
+            new: row(2, 1, Some(30))
+        }]
+    );
+    // Deleted before the snapshot read it.
+    assert_eq!(
+        keys.apply(
+            &table,
+            Change::Operation(Operation::Delete {
+                old: row(3, 1, None)
+            })
+        ),
+        vec![]
+    );
+
+    assert_eq!(
+        keys.apply(&table, Change::PartitionDelete(vec![Field::Int(1)])),
+        vec![
+            Operation::Delete {
+                old: row(1, 1, None)
+            },
+            Operation::Delete {
+                old: row(1, 2, None)
+            }
+        ]
+    );
+    assert_eq!(
+        keys.apply(&table, write(row(1, 1, Some(11)))),
+        vec![Operation::Insert {
+            new: row(1, 1, Some(11))
+        }]
+    );
+}
+
+#[test]
+fn test_cdc_operation() {
+    assert_eq!(CdcOperation::from_i8(2), Some(CdcOperation::Insert));
+    assert_eq!(CdcOperation::from_i8(7), Some(CdcOperation::RangeDelete));
+    assert_eq!(CdcOperation::from_i8(9), Some(CdcOperation::PostImage));
+    assert_eq!(CdcOperation::from_i8(10), None);
+}
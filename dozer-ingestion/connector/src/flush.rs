@@ -0,0 +1,77 @@
+use dozer_types::event::Event;
+use dozer_types::node::{NodeHandle, OpIdentifier, SourceState};
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+
+/// Follows the flushes of the sinks, so a connector can acknowledge messages to its source only once they are durable.
+#[derive(Debug)]
+pub struct FlushWaiter {
+    node_handle: NodeHandle,
+    event_receiver: Receiver<Event>,
+    flushed: Option<OpIdentifier>,
+}
+
+impl FlushWaiter {
+    pub fn new(node_handle: NodeHandle, event_receiver: Receiver<Event>) -> Self {
+        Self {
+            node_handle,
+            event_receiver,
+            flushed: None,
+        }
+    }
+
+    /// Waits until a sink has flushed the commit `id` of the source, or a later one. Returns `false` if the pipeline is down.
+    pub async fn wait_flushed(&mut self, id: OpIdentifier) -> bool {
+        while self.flushed < Some(id) {
+            let event = match self.event_receiver.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Closed) => return false,
+                // A later flush covers the missed ones.
+                Err(RecvError::Lagged(_)) => continue,
+            };
+            let Event::SinkFlushed { epoch, .. } = event;
+            if let Some(SourceState::Restartable(flushed)) =
+                epoch.common_info.source_states.get(&self.node_handle)
+            {
+                self.flushed = self.flushed.max(Some(*flushed));
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc, time::SystemTime};
+
+    use dozer_types::epoch::Epoch;
+    use tokio::sync::broadcast;
+
+    use super::*;
+
+    fn flushed(node: &NodeHandle, txid: u64) -> Event {
+        let states = HashMap::from([(
+            node.clone(),
+            SourceState::Restartable(OpIdentifier::new(txid, 0)),
+        )]);
+        Event::SinkFlushed {
+            node: NodeHandle::new(None, "sink".to_string()),
+            epoch: Epoch::new(0, Arc::new(states), SystemTime::now()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flush_waiter() {
+        let node = NodeHandle::new(None, "source".to_string());
+        let (sender, receiver) = broadcast::channel(16);
+        let mut waiter = FlushWaiter::new(node.clone(), receiver);
+
+        sender.send(flushed(&node, 1)).unwrap();
+        sender.send(flushed(&node, 3)).unwrap();
+        assert!(waiter.wait_flushed(OpIdentifier::new(2, 0)).await);
+        // Already covered by the flush of 3.
+        assert!(waiter.wait_flushed(OpIdentifier::new(3, 0)).await);
+
+        drop(sender);
+        assert!(!waiter.wait_flushed(OpIdentifier::new(4, 0)).await);
+    }
+}
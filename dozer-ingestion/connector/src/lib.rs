@@ -7,14 +7,18 @@ use dozer_types::serde::{Deserialize, Serialize};
 pub use dozer_types::tonic::async_trait;
 use dozer_types::types::{FieldType, Schema};
 
+pub mod flush;
 mod ingestor;
+pub mod pool;
 pub mod schema_parser;
+pub mod ssh_tunnel;
 pub mod test_util;
 pub mod utils;
 
 pub use ingestor::{IngestionConfig, IngestionIterator, Ingestor};
 
 pub use dozer_types;
+pub use dozer_types::retry;
 pub use futures;
 pub use tokio;
 
@@ -85,6 +89,16 @@ pub trait Connector: Send + Sync + Debug {
         table_infos: &[TableInfo],
     ) -> Result<Vec<SourceSchemaResult>, BoxedError>;
 
+    /// Whether the connector pushes the `filter` of its tables down to the source.
+    fn supports_filter(&self) -> bool {
+        false
+    }
+
+    /// Whether every operation the connector sends has an `OpIdentifier` that no other operation has.
+    fn sends_op_identifiers(&self) -> bool {
+        false
+    }
+
     /// Lists all tables and columns and gets the schema for each table.
     async fn list_all_schemas(
         &mut self,
@@ -142,4 +156,7 @@ pub struct TableInfo {
     pub name: String,
     /// The column names to be mapped.
     pub column_names: Vec<String>,
+    /// SQL condition the rows must satisfy, pushed down to the source. Only set for connectors that [`Connector::supports_filter`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
 }
@@ -0,0 +1,117 @@
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// A pool of at most `max_size` connections, opened on demand and reused once returned.
+///
+/// Connectors that snapshot tables in parallel use it to bound the number of connections they open to the source.
+#[derive(Debug)]
+pub struct ConnectionPool<T> {
+    idle: Arc<Mutex<Vec<T>>>,
+    permits: Arc<Semaphore>,
+}
+
+impl<T> Clone for ConnectionPool<T> {
+    fn clone(&self) -> Self {
+        Self {
+            idle: self.idle.clone(),
+            permits: self.permits.clone(),
+        }
+    }
+}
+
+impl<T> ConnectionPool<T> {
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            idle: Arc::new(Mutex::new(vec![])),
+            permits: Arc::new(Semaphore::new(max_size.max(1))),
+        }
+    }
+
+    /// Waits until fewer than `max_size` connections are in use, then returns an idle connection, or opens a new one with `connect`.
+    pub async fn get<F, Fut, E>(&self, connect: F) -> Result<PooledConnection<T>, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let permit = self
+            .permits
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("semaphore is never closed");
+        let idle = self.idle.lock().unwrap().pop();
+        let connection = match idle {
+            Some(connection) => connection,
+            None => connect().await?,
+        };
+        Ok(PooledConnection {
+            connection: Some(connection),
+            idle: self.idle.clone(),
+            _permit: permit,
+        })
+    }
+}
+
+/// A connection taken from a [`ConnectionPool`], returned to it when dropped.
+#[derive(Debug)]
+pub struct PooledConnection<T> {
+    connection: Option<T>,
+    idle: Arc<Mutex<Vec<T>>>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl<T> PooledConnection<T> {
+    /// Closes the connection instead of returning it to the pool, e.g. after an error left it in an unknown state.
+    pub fn discard(mut self) {
+        self.connection = None;
+    }
+}
+
+impl<T> Deref for PooledConnection<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.connection
+            .as_ref()
+            .expect("connection is only taken on drop")
+    }
+}
+
+impl<T> DerefMut for PooledConnection<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.connection
+            .as_mut()
+            .expect("connection is only taken on drop")
+    }
+}
+
+impl<T> Drop for PooledConnection<T> {
+    fn drop(&mut self) {
+        if let Some(connection) = self.connection.take() {
+            self.idle.lock().unwrap().push(connection);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_connection_pool_reuses_connections() {
+        let pool = ConnectionPool::new(2);
+        let connect = |id| move || async move { Ok::<_, ()>(id) };
+
+        let first = pool.get(connect(1)).await.unwrap();
+        let second = pool.get(connect(2)).await.unwrap();
+        assert_eq!((*first, *second), (1, 2));
+        drop(first);
+        second.discard();
+
+        assert_eq!(*pool.get(connect(3)).await.unwrap(), 1);
+        assert_eq!(*pool.get(connect(4)).await.unwrap(), 1);
+    }
+}
@@ -0,0 +1,198 @@
+use std::io;
+use std::net::{Ipv4Addr, Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use dozer_types::log::{debug, error, warn};
+use dozer_types::models::ingestion_types::{default_ssh_port, SshTunnelConfig};
+use dozer_types::thiserror::{self, Error};
+
+#[derive(Debug, Error)]
+pub enum SshTunnelError {
+    #[error("Failed to listen on a local port: {0}")]
+    Listen(#[source] io::Error),
+}
+
+/// A local port forwarded to a remote host through an SSH bastion host.
+///
+/// Every connection to the local port is forwarded by its own `ssh -W` process, so a connection broken by a failed
+/// `ssh` process doesn't affect the next ones. The tunnel stops accepting connections when dropped.
+#[derive(Debug)]
+pub struct SshTunnel {
+    local_port: u16,
+    closed: Arc<AtomicBool>,
+}
+
+impl SshTunnel {
+    /// Forwards a local port to `remote_host:remote_port`, as seen from the bastion host.
+    ///
+    /// The bastion host is only connected to when the local port is, so this doesn't block.
+    pub fn open(
+        config: &SshTunnelConfig,
+        remote_host: &str,
+        remote_port: u16,
+    ) -> Result<Self, SshTunnelError> {
+        let config = config.clone();
+        let remote = format!("{remote_host}:{remote_port}");
+        let tunnel = Self::forward(move || ssh_command(&config, &remote))?;
+        debug!(
+            "Forwarding port {} to {remote_host}:{remote_port}",
+            tunnel.local_port
+        );
+        Ok(tunnel)
+    }
+
+    /// Forwards every connection to a local port to the standard input and output of a process created by `command`.
+    fn forward(command: impl Fn() -> Command + Send + 'static) -> Result<Self, SshTunnelError> {
+        // The listener is kept, so no other process can take the port.
+        let listener =
+            TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).map_err(SshTunnelError::Listen)?;
+        let local_port = listener
+            .local_addr()
+            .map_err(SshTunnelError::Listen)?
+            .port();
+        let closed = Arc::new(AtomicBool::new(false));
+
+        let closed_clone = closed.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                if closed_clone.load(Ordering::Relaxed) {
+                    break;
+                }
+                match stream {
+                    Ok(stream) => {
+                        let command = command();
+                        std::thread::spawn(move || forward_connection(stream, command));
+                    }
+                    Err(e) => warn!("Failed to accept a connection to port {local_port}: {e}"),
+                }
+            }
+        });
+
+        Ok(Self { local_port, closed })
+    }
+
+    pub fn local_port(&self) -> u16 {
+        self.local_port
+    }
+}
+
+impl Drop for SshTunnel {
+    fn drop(&mut self) {
+        self.closed.store(true, Ordering::Relaxed);
+        // Wakes up the accepting thread, so it sees the tunnel is closed.
+        let _ = TcpStream::connect(SocketAddr::from((Ipv4Addr::LOCALHOST, self.local_port)));
+    }
+}
+
+/// The host key of the bastion host must be in the known hosts file, so a connection is never made to an unknown host.
+fn ssh_command(config: &SshTunnelConfig, remote: &str) -> Command {
+    let mut command = Command::new("ssh");
+    command
+        .arg("-W")
+        .arg(remote)
+        .arg("-p")
+        .arg(config.port.unwrap_or_else(default_ssh_port).to_string())
+        .arg("-i")
+        .arg(&config.private_key_path)
+        .args(["-o", "BatchMode=yes", "-o", "StrictHostKeyChecking=yes"])
+        .args(["-o", "ServerAliveInterval=30"]);
+    if let Some(path) = &config.known_hosts_path {
+        command.arg("-o").arg(format!("UserKnownHostsFile={path}"));
+    }
+    command.arg(format!("{}@{}", config.user, config.host));
+    command
+}
+
+fn forward_connection(stream: TcpStream, mut command: Command) {
+    let mut child = match command.stdin(Stdio::piped()).stdout(Stdio::piped()).spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            error!("Failed to run {:?}: {e}", command.get_program());
+            return;
+        }
+    };
+    let (Some(mut stdin), Some(mut stdout)) = (child.stdin.take(), child.stdout.take()) else {
+        unreachable!("stdin and stdout are piped");
+    };
+    let mut reader = match stream.try_clone() {
+        Ok(reader) => reader,
+        Err(e) => {
+            error!("Failed to forward a connection: {e}");
+            let _ = child.kill();
+            let _ = child.wait();
+            return;
+        }
+    };
+    let mut writer = stream;
+
+    // Closing the process' standard input when the client disconnects makes it exit.
+    let upload = std::thread::spawn(move || {
+        let _ = io::copy(&mut reader, &mut stdin);
+    });
+    let _ = io::copy(&mut stdout, &mut writer);
+    let _ = writer.shutdown(Shutdown::Both);
+    let _ = upload.join();
+
+    let _ = child.kill();
+    match child.wait() {
+        Ok(status) if !status.success() => {
+            warn!("{:?} exited with {status}", command.get_program())
+        }
+        Err(e) => warn!("Failed to wait for {:?}: {e}", command.get_program()),
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use super::*;
+
+    fn echo(tunnel: &SshTunnel, message: &[u8]) -> Vec<u8> {
+        let mut stream =
+            TcpStream::connect(SocketAddr::from((Ipv4Addr::LOCALHOST, tunnel.local_port())))
+                .unwrap();
+        stream.write_all(message).unwrap();
+        stream.shutdown(Shutdown::Write).unwrap();
+        let mut response = vec![];
+        stream.read_to_end(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn test_forward_connections_to_process() {
+        let tunnel = SshTunnel::forward(|| Command::new("cat")).unwrap();
+        assert_eq!(echo(&tunnel, b"first"), b"first");
+        // Every connection gets a new process.
+        assert_eq!(echo(&tunnel, b"second"), b"second");
+    }
+
+    #[test]
+    fn test_failed_process_does_not_close_tunnel() {
+        let tunnel = SshTunnel::forward(|| Command::new("false")).unwrap();
+        assert_eq!(echo(&tunnel, b"message"), b"");
+        assert_eq!(echo(&tunnel, b"message"), b"");
+    }
+
+    #[test]
+    fn test_ssh_command_checks_host_key() {
+        let config = SshTunnelConfig {
+            host: "bastion".to_string(),
+            port: None,
+            user: "dozer".to_string(),
+            private_key_path: "id_ed25519".to_string(),
+            known_hosts_path: None,
+        };
+        let command = ssh_command(&config, "db:5432");
+        let args = command
+            .get_args()
+            .map(|arg| arg.to_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(&args[..2], ["-W", "db:5432"]);
+        assert!(args.contains(&"StrictHostKeyChecking=yes"));
+        assert_eq!(args.last(), Some(&"dozer@bastion"));
+    }
+}
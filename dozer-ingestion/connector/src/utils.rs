@@ -30,15 +30,18 @@ pub fn warn_dropped_primary_index(table_name: &str) {
     );
 }
 
+/// Retries `$operation` while it fails with a network error, backing off as the [`RetryPolicy`](crate::retry::RetryPolicy)
+/// `$retry_policy` says, and runs `$reconnect` before each retry.
 #[macro_export]
 macro_rules! retry_on_network_failure {
-    ($description:expr, $operation:expr, $network_error_predicate:expr $(, $reconnect:expr)? $(,)?) =>
+    ($retry_policy:expr, $description:expr, $operation:expr, $network_error_predicate:expr $(, $reconnect:expr)? $(,)?) =>
         {
             $crate::retry_on_network_failure_impl!(
+                $retry_policy,
                 $description,
                 $operation,
                 $network_error_predicate,
-                tokio::time::sleep(RETRY_INTERVAL).await
+                delay => tokio::time::sleep(delay).await
                 $(, $reconnect)?
             )
         }
@@ -46,13 +49,14 @@ macro_rules! retry_on_network_failure {
 
 #[macro_export]
 macro_rules! blocking_retry_on_network_failure {
-    ($description:expr, $operation:expr, $network_error_predicate:expr $(, $reconnect:expr)? $(,)?) =>
+    ($retry_policy:expr, $description:expr, $operation:expr, $network_error_predicate:expr $(, $reconnect:expr)? $(,)?) =>
         {
             $crate::retry_on_network_failure_impl!(
+                $retry_policy,
                 $description,
                 $operation,
                 $network_error_predicate,
-                std::thread::sleep(RETRY_INTERVAL)
+                delay => std::thread::sleep(delay)
                 $(, $reconnect)?
             )
         }
@@ -60,24 +64,32 @@ macro_rules! blocking_retry_on_network_failure {
 
 #[macro_export]
 macro_rules! retry_on_network_failure_impl {
-    ($description:expr, $operation:expr, $network_error_predicate:expr, $sleep:expr $(, $reconnect:expr)? $(,)?) => {
+    ($retry_policy:expr, $description:expr, $operation:expr, $network_error_predicate:expr, $delay:ident => $sleep:expr $(, $reconnect:expr)? $(,)?) => {{
+        let mut backoff = $crate::retry::RetryPolicy::backoff(&$retry_policy);
         loop {
             match $operation {
                 ok @ Ok(_) => break ok,
                 Err(err) => {
-                    if ($network_error_predicate)(&err) {
-                        const RETRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+                    if !($network_error_predicate)(&err) {
+                        break Err(err);
+                    }
+                    let Some($delay) = backoff.next_delay() else {
                         dozer_types::log::error!(
-                            "network error during {}: {err:?}. retrying in {RETRY_INTERVAL:?}...",
-                            $description
+                            "network error during {}: {err:?}. giving up after {} retries",
+                            $description,
+                            backoff.retries()
                         );
-                        $sleep;
-                        $($reconnect)?
-                    } else {
                         break Err(err);
-                    }
+                    };
+                    dozer_types::log::error!(
+                        "network error during {}: {err:?}. retrying in {:?}...",
+                        $description,
+                        $delay
+                    );
+                    $sleep;
+                    $($reconnect)?
                 }
             }
         }
-    };
+    }};
 }
@@ -88,6 +88,7 @@ impl Connector for DeltaLakeConnector {
                 schema: None,
                 name: table_info.name,
                 column_names,
+                filter: None,
             })
         }
         Ok(result)
@@ -197,6 +197,7 @@ impl Connector for EthLogConnector {
                 schema: table.schema,
                 name: table.name,
                 column_names,
+                filter: None,
             })
         }
         Ok(result)
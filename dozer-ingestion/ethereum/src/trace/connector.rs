@@ -7,6 +7,8 @@ use dozer_ingestion_connector::{
         node::OpIdentifier,
         types::FieldType,
     },
+    retry::RetryPolicy,
+    tokio,
     utils::TableNotFound,
     CdcType, Connector, Ingestor, SourceSchema, SourceSchemaResult, TableIdentifier, TableInfo,
 };
@@ -82,6 +84,7 @@ impl Connector for EthTraceConnector {
                 schema: table.schema,
                 name: table.name,
                 column_names,
+                filter: None,
             })
         }
         Ok(result)
@@ -141,45 +144,45 @@ pub async fn run(
         config.batch_size.unwrap_or_else(default_batch_size),
     );
 
-    let mut errors: Vec<BoxedError> = vec![];
+    let retry_policy = RetryPolicy {
+        max_retries: Some(RETRIES as u32 - 1),
+        ..Default::default()
+    };
     for batch in batch_iter {
-        for retry in 0..RETRIES {
-            if retry >= RETRIES - 1 {
-                error!("Eth Trace connector failed more than {RETRIES} times");
-                return Err(errors.pop().unwrap());
-            }
-
-            let res = get_block_traces(client_tuple.clone(), batch).await;
-            match res {
-                Ok(arr) => {
-                    for result in arr {
-                        let ops = map_trace_to_ops(&result.result);
-
-                        for op in ops {
-                            if ingestor
-                                .handle_message(IngestionMessage::OperationEvent {
-                                    table_index: 0, // We have only one table
-                                    op,
-                                    id: None,
-                                })
-                                .await
-                                .is_err()
-                            {
-                                // If receiving end is closed, exit
-                                return Ok(());
-                            }
-                        }
-                    }
-
-                    break;
-                }
+        let mut backoff = retry_policy.backoff();
+        let arr = loop {
+            match get_block_traces(client_tuple.clone(), batch).await {
+                Ok(arr) => break arr,
                 Err(e) => {
-                    errors.push(e);
+                    let Some(delay) = backoff.next_delay() else {
+                        error!("Eth Trace connector failed {RETRIES} times");
+                        return Err(e);
+                    };
                     error!(
-                        "Failed to get traces for block {}.. Attempt {}",
+                        "Failed to get traces for block {}: {e}. Attempt {}, retrying in {delay:?}",
                         batch.0,
-                        retry + 1
+                        backoff.retries()
                     );
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        };
+
+        for result in arr {
+            let ops = map_trace_to_ops(&result.result);
+
+            for op in ops {
+                if ingestor
+                    .handle_message(IngestionMessage::OperationEvent {
+                        table_index: 0, // We have only one table
+                        op,
+                        id: None,
+                    })
+                    .await
+                    .is_err()
+                {
+                    // If receiving end is closed, exit
+                    return Ok(());
                 }
             }
         }
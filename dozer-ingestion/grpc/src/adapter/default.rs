@@ -1,14 +1,11 @@
 use dozer_ingestion_connector::{
     async_trait,
     dozer_types::{
-        self, chrono,
         grpc_types::{self, ingest::IngestRequest},
-        json_types::prost_to_json_value,
         models::ingestion_types::{IngestionMessage, TransactionInfo},
-        ordered_float::OrderedFloat,
-        rust_decimal::Decimal,
+        proto_record::grpc_value_to_field,
         serde_json,
-        types::{Field, Operation, Record, Schema},
+        types::{Operation, Record, Schema},
     },
     Ingestor, SourceSchema,
 };
@@ -100,7 +97,6 @@ pub async fn handle_message(
 }
 
 fn map_record(rec: Vec<grpc_types::types::Value>, schema: &Schema) -> Result<Record, Error> {
-    let mut values: Vec<Field> = vec![];
     let values_count = rec.len();
     let schema_fields_count = schema.fields.len();
     if values_count != schema_fields_count {
@@ -110,90 +106,17 @@ fn map_record(rec: Vec<grpc_types::types::Value>, schema: &Schema) -> Result<Rec
         });
     }
 
-    for (idx, v) in rec.into_iter().enumerate() {
-        let typ = schema.fields[idx].typ;
-
-        let val = v.value.map(|value| match (value, typ) {
-            (
-                grpc_types::types::value::Value::UintValue(a),
-                dozer_types::types::FieldType::UInt,
-            ) => Ok(dozer_types::types::Field::UInt(a)),
-
-            (grpc_types::types::value::Value::IntValue(a), dozer_types::types::FieldType::Int) => {
-                Ok(dozer_types::types::Field::Int(a))
-            }
-
-            (
-                grpc_types::types::value::Value::FloatValue(a),
-                dozer_types::types::FieldType::Float,
-            ) => Ok(dozer_types::types::Field::Float(OrderedFloat(a))),
-
-            (
-                grpc_types::types::value::Value::BoolValue(a),
-                dozer_types::types::FieldType::Boolean,
-            ) => Ok(dozer_types::types::Field::Boolean(a)),
-
-            (
-                grpc_types::types::value::Value::StringValue(a),
-                dozer_types::types::FieldType::String,
-            ) => Ok(dozer_types::types::Field::String(a)),
-
-            (
-                grpc_types::types::value::Value::BytesValue(a),
-                dozer_types::types::FieldType::Binary,
-            ) => Ok(dozer_types::types::Field::Binary(a)),
-            (
-                grpc_types::types::value::Value::StringValue(a),
-                dozer_types::types::FieldType::Text,
-            ) => Ok(dozer_types::types::Field::Text(a)),
-            (
-                grpc_types::types::value::Value::JsonValue(a),
-                dozer_types::types::FieldType::Json,
-            ) => Ok(dozer_types::types::Field::Json(prost_to_json_value(a))),
-            (
-                grpc_types::types::value::Value::TimestampValue(a),
-                dozer_types::types::FieldType::Timestamp,
-            ) => Ok(
-                chrono::NaiveDateTime::from_timestamp_opt(a.seconds, a.nanos as u32)
-                    .map(|t| {
-                        dozer_types::types::Field::Timestamp(
-                            chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(
-                                t,
-                                chrono::Utc,
-                            )
-                            .into(),
-                        )
-                    })
-                    .unwrap_or(dozer_types::types::Field::Null),
-            ),
-            (
-                grpc_types::types::value::Value::DecimalValue(d),
-                dozer_types::types::FieldType::Decimal,
-            ) => Ok(dozer_types::types::Field::Decimal(Decimal::from_parts(
-                d.lo, d.mid, d.hi, d.negative, d.scale,
-            ))),
-            (
-                grpc_types::types::value::Value::DateValue(_),
-                dozer_types::types::FieldType::UInt,
-            )
-            | (
-                grpc_types::types::value::Value::DateValue(_),
-                dozer_types::types::FieldType::Date,
-            )
-            | (
-                grpc_types::types::value::Value::PointValue(_),
-                dozer_types::types::FieldType::Point,
-            ) => Ok(dozer_types::types::Field::Null),
-            (a, b) => Err(Error::FieldTypeMismatch {
-                index: idx,
-                value: a,
-                field_type: b,
-            }),
-        });
-        values.push(val.unwrap_or(Ok(dozer_types::types::Field::Null))?);
-    }
-    Ok(Record {
-        values,
-        lifetime: None,
-    })
+    let values = rec
+        .into_iter()
+        .zip(&schema.fields)
+        .enumerate()
+        .map(|(index, (value, field))| {
+            grpc_value_to_field(value.clone(), field.typ).ok_or_else(|| Error::FieldTypeMismatch {
+                index,
+                value: value.value,
+                field_type: field.typ,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Record::new(values))
 }
@@ -152,6 +152,7 @@ where
                     schema: table.schema,
                     name: table.name,
                     column_names,
+                    filter: None,
                 })
             } else {
                 return Err(TableNotFound {
@@ -9,7 +9,7 @@ use dozer_ingestion_connector::{
         tonic::{self, Streaming},
     },
     futures::StreamExt,
-    tokio, Ingestor, TableInfo,
+    Ingestor, TableInfo,
 };
 
 use super::adapter::{GrpcIngestMessage, GrpcIngestor, IngestAdapter};
@@ -70,40 +70,7 @@ where
         &self,
         req: tonic::Request<Streaming<IngestRequest>>,
     ) -> Result<tonic::Response<IngestResponse>, tonic::Status> {
-        let mut in_stream = req.into_inner();
-
-        let adapter = self.adapter.clone();
-        let ingestor = self.ingestor;
-        let table_names = self.tables.clone();
-        let seq_no = tokio::spawn(async move {
-            let mut seq_no = 0;
-            while let Some(result) = in_stream.next().await {
-                if let Ok(req) = result {
-                    let Some(table_index) = table_names
-                        .iter()
-                        .position(|table| table.name == req.schema_name)
-                    else {
-                        error!("schema name not found: {}", req.schema_name);
-                        break;
-                    };
-
-                    seq_no = req.seq_no;
-                    let res = adapter
-                        .handle_message(table_index, GrpcIngestMessage::Default(req), ingestor)
-                        .await;
-                    if let Err(e) = res {
-                        error!("ingestion stream insertion errored: {:#?}", e);
-                        break;
-                    }
-                } else {
-                    error!("ingestion stream errored: {:#?}", result);
-                    break;
-                }
-            }
-            seq_no
-        })
-        .await
-        .map_err(|e| tonic::Status::internal(format!("ingestion stream error: {e}")))?;
+        let seq_no = self.ingest_requests(req.into_inner()).await?;
         Ok(tonic::Response::new(IngestResponse { seq_no }))
     }
 
@@ -134,40 +101,92 @@ where
         &self,
         req: tonic::Request<Streaming<IngestArrowRequest>>,
     ) -> Result<tonic::Response<IngestResponse>, tonic::Status> {
-        let mut in_stream = req.into_inner();
-
-        let adapter = self.adapter.clone();
-        let ingestor = self.ingestor;
-        let table_names = self.tables.clone();
-        let seq_no = tokio::spawn(async move {
-            let mut seq_no = 0;
-            while let Some(result) = in_stream.next().await {
-                if let Ok(req) = result {
-                    let Some(table_index) = table_names
-                        .iter()
-                        .position(|table| table.name == req.schema_name)
-                    else {
-                        error!("schema name not found: {}", req.schema_name);
-                        break;
-                    };
-
-                    seq_no = req.seq_no;
-                    let res = adapter
-                        .handle_message(table_index, GrpcIngestMessage::Arrow(req), ingestor)
-                        .await;
-                    if let Err(e) = res {
-                        error!("ingestion stream insertion errored: {:#?}", e);
-                        break;
-                    }
-                } else {
-                    error!("ingestion stream errored: {:#?}", result);
-                    break;
-                }
-            }
-            seq_no
-        })
-        .await
-        .map_err(|e| tonic::Status::internal(format!("ingestion stream error: {e}")))?;
+        let seq_no = self.ingest_requests(req.into_inner()).await?;
         Ok(tonic::Response::new(IngestResponse { seq_no }))
     }
 }
+
+/// A request of an ingestion stream.
+trait StreamedRequest: Send + 'static {
+    fn schema_name(&self) -> &str;
+    fn seq_no(&self) -> u32;
+    fn into_message(self) -> GrpcIngestMessage;
+}
+
+impl StreamedRequest for IngestRequest {
+    fn schema_name(&self) -> &str {
+        &self.schema_name
+    }
+
+    fn seq_no(&self) -> u32 {
+        self.seq_no
+    }
+
+    fn into_message(self) -> GrpcIngestMessage {
+        GrpcIngestMessage::Default(self)
+    }
+}
+
+impl StreamedRequest for IngestArrowRequest {
+    fn schema_name(&self) -> &str {
+        &self.schema_name
+    }
+
+    fn seq_no(&self) -> u32 {
+        self.seq_no
+    }
+
+    fn into_message(self) -> GrpcIngestMessage {
+        GrpcIngestMessage::Arrow(self)
+    }
+}
+
+impl<T> IngestorServiceImpl<T>
+where
+    T: IngestAdapter,
+{
+    /// Ingests the requests of `in_stream` until it ends, returning the `seq_no` of the last one.
+    ///
+    /// Stops at the first request that fails, and reports the `seq_no` of the last ingested request in the returned
+    /// status, so the client knows where to resume from.
+    async fn ingest_requests<R: StreamedRequest>(
+        &self,
+        mut in_stream: Streaming<R>,
+    ) -> Result<u32, tonic::Status> {
+        let mut seq_no = 0;
+        while let Some(result) = in_stream.next().await {
+            let req = result.map_err(|status| {
+                error!("ingestion stream errored: {:#?}", status);
+                tonic::Status::new(
+                    status.code(),
+                    format!("{} (last ingested seq_no: {seq_no})", status.message()),
+                )
+            })?;
+
+            let Some(table_index) = self
+                .tables
+                .iter()
+                .position(|table| table.name == req.schema_name())
+            else {
+                error!("schema name not found: {}", req.schema_name());
+                return Err(tonic::Status::not_found(format!(
+                    "schema name not found: {} (last ingested seq_no: {seq_no})",
+                    req.schema_name()
+                )));
+            };
+
+            let req_seq_no = req.seq_no();
+            self.adapter
+                .handle_message(table_index, req.into_message(), self.ingestor)
+                .await
+                .map_err(|e| {
+                    error!("ingestion stream insertion errored: {:#?}", e);
+                    tonic::Status::internal(format!(
+                        "ingestion stream error: {e} (last ingested seq_no: {seq_no})"
+                    ))
+                })?;
+            seq_no = req_seq_no;
+        }
+        Ok(seq_no)
+    }
+}
@@ -46,7 +46,7 @@ pub enum Error {
     #[error("data is not valid at index: {index}, Type: {value:?}, Expected Type: {field_type}")]
     FieldTypeMismatch {
         index: usize,
-        value: grpc_types::types::value::Value,
+        value: Option<grpc_types::types::value::Value>,
         field_type: FieldType,
     },
 }
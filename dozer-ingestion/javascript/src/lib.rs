@@ -53,6 +53,7 @@ impl Connector for JavaScriptConnector {
             schema: None,
             name: "json_records".to_string(),
             column_names: vec!["value".to_string()],
+            filter: None,
         }])
     }
 
@@ -67,6 +68,7 @@ impl Connector for JavaScriptConnector {
                     typ: FieldType::Json,
                     nullable: false,
                     source: SourceDefinition::Dynamic,
+                    annotations: Default::default(),
                 }],
                 primary_index: vec![],
             },
@@ -3,6 +3,7 @@ use dozer_ingestion_connector::dozer_types::errors::internal::BoxedError;
 use dozer_ingestion_connector::dozer_types::models::ingestion_types::KafkaConfig;
 use dozer_ingestion_connector::dozer_types::node::OpIdentifier;
 use dozer_ingestion_connector::dozer_types::types::FieldType;
+use dozer_ingestion_connector::retry::RetryPolicy;
 use dozer_ingestion_connector::Connector;
 use dozer_ingestion_connector::Ingestor;
 use dozer_ingestion_connector::SourceSchema;
@@ -23,11 +24,20 @@ use crate::KafkaError;
 #[derive(Debug)]
 pub struct KafkaConnector {
     config: KafkaConfig,
+    retry_policy: RetryPolicy,
 }
 
 impl KafkaConnector {
     pub fn new(config: KafkaConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
     }
 
     async fn get_schemas_impl(
@@ -35,7 +45,12 @@ impl KafkaConnector {
         table_names: Option<&[String]>,
     ) -> Result<Vec<SourceSchema>, KafkaError> {
         if let Some(schema_registry_url) = &self.config.schema_registry_url {
-            SchemaRegistryBasic::get_schema(table_names, schema_registry_url.clone()).await
+            SchemaRegistryBasic::get_schema(
+                table_names,
+                schema_registry_url.clone(),
+                &self.retry_policy,
+            )
+            .await
         } else {
             NoSchemaRegistryBasic::get_schema(table_names)
         }
@@ -106,6 +121,7 @@ impl Connector for KafkaConnector {
                 schema: table.schema,
                 name: table.name,
                 column_names,
+                filter: None,
             });
         }
         Ok(result)
@@ -144,6 +160,7 @@ impl Connector for KafkaConnector {
             last_checkpoint,
             ingestor,
             &self.config.schema_registry_url,
+            self.retry_policy,
         )
         .await
         .map_err(Into::into)
@@ -156,6 +173,7 @@ async fn run(
     last_checkpoint: Option<OpIdentifier>,
     ingestor: &Ingestor,
     schema_registry_url: &Option<String>,
+    retry_policy: RetryPolicy,
 ) -> Result<(), KafkaError> {
     let mut client_config = ClientConfig::new();
     client_config
@@ -163,7 +181,7 @@ async fn run(
         .set("group.id", "dozer")
         .set("enable.auto.commit", "true");
 
-    let consumer = StreamConsumerBasic::default();
+    let consumer = StreamConsumerBasic { retry_policy };
     consumer
         .run(
             client_config,
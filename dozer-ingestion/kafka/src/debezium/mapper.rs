@@ -1,13 +1,14 @@
 use base64::{engine, Engine};
 use dozer_ingestion_connector::dozer_types::{
+    json_value_to_field,
     serde_json::Value,
-    types::{Field, Schema},
+    types::{Field, FieldType, Schema},
 };
 use std::collections::HashMap;
 
 use crate::KafkaSchemaError;
 
-use super::stream_consumer::DebeziumSchemaStruct;
+use super::{schema::map_primitive_type, stream_consumer::DebeziumSchemaStruct};
 
 // fn convert_decimal(value: &str, scale: u32) -> Result<Field, DebeziumSchemaError> {
 //     let decoded_value = engine::general_purpose::STANDARD
@@ -28,34 +29,19 @@ use super::stream_consumer::DebeziumSchemaStruct;
 //     ))
 // }
 
+/// Converts a value encoded by Debezium. Bytes are base64 encoded, other values have their canonical JSON form, see
+/// [`json_value_to_field`].
 fn convert_value(value: Value, schema: &DebeziumSchemaStruct) -> Result<Field, KafkaSchemaError> {
     // match schema.name.clone() {
-    match schema.r#type.clone() {
-        Value::String(typ) => match typ.as_str() {
-            "int" | "int8" | "int16" | "int32" | "int64" => value
-                .as_i64()
-                .map_or(Ok(Field::Null), |v| Ok(Field::from(v))),
-            "string" => value
-                .as_str()
-                .map_or(Ok(Field::Null), |s| Ok(Field::from(s.to_string()))),
-            "bytes" => value.as_str().map_or(Ok(Field::Null), |s| {
-                Ok(Field::Binary(
-                    engine::general_purpose::STANDARD
-                        .decode(s)
-                        .map_err(KafkaSchemaError::BinaryDecodeError)?,
-                ))
-            }),
-            "float" | "float32" | "float64" | "double" => value
-                .as_f64()
-                .map_or(Ok(Field::Null), |s| Ok(Field::from(s))),
-            "boolean" => value
-                .as_bool()
-                .map_or(Ok(Field::Null), |s| Ok(Field::from(s))),
-            _ => Err(KafkaSchemaError::TypeNotSupported(typ)),
-        },
-        _ => Err(KafkaSchemaError::TypeNotSupported(
-            "Unexpected value type".to_string(),
+    match (map_primitive_type(&schema.r#type)?, value) {
+        (_, Value::Null) => Ok(Field::Null),
+        (FieldType::Binary, Value::String(s)) => Ok(Field::Binary(
+            engine::general_purpose::STANDARD
+                .decode(s)
+                .map_err(KafkaSchemaError::BinaryDecodeError)?,
         )),
+        (typ, value) => json_value_to_field(value, typ, true)
+            .map_err(|e| KafkaSchemaError::InvalidValue(typ, e.to_string())),
     }
     // Some(name) => {
     //     match name.as_str() {
@@ -281,6 +267,21 @@ mod tests {
             KafkaSchemaError::TypeNotSupported("Unknown type".to_string()),
             None
         );
+        assert!(matches!(
+            convert_value(
+                Value::from("ABC"),
+                &DebeziumSchemaStruct {
+                    r#type: Value::String("int32".to_string()),
+                    fields: None,
+                    optional: Some(false),
+                    name: None,
+                    field: None,
+                    version: None,
+                    parameters: None,
+                },
+            ),
+            Err(KafkaSchemaError::InvalidValue(FieldType::Int, _))
+        ));
         // test_conversion_debezium_error!(
         //     1234,
         //     "-",
@@ -317,24 +318,28 @@ mod tests {
                     typ: FieldType::Int,
                     nullable: false,
                     source: SourceDefinition::Dynamic,
+                    annotations: Default::default(),
                 },
                 FieldDefinition {
                     name: "name".to_string(),
                     typ: FieldType::String,
                     nullable: false,
                     source: SourceDefinition::Dynamic,
+                    annotations: Default::default(),
                 },
                 FieldDefinition {
                     name: "description".to_string(),
                     typ: FieldType::String,
                     nullable: false,
                     source: SourceDefinition::Dynamic,
+                    annotations: Default::default(),
                 },
                 FieldDefinition {
                     name: "weight".to_string(),
                     typ: FieldType::Float,
                     nullable: false,
                     source: SourceDefinition::Dynamic,
+                    annotations: Default::default(),
                 },
             ],
             primary_index: vec![],
@@ -406,12 +411,14 @@ mod tests {
                     typ: FieldType::Int,
                     nullable: false,
                     source: SourceDefinition::Dynamic,
+                    annotations: Default::default(),
                 },
                 FieldDefinition {
                     name: "name".to_string(),
                     typ: FieldType::String,
                     nullable: true,
                     source: SourceDefinition::Dynamic,
+                    annotations: Default::default(),
                 },
             ],
             primary_index: vec![],
@@ -12,19 +12,7 @@ use super::stream_consumer::DebeziumSchemaStruct;
 // Reference: https://debezium.io/documentation/reference/0.9/connectors/postgresql.html
 pub fn map_type(schema: &DebeziumSchemaStruct) -> Result<FieldType, KafkaSchemaError> {
     match schema.name.clone() {
-        None => match schema.r#type.clone() {
-            Value::String(typ) => match typ.as_str() {
-                "int" | "int8" | "int16" | "int32" | "int64" => Ok(FieldType::Int),
-                "string" => Ok(FieldType::String),
-                "bytes" => Ok(FieldType::Binary),
-                "float" | "float32" | "float64" | "double" => Ok(FieldType::Float),
-                "boolean" => Ok(FieldType::Boolean),
-                _ => Err(KafkaSchemaError::TypeNotSupported(typ)),
-            },
-            _ => Err(KafkaSchemaError::TypeNotSupported(
-                "Unexpected value type".to_string(),
-            )),
-        },
+        None => map_primitive_type(&schema.r#type),
         Some(name) => match name.as_str() {
             "io.debezium.time.MicroTime"
             | "io.debezium.time.Timestamp"
@@ -41,6 +29,31 @@ pub fn map_type(schema: &DebeziumSchemaStruct) -> Result<FieldType, KafkaSchemaE
     }
 }
 
+/// Maps the type a value is encoded with, ignoring any logical type. Avro unions map to their non null type.
+pub fn map_primitive_type(typ: &Value) -> Result<FieldType, KafkaSchemaError> {
+    match typ {
+        Value::Array(types) => types
+            .iter()
+            .find(|typ| typ.as_str() != Some("null"))
+            .map_or_else(
+                || Err(KafkaSchemaError::TypeNotSupported("null".to_string())),
+                map_primitive_type,
+            ),
+        Value::Object(object) if object.contains_key("type") => map_primitive_type(&object["type"]),
+        Value::String(typ) => match typ.as_str() {
+            "int" | "int8" | "int16" | "int32" | "int64" | "long" => Ok(FieldType::Int),
+            "string" => Ok(FieldType::String),
+            "bytes" => Ok(FieldType::Binary),
+            "float" | "float32" | "float64" | "double" => Ok(FieldType::Float),
+            "boolean" => Ok(FieldType::Boolean),
+            _ => Err(KafkaSchemaError::TypeNotSupported(typ.clone())),
+        },
+        _ => Err(KafkaSchemaError::TypeNotSupported(
+            "Unexpected value type".to_string(),
+        )),
+    }
+}
+
 pub fn map_schema(
     schema: &DebeziumSchemaStruct,
     key_schema: &DebeziumSchemaStruct,
@@ -82,6 +95,7 @@ pub fn map_schema(
                                 typ,
                                 nullable: f.optional.map_or(false, |o| o),
                                 source: SourceDefinition::Dynamic,
+                                annotations: Default::default(),
                             })
                         })
                         .collect(),
@@ -196,12 +210,14 @@ mod tests {
                     typ: FieldType::Int,
                     nullable: false,
                     source: SourceDefinition::Dynamic,
+                    annotations: Default::default(),
                 },
                 FieldDefinition {
                     name: "name".to_string(),
                     typ: FieldType::String,
                     nullable: true,
                     source: SourceDefinition::Dynamic,
+                    annotations: Default::default(),
                 },
             ],
             primary_index: vec![0],
@@ -1,12 +1,14 @@
 use std::collections::HashMap;
 
-use dozer_ingestion_connector::dozer_types::log::error;
 use dozer_ingestion_connector::dozer_types::serde_json::{self, Value};
 use dozer_ingestion_connector::dozer_types::types::{
     FieldDefinition, FieldType, Schema, SourceDefinition,
 };
-use dozer_ingestion_connector::{tokio, CdcType, SourceSchema};
+use dozer_ingestion_connector::{
+    dozer_types, retry::RetryPolicy, retry_on_network_failure, tokio, CdcType, SourceSchema,
+};
 use schema_registry_converter::async_impl::schema_registry::SrSettings;
+use schema_registry_converter::error::SRCError;
 use schema_registry_converter::schema_registry_common::SubjectNameStrategy;
 
 use crate::{KafkaError, KafkaSchemaError};
@@ -67,24 +69,19 @@ impl SchemaRegistry {
         sr_settings: &SrSettings,
         table_name: &str,
         is_key: bool,
+        retry_policy: &RetryPolicy,
     ) -> Result<DebeziumSchemaStruct, KafkaError> {
-        let schema_result = loop {
-            match schema_registry_converter::async_impl::schema_registry::get_schema_by_subject(
+        let schema_result = retry_on_network_failure!(
+            retry_policy,
+            "schema registry fetch",
+            schema_registry_converter::async_impl::schema_registry::get_schema_by_subject(
                 sr_settings,
                 &SubjectNameStrategy::TopicNameStrategy(table_name.to_string(), is_key),
             )
-            .await
-            {
-                Ok(schema_result) => break schema_result,
-                Err(err) if err.retriable => {
-                    const RETRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
-                    error!("schema registry fetch error {err}. retrying in {RETRY_INTERVAL:?}...");
-                    tokio::time::sleep(RETRY_INTERVAL).await;
-                    continue;
-                }
-                Err(err) => return Err(KafkaError::SchemaRegistryFetchError(err)),
-            }
-        };
+            .await,
+            |err: &SRCError| err.retriable
+        )
+        .map_err(KafkaError::SchemaRegistryFetchError)?;
 
         serde_json::from_str::<DebeziumSchemaStruct>(&schema_result.schema)
             .map_err(KafkaError::JsonDecodeError)
@@ -93,6 +90,7 @@ impl SchemaRegistry {
     pub async fn get_schema(
         table_names: Option<&[String]>,
         schema_registry_url: String,
+        retry_policy: &RetryPolicy,
     ) -> Result<Vec<SourceSchema>, KafkaError> {
         let sr_settings = SrSettings::new(schema_registry_url);
         match table_names {
@@ -101,9 +99,11 @@ impl SchemaRegistry {
                 None => Ok(vec![]),
                 Some(table) => {
                     let key_result =
-                        SchemaRegistry::fetch_struct(&sr_settings, table, true).await?;
+                        SchemaRegistry::fetch_struct(&sr_settings, table, true, retry_policy)
+                            .await?;
                     let schema_result =
-                        SchemaRegistry::fetch_struct(&sr_settings, table, false).await?;
+                        SchemaRegistry::fetch_struct(&sr_settings, table, false, retry_policy)
+                            .await?;
 
                     let pk_fields = key_result.fields.map_or(vec![], |fields| {
                         fields
@@ -133,6 +133,7 @@ impl SchemaRegistry {
                                 typ,
                                 nullable,
                                 source: SourceDefinition::Dynamic,
+                                annotations: Default::default(),
                             })
                         })
                         .collect();
@@ -15,6 +15,7 @@ use dozer_ingestion_connector::{
         serde_json::Value,
         types::{Operation, Record},
     },
+    retry::RetryPolicy,
     Ingestor,
 };
 use rdkafka::{ClientConfig, Message};
@@ -80,7 +81,9 @@ pub struct DebeziumMessage {
 }
 
 #[derive(Default)]
-pub struct DebeziumStreamConsumer {}
+pub struct DebeziumStreamConsumer {
+    pub retry_policy: RetryPolicy,
+}
 
 impl DebeziumStreamConsumer {}
 
@@ -96,13 +99,20 @@ impl StreamConsumer for DebeziumStreamConsumer {
     ) -> Result<(), KafkaError> {
         assert!(last_checkpoint.is_none());
         let topics: Vec<&str> = tables.iter().map(|t| t.name.as_str()).collect();
-        let mut con = StreamConsumerHelper::start(&client_config, &topics).await?;
+        let mut con =
+            StreamConsumerHelper::start(&client_config, &topics, &self.retry_policy).await?;
         let mut offsets = OffsetsMap::new();
         loop {
             let m = match con.poll(None).unwrap() {
                 Ok(m) => m,
                 Err(err) if is_network_failure(&err) => {
-                    con = StreamConsumerHelper::resume(&client_config, &topics, &offsets).await?;
+                    con = StreamConsumerHelper::resume(
+                        &client_config,
+                        &topics,
+                        &offsets,
+                        &self.retry_policy,
+                    )
+                    .await?;
                     continue;
                 }
                 Err(err) => Err(KafkaError::KafkaStreamError(
@@ -4,6 +4,7 @@ use base64::DecodeError;
 use dozer_ingestion_connector::dozer_types::{
     rust_decimal, serde_json,
     thiserror::{self, Error},
+    types::FieldType,
 };
 use schema_registry_converter::error::SRCError;
 
@@ -37,6 +38,12 @@ pub enum KafkaError {
     #[error("Schema registry fetch failed. Error: {0}")]
     SchemaRegistryFetchError(#[source] SRCError),
 
+    #[error("Avro decode error. Error: {0}")]
+    AvroDecodeError(#[source] SRCError),
+
+    #[error("Avro value conversion error. Error: {0}")]
+    AvroConversionError(#[source] apache_avro::Error),
+
     #[error("Topic not defined")]
     TopicNotDefined,
 }
@@ -82,6 +89,9 @@ pub enum KafkaSchemaError {
     #[error("Invalid json: {0}")]
     InvalidJsonError(String),
 
+    #[error("Invalid {0} value: {1}")]
+    InvalidValue(FieldType, String),
+
     // #[error("Invalid time")]
     // InvalidTimeError,
     #[error("Invalid timestamp")]
@@ -16,12 +16,14 @@ impl NoSchemaRegistryBasic {
                     typ: FieldType::String,
                     nullable: false,
                     source: SourceDefinition::Dynamic,
+                    annotations: Default::default(),
                 },
                 FieldDefinition {
                     name: "message".to_string(),
                     typ: FieldType::String,
                     nullable: true,
                     source: SourceDefinition::Dynamic,
+                    annotations: Default::default(),
                 },
             ],
             primary_index: vec![0],
@@ -1,7 +1,11 @@
 #![allow(clippy::type_complexity)]
 
 use dozer_ingestion_connector::{
-    dozer_types::types::{FieldDefinition, Schema, SourceDefinition},
+    dozer_types::{
+        serde_json::{self, Value},
+        types::{FieldDefinition, Schema, SourceDefinition},
+    },
+    retry::RetryPolicy,
     CdcType, SourceSchema,
 };
 use schema_registry_converter::async_impl::schema_registry::SrSettings;
@@ -18,10 +22,14 @@ impl SchemaRegistryBasic {
     pub async fn get_single_schema(
         table_name: &str,
         schema_registry_url: &str,
+        retry_policy: &RetryPolicy,
     ) -> Result<(SourceSchema, HashMap<String, DebeziumSchemaStruct>), KafkaError> {
         let sr_settings = SrSettings::new(schema_registry_url.to_string());
-        let key_result = SchemaRegistry::fetch_struct(&sr_settings, table_name, true).await?;
-        let schema_result = SchemaRegistry::fetch_struct(&sr_settings, table_name, false).await?;
+        let key_result =
+            SchemaRegistry::fetch_struct(&sr_settings, table_name, true, retry_policy).await?;
+        let schema_result = row_schema(
+            SchemaRegistry::fetch_struct(&sr_settings, table_name, false, retry_policy).await?,
+        );
 
         let pk_fields = key_result.fields.map_or(vec![], |fields| {
             fields
@@ -51,6 +59,7 @@ impl SchemaRegistryBasic {
                     typ,
                     nullable,
                     source: SourceDefinition::Dynamic,
+                    annotations: Default::default(),
                 })
             })
             .collect();
@@ -69,11 +78,13 @@ impl SchemaRegistryBasic {
     pub async fn get_schema(
         table_names: Option<&[String]>,
         schema_registry_url: String,
+        retry_policy: &RetryPolicy,
     ) -> Result<Vec<SourceSchema>, KafkaError> {
         let mut schemas = vec![];
         if let Some(tables) = table_names {
             for table_name in tables.iter() {
-                let (schema, _) = Self::get_single_schema(table_name, &schema_registry_url).await?;
+                let (schema, _) =
+                    Self::get_single_schema(table_name, &schema_registry_url, retry_policy).await?;
                 schemas.push(schema);
             }
         }
@@ -81,3 +92,19 @@ impl SchemaRegistryBasic {
         Ok(schemas)
     }
 }
+
+/// The schema of the rows of a Debezium change event schema, which is the type of its `after` field, or `schema` if
+/// it isn't one.
+pub fn row_schema(schema: DebeziumSchemaStruct) -> DebeziumSchemaStruct {
+    let fields = schema.fields.as_deref().unwrap_or_default();
+    let field = |name: &str| fields.iter().find(|f| f.name.as_deref() == Some(name));
+    let row = field("op")
+        .and(field("after"))
+        .and_then(|after| match &after.r#type {
+            Value::Array(types) => types.iter().find(|typ| typ.is_object()).cloned(),
+            Value::Object(_) => Some(after.r#type.clone()),
+            _ => None,
+        });
+    row.and_then(|row| serde_json::from_value(row).ok())
+        .unwrap_or(schema)
+}
@@ -9,9 +9,11 @@ use dozer_ingestion_connector::{
         serde_json::{self, Value},
         types::{Field, Operation, Record},
     },
+    retry::RetryPolicy,
     Ingestor, TableInfo,
 };
 use rdkafka::{ClientConfig, Message};
+use schema_registry_converter::async_impl::{avro::AvroDecoder, schema_registry::SrSettings};
 
 use crate::schema_registry_basic::SchemaRegistryBasic;
 use crate::stream_consumer::StreamConsumer;
@@ -73,8 +75,76 @@ pub struct Payload {
     pub op: Option<String>,
 }
 
+/// First byte of the messages in the Confluent wire format, followed by the schema id and the Avro encoded value.
+const CONFLUENT_MAGIC_BYTE: u8 = 0;
+
+/// Decodes a message value, Avro encoded in the Confluent wire format, or JSON.
+async fn decode_value(decoder: &AvroDecoder<'_>, payload: &[u8]) -> Result<Value, KafkaError> {
+    if payload.first() == Some(&CONFLUENT_MAGIC_BYTE) {
+        let result = decoder
+            .decode(Some(payload))
+            .await
+            .map_err(KafkaError::AvroDecodeError)?;
+        Value::try_from(result.value).map_err(KafkaError::AvroConversionError)
+    } else {
+        serde_json::from_str(std::str::from_utf8(payload).map_err(KafkaError::BytesConvertError)?)
+            .map_err(KafkaError::JsonDecodeError)
+    }
+}
+
+/// Unwraps a Debezium change event, with or without its `schema`, returning `value` back if it isn't one.
+fn unwrap_envelope(value: Value) -> Result<Payload, Value> {
+    let Value::Object(mut object) = value else {
+        return Err(value);
+    };
+    if let Some(Value::Object(payload)) = object.get_mut("payload") {
+        if payload.contains_key("op") {
+            object = std::mem::take(payload);
+        }
+    }
+    let Some(Value::String(op)) = object.remove("op") else {
+        return Err(Value::Object(object));
+    };
+    let mut image = |name: &str| object.remove(name).filter(|value| !value.is_null());
+    Ok(Payload {
+        before: image("before"),
+        after: image("after"),
+        op: Some(op),
+    })
+}
+
+/// The operation of a Debezium change event, `None` for truncates, messages and events missing the row image.
+///
+/// Updates only have the old row if the source logs it, e.g. Postgres with `REPLICA IDENTITY FULL`. Otherwise the new
+/// row is used as the old one, which has the same primary key.
+fn envelope_operation(
+    payload: Payload,
+    mut to_record: impl FnMut(Value) -> Result<Record, KafkaError>,
+) -> Result<Option<Operation>, KafkaError> {
+    let Payload { before, after, op } = payload;
+    Ok(match (op.as_deref(), before, after) {
+        (Some("c" | "r"), _, Some(after)) => Some(Operation::Insert {
+            new: to_record(after)?,
+        }),
+        (Some("u"), before, Some(after)) => {
+            let new = to_record(after)?;
+            let old = match before {
+                Some(before) => to_record(before)?,
+                None => new.clone(),
+            };
+            Some(Operation::Update { old, new })
+        }
+        (Some("d"), Some(before), _) => Some(Operation::Delete {
+            old: to_record(before)?,
+        }),
+        _ => None,
+    })
+}
+
 #[derive(Default)]
-pub struct StreamConsumerBasic {}
+pub struct StreamConsumerBasic {
+    pub retry_policy: RetryPolicy,
+}
 
 #[async_trait]
 impl StreamConsumer for StreamConsumerBasic {
@@ -92,7 +162,7 @@ impl StreamConsumer for StreamConsumerBasic {
         let mut schemas = HashMap::new();
         for (table_index, table) in tables.into_iter().enumerate() {
             let schema = if let Some(url) = schema_registry_url {
-                SchemaRegistryBasic::get_single_schema(&table.name, url).await?
+                SchemaRegistryBasic::get_single_schema(&table.name, url, &self.retry_policy).await?
             } else {
                 (NoSchemaRegistryBasic::get_single_schema(), HashMap::new())
             };
@@ -100,14 +170,25 @@ impl StreamConsumer for StreamConsumerBasic {
             schemas.insert(table.name.clone(), (table_index, schema));
         }
 
+        let decoder = schema_registry_url
+            .as_ref()
+            .map(|url| AvroDecoder::new(SrSettings::new(url.clone())));
+
         let topics: Vec<&str> = topics.iter().map(|t| t.as_str()).collect();
-        let mut con = StreamConsumerHelper::start(&client_config, &topics).await?;
+        let mut con =
+            StreamConsumerHelper::start(&client_config, &topics, &self.retry_policy).await?;
 
         let mut offsets = OffsetsMap::new();
         loop {
             if let Some(result) = con.poll(None) {
                 if matches!(result.as_ref(), Err(err) if is_network_failure(err)) {
-                    con = StreamConsumerHelper::resume(&client_config, &topics, &offsets).await?;
+                    con = StreamConsumerHelper::resume(
+                        &client_config,
+                        &topics,
+                        &offsets,
+                        &self.retry_policy,
+                    )
+                    .await?;
                     continue;
                 }
                 let m = result
@@ -117,48 +198,42 @@ impl StreamConsumer for StreamConsumerBasic {
                     None => return Err(KafkaError::TopicNotDefined),
                     Some((table_index, (schema, fields_map))) => {
                         if let (Some(message), Some(key)) = (m.payload(), m.key()) {
-                            let new = match schema_registry_url {
+                            let op = match &decoder {
                                 None => {
                                     let value = std::str::from_utf8(message)
                                         .map_err(KafkaError::BytesConvertError)?;
                                     let key = std::str::from_utf8(key)
                                         .map_err(KafkaError::BytesConvertError)?;
 
-                                    vec![
-                                        Field::String(key.to_string()),
-                                        Field::String(value.to_string()),
-                                    ]
+                                    Some(Operation::Insert {
+                                        new: Record::new(vec![
+                                            Field::String(key.to_string()),
+                                            Field::String(value.to_string()),
+                                        ]),
+                                    })
                                 }
-                                Some(_) => {
-                                    let value_struct: Value = serde_json::from_str(
-                                        std::str::from_utf8(message)
-                                            .map_err(KafkaError::BytesConvertError)?,
-                                    )
-                                    .map_err(KafkaError::JsonDecodeError)?;
-                                    let _key_struct: Value = serde_json::from_str(
-                                        std::str::from_utf8(key)
-                                            .map_err(KafkaError::BytesConvertError)?,
-                                    )
-                                    .map_err(KafkaError::JsonDecodeError)?;
-
-                                    convert_value_to_schema(
-                                        value_struct,
-                                        &schema.schema,
-                                        fields_map,
-                                    )
-                                    .map_err(KafkaError::KafkaSchemaError)?
+                                Some(decoder) => {
+                                    let to_record = |value| {
+                                        convert_value_to_schema(value, &schema.schema, fields_map)
+                                            .map(Record::new)
+                                            .map_err(KafkaError::KafkaSchemaError)
+                                    };
+                                    match unwrap_envelope(decode_value(decoder, message).await?) {
+                                        Ok(payload) => envelope_operation(payload, to_record)?,
+                                        Err(value) => Some(Operation::Insert {
+                                            new: to_record(value)?,
+                                        }),
+                                    }
                                 }
                             };
 
+                            let Some(op) = op else {
+                                continue;
+                            };
                             if ingestor
                                 .handle_message(IngestionMessage::OperationEvent {
                                     table_index: *table_index,
-                                    op: Operation::Insert {
-                                        new: Record {
-                                            values: new,
-                                            lifetime: None,
-                                        },
-                                    },
+                                    op,
                                     id: None,
                                 })
                                 .await
@@ -174,3 +249,113 @@ impl StreamConsumer for StreamConsumerBasic {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    use apache_avro::types::Value as AvroValue;
+    use dozer_ingestion_connector::{dozer_types::serde_json::json, tokio::runtime::Runtime};
+
+    use super::*;
+
+    /// Serves `schema` for every request, as a schema registry does for `/schemas/ids/{id}`.
+    fn serve_schema(schema: &str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let body = json!({ "schema": schema }).to_string();
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut request = [0; 4096];
+                let _ = stream.read(&mut request);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+            }
+        });
+        url
+    }
+
+    #[test]
+    fn test_decode_confluent_avro_value() {
+        let schema = r#"{"type": "record", "name": "Product", "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "name", "type": ["null", "string"]}
+        ]}"#;
+        let decoder = AvroDecoder::new(SrSettings::new(serve_schema(schema)));
+        let datum = apache_avro::to_avro_datum(
+            &apache_avro::Schema::parse_str(schema).unwrap(),
+            AvroValue::Record(vec![
+                ("id".to_string(), AvroValue::Long(1)),
+                (
+                    "name".to_string(),
+                    AvroValue::Union(1, Box::new(AvroValue::String("Product".to_string()))),
+                ),
+            ]),
+        )
+        .unwrap();
+        let mut payload = vec![CONFLUENT_MAGIC_BYTE, 0, 0, 0, 1];
+        payload.extend(datum);
+
+        let runtime = Runtime::new().unwrap();
+        assert_eq!(
+            runtime.block_on(decode_value(&decoder, &payload)).unwrap(),
+            json!({"id": 1, "name": "Product"})
+        );
+        assert_eq!(
+            runtime
+                .block_on(decode_value(&decoder, br#"{"id": 2}"#))
+                .unwrap(),
+            json!({"id": 2})
+        );
+    }
+
+    fn to_record(value: Value) -> Result<Record, KafkaError> {
+        Ok(Record::new(vec![Field::Int(value["id"].as_i64().unwrap())]))
+    }
+
+    fn record(id: i64) -> Record {
+        Record::new(vec![Field::Int(id)])
+    }
+
+    fn operation(value: Value) -> Option<Operation> {
+        let payload = unwrap_envelope(value).unwrap();
+        envelope_operation(payload, to_record).unwrap()
+    }
+
+    #[test]
+    fn test_unwrap_debezium_envelope() {
+        assert_eq!(
+            operation(json!({"before": null, "after": {"id": 1}, "op": "c"})),
+            Some(Operation::Insert { new: record(1) })
+        );
+        assert_eq!(
+            operation(json!({
+                "schema": {},
+                "payload": {"before": {"id": 1}, "after": {"id": 2}, "op": "u"}
+            })),
+            Some(Operation::Update {
+                old: record(1),
+                new: record(2)
+            })
+        );
+        assert_eq!(
+            operation(json!({"before": null, "after": {"id": 3}, "op": "u"})),
+            Some(Operation::Update {
+                old: record(3),
+                new: record(3)
+            })
+        );
+        assert_eq!(
+            operation(json!({"before": {"id": 4}, "after": null, "op": "d"})),
+            Some(Operation::Delete { old: record(4) })
+        );
+        assert_eq!(operation(json!({"op": "t"})), None);
+
+        let row = json!({"id": 5, "op": 1});
+        assert_eq!(unwrap_envelope(row.clone()).unwrap_err(), row);
+    }
+}
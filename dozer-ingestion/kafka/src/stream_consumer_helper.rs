@@ -1,4 +1,4 @@
-use dozer_ingestion_connector::{dozer_types, tokio};
+use dozer_ingestion_connector::{dozer_types, retry::RetryPolicy, retry_on_network_failure, tokio};
 use rdkafka::{
     consumer::{BaseConsumer, Consumer},
     message::BorrowedMessage,
@@ -17,16 +17,18 @@ impl StreamConsumerHelper {
     pub async fn start(
         client_config: &ClientConfig,
         topics: &[&str],
+        retry_policy: &RetryPolicy,
     ) -> Result<BaseConsumer, KafkaError> {
-        Self::resume_impl(client_config, topics, None).await
+        Self::resume_impl(client_config, topics, None, retry_policy).await
     }
 
     pub async fn resume(
         client_config: &ClientConfig,
         topics: &[&str],
         offsets: &OffsetsMap,
+        retry_policy: &RetryPolicy,
     ) -> Result<BaseConsumer, KafkaError> {
-        Self::resume_impl(client_config, topics, Some(offsets)).await
+        Self::resume_impl(client_config, topics, Some(offsets), retry_policy).await
     }
 
     pub fn update_offsets(offsets: &mut OffsetsMap, message: &BorrowedMessage<'_>) {
@@ -40,21 +42,15 @@ impl StreamConsumerHelper {
         client_config: &ClientConfig,
         topics: &[&str],
         offsets: Option<&OffsetsMap>,
+        retry_policy: &RetryPolicy,
     ) -> Result<BaseConsumer, KafkaError> {
-        loop {
-            match Self::try_resume(client_config, topics, offsets).await {
-                Ok(con) => return Ok(con),
-                Err(err) if is_network_failure(&err) => {
-                    const RETRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
-                    dozer_types::log::error!(
-                        "stream resume error {err}. retrying in {RETRY_INTERVAL:?}..."
-                    );
-                    tokio::time::sleep(RETRY_INTERVAL).await;
-                    continue;
-                }
-                Err(err) => Err(KafkaError::KafkaConnectionError(err))?,
-            }
-        }
+        retry_on_network_failure!(
+            retry_policy,
+            "stream resume",
+            Self::try_resume(client_config, topics, offsets).await,
+            is_network_failure
+        )
+        .map_err(KafkaError::KafkaConnectionError)
     }
 
     async fn try_resume(
@@ -481,6 +481,7 @@ impl Connector for MongodbConnector {
                 schema: None,
                 name: table.name,
                 column_names: vec!["data".to_owned()],
+                filter: None,
             })
             .collect())
     }
@@ -501,12 +502,14 @@ impl Connector for MongodbConnector {
                                 typ: FieldType::Json,
                                 nullable: false,
                                 source: SourceDefinition::Dynamic,
+                                annotations: Default::default(),
                             },
                             FieldDefinition {
                                 name: "data".to_owned(),
                                 typ: FieldType::Json,
                                 nullable: false,
                                 source: SourceDefinition::Dynamic,
+                                annotations: Default::default(),
                             },
                         ],
                         primary_index: vec![0],
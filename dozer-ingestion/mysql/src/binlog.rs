@@ -4,7 +4,7 @@ use crate::{
 };
 
 use super::{
-    connection::Conn,
+    connection::{Conn, Pool},
     conversion::{IntoField, IntoFields, IntoJsonValue},
     schema::{ColumnDefinition, TableDefinition},
 };
@@ -30,7 +30,7 @@ use mysql_async::{
         value::BinlogValue,
         EventFlags,
     },
-    BinlogStream, Pool, Row,
+    BinlogStream, Row,
 };
 
 use std::{
@@ -1,17 +1,40 @@
 use dozer_ingestion_connector::{
-    dozer_types, retry_on_network_failure,
+    dozer_types,
+    retry::RetryPolicy,
+    retry_on_network_failure,
     tokio::{self, sync::mpsc::Receiver},
 };
-use mysql_async::{prelude::*, BinlogStream, Params, Pool, Row};
+use mysql_async::{prelude::*, BinlogStream, Opts, Params, Row};
+
+/// A connection pool, with the policy its connections retry network failures with.
+#[derive(Debug, Clone)]
+pub struct Pool {
+    inner: mysql_async::Pool,
+    retry_policy: RetryPolicy,
+}
+
+impl Pool {
+    pub fn new(opts: Opts) -> Self {
+        Self {
+            inner: mysql_async::Pool::new(opts),
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+}
 
 #[derive(Debug)]
 pub struct Conn {
-    pool: mysql_async::Pool,
+    pool: Pool,
     inner: mysql_async::Conn,
 }
 
 impl Conn {
-    pub async fn new(pool: mysql_async::Pool) -> Result<Conn, mysql_async::Error> {
+    pub async fn new(pool: Pool) -> Result<Conn, mysql_async::Error> {
         let conn = new_mysql_connection(&pool).await?;
         Ok(Conn { pool, inner: conn })
     }
@@ -26,6 +49,7 @@ impl Conn {
         T: FromRow + Send + 'static,
     {
         retry_on_network_failure!(
+            self.pool.retry_policy,
             "query",
             self.inner.exec_first(query, params).await,
             is_network_failure,
@@ -47,6 +71,7 @@ impl Conn {
         P: Into<Params> + Send + Copy + 'b,
     {
         retry_on_network_failure!(
+            self.pool.retry_policy,
             "query",
             self.inner.exec_drop(query, params).await,
             is_network_failure,
@@ -56,6 +81,7 @@ impl Conn {
 
     pub async fn query_drop(&mut self, query: &str) -> Result<(), mysql_async::Error> {
         retry_on_network_failure!(
+            self.pool.retry_policy,
             "query",
             self.inner.query_drop(query).await,
             is_network_failure,
@@ -71,6 +97,7 @@ impl Conn {
     ) -> Result<BinlogStream, mysql_async::Error> {
         let mut inner = self.inner;
         retry_on_network_failure!(
+            self.pool.retry_policy,
             "get_binlog_stream",
             {
                 let request = mysql_async::BinlogStreamRequest::new(server_id)
@@ -90,7 +117,12 @@ impl Conn {
 }
 
 async fn new_mysql_connection(pool: &Pool) -> Result<mysql_async::Conn, mysql_async::Error> {
-    retry_on_network_failure!("connect", pool.get_conn().await, is_network_failure)
+    retry_on_network_failure!(
+        pool.retry_policy,
+        "connect",
+        pool.inner.get_conn().await,
+        is_network_failure
+    )
 }
 
 pub fn is_network_failure(err: &mysql_async::Error) -> bool {
@@ -132,6 +164,7 @@ fn exec_iter_impl(pool: Pool, query: String, params: Vec<mysql_async::Value>) ->
                 }
             };
             let mut rows = match retry_on_network_failure!(
+                pool.retry_policy,
                 "query",
                 conn.exec_iter(add_query_offset(&query, cursor_position), &params)
                     .await,
@@ -146,6 +179,7 @@ fn exec_iter_impl(pool: Pool, query: String, params: Vec<mysql_async::Value>) ->
             };
             loop {
                 let result = retry_on_network_failure!(
+                    pool.retry_policy,
                     "query",
                     rows.next().await,
                     is_network_failure,
@@ -2,7 +2,7 @@ use crate::MySQLConnectorError;
 
 use super::{
     binlog::{get_binlog_format, get_master_binlog_position, BinlogIngestor, BinlogPosition},
-    connection::Conn,
+    connection::{Conn, Pool},
     conversion::IntoFields,
     helpers::{escape_identifier, qualify_table_name},
     schema::{ColumnDefinition, SchemaHelper, TableDefinition},
@@ -14,36 +14,99 @@ use dozer_ingestion_connector::{
         errors::internal::BoxedError,
         log::info,
         models::ingestion_types::IngestionMessage,
-        models::ingestion_types::TransactionInfo,
+        models::ingestion_types::{MySQLConfig, TransactionInfo},
         node::OpIdentifier,
         types::{FieldDefinition, FieldType, Operation, Record, Schema, SourceDefinition},
     },
+    retry::RetryPolicy,
+    ssh_tunnel::SshTunnel,
     utils::TableNotFound,
     CdcType, Connector, Ingestor, SourceSchema, SourceSchemaResult, TableIdentifier, TableInfo,
 };
-use mysql_async::{Opts, Pool};
+use mysql_async::{ClientIdentity, Opts, OptsBuilder, PathOrBuf};
 use mysql_common::Row;
 use rand::Rng;
+use std::{borrow::Cow, path::PathBuf};
 
 #[derive(Debug)]
 pub struct MySQLConnector {
     conn_url: String,
     conn_pool: Pool,
     server_id: Option<u32>,
+    /// Kept open as long as the connector exists.
+    ssh_tunnel: Option<SshTunnel>,
 }
 
 pub fn mysql_connection_opts_from_url(url: &str) -> Result<Opts, MySQLConnectorError> {
     Opts::from_url(url).map_err(MySQLConnectorError::InvalidConnectionURLError)
 }
 
+fn tls_file(path: &str) -> PathOrBuf<'static> {
+    PathOrBuf::Path(Cow::Owned(PathBuf::from(path)))
+}
+
+/// Returns the connection options of `config`, with its TLS certificates, and its SSH tunnel if it has one.
+pub fn mysql_connection_opts(
+    config: &MySQLConfig,
+) -> Result<(Opts, Option<SshTunnel>), MySQLConnectorError> {
+    let opts = mysql_connection_opts_from_url(&config.url)?;
+
+    let mut ssl_opts = opts.ssl_opts().cloned();
+    if let Some(tls) = &config.tls {
+        let mut tls_opts = ssl_opts.unwrap_or_default();
+        if let Some(ca_cert_path) = &tls.ca_cert_path {
+            tls_opts = tls_opts.with_root_certs(vec![tls_file(ca_cert_path)]);
+        }
+        match (&tls.client_cert_path, &tls.client_key_path) {
+            (Some(cert_path), Some(key_path)) => {
+                tls_opts = tls_opts.with_client_identity(Some(ClientIdentity::new(
+                    tls_file(cert_path),
+                    tls_file(key_path),
+                )));
+            }
+            (None, None) => (),
+            _ => return Err(MySQLConnectorError::IncompleteClientCertificate),
+        }
+        ssl_opts = Some(tls_opts);
+    }
+
+    let ssh_tunnel = config
+        .ssh_tunnel
+        .as_ref()
+        .map(|ssh_tunnel| SshTunnel::open(ssh_tunnel, opts.ip_or_hostname(), opts.tcp_port()))
+        .transpose()?;
+    let mut builder = OptsBuilder::from_opts(opts.clone());
+    if let Some(ssh_tunnel) = &ssh_tunnel {
+        builder = builder
+            .ip_or_hostname("127.0.0.1")
+            .tcp_port(ssh_tunnel.local_port());
+        // The server certificate is still verified against the original host.
+        ssl_opts = ssl_opts.map(|ssl_opts| {
+            ssl_opts.with_tls_hostname_override(Some(opts.ip_or_hostname().to_string()))
+        });
+    }
+    Ok((builder.ssl_opts(ssl_opts).into(), ssh_tunnel))
+}
+
 impl MySQLConnector {
     pub fn new(conn_url: String, opts: Opts, server_id: Option<u32>) -> MySQLConnector {
         MySQLConnector {
             conn_url,
             conn_pool: Pool::new(opts),
             server_id,
+            ssh_tunnel: None,
         }
     }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.conn_pool = self.conn_pool.with_retry_policy(retry_policy);
+        self
+    }
+
+    pub fn with_ssh_tunnel(mut self, ssh_tunnel: Option<SshTunnel>) -> Self {
+        self.ssh_tunnel = ssh_tunnel;
+        self
+    }
 }
 
 #[async_trait]
@@ -177,6 +240,7 @@ impl Connector for MySQLConnector {
                                         typ,
                                         nullable,
                                         source: SourceDefinition::Dynamic,
+                                        annotations: Default::default(),
                                     }
                                 },
                             )
@@ -233,6 +297,7 @@ impl MySQLConnector {
                         schema: table.schema.clone(),
                         name: table.name.clone(),
                         column_names: table.column_names.clone(),
+                        filter: table.filter.clone(),
                     })
                     .collect::<Vec<TableInfo>>()
                     .as_slice(),
@@ -487,10 +552,13 @@ mod tests {
         tests::{create_test_table, mariadb_test_config, mysql_test_config, TestConfig},
     };
 
-    use super::MySQLConnector;
+    use super::{mysql_connection_opts, MySQLConnector};
+    use crate::MySQLConnectorError;
     use dozer_ingestion_connector::{
         dozer_types::{
-            models::ingestion_types::{IngestionMessage, TransactionInfo},
+            models::ingestion_types::{
+                IngestionMessage, MySQLConfig, SshTunnelConfig, TlsConfig, TransactionInfo,
+            },
             types::{
                 Field, FieldDefinition, FieldType, Operation::*, Record, Schema, SourceDefinition,
             },
@@ -522,6 +590,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_connection_opts_with_tls_and_ssh_tunnel() {
+        let mut config = MySQLConfig {
+            url: "mysql://root@db.example.com:3306/test".to_string(),
+            server_id: None,
+            ssh_tunnel: Some(SshTunnelConfig {
+                host: "bastion".to_string(),
+                port: None,
+                user: "dozer".to_string(),
+                private_key_path: "id_ed25519".to_string(),
+                known_hosts_path: None,
+            }),
+            tls: Some(TlsConfig {
+                ca_cert_path: Some("ca.pem".to_string()),
+                client_cert_path: Some("client.pem".to_string()),
+                client_key_path: Some("client.key".to_string()),
+            }),
+        };
+
+        let (opts, ssh_tunnel) = mysql_connection_opts(&config).unwrap();
+        let ssh_tunnel = ssh_tunnel.unwrap();
+        assert_eq!(opts.ip_or_hostname(), "127.0.0.1");
+        assert_eq!(opts.tcp_port(), ssh_tunnel.local_port());
+        let ssl_opts = opts.ssl_opts().unwrap();
+        assert_eq!(ssl_opts.root_certs().len(), 1);
+        assert!(ssl_opts.client_identity().is_some());
+        assert_eq!(ssl_opts.tls_hostname_override(), Some("db.example.com"));
+
+        config.tls = Some(TlsConfig {
+            client_cert_path: Some("client.pem".to_string()),
+            ..Default::default()
+        });
+        assert!(matches!(
+            mysql_connection_opts(&config),
+            Err(MySQLConnectorError::IncompleteClientCertificate)
+        ));
+    }
+
     async fn check_ingestion_messages(
         iterator: &mut IngestionIterator,
         expected_ingestion_messages: Vec<IngestionMessage>,
@@ -831,18 +937,21 @@ mod tests {
                             typ: FieldType::Int,
                             nullable: false,
                             source: SourceDefinition::Dynamic,
+                            annotations: Default::default(),
                         },
                         FieldDefinition {
                             name: "c2".into(),
                             typ: FieldType::Text,
                             nullable: true,
                             source: SourceDefinition::Dynamic,
+                            annotations: Default::default(),
                         },
                         FieldDefinition {
                             name: "c3".into(),
                             typ: FieldType::Float,
                             nullable: true,
                             source: SourceDefinition::Dynamic,
+                            annotations: Default::default(),
                         },
                     ],
                     primary_index: vec![0],
@@ -857,12 +966,14 @@ mod tests {
                             typ: FieldType::Int,
                             nullable: false,
                             source: SourceDefinition::Dynamic,
+                            annotations: Default::default(),
                         },
                         FieldDefinition {
                             name: "value".into(),
                             typ: FieldType::Json,
                             nullable: true,
                             source: SourceDefinition::Dynamic,
+                            annotations: Default::default(),
                         },
                     ],
                     primary_index: vec![0],
@@ -3,6 +3,7 @@ use dozer_ingestion_connector::dozer_types::{
     thiserror::{self, Error},
     types::FieldType,
 };
+use dozer_ingestion_connector::ssh_tunnel::SshTunnelError;
 use geozero::error::GeozeroError;
 
 mod binlog;
@@ -67,6 +68,12 @@ pub enum MySQLConnectorError {
 
     #[error("Multiple binlogs with the same suffix")]
     MultipleBinlogsWithSameSuffix,
+
+    #[error("Client certificate and client key must be configured together")]
+    IncompleteClientCertificate,
+
+    #[error("Failed to open SSH tunnel: {0}")]
+    SshTunnel(#[from] SshTunnelError),
 }
 
 #[derive(Error, Debug)]
@@ -1,11 +1,11 @@
 use crate::{helpers::escape_identifier, BreakingSchemaChange, MySQLConnectorError};
 
 use super::{
-    connection::{Conn, QueryResult},
+    connection::{Conn, Pool, QueryResult},
     conversion::get_field_type_for_mysql_column_type,
 };
 use dozer_ingestion_connector::{dozer_types::types::FieldType, TableIdentifier, TableInfo};
-use mysql_async::{from_row, Pool};
+use mysql_async::from_row;
 use mysql_common::Value;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -121,6 +121,7 @@ impl SchemaHelper<'_> {
                             schema: Some(table_schema),
                             name: table_name,
                             column_names: vec![column_name],
+                            filter: None,
                         }),
                     }
 
@@ -577,7 +578,7 @@ impl<'a> From<&'a &mut TableDefinition> for TableInfoRef<'a> {
 
 #[cfg(test)]
 mod tests {
-    use super::{ColumnDefinition, SchemaHelper, TableDefinition};
+    use super::{ColumnDefinition, Pool, SchemaHelper, TableDefinition};
     use crate::tests::{create_test_table, mariadb_test_config, mysql_test_config, TestConfig};
     use dozer_ingestion_connector::{
         dozer_types::types::FieldType, tokio, TableIdentifier, TableInfo,
@@ -587,7 +588,7 @@ mod tests {
     async fn test_connector_schemas(config: TestConfig) {
         // setup
         let url = &config.url;
-        let pool = &config.pool;
+        let pool = &Pool::new(config.opts.clone());
 
         let schema_helper = SchemaHelper::new(url, pool);
 
@@ -613,7 +614,8 @@ mod tests {
             vec![TableInfo {
                 schema: Some("test".into()),
                 name: "test1".into(),
-                column_names: vec!["c1".into(), "c2".into(), "c3".into()]
+                column_names: vec!["c1".into(), "c2".into(), "c3".into()],
+                filter: None,
             }]
         );
 
@@ -3,6 +3,11 @@ use dozer_ingestion_connector::dozer_types::node::OpIdentifier;
 use crate::binlog::BinlogPosition;
 use crate::MysqlStateError;
 
+/// Encodes a binlog position in the high 64 bits of an `OpIdentifier`.
+///
+/// Replication resumes from a binlog file and position rather than a GTID set: an `OpIdentifier` has 128 bits, and a
+/// GTID set is an unbounded list of source UUIDs with transaction intervals. Even a single GTID, a 128-bit UUID and a
+/// 64-bit transaction number, doesn't fit. So resuming after a failover to another server isn't supported.
 pub fn encode_state(pos: &BinlogPosition) -> OpIdentifier {
     let lsn = (pos.binlog_id << 32) | pos.position;
 
@@ -51,6 +51,7 @@ pub fn test_tables() -> Vec<TestTable> {
                 schema: Some("test".into()),
                 name: "test1".into(),
                 column_names: vec!["c1".into(), "c2".into(), "c3".into()],
+                filter: None,
             }
         },
         TestTable {
@@ -60,6 +61,7 @@ pub fn test_tables() -> Vec<TestTable> {
                 schema: Some("test".into()),
                 name: "test2".into(),
                 column_names: vec!["id".into(), "value".into()],
+                filter: None,
             }
         },
         TestTable {
@@ -69,6 +71,7 @@ pub fn test_tables() -> Vec<TestTable> {
                 schema: Some("test".into()),
                 name: "test3".into(),
                 column_names: vec!["a".into(), "b".into()],
+                filter: None,
             }
         },
     ]
@@ -0,0 +1,367 @@
+use std::{collections::HashMap, time::Duration};
+
+use async_nats::jetstream::{
+    self,
+    consumer::{pull, AckPolicy, PullConsumer},
+};
+use dozer_ingestion_connector::{
+    async_trait,
+    dozer_types::{
+        errors::internal::BoxedError,
+        event::Event,
+        log::warn,
+        models::ingestion_types::{
+            default_nats_consumer, IngestionMessage, NatsConfig, NatsSubject, TransactionInfo,
+        },
+        node::{NodeHandle, OpIdentifier},
+        serde_json,
+        types::{FieldType, Operation},
+    },
+    flush::FlushWaiter,
+    futures::StreamExt,
+    schema_parser::SchemaParser,
+    tokio::{
+        self,
+        sync::{broadcast::Receiver, mpsc},
+    },
+    utils::TableNotFound,
+    Connector, Ingestor, SourceSchema, SourceSchemaResult, TableIdentifier, TableInfo,
+};
+
+use crate::{
+    decoding::{map_record, Decoder},
+    subject::{match_subject, wildcard_count},
+    NatsError,
+};
+
+const BATCH_SIZE: usize = 1000;
+const BATCH_EXPIRY: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+pub struct NatsConnector {
+    name: String,
+    config: NatsConfig,
+    node_handle: NodeHandle,
+    event_receiver: Receiver<Event>,
+}
+
+/// A table being ingested, with everything needed to turn its messages into records.
+struct SubjectTable<'a> {
+    table_index: usize,
+    subject: &'a NatsSubject,
+    schema: SourceSchema,
+    decoder: Decoder,
+}
+
+impl NatsConnector {
+    pub fn new(
+        name: String,
+        config: NatsConfig,
+        node_handle: NodeHandle,
+        event_receiver: Receiver<Event>,
+    ) -> Self {
+        Self {
+            name,
+            config,
+            node_handle,
+            event_receiver,
+        }
+    }
+
+    fn schemas(&self) -> Result<HashMap<String, SourceSchema>, NatsError> {
+        let schemas = SchemaParser::parse_config(&self.config.schemas)?;
+        serde_json::from_str(&schemas).map_err(NatsError::Schemas)
+    }
+
+    fn subject(&self, table: &str) -> Option<&NatsSubject> {
+        self.config
+            .subjects
+            .iter()
+            .find(|subject| subject.name == table)
+    }
+
+    async fn client(&self) -> Result<async_nats::Client, NatsError> {
+        let mut options = async_nats::ConnectOptions::new().name(format!("dozer-{}", self.name));
+        if let Some(path) = &self.config.credentials_path {
+            options = options
+                .credentials_file(path)
+                .await
+                .map_err(NatsError::nats)?;
+        }
+        options
+            .connect(&self.config.url)
+            .await
+            .map_err(NatsError::nats)
+    }
+
+    async fn stream(&self) -> Result<jetstream::stream::Stream, NatsError> {
+        jetstream::new(self.client().await?)
+            .get_stream(&self.config.stream)
+            .await
+            .map_err(NatsError::nats)
+    }
+
+    async fn consumer(&self) -> Result<PullConsumer, NatsError> {
+        let stream = self.stream().await?;
+        let durable_name = self
+            .config
+            .consumer
+            .clone()
+            .unwrap_or_else(default_nats_consumer);
+        stream
+            .get_or_create_consumer(
+                &durable_name,
+                pull::Config {
+                    durable_name: Some(durable_name.clone()),
+                    filter_subjects: self
+                        .config
+                        .subjects
+                        .iter()
+                        .map(|subject| subject.subject.clone())
+                        .collect(),
+                    // Acknowledging a message acknowledges all the messages before it.
+                    ack_policy: AckPolicy::All,
+                    ..Default::default()
+                },
+            )
+            .await
+            .map_err(NatsError::nats)
+    }
+
+    /// Checks that the table is configured and that its wildcard fields are in its schema.
+    fn subject_table(
+        &self,
+        schemas: &HashMap<String, SourceSchema>,
+        table_index: usize,
+        schema_name: &Option<String>,
+        name: &str,
+    ) -> Result<SubjectTable, BoxedError> {
+        let subject = self
+            .subject(name)
+            .filter(|_| schema_name.is_none())
+            .ok_or_else(|| TableNotFound {
+                schema: schema_name.clone(),
+                name: name.to_string(),
+            })?;
+        let schema = schemas
+            .get(name)
+            .cloned()
+            .ok_or_else(|| NatsError::SchemaNotFound(name.to_string()))?;
+        let wildcards = wildcard_count(&subject.subject);
+        if subject.wildcard_fields.len() > wildcards {
+            return Err(NatsError::TooManyWildcardFields(
+                subject.subject.clone(),
+                wildcards,
+                subject.wildcard_fields.len(),
+            )
+            .into());
+        }
+        if let Some(field) = subject.wildcard_fields.iter().find(|field| {
+            !schema
+                .schema
+                .fields
+                .iter()
+                .any(|definition| definition.name == **field)
+        }) {
+            return Err(NatsError::FieldNotFound(name.to_string(), field.clone()).into());
+        }
+        Ok(SubjectTable {
+            table_index,
+            subject,
+            schema,
+            decoder: Decoder::new(&subject.format)?,
+        })
+    }
+}
+
+#[async_trait]
+impl Connector for NatsConnector {
+    fn types_mapping() -> Vec<(String, Option<FieldType>)>
+    where
+        Self: Sized,
+    {
+        vec![]
+    }
+
+    async fn validate_connection(&mut self) -> Result<(), BoxedError> {
+        self.stream().await?;
+        Ok(())
+    }
+
+    async fn list_tables(&mut self) -> Result<Vec<TableIdentifier>, BoxedError> {
+        Ok(self
+            .config
+            .subjects
+            .iter()
+            .map(|subject| TableIdentifier::from_table_name(subject.name.clone()))
+            .collect())
+    }
+
+    async fn validate_tables(&mut self, tables: &[TableIdentifier]) -> Result<(), BoxedError> {
+        let schemas = self.schemas()?;
+        for (table_index, table) in tables.iter().enumerate() {
+            self.subject_table(&schemas, table_index, &table.schema, &table.name)?;
+        }
+        Ok(())
+    }
+
+    async fn list_columns(
+        &mut self,
+        tables: Vec<TableIdentifier>,
+    ) -> Result<Vec<TableInfo>, BoxedError> {
+        let schemas = self.schemas()?;
+        let mut result = vec![];
+        for (table_index, table) in tables.into_iter().enumerate() {
+            let column_names = self
+                .subject_table(&schemas, table_index, &table.schema, &table.name)?
+                .schema
+                .schema
+                .fields
+                .into_iter()
+                .map(|field| field.name)
+                .collect();
+            result.push(TableInfo {
+                schema: table.schema,
+                name: table.name,
+                column_names,
+                filter: None,
+            });
+        }
+        Ok(result)
+    }
+
+    async fn get_schemas(
+        &mut self,
+        table_infos: &[TableInfo],
+    ) -> Result<Vec<SourceSchemaResult>, BoxedError> {
+        let schemas = self.schemas()?;
+        Ok(table_infos
+            .iter()
+            .enumerate()
+            .map(|(table_index, table)| {
+                self.subject_table(&schemas, table_index, &table.schema, &table.name)
+                    .map(|table| table.schema)
+            })
+            .collect())
+    }
+
+    fn sends_op_identifiers(&self) -> bool {
+        true
+    }
+
+    async fn serialize_state(&self) -> Result<Vec<u8>, BoxedError> {
+        Ok(vec![])
+    }
+
+    async fn start(
+        &mut self,
+        ingestor: &Ingestor,
+        tables: Vec<TableInfo>,
+        last_checkpoint: Option<OpIdentifier>,
+    ) -> Result<(), BoxedError> {
+        let schemas = self.schemas()?;
+        let tables = tables
+            .iter()
+            .enumerate()
+            .map(|(table_index, table)| {
+                self.subject_table(&schemas, table_index, &table.schema, &table.name)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let consumer = self.consumer().await?;
+
+        // A batch is acknowledged once a sink has flushed its commit, so the messages are redelivered if the
+        // pipeline stops before they are durable. Ingestion goes on meanwhile.
+        let (ack_sender, mut ack_receiver) =
+            mpsc::unbounded_channel::<(OpIdentifier, jetstream::Message)>();
+        let mut flush_waiter =
+            FlushWaiter::new(self.node_handle.clone(), self.event_receiver.resubscribe());
+        let acks = tokio::spawn(async move {
+            while let Some((id, message)) = ack_receiver.recv().await {
+                if !flush_waiter.wait_flushed(id).await {
+                    break;
+                }
+                message.ack().await.map_err(NatsError::nats)?;
+            }
+            Ok::<_, NatsError>(())
+        });
+
+        // Messages up to the checkpoint were ingested, but may not have been acknowledged.
+        let mut last_sequence = last_checkpoint.map_or(0, |checkpoint| checkpoint.txid);
+        loop {
+            let mut messages = consumer
+                .batch()
+                .max_messages(BATCH_SIZE)
+                .expires(BATCH_EXPIRY)
+                .messages()
+                .await
+                .map_err(NatsError::nats)?;
+            let mut last_message = None;
+            while let Some(message) = messages.next().await {
+                let message = message.map_err(NatsError::nats)?;
+                let sequence = message.info().map_err(NatsError::nats)?.stream_sequence;
+                if sequence > last_sequence {
+                    last_sequence = sequence;
+                    if let Some((table_index, op)) = map_message(&tables, &message)? {
+                        if ingestor
+                            .handle_message(IngestionMessage::OperationEvent {
+                                table_index,
+                                op,
+                                id: Some(OpIdentifier::new(sequence, 0)),
+                            })
+                            .await
+                            .is_err()
+                        {
+                            // If the ingestor is closed, we should stop
+                            return Ok(());
+                        }
+                    }
+                }
+                last_message = Some(message);
+            }
+
+            let Some(last_message) = last_message else {
+                continue;
+            };
+            let id = OpIdentifier::new(last_sequence, 0);
+            if ingestor
+                .handle_message(IngestionMessage::TransactionInfo(TransactionInfo::Commit {
+                    id: Some(id),
+                }))
+                .await
+                .is_err()
+            {
+                return Ok(());
+            }
+            if ack_sender.send((id, last_message)).is_err() {
+                // The acknowledging task stopped, either because the pipeline is down or on an error.
+                return match acks.await {
+                    Ok(Err(e)) => Err(e.into()),
+                    _ => Ok(()),
+                };
+            }
+        }
+    }
+}
+
+fn map_message(
+    tables: &[SubjectTable],
+    message: &jetstream::Message,
+) -> Result<Option<(usize, Operation)>, NatsError> {
+    let Some((table, tokens)) = tables.iter().find_map(|table| {
+        match_subject(&table.subject.subject, &message.subject).map(|tokens| (table, tokens))
+    }) else {
+        warn!(
+            "Ignoring message on unconfigured subject {}",
+            message.subject
+        );
+        return Ok(None);
+    };
+    let payload = table.decoder.decode(&message.payload)?;
+    let new = map_record(
+        &table.schema.schema,
+        &table.subject.wildcard_fields,
+        &tokens,
+        payload,
+    )?;
+    Ok(Some((table.table_index, Operation::Insert { new })))
+}
@@ -0,0 +1,60 @@
+use dozer_ingestion_connector::dozer_types::{
+    json_record::{json_object_to_record, JsonCoercionPolicy},
+    models::ingestion_types::NatsMessageFormat,
+    serde_json::{self, Map, Value},
+    types::{Record, Schema},
+};
+
+use crate::NatsError;
+
+/// Decodes message payloads to JSON objects.
+#[derive(Debug)]
+pub enum Decoder {
+    Json,
+    Avro(apache_avro::Schema),
+}
+
+impl Decoder {
+    pub fn new(format: &NatsMessageFormat) -> Result<Self, NatsError> {
+        match format {
+            NatsMessageFormat::Json => Ok(Decoder::Json),
+            NatsMessageFormat::Avro(schema) => apache_avro::Schema::parse_str(schema)
+                .map(Decoder::Avro)
+                .map_err(NatsError::AvroSchema),
+        }
+    }
+
+    pub fn decode(&self, payload: &[u8]) -> Result<Map<String, Value>, NatsError> {
+        let value = match self {
+            Decoder::Json => serde_json::from_slice(payload).map_err(NatsError::Json)?,
+            Decoder::Avro(schema) => {
+                let value = apache_avro::from_avro_datum(schema, &mut &payload[..], None)
+                    .map_err(NatsError::Avro)?;
+                Value::try_from(value).map_err(NatsError::Avro)?
+            }
+        };
+        match value {
+            Value::Object(object) => Ok(object),
+            _ => Err(NatsError::NotAnObject),
+        }
+    }
+}
+
+/// Builds a record of `schema` from the subject tokens matched by the wildcards and the message payload.
+/// Fields named in `wildcard_fields` take their value from the subject, the others from the payload.
+pub fn map_record(
+    schema: &Schema,
+    wildcard_fields: &[String],
+    tokens: &[String],
+    mut payload: Map<String, Value>,
+) -> Result<Record, NatsError> {
+    for (field, token) in wildcard_fields.iter().zip(tokens) {
+        payload.insert(field.clone(), Value::String(token.clone()));
+    }
+    // Subject tokens are strings, so they are parsed to the field types.
+    let policy = JsonCoercionPolicy {
+        parse_strings: true,
+        ..Default::default()
+    };
+    Ok(json_object_to_record(payload, schema, policy)?)
+}
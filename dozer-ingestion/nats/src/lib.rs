@@ -0,0 +1,58 @@
+use dozer_ingestion_connector::{
+    dozer_types::{
+        json_record::JsonRecordError,
+        serde_json,
+        thiserror::{self, Error},
+    },
+    schema_parser::SchemaParserError,
+};
+
+pub mod connector;
+mod decoding;
+mod subject;
+#[cfg(test)]
+mod tests;
+
+pub use connector::NatsConnector;
+
+#[derive(Error, Debug)]
+pub enum NatsError {
+    #[error("nats error: {0}")]
+    Nats(#[source] async_nats::Error),
+
+    #[error(transparent)]
+    SchemaParser(#[from] SchemaParserError),
+
+    #[error("cannot parse schemas: {0}")]
+    Schemas(#[source] serde_json::Error),
+
+    #[error("no schema defined for table {0}")]
+    SchemaNotFound(String),
+
+    #[error("field {1} of table {0} is not in its schema")]
+    FieldNotFound(String, String),
+
+    #[error("subject {0} has {1} wildcards, but {2} wildcard fields are configured")]
+    TooManyWildcardFields(String, usize, usize),
+
+    #[error("invalid avro schema: {0}")]
+    AvroSchema(#[source] apache_avro::Error),
+
+    #[error("cannot decode avro message: {0}")]
+    Avro(#[source] apache_avro::Error),
+
+    #[error("cannot decode json message: {0}")]
+    Json(#[source] serde_json::Error),
+
+    #[error("message is not an object")]
+    NotAnObject,
+
+    #[error("cannot convert message: {0}")]
+    Record(#[from] JsonRecordError),
+}
+
+impl NatsError {
+    fn nats(error: impl Into<async_nats::Error>) -> Self {
+        NatsError::Nats(error.into())
+    }
+}
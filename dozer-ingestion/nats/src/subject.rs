@@ -0,0 +1,33 @@
+/// Matches a subject against a subject filter and returns the tokens matched by the wildcards.
+///
+/// `*` matches exactly one token. `>` matches one or more trailing tokens, which are returned joined by `.`.
+pub fn match_subject(filter: &str, subject: &str) -> Option<Vec<String>> {
+    let mut captures = vec![];
+    let mut tokens = subject.split('.');
+    for pattern in filter.split('.') {
+        match pattern {
+            ">" => {
+                let rest = tokens.by_ref().collect::<Vec<_>>();
+                if rest.is_empty() {
+                    return None;
+                }
+                captures.push(rest.join("."));
+                return Some(captures);
+            }
+            "*" => captures.push(tokens.next()?.to_string()),
+            literal => {
+                if tokens.next()? != literal {
+                    return None;
+                }
+            }
+        }
+    }
+    tokens.next().is_none().then_some(captures)
+}
+
+pub fn wildcard_count(filter: &str) -> usize {
+    filter
+        .split('.')
+        .filter(|token| *token == "*" || *token == ">")
+        .count()
+}
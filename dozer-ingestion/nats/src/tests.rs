@@ -0,0 +1,132 @@
+use dozer_ingestion_connector::dozer_types::{
+    json_record::JsonRecordError,
+    models::ingestion_types::NatsMessageFormat,
+    serde_json::json,
+    types::{Field, FieldDefinition, FieldType, Record, Schema, SourceDefinition},
+};
+
+use crate::{
+    decoding::{map_record, Decoder},
+    subject::{match_subject, wildcard_count},
+    NatsError,
+};
+
+#[test]
+fn test_match_subject() {
+    assert_eq!(
+        match_subject("orders.created", "orders.created"),
+        Some(vec![])
+    );
+    assert_eq!(match_subject("orders.created", "orders.updated"), None);
+    assert_eq!(
+        match_subject("orders.*.created", "orders.eu.created"),
+        Some(vec!["eu".to_string()])
+    );
+    assert_eq!(match_subject("orders.*", "orders.eu.created"), None);
+    assert_eq!(
+        match_subject("orders.*.>", "orders.eu.shop.1"),
+        Some(vec!["eu".to_string(), "shop.1".to_string()])
+    );
+    assert_eq!(match_subject("orders.>", "orders"), None);
+    assert_eq!(wildcard_count("orders.*.>"), 2);
+}
+
+fn schema() -> Schema {
+    let mut schema = Schema::new();
+    schema
+        .field(
+            FieldDefinition::new(
+                "region".to_string(),
+                FieldType::String,
+                false,
+                SourceDefinition::Dynamic,
+            ),
+            true,
+        )
+        .field(
+            FieldDefinition::new(
+                "id".to_string(),
+                FieldType::Int,
+                false,
+                SourceDefinition::Dynamic,
+            ),
+            true,
+        )
+        .field(
+            FieldDefinition::new(
+                "amount".to_string(),
+                FieldType::Float,
+                true,
+                SourceDefinition::Dynamic,
+            ),
+            false,
+        );
+    schema
+}
+
+#[test]
+fn test_map_json_record() {
+    let decoder = Decoder::new(&NatsMessageFormat::Json).unwrap();
+    let payload = decoder.decode(br#"{"id": 7, "amount": 1.5}"#).unwrap();
+    let record = map_record(
+        &schema(),
+        &["region".to_string()],
+        &["eu".to_string()],
+        payload,
+    )
+    .unwrap();
+    assert_eq!(
+        record,
+        Record::new(vec![
+            Field::String("eu".to_string()),
+            Field::Int(7),
+            Field::Float(1.5.into()),
+        ])
+    );
+
+    let payload = json!({ "amount": 1.5 }).as_object().unwrap().clone();
+    assert!(matches!(
+        map_record(&schema(), &["region".to_string()], &["eu".to_string()], payload),
+        Err(NatsError::Record(JsonRecordError::Fields(errors))) if errors[0].field == "id"
+    ));
+    assert!(matches!(
+        decoder.decode(b"[1]"),
+        Err(NatsError::NotAnObject)
+    ));
+}
+
+#[test]
+fn test_map_avro_record() {
+    let avro_schema = r#"{
+        "type": "record",
+        "name": "order",
+        "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "amount", "type": "double"}
+        ]
+    }"#;
+    let decoder = Decoder::new(&NatsMessageFormat::Avro(avro_schema.to_string())).unwrap();
+    let Decoder::Avro(parsed) = &decoder else {
+        panic!("expected an avro decoder");
+    };
+    let mut record = apache_avro::types::Record::new(parsed).unwrap();
+    record.put("id", 7i64);
+    record.put("amount", 1.5f64);
+    let payload = apache_avro::to_avro_datum(parsed, record).unwrap();
+
+    let record = map_record(
+        &schema(),
+        &["region".to_string()],
+        &["us".to_string()],
+        decoder.decode(&payload).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        record,
+        Record::new(vec![
+            Field::String("us".to_string()),
+            Field::Int(7),
+            Field::Float(1.5.into()),
+        ])
+    );
+}
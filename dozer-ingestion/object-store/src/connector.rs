@@ -74,6 +74,7 @@ impl<T: DozerObjectStore> Connector for ObjectStoreConnector<T> {
                 schema: table.schema,
                 name: table.name,
                 column_names,
+                filter: None,
             };
             result.push(table_info);
         }
@@ -146,6 +147,7 @@ impl<T: DozerObjectStore> Connector for ObjectStoreConnector<T> {
                 schema: table_info.schema.clone(),
                 name: table_info.name.clone(),
                 column_names: table_info.column_names.clone(),
+                filter: None,
             };
 
             let mut found = false;
@@ -199,6 +201,7 @@ impl<T: DozerObjectStore> Connector for ObjectStoreConnector<T> {
                 schema: table_info.schema,
                 name: table_info.name,
                 column_names: table_info.column_names,
+                filter: None,
             };
 
             for table in self.config.tables() {
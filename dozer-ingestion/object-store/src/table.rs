@@ -5,7 +5,9 @@ use dozer_ingestion_connector::{
     dozer_types::{
         chrono::{DateTime, Utc},
         log::info,
-        models::ingestion_types::{self, CsvConfig, IngestionMessage, ParquetConfig},
+        models::ingestion_types::{
+            self, default_watch_interval_ms, CsvConfig, IngestionMessage, ParquetConfig,
+        },
     },
     futures::StreamExt,
     tokio::{self, sync::mpsc::Sender},
@@ -26,6 +28,7 @@ pub trait TableConfig {
     fn path(&self) -> &str;
     fn extension(&self) -> &str;
     fn marker_extension(&self) -> Option<&str>;
+    fn watch_interval(&self) -> Duration;
 }
 
 pub struct ObjectStoreTable<C: TableConfig, O: DozerObjectStore> {
@@ -323,9 +326,7 @@ impl<C: TableConfig, O: DozerObjectStore> ObjectStoreTable<C, O> {
                 }
             }
 
-            // Wait for 10 seconds before checking again
-            const WATCHER_INTERVAL: Duration = Duration::from_secs(1);
-            tokio::time::sleep(WATCHER_INTERVAL).await;
+            tokio::time::sleep(self.table_config.watch_interval()).await;
         }
     }
 }
@@ -342,6 +343,13 @@ impl TableConfig for CsvConfig {
     fn marker_extension(&self) -> Option<&str> {
         self.marker_extension.as_deref()
     }
+
+    fn watch_interval(&self) -> Duration {
+        Duration::from_millis(
+            self.watch_interval_ms
+                .unwrap_or_else(default_watch_interval_ms),
+        )
+    }
 }
 
 impl TableConfig for ParquetConfig {
@@ -356,6 +364,13 @@ impl TableConfig for ParquetConfig {
     fn marker_extension(&self) -> Option<&str> {
         self.marker_extension.as_deref()
     }
+
+    fn watch_interval(&self) -> Duration {
+        Duration::from_millis(
+            self.watch_interval_ms
+                .unwrap_or_else(default_watch_interval_ms),
+        )
+    }
 }
 
 impl TableConfig for ingestion_types::TableConfig {
@@ -381,4 +396,13 @@ impl TableConfig for ingestion_types::TableConfig {
             }
         }
     }
+
+    fn watch_interval(&self) -> Duration {
+        match self {
+            ingestion_types::TableConfig::CSV(csv_config) => csv_config.watch_interval(),
+            ingestion_types::TableConfig::Parquet(parquet_config) => {
+                parquet_config.watch_interval()
+            }
+        }
+    }
 }
@@ -16,6 +16,7 @@ pub fn get_local_storage_config(typ: &str, prefix: &str) -> LocalStorage {
                         extension: typ.to_string(),
                         path: format!("all_types_{typ}"),
                         marker_extension: None,
+                        watch_interval_ms: None,
                     }),
                     name: format!("all_types_{typ}"),
                 }],
@@ -29,6 +30,7 @@ pub fn get_local_storage_config(typ: &str, prefix: &str) -> LocalStorage {
                         extension: typ.to_string(),
                         path: format!("{prefix}_{typ}"),
                         marker_extension: Some(String::from(".marker")),
+                        watch_interval_ms: None,
                     }),
                     name: format!("{prefix}_{typ}"),
                 }],
@@ -44,6 +46,7 @@ pub fn get_local_storage_config(typ: &str, prefix: &str) -> LocalStorage {
                         extension: typ.to_string(),
                         path: format!("all_types_{typ}"),
                         marker_extension: None,
+                        watch_interval_ms: None,
                     }),
                     name: format!("all_types_{typ}"),
                 }],
@@ -57,6 +60,7 @@ pub fn get_local_storage_config(typ: &str, prefix: &str) -> LocalStorage {
                         extension: typ.to_string(),
                         path: format!("{prefix}_{typ}"),
                         marker_extension: Some(String::from(".marker")),
+                        watch_interval_ms: None,
                     }),
                     name: format!("{prefix}_{typ}"),
                 }],
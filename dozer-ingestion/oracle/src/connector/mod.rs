@@ -191,6 +191,7 @@ impl Connector {
                 schema: table.schema,
                 name: table.name,
                 column_names,
+                filter: None,
             });
         }
         self.connection.commit()?;
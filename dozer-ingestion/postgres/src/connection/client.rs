@@ -11,33 +11,34 @@ use dozer_ingestion_connector::{
     tokio::{self, sync::Mutex},
 };
 use tokio_postgres::types::ToSql;
-use tokio_postgres::{Config, CopyBothDuplex, Row, SimpleQueryMessage, Statement, ToStatement};
+use tokio_postgres::{CopyBothDuplex, Row, SimpleQueryMessage, Statement, ToStatement};
 
-use crate::connection::helper::is_network_failure;
+use crate::connection::helper::{is_network_failure, PostgresConnectionConfig};
 use crate::PostgresConnectorError;
 
 use super::helper;
 
 #[derive(Debug)]
 pub struct Client {
-    config: tokio_postgres::Config,
+    config: PostgresConnectionConfig,
     inner: tokio_postgres::Client,
 }
 
 impl Client {
-    pub fn new(config: Config, client: tokio_postgres::Client) -> Self {
+    pub fn new(config: PostgresConnectionConfig, client: tokio_postgres::Client) -> Self {
         Self {
             config,
             inner: client,
         }
     }
 
-    pub fn config(&self) -> &Config {
+    pub fn config(&self) -> &PostgresConnectionConfig {
         &self.config
     }
 
     pub async fn prepare(&mut self, query: &str) -> Result<Statement, tokio_postgres::Error> {
         retry_on_network_failure!(
+            self.config.retry_policy(),
             "prepare",
             self.inner.prepare(query).await,
             is_network_failure,
@@ -50,6 +51,7 @@ impl Client {
         query: &str,
     ) -> Result<Vec<SimpleQueryMessage>, tokio_postgres::Error> {
         retry_on_network_failure!(
+            self.config.retry_policy(),
             "simple_query",
             self.inner.simple_query(query).await,
             is_network_failure,
@@ -66,6 +68,7 @@ impl Client {
         T: ?Sized + ToStatement,
     {
         retry_on_network_failure!(
+            self.config.retry_policy(),
             "query_one",
             self.inner.query_one(statement, params).await,
             is_network_failure,
@@ -82,6 +85,7 @@ impl Client {
         T: ?Sized + ToStatement,
     {
         retry_on_network_failure!(
+            self.config.retry_policy(),
             "query",
             self.inner.query(statement, params).await,
             is_network_failure,
@@ -99,6 +103,18 @@ impl Client {
         Ok(Box::pin(row_stream))
     }
 
+    /// Unlike `query_raw`, streams the rows on this connection, so they are read in its current transaction.
+    /// The query is not resumed on network failures, as the transaction would be lost.
+    pub async fn query_raw_in_transaction(
+        &mut self,
+        query: &str,
+    ) -> Result<BoxStream<'static, Result<Row, tokio_postgres::Error>>, tokio_postgres::Error> {
+        let statement = self.inner.prepare(query).await?;
+        let params: Vec<String> = Vec::new();
+        let row_stream = self.inner.query_raw(&statement, params).await?;
+        Ok(Box::pin(row_stream))
+    }
+
     pub async fn copy_both_simple<T>(
         &mut self,
         query: &str,
@@ -107,6 +123,7 @@ impl Client {
         T: bytes::Buf + 'static + Send,
     {
         retry_on_network_failure!(
+            self.config.retry_policy(),
             "copy_both_simple",
             self.inner.copy_both_simple(query).await,
             is_network_failure,
@@ -116,6 +133,7 @@ impl Client {
 
     pub async fn batch_execute(&mut self, query: &str) -> Result<(), tokio_postgres::Error> {
         retry_on_network_failure!(
+            self.config.retry_policy(),
             "batch_execute",
             self.inner.batch_execute(query).await,
             is_network_failure,
@@ -129,7 +147,9 @@ impl Client {
         Ok(())
     }
 
-    pub async fn connect(config: Config) -> Result<Client, tokio_postgres::Error> {
+    pub async fn connect(
+        config: PostgresConnectionConfig,
+    ) -> Result<Client, tokio_postgres::Error> {
         let client = match helper::connect(config).await {
             Ok(client) => client,
             Err(PostgresConnectorError::ConnectionFailure(err)) => return Err(err),
@@ -144,6 +164,7 @@ impl Client {
         params: Vec<String>,
     ) -> Result<tokio_postgres::RowStream, tokio_postgres::Error> {
         retry_on_network_failure!(
+            self.config.retry_policy(),
             "query_raw",
             self.inner.query_raw(&statement, &params).await,
             is_network_failure,
@@ -1,6 +1,6 @@
 use crate::{connector::ReplicationSlotInfo, PostgresConnectorError};
 
-use super::{client::Client, tables_validator::TablesValidator};
+use super::{client::Client, helper::PostgresConnectionConfig, tables_validator::TablesValidator};
 use dozer_ingestion_connector::{
     dozer_types::indicatif::{ProgressBar, ProgressStyle},
     utils::ListOrFilterColumns,
@@ -18,7 +18,7 @@ pub enum Validations {
 
 pub async fn validate_connection(
     name: &str,
-    config: tokio_postgres::Config,
+    config: PostgresConnectionConfig,
     tables: Option<&Vec<ListOrFilterColumns>>,
     replication_info: Option<ReplicationSlotInfo>,
 ) -> Result<(), PostgresConnectorError> {
@@ -1,3 +1,4 @@
+use crate::connection::helper::PostgresConnectionConfig;
 use dozer_ingestion_connector::dozer_types::node::OpIdentifier;
 use dozer_ingestion_connector::{
     async_trait,
@@ -9,7 +10,7 @@ use postgres_types::PgLsn;
 use rand::distributions::Alphanumeric;
 use rand::Rng;
 use tokio_postgres::config::ReplicationMode;
-use tokio_postgres::Config;
+use tokio_postgres::SimpleQueryMessage;
 
 use crate::{
     connection::validator::validate_connection,
@@ -24,20 +25,23 @@ use super::connection::helper;
 #[derive(Clone, Debug)]
 pub struct PostgresConfig {
     pub name: String,
-    pub config: Config,
+    pub config: PostgresConnectionConfig,
     pub schema: Option<String>,
     pub batch_size: usize,
+    /// The maximum number of connections tables are snapshotted over in parallel.
+    pub snapshot_connections: usize,
 }
 
 #[derive(Debug)]
 pub struct PostgresConnector {
     pub name: String,
     pub slot_name: String,
-    replication_conn_config: Config,
-    conn_config: Config,
+    replication_conn_config: PostgresConnectionConfig,
+    conn_config: PostgresConnectionConfig,
     schema_helper: SchemaHelper,
     pub schema: Option<String>,
     batch_size: usize,
+    snapshot_connections: usize,
 }
 
 #[derive(Debug)]
@@ -74,6 +78,7 @@ impl PostgresConnector {
             schema_helper: helper,
             schema: config.schema,
             batch_size: config.batch_size,
+            snapshot_connections: config.snapshot_connections,
         })
     }
 }
@@ -138,6 +143,7 @@ impl Connector for PostgresConnector {
                 schema: Some(table.schema),
                 name: table.name,
                 column_names: table.columns,
+                filter: None,
             })
             .collect())
     }
@@ -163,6 +169,10 @@ impl Connector for PostgresConnector {
             .collect())
     }
 
+    fn supports_filter(&self) -> bool {
+        true
+    }
+
     async fn serialize_state(&self) -> Result<Vec<u8>, BoxedError> {
         Ok(self.slot_name.as_bytes().to_vec())
     }
@@ -177,13 +187,13 @@ impl Connector for PostgresConnector {
 
         if lsn.is_none() {
             let client = helper::connect(self.replication_conn_config.clone()).await?;
-            let table_identifiers = tables
-                .iter()
-                .map(|table| TableIdentifier::new(table.schema.clone(), table.name.clone()))
-                .collect::<Vec<_>>();
-            create_publication(client, &self.name, Some(&table_identifiers)).await?;
+            create_publication(client, &self.name, Some(&tables)).await?;
         }
 
+        let filters = tables
+            .iter()
+            .map(|table| table.filter.clone())
+            .collect::<Vec<_>>();
         let tables = tables
             .into_iter()
             .map(|table| ListOrFilterColumns {
@@ -192,16 +202,21 @@ impl Connector for PostgresConnector {
                 columns: Some(table.column_names),
             })
             .collect::<Vec<_>>();
+        let mut table_infos = self.schema_helper.get_tables(Some(&tables)).await?;
+        for (table_info, filter) in table_infos.iter_mut().zip(filters) {
+            table_info.filter = filter;
+        }
         let iterator = PostgresIterator::new(
             self.name.clone(),
             get_publication_name(&self.name),
             self.slot_name.clone(),
-            self.schema_helper.get_tables(Some(&tables)).await?,
+            table_infos,
             self.replication_conn_config.clone(),
             ingestor,
             self.conn_config.clone(),
             self.schema.clone(),
             self.batch_size,
+            self.snapshot_connections,
         );
         iterator.start(lsn).await.map_err(Into::into)
     }
@@ -225,28 +240,56 @@ pub fn get_slot_name(conn_name: &str) -> String {
     )
 }
 
+/// Oldest `server_version_num` supporting row filters and column lists in publications.
+const PUBLICATION_FILTERS_MIN_VERSION: u32 = 150000;
+
+/// Publishes the changes of `tables`, or of all tables if `None`.
+///
+/// Since PostgreSQL 15, only the selected columns of a table are published, along with its replica identity, which the
+/// publication must cover. Filtered tables publish only the changes of matching rows, which needs PostgreSQL 15 or
+/// later. The filter of a table publishing updates and deletes may only use columns of its replica identity.
 pub async fn create_publication(
     mut client: Client,
     conn_name: &str,
-    table_identifiers: Option<&[TableIdentifier]>,
+    tables: Option<&[TableInfo]>,
 ) -> Result<(), PostgresConnectorError> {
     let publication_name = get_publication_name(conn_name);
-    let table_str: String = match table_identifiers {
+    let table_str: String = match tables {
         None => "ALL TABLES".to_string(),
-        Some(table_identifiers) => {
-            let table_names = table_identifiers
-                .iter()
-                .map(|table_identifier| {
-                    format!(
-                        r#""{}"."{}""#,
-                        table_identifier
-                            .schema
-                            .as_deref()
-                            .unwrap_or(DEFAULT_SCHEMA_NAME),
-                        table_identifier.name
-                    )
-                })
-                .collect::<Vec<_>>();
+        Some(tables) => {
+            let version = server_version_num(&mut client).await?;
+            if version < PUBLICATION_FILTERS_MIN_VERSION
+                && tables.iter().any(|table| table.filter.is_some())
+            {
+                return Err(PostgresConnectorError::FilterNotSupported(version));
+            }
+
+            let mut table_names = vec![];
+            for table in tables {
+                let schema = table.schema.as_deref().unwrap_or(DEFAULT_SCHEMA_NAME);
+                let mut name = format!(r#""{}"."{}""#, schema, table.name);
+                if version >= PUBLICATION_FILTERS_MIN_VERSION && !table.column_names.is_empty() {
+                    if let Some(identity) =
+                        replica_identity_columns(&mut client, schema, &table.name).await?
+                    {
+                        let mut columns = table.column_names.clone();
+                        for column in identity {
+                            if !columns.contains(&column) {
+                                columns.push(column);
+                            }
+                        }
+                        let columns = columns
+                            .iter()
+                            .map(|column| format!(r#""{column}""#))
+                            .collect::<Vec<_>>();
+                        name = format!("{name} ({})", columns.join(", "));
+                    }
+                }
+                if let Some(filter) = &table.filter {
+                    name = format!("{name} WHERE ({filter})");
+                }
+                table_names.push(name);
+            }
             format!("TABLE {}", table_names.join(" , "))
         }
     };
@@ -263,3 +306,61 @@ pub async fn create_publication(
 
     Ok(())
 }
+
+fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+async fn server_version_num(client: &mut Client) -> Result<u32, PostgresConnectorError> {
+    let messages = client
+        .simple_query("SHOW server_version_num")
+        .await
+        .map_err(PostgresConnectorError::PublicationTablesQueryError)?;
+    messages
+        .iter()
+        .find_map(|message| match message {
+            SimpleQueryMessage::Row(row) => row.get(0),
+            _ => None,
+        })
+        .and_then(|version| version.parse().ok())
+        .ok_or_else(|| {
+            PostgresConnectorError::ValueConversionError("server_version_num".to_string())
+        })
+}
+
+/// Returns the columns of the replica identity of a table, or `None` if it's all of them.
+///
+/// The replication connection doesn't support the extended query protocol, so the names are quoted in the query.
+async fn replica_identity_columns(
+    client: &mut Client,
+    schema: &str,
+    table: &str,
+) -> Result<Option<Vec<String>>, PostgresConnectorError> {
+    let query = format!(
+        "SELECT c.relreplident, a.attname
+        FROM pg_class c
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            LEFT JOIN pg_index i ON i.indrelid = c.oid
+                AND (i.indisreplident OR (c.relreplident = 'd' AND i.indisprimary))
+            LEFT JOIN pg_attribute a ON a.attrelid = c.oid AND a.attnum = ANY(i.indkey)
+        WHERE n.nspname = {} AND c.relname = {}",
+        quote_literal(schema),
+        quote_literal(table)
+    );
+    let messages = client
+        .simple_query(&query)
+        .await
+        .map_err(PostgresConnectorError::PublicationTablesQueryError)?;
+    let mut columns = vec![];
+    for message in &messages {
+        if let SimpleQueryMessage::Row(row) = message {
+            if row.get(0) == Some("f") {
+                return Ok(None);
+            }
+            if let Some(column) = row.get(1) {
+                columns.push(column.to_string());
+            }
+        }
+    }
+    Ok(Some(columns))
+}
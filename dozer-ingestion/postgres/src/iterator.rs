@@ -6,7 +6,7 @@ use dozer_ingestion_connector::utils::ListOrFilterColumns;
 use dozer_ingestion_connector::Ingestor;
 use postgres_types::PgLsn;
 
-use crate::connection::helper;
+use crate::connection::helper::{self, PostgresConnectionConfig};
 use crate::connector::REPLICATION_SLOT_PREFIX;
 use crate::replication_slot_helper::ReplicationSlotHelper;
 use crate::replicator::CDCHandler;
@@ -20,10 +20,11 @@ pub struct Details {
     publication_name: String,
     slot_name: String,
     tables: Vec<PostgresTableInfo>,
-    replication_conn_config: tokio_postgres::Config,
-    conn_config: tokio_postgres::Config,
+    replication_conn_config: PostgresConnectionConfig,
+    conn_config: PostgresConnectionConfig,
     schema: Option<String>,
     batch_size: usize,
+    snapshot_connections: usize,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -45,11 +46,12 @@ impl<'a> PostgresIterator<'a> {
         publication_name: String,
         slot_name: String,
         tables: Vec<PostgresTableInfo>,
-        replication_conn_config: tokio_postgres::Config,
+        replication_conn_config: PostgresConnectionConfig,
         ingestor: &'a Ingestor,
-        conn_config: tokio_postgres::Config,
+        conn_config: PostgresConnectionConfig,
         schema: Option<String>,
         batch_size: usize,
+        snapshot_connections: usize,
     ) -> Self {
         let details = Arc::new(Details {
             name,
@@ -60,6 +62,7 @@ impl<'a> PostgresIterator<'a> {
             conn_config,
             schema,
             batch_size,
+            snapshot_connections,
         });
         PostgresIterator { details, ingestor }
     }
@@ -132,17 +135,12 @@ impl<'a> PostgresIteratorHandler<'a> {
                     .map_err(PostgresConnectorError::InvalidQueryError)?;
             }
 
-            client
-                .simple_query("BEGIN READ ONLY ISOLATION LEVEL REPEATABLE READ;")
-                .await
-                .map_err(|_e| {
-                    debug!("failed to begin txn for replication");
-                    PostgresConnectorError::BeginReplication
-                })?;
-
-            let replication_slot_lsn =
-                ReplicationSlotHelper::create_replication_slot(&mut client, &details.slot_name)
-                    .await?;
+            let (replication_slot_lsn, snapshot_name) =
+                ReplicationSlotHelper::create_replication_slot_exporting_snapshot(
+                    &mut client,
+                    &details.slot_name,
+                )
+                .await?;
             if let Some(lsn) = replication_slot_lsn {
                 self.lsn = PgLsn::from_str(&lsn).map_or_else(
                     |_| Err(PostgresConnectorError::LsnParseError(lsn.to_string())),
@@ -162,6 +160,8 @@ impl<'a> PostgresIteratorHandler<'a> {
                 ingestor: self.ingestor,
                 schema: details.schema.clone(),
                 batch_size: details.batch_size,
+                snapshot_connections: details.snapshot_connections,
+                snapshot_name,
             };
             let tables = details
                 .tables
@@ -172,14 +172,17 @@ impl<'a> PostgresIteratorHandler<'a> {
                     schema: Some(table_info.schema.clone()),
                 })
                 .collect::<Vec<_>>();
-            snapshotter.sync_tables(&tables).await?;
+            let filters = details
+                .tables
+                .iter()
+                .map(|table_info| table_info.filter.clone())
+                .collect::<Vec<_>>();
+            snapshotter.sync_tables(&tables, &filters).await?;
 
             debug!("\nInitialized with tables: {:?}", details.tables);
 
-            client.simple_query("COMMIT;").await.map_err(|_e| {
-                debug!("failed to commit txn for replication");
-                PostgresConnectorError::CommitReplication
-            })?;
+            // The exported snapshot is released with the connection that created the slot.
+            drop(client);
         }
 
         self.state = ReplicationState::Replicating;
@@ -6,6 +6,7 @@ use dozer_ingestion_connector::dozer_types::{
     errors::types::{DeserializationError, TypeError},
     thiserror::{self, Error},
 };
+use dozer_ingestion_connector::ssh_tunnel::SshTunnelError;
 use tokio_postgres::config::SslMode;
 
 pub mod connection;
@@ -65,6 +66,12 @@ pub enum PostgresConnectorError {
     #[error("Failed to drop publication: {0}")]
     DropPublicationError(#[source] tokio_postgres::Error),
 
+    #[error("Failed to query the tables to publish: {0}")]
+    PublicationTablesQueryError(#[source] tokio_postgres::Error),
+
+    #[error("Filtering rows needs PostgreSQL 15 or later, but the server version is {0}")]
+    FilterNotSupported(u32),
+
     #[error("Failed to begin txn for replication")]
     BeginReplication,
 
@@ -128,6 +135,21 @@ pub enum PostgresConnectorError {
     #[error("Failed to load native certs: {0}")]
     LoadNativeCerts(#[source] std::io::Error),
 
+    #[error("Failed to load TLS file {0}: {1}")]
+    LoadTlsFile(String, #[source] std::io::Error),
+
+    #[error("No private key found in {0}")]
+    MissingPrivateKey(String),
+
+    #[error("Client certificate and client key must be configured together")]
+    IncompleteClientCertificate,
+
+    #[error("Invalid TLS configuration: {0}")]
+    InvalidTlsConfig(#[source] rustls::Error),
+
+    #[error("Failed to open SSH tunnel: {0}")]
+    SshTunnel(#[from] SshTunnelError),
+
     #[error("Non utf8 column name in table {table_index} column {column_index}")]
     NonUtf8ColumnName {
         table_index: usize,
@@ -44,6 +44,34 @@ impl ReplicationSlotHelper {
         }
     }
 
+    /// Creates the slot outside of a transaction, exporting its snapshot so other connections can read the tables as of
+    /// the slot's consistent point. Returns the consistent point and the snapshot name. The snapshot stays valid until
+    /// the next command on `client`.
+    pub async fn create_replication_slot_exporting_snapshot(
+        client: &mut Client,
+        slot_name: &str,
+    ) -> Result<(Option<String>, Option<String>), PostgresConnectorError> {
+        let create_replication_slot_query =
+            format!(r#"CREATE_REPLICATION_SLOT {slot_name:?} LOGICAL "pgoutput" EXPORT_SNAPSHOT"#);
+
+        let slot_query_row = client
+            .simple_query(&create_replication_slot_query)
+            .await
+            .map_err(|e| {
+                debug!("failed to create replication slot {}", slot_name);
+                PostgresConnectorError::CreateSlotError(slot_name.to_string(), e)
+            })?;
+
+        if let SimpleQueryMessage::Row(row) = &slot_query_row[0] {
+            Ok((
+                row.get("consistent_point").map(|lsn| lsn.to_string()),
+                row.get("snapshot_name").map(|name| name.to_string()),
+            ))
+        } else {
+            Err(PostgresConnectorError::UnexpectedQueryMessageError)
+        }
+    }
+
     pub async fn replication_slot_exists(
         client: &mut Client,
         slot_name: &str,
@@ -17,7 +17,7 @@ use std::pin::Pin;
 use std::time::SystemTime;
 
 use crate::connection::client::Client;
-use crate::connection::helper::{self, is_network_failure};
+use crate::connection::helper::{self, is_network_failure, PostgresConnectionConfig};
 use crate::xlog_mapper::XlogMapper;
 use crate::PostgresConnectorError;
 
@@ -28,7 +28,7 @@ pub struct CDCHandler<'a> {
     pub name: String,
     pub ingestor: &'a Ingestor,
 
-    pub replication_conn_config: tokio_postgres::Config,
+    pub replication_conn_config: PostgresConnectionConfig,
     pub publication_name: String,
     pub slot_name: String,
 
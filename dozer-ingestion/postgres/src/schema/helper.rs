@@ -9,15 +9,16 @@ use postgres_types::Type;
 use tokio_postgres::Row;
 
 use crate::{
-    connection::helper, helper::postgres_type_to_dozer_type, PostgresConnectorError,
-    PostgresSchemaError,
+    connection::helper::{self, PostgresConnectionConfig},
+    helper::postgres_type_to_dozer_type,
+    PostgresConnectorError, PostgresSchemaError,
 };
 
 use super::sorter::sort_schemas;
 
 #[derive(Debug)]
 pub struct SchemaHelper {
-    conn_config: tokio_postgres::Config,
+    conn_config: PostgresConnectionConfig,
     // Postgres schema
     schema: Option<String>,
 }
@@ -81,12 +82,14 @@ pub struct PostgresTableInfo {
     pub name: String,
     pub relation_id: u32,
     pub columns: Vec<String>,
+    /// Condition the replicated rows must satisfy.
+    pub filter: Option<String>,
 }
 
 type RowsWithColumnsMap = (Vec<Row>, HashMap<SchemaTableIdentifier, Vec<String>>);
 
 impl SchemaHelper {
-    pub fn new(conn_config: tokio_postgres::Config, schema: Option<String>) -> SchemaHelper {
+    pub fn new(conn_config: PostgresConnectionConfig, schema: Option<String>) -> SchemaHelper {
         Self {
             conn_config,
             schema,
@@ -147,6 +150,7 @@ impl SchemaHelper {
                         relation_id,
                         columns,
                         schema,
+                        filter: None,
                     },
                 )
                 .collect()
@@ -370,6 +374,7 @@ fn find_table(
             name: schema_table_identifier.1,
             relation_id: *relation_id,
             columns: columns.clone(),
+            filter: None,
         })
     } else {
         Err(PostgresConnectorError::TablesNotFound(vec![
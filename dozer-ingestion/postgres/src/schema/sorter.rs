@@ -93,6 +93,7 @@ mod tests {
                 typ: FieldType::UInt,
                 nullable: false,
                 source: SourceDefinition::Dynamic,
+                annotations: Default::default(),
             },
             false,
         );
@@ -102,6 +103,7 @@ mod tests {
                 typ: FieldType::UInt,
                 nullable: false,
                 source: SourceDefinition::Dynamic,
+                annotations: Default::default(),
             },
             true,
         );
@@ -111,6 +113,7 @@ mod tests {
                 typ: FieldType::UInt,
                 nullable: false,
                 source: SourceDefinition::Dynamic,
+                annotations: Default::default(),
             },
             false,
         );
@@ -4,6 +4,7 @@ use dozer_ingestion_connector::{
         types::{Operation, Schema},
     },
     futures::StreamExt,
+    pool::ConnectionPool,
     tokio::{
         self,
         sync::mpsc::{channel, Sender},
@@ -14,17 +15,66 @@ use dozer_ingestion_connector::{
 };
 
 use crate::{
-    connection::helper as connection_helper, helper::get_conversion_fn,
-    schema::helper::SchemaHelper, PostgresConnectorError,
+    connection::{
+        client::Client,
+        helper::{self as connection_helper, PostgresConnectionConfig},
+    },
+    helper::get_conversion_fn,
+    schema::helper::SchemaHelper,
+    PostgresConnectorError,
 };
 
 use super::helper;
 
+/// Tables larger than this many pages are split into chunks snapshotted in parallel, when the snapshot is shared.
+const SNAPSHOT_CHUNK_PAGES: u64 = 10_000;
+
+/// The rows of a table, or of a range of its pages, to snapshot.
+#[derive(Debug, Clone)]
+pub struct SnapshotChunk {
+    pub schema: Schema,
+    pub schema_name: String,
+    pub table_name: String,
+    pub filter: Option<String>,
+    pub table_index: usize,
+    /// Start and end page, with no end for the last chunk of the table.
+    pub pages: Option<(u64, Option<u64>)>,
+}
+
+fn ctid_condition((start, end): (u64, Option<u64>)) -> String {
+    let mut condition = format!("ctid >= '({start},0)'::tid");
+    if let Some(end) = end {
+        condition += &format!(" and ctid < '({end},0)'::tid");
+    }
+    condition
+}
+
+/// Splits `pages` into chunks of `chunk_pages`. The last chunk has no end, in case the table grew since it was measured.
+fn page_chunks(pages: u64, chunk_pages: u64) -> Vec<Option<(u64, Option<u64>)>> {
+    let chunk_pages = chunk_pages.max(1);
+    if pages <= chunk_pages {
+        return vec![None];
+    }
+    let mut chunks = (0..pages)
+        .step_by(chunk_pages as usize)
+        .map(|start| Some((start, Some(start + chunk_pages))))
+        .collect::<Vec<_>>();
+    if let Some(Some((_, end))) = chunks.last_mut() {
+        *end = None;
+    }
+    chunks
+}
+
 pub struct PostgresSnapshotter<'a> {
-    pub conn_config: tokio_postgres::Config,
+    pub conn_config: PostgresConnectionConfig,
     pub ingestor: &'a Ingestor,
     pub schema: Option<String>,
     pub batch_size: usize,
+    /// Tables are snapshotted in parallel, over at most this many connections.
+    pub snapshot_connections: usize,
+    /// The snapshot exported when the replication slot was created. Without it, tables are not split into chunks,
+    /// as each chunk would be read at a different point in time.
+    pub snapshot_name: Option<String>,
 }
 
 impl<'a> PostgresSnapshotter<'a> {
@@ -37,24 +87,59 @@ impl<'a> PostgresSnapshotter<'a> {
     }
 
     pub async fn sync_table(
-        schema: Schema,
-        schema_name: String,
-        table_name: String,
-        table_index: usize,
-        conn_config: tokio_postgres::Config,
+        table: SnapshotChunk,
+        snapshot_name: Option<String>,
+        conn_config: PostgresConnectionConfig,
+        pool: ConnectionPool<Client>,
         batch_size: usize,
         sender: Sender<Result<(usize, Operation), PostgresConnectorError>>,
     ) -> Result<(), PostgresConnectorError> {
-        let mut client_plain = connection_helper::connect(conn_config).await?;
+        let mut client_plain = pool.get(|| connection_helper::connect(conn_config)).await?;
+        match Self::read_table(&mut client_plain, table, snapshot_name, batch_size, &sender).await {
+            Ok(true) => Ok(()),
+            // The connection may be left in a transaction, so it is not returned to the pool.
+            Ok(false) => {
+                client_plain.discard();
+                Ok(())
+            }
+            Err(e) => {
+                client_plain.discard();
+                Err(e)
+            }
+        }
+    }
 
-        let column_str: Vec<String> = schema
+    /// Sends the rows of `table` to `sender`, returning `false` if it stopped early because the receiver has quit.
+    async fn read_table(
+        client_plain: &mut Client,
+        table: SnapshotChunk,
+        snapshot_name: Option<String>,
+        batch_size: usize,
+        sender: &Sender<Result<(usize, Operation), PostgresConnectorError>>,
+    ) -> Result<bool, PostgresConnectorError> {
+        let column_str: Vec<String> = table
+            .schema
             .fields
             .iter()
             .map(|f| format!("\"{0}\"", f.name))
             .collect();
 
         let column_str = column_str.join(",");
-        let query = format!(r#"select {column_str} from "{schema_name}"."{table_name}""#);
+        let SnapshotChunk {
+            schema_name,
+            table_name,
+            ..
+        } = &table;
+        let mut query = format!(r#"select {column_str} from "{schema_name}"."{table_name}""#);
+        let conditions = table
+            .filter
+            .iter()
+            .map(|filter| format!("({filter})"))
+            .chain(table.pages.map(ctid_condition))
+            .collect::<Vec<_>>();
+        if !conditions.is_empty() {
+            query += &format!(" where {}", conditions.join(" and "));
+        }
         let stmt = client_plain
             .prepare(&query)
             .await
@@ -65,13 +150,26 @@ impl<'a> PostgresSnapshotter<'a> {
             .map(|col| get_conversion_fn(col.type_()))
             .collect::<Result<Vec<_>, _>>()?;
 
-        let empty_vec: Vec<String> = Vec::new();
-        let row_stream = client_plain
-            .query_raw(query, empty_vec)
-            .await
-            .map_err(PostgresConnectorError::InvalidQueryError)?;
+        let row_stream = match &snapshot_name {
+            // The rows must be read in the exported snapshot, so the query can't be resumed on a new connection.
+            Some(snapshot_name) => {
+                client_plain
+                    .simple_query(&format!(
+                        "BEGIN READ ONLY ISOLATION LEVEL REPEATABLE READ; SET TRANSACTION SNAPSHOT '{snapshot_name}';"
+                    ))
+                    .await
+                    .map_err(PostgresConnectorError::InvalidQueryError)?;
+                client_plain.query_raw_in_transaction(&query).await
+            }
+            None => {
+                let empty_vec: Vec<String> = Vec::new();
+                client_plain.query_raw(query, empty_vec).await
+            }
+        }
+        .map_err(PostgresConnectorError::InvalidQueryError)?;
         tokio::pin!(row_stream);
 
+        let table_index = table.table_index;
         let mut batch = Vec::with_capacity(batch_size);
 
         while let Some(msg) = row_stream.next().await {
@@ -89,48 +187,103 @@ impl<'a> PostgresSnapshotter<'a> {
                             // If we can't send, the parent task has quit. There is
                             // no use in going on, but if there was an error, it was
                             // handled by the parent.
-                            return Ok(());
+                            return Ok(false);
                         };
                         batch = Vec::with_capacity(batch_size);
                     }
                 }
-                Err(e) => return Err(PostgresConnectorError::SyncWithSnapshotError(e.to_string())),
+                Err(e) => {
+                    return Err(PostgresConnectorError::SyncWithSnapshotError(e.to_string()));
+                }
             }
         }
 
+        if snapshot_name.is_some() {
+            client_plain
+                .simple_query("COMMIT;")
+                .await
+                .map_err(PostgresConnectorError::InvalidQueryError)?;
+        }
+
         if !batch.is_empty() {
             let _ = sender
                 .send(Ok((table_index, Operation::BatchInsert { new: batch })))
                 .await;
         }
 
-        Ok(())
+        Ok(true)
+    }
+
+    /// Number of pages of a table, so it can be split into chunks of pages.
+    async fn table_pages(
+        pool: &ConnectionPool<Client>,
+        conn_config: PostgresConnectionConfig,
+        schema_name: &str,
+        table_name: &str,
+    ) -> Result<u64, PostgresConnectorError> {
+        let mut client = pool.get(|| connection_helper::connect(conn_config)).await?;
+        let row = client
+            .query_one(
+                "select (pg_relation_size(format('%I.%I', $1::text, $2::text)::regclass) / current_setting('block_size')::bigint)::bigint",
+                &[&schema_name, &table_name],
+            )
+            .await
+            .map_err(PostgresConnectorError::InvalidQueryError)?;
+        Ok(row.get::<_, i64>(0).max(0) as u64)
     }
 
+    /// Snapshots `tables`, only taking the rows matching the filter at the same index in `filters`.
     pub async fn sync_tables(
         &self,
         tables: &[ListOrFilterColumns],
+        filters: &[Option<String>],
     ) -> Result<(), PostgresConnectorError> {
         let schemas = self.get_tables(tables).await?;
 
         let (tx, mut rx) = channel(16);
 
-        let mut joinset = JoinSet::new();
-        for (table_index, (schema, table)) in schemas.into_iter().zip(tables).enumerate() {
+        let pool = ConnectionPool::new(self.snapshot_connections);
+        let mut chunks = vec![];
+        for (table_index, ((schema, table), filter)) in schemas
+            .into_iter()
+            .zip(tables)
+            .zip(filters.iter().cloned())
+            .enumerate()
+        {
             let schema = schema?;
-            let schema = schema.schema;
             let schema_name = table.schema.clone().unwrap_or("public".to_string());
             let table_name = table.name.clone();
+            let pages = if self.snapshot_name.is_some() {
+                let pages =
+                    Self::table_pages(&pool, self.conn_config.clone(), &schema_name, &table_name)
+                        .await?;
+                page_chunks(pages, SNAPSHOT_CHUNK_PAGES)
+            } else {
+                vec![None]
+            };
+            chunks.extend(pages.into_iter().map(|pages| SnapshotChunk {
+                schema: schema.schema.clone(),
+                schema_name: schema_name.clone(),
+                table_name: table_name.clone(),
+                filter: filter.clone(),
+                table_index,
+                pages,
+            }));
+        }
+
+        let mut joinset = JoinSet::new();
+        for chunk in chunks {
+            let snapshot_name = self.snapshot_name.clone();
             let conn_config = self.conn_config.clone();
+            let pool = pool.clone();
             let batch_size = self.batch_size;
             let sender = tx.clone();
             joinset.spawn(async move {
                 if let Err(e) = Self::sync_table(
-                    schema,
-                    schema_name,
-                    table_name,
-                    table_index,
+                    chunk,
+                    snapshot_name,
                     conn_config,
+                    pool,
                     batch_size,
                     sender.clone(),
                 )
@@ -204,7 +357,17 @@ mod tests {
         tests::client::TestPostgresClient,
     };
 
-    use super::PostgresSnapshotter;
+    use super::{page_chunks, PostgresSnapshotter};
+
+    #[test]
+    fn test_page_chunks() {
+        assert_eq!(page_chunks(0, 10), vec![None]);
+        assert_eq!(page_chunks(10, 10), vec![None]);
+        assert_eq!(
+            page_chunks(25, 10),
+            vec![Some((0, Some(10))), Some((10, Some(20))), Some((20, None))]
+        );
+    }
 
     #[tokio::test]
     #[ignore]
@@ -236,9 +399,14 @@ mod tests {
             ingestor: &ingestor,
             schema: None,
             batch_size: 1000,
+            snapshot_connections: 8,
+            snapshot_name: None,
         };
 
-        snapshotter.sync_tables(&input_tables).await.unwrap();
+        snapshotter
+            .sync_tables(&input_tables, &[None])
+            .await
+            .unwrap();
 
         let mut i = 0;
         while i < 2 {
@@ -284,9 +452,11 @@ mod tests {
             ingestor: &ingestor,
             schema: None,
             batch_size: 1000,
+            snapshot_connections: 8,
+            snapshot_name: None,
         };
 
-        let actual = snapshotter.sync_tables(&input_tables).await;
+        let actual = snapshotter.sync_tables(&input_tables, &[None]).await;
 
         assert!(actual.is_err());
     }
@@ -316,9 +486,11 @@ mod tests {
             ingestor: &ingestor,
             schema: None,
             batch_size: 1000,
+            snapshot_connections: 8,
+            snapshot_name: None,
         };
 
-        let actual = snapshotter.sync_tables(&input_tables).await;
+        let actual = snapshotter.sync_tables(&input_tables, &[None]).await;
 
         assert!(actual.is_err());
     }
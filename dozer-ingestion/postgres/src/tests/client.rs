@@ -6,12 +6,12 @@ use dozer_ingestion_connector::dozer_types::{
 
 use crate::connection::{
     client::Client,
-    helper::{connect, map_connection_config},
+    helper::{connect, map_connection_config, PostgresConnectionConfig},
 };
 
 pub struct TestPostgresClient {
     client: Client,
-    pub postgres_config: tokio_postgres::Config,
+    pub postgres_config: PostgresConnectionConfig,
 }
 
 impl TestPostgresClient {
@@ -26,7 +26,7 @@ impl TestPostgresClient {
         }
     }
 
-    pub async fn new_with_postgres_config(postgres_config: tokio_postgres::Config) -> Self {
+    pub async fn new_with_postgres_config(postgres_config: PostgresConnectionConfig) -> Self {
         let client = connect(postgres_config.clone()).await.unwrap();
 
         Self {
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use dozer_ingestion_connector::{tokio, TableIdentifier};
+    use dozer_ingestion_connector::{tokio, TableInfo};
     // use crate::connectors::Connector;
     // use crate::ingestion::IngestionConfig;
     // use dozer_types::models::ingestion_types::IngestionMessage;
@@ -28,6 +28,7 @@ mod tests {
             config: conn_config.clone(),
             schema: None,
             batch_size: 1000,
+            snapshot_connections: 8,
         };
 
         let connector = PostgresConnector::new(postgres_config, None).unwrap();
@@ -83,6 +84,7 @@ mod tests {
             config: conn_config.clone(),
             schema: None,
             batch_size: 1000,
+            snapshot_connections: 8,
         };
 
         let connector = PostgresConnector::new(postgres_config, None).unwrap();
@@ -94,11 +96,13 @@ mod tests {
         let client = helper::connect(replication_conn_config.clone())
             .await
             .unwrap();
-        let table_identifier = TableIdentifier {
+        let table_info = TableInfo {
             schema: Some("public".to_string()),
             name: table_name.clone(),
+            column_names: vec![],
+            filter: None,
         };
-        create_publication(client, &connector.name, Some(&[table_identifier]))
+        create_publication(client, &connector.name, Some(&[table_info]))
             .await
             .unwrap();
 
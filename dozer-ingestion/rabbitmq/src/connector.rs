@@ -0,0 +1,347 @@
+use std::{collections::HashMap, time::Duration};
+
+use dozer_ingestion_connector::{
+    async_trait,
+    dozer_types::{
+        errors::internal::BoxedError,
+        event::Event,
+        models::ingestion_types::{
+            default_rabbitmq_prefetch_count, IngestionMessage, RabbitMQConfig, RabbitMQQueue,
+            TransactionInfo,
+        },
+        node::{NodeHandle, OpIdentifier},
+        serde_json,
+        types::{FieldType, Operation},
+    },
+    flush::FlushWaiter,
+    futures::{stream::select_all, StreamExt},
+    schema_parser::SchemaParser,
+    tokio::{
+        self,
+        sync::{broadcast::Receiver, mpsc},
+        time::{timeout_at, Instant},
+    },
+    utils::TableNotFound,
+    Connector, Ingestor, SourceSchema, SourceSchemaResult, TableIdentifier, TableInfo,
+};
+use lapin::{
+    options::{
+        BasicAckOptions, BasicConsumeOptions, BasicQosOptions, QueueBindOptions,
+        QueueDeclareOptions,
+    },
+    types::FieldTable,
+    Channel, Connection, ConnectionProperties,
+};
+
+use crate::{decoding::map_record, RabbitMQError};
+
+/// Maximum time a message waits for its batch to be committed.
+const COMMIT_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+pub struct RabbitMQConnector {
+    name: String,
+    config: RabbitMQConfig,
+    node_handle: NodeHandle,
+    event_receiver: Receiver<Event>,
+}
+
+/// A table being ingested, with the queue it is consumed from.
+struct QueueTable<'a> {
+    table_index: usize,
+    queue: &'a RabbitMQQueue,
+    schema: SourceSchema,
+}
+
+impl RabbitMQConnector {
+    pub fn new(
+        name: String,
+        config: RabbitMQConfig,
+        node_handle: NodeHandle,
+        event_receiver: Receiver<Event>,
+    ) -> Self {
+        Self {
+            name,
+            config,
+            node_handle,
+            event_receiver,
+        }
+    }
+
+    fn schemas(&self) -> Result<HashMap<String, SourceSchema>, RabbitMQError> {
+        let schemas = SchemaParser::parse_config(&self.config.schemas)?;
+        serde_json::from_str(&schemas).map_err(RabbitMQError::Schemas)
+    }
+
+    fn queue_table(
+        &self,
+        schemas: &HashMap<String, SourceSchema>,
+        table_index: usize,
+        schema_name: &Option<String>,
+        name: &str,
+    ) -> Result<QueueTable, BoxedError> {
+        let queue = self
+            .config
+            .queues
+            .iter()
+            .find(|queue| queue.name == name && schema_name.is_none())
+            .ok_or_else(|| TableNotFound {
+                schema: schema_name.clone(),
+                name: name.to_string(),
+            })?;
+        let schema = schemas
+            .get(name)
+            .cloned()
+            .ok_or_else(|| RabbitMQError::SchemaNotFound(name.to_string()))?;
+        Ok(QueueTable {
+            table_index,
+            queue,
+            schema,
+        })
+    }
+
+    async fn connect(&self) -> Result<(Connection, Channel), RabbitMQError> {
+        let properties = ConnectionProperties::default()
+            .with_connection_name(format!("dozer-{}", self.name).into());
+        let connection = Connection::connect(&self.config.url, properties).await?;
+        let channel = connection.create_channel().await?;
+        Ok((connection, channel))
+    }
+}
+
+/// Declares the queue and creates its bindings.
+async fn declare(channel: &Channel, queue: &RabbitMQQueue) -> Result<(), RabbitMQError> {
+    channel
+        .queue_declare(
+            &queue.queue,
+            QueueDeclareOptions {
+                durable: true,
+                ..Default::default()
+            },
+            FieldTable::default(),
+        )
+        .await?;
+    for binding in &queue.bindings {
+        channel
+            .queue_bind(
+                &queue.queue,
+                &binding.exchange,
+                &binding.routing_key,
+                QueueBindOptions::default(),
+                FieldTable::default(),
+            )
+            .await?;
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl Connector for RabbitMQConnector {
+    fn types_mapping() -> Vec<(String, Option<FieldType>)>
+    where
+        Self: Sized,
+    {
+        vec![]
+    }
+
+    async fn validate_connection(&mut self) -> Result<(), BoxedError> {
+        let (connection, _) = self.connect().await?;
+        connection
+            .close(0, "")
+            .await
+            .map_err(RabbitMQError::Lapin)?;
+        Ok(())
+    }
+
+    async fn list_tables(&mut self) -> Result<Vec<TableIdentifier>, BoxedError> {
+        Ok(self
+            .config
+            .queues
+            .iter()
+            .map(|queue| TableIdentifier::from_table_name(queue.name.clone()))
+            .collect())
+    }
+
+    async fn validate_tables(&mut self, tables: &[TableIdentifier]) -> Result<(), BoxedError> {
+        let schemas = self.schemas()?;
+        for (table_index, table) in tables.iter().enumerate() {
+            self.queue_table(&schemas, table_index, &table.schema, &table.name)?;
+        }
+        Ok(())
+    }
+
+    async fn list_columns(
+        &mut self,
+        tables: Vec<TableIdentifier>,
+    ) -> Result<Vec<TableInfo>, BoxedError> {
+        let schemas = self.schemas()?;
+        let mut result = vec![];
+        for (table_index, table) in tables.into_iter().enumerate() {
+            let column_names = self
+                .queue_table(&schemas, table_index, &table.schema, &table.name)?
+                .schema
+                .schema
+                .fields
+                .into_iter()
+                .map(|field| field.name)
+                .collect();
+            result.push(TableInfo {
+                schema: table.schema,
+                name: table.name,
+                column_names,
+                filter: None,
+            });
+        }
+        Ok(result)
+    }
+
+    async fn get_schemas(
+        &mut self,
+        table_infos: &[TableInfo],
+    ) -> Result<Vec<SourceSchemaResult>, BoxedError> {
+        let schemas = self.schemas()?;
+        Ok(table_infos
+            .iter()
+            .enumerate()
+            .map(|(table_index, table)| {
+                self.queue_table(&schemas, table_index, &table.schema, &table.name)
+                    .map(|table| table.schema)
+            })
+            .collect())
+    }
+
+    async fn serialize_state(&self) -> Result<Vec<u8>, BoxedError> {
+        Ok(vec![])
+    }
+
+    async fn start(
+        &mut self,
+        ingestor: &Ingestor,
+        tables: Vec<TableInfo>,
+        last_checkpoint: Option<OpIdentifier>,
+    ) -> Result<(), BoxedError> {
+        let schemas = self.schemas()?;
+        let tables = tables
+            .iter()
+            .enumerate()
+            .map(|(table_index, table)| {
+                self.queue_table(&schemas, table_index, &table.schema, &table.name)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (_connection, channel) = self.connect().await?;
+        // Unacknowledged messages are held until their batch is committed, so the broker stops
+        // delivering when the pipeline applies backpressure.
+        let prefetch_count = self
+            .config
+            .prefetch_count
+            .unwrap_or_else(default_rabbitmq_prefetch_count);
+        channel
+            .basic_qos(prefetch_count, BasicQosOptions::default())
+            .await
+            .map_err(RabbitMQError::Lapin)?;
+
+        let mut consumers = vec![];
+        for (index, table) in tables.iter().enumerate() {
+            declare(&channel, table.queue).await?;
+            let consumer = channel
+                .basic_consume(
+                    &table.queue.queue,
+                    &format!("dozer-{}-{}", self.name, table.queue.name),
+                    BasicConsumeOptions::default(),
+                    FieldTable::default(),
+                )
+                .await
+                .map_err(RabbitMQError::Lapin)?;
+            consumers.push(consumer.map(move |delivery| (index, delivery)));
+        }
+        let mut deliveries = select_all(consumers);
+
+        // A batch is acknowledged once a sink has flushed its commit, so the messages are redelivered if the
+        // pipeline stops before they are durable. Ingestion goes on meanwhile, up to the prefetch count.
+        let (ack_sender, mut ack_receiver) = mpsc::unbounded_channel::<(OpIdentifier, u64)>();
+        let mut flush_waiter =
+            FlushWaiter::new(self.node_handle.clone(), self.event_receiver.resubscribe());
+        let ack_channel = channel.clone();
+        let acks = tokio::spawn(async move {
+            while let Some((id, delivery_tag)) = ack_receiver.recv().await {
+                if !flush_waiter.wait_flushed(id).await {
+                    break;
+                }
+                ack_channel
+                    .basic_ack(delivery_tag, BasicAckOptions { multiple: true })
+                    .await?;
+            }
+            Ok::<_, lapin::Error>(())
+        });
+
+        // Unacknowledged messages are redelivered, so commits only need to be ordered.
+        let mut commit = last_checkpoint.map_or(0, |checkpoint| checkpoint.txid);
+        let mut pending = 0;
+        let mut last_delivery_tag = 0;
+        let mut deadline = Instant::now();
+        loop {
+            let next = if pending == 0 {
+                Ok(deliveries.next().await)
+            } else {
+                timeout_at(deadline, deliveries.next()).await
+            };
+            match next {
+                Ok(Some((index, delivery))) => {
+                    let delivery = delivery.map_err(RabbitMQError::Lapin)?;
+                    let table = &tables[index];
+                    let new = map_record(&table.schema.schema, &delivery.data)?;
+                    if ingestor
+                        .handle_message(IngestionMessage::OperationEvent {
+                            table_index: table.table_index,
+                            op: Operation::Insert { new },
+                            id: None,
+                        })
+                        .await
+                        .is_err()
+                    {
+                        // If the ingestor is closed, we should stop
+                        return Ok(());
+                    }
+                    if pending == 0 {
+                        deadline = Instant::now() + COMMIT_INTERVAL;
+                    }
+                    pending += 1;
+                    last_delivery_tag = delivery.delivery_tag;
+                    if pending < prefetch_count {
+                        continue;
+                    }
+                }
+                Ok(None) => {
+                    let queues = tables
+                        .iter()
+                        .map(|table| table.queue.queue.as_str())
+                        .collect::<Vec<_>>();
+                    return Err(RabbitMQError::ConsumerCancelled(queues.join(", ")).into());
+                }
+                // The commit interval elapsed.
+                Err(_) => (),
+            }
+
+            commit += 1;
+            let id = OpIdentifier::new(commit, 0);
+            if ingestor
+                .handle_message(IngestionMessage::TransactionInfo(TransactionInfo::Commit {
+                    id: Some(id),
+                }))
+                .await
+                .is_err()
+            {
+                return Ok(());
+            }
+            if ack_sender.send((id, last_delivery_tag)).is_err() {
+                // The acknowledging task stopped, either because the pipeline is down or on an error.
+                return match acks.await {
+                    Ok(Err(e)) => Err(RabbitMQError::Lapin(e).into()),
+                    _ => Ok(()),
+                };
+            }
+            pending = 0;
+        }
+    }
+}
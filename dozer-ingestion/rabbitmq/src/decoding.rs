@@ -0,0 +1,13 @@
+use dozer_ingestion_connector::dozer_types::{
+    json_record::json_to_record,
+    serde_json,
+    types::{Record, Schema},
+};
+
+use crate::RabbitMQError;
+
+/// Builds a record of `schema` from a JSON object payload. Fields missing from the payload are null.
+pub fn map_record(schema: &Schema, payload: &[u8]) -> Result<Record, RabbitMQError> {
+    let value = serde_json::from_slice(payload).map_err(RabbitMQError::Json)?;
+    Ok(json_to_record(value, schema, Default::default())?)
+}
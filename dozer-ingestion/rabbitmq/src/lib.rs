@@ -0,0 +1,39 @@
+use dozer_ingestion_connector::{
+    dozer_types::{
+        json_record::JsonRecordError,
+        serde_json,
+        thiserror::{self, Error},
+    },
+    schema_parser::SchemaParserError,
+};
+
+pub mod connector;
+mod decoding;
+#[cfg(test)]
+mod tests;
+
+pub use connector::RabbitMQConnector;
+
+#[derive(Error, Debug)]
+pub enum RabbitMQError {
+    #[error("amqp error: {0}")]
+    Lapin(#[from] lapin::Error),
+
+    #[error(transparent)]
+    SchemaParser(#[from] SchemaParserError),
+
+    #[error("cannot parse schemas: {0}")]
+    Schemas(#[source] serde_json::Error),
+
+    #[error("no schema defined for table {0}")]
+    SchemaNotFound(String),
+
+    #[error("consumer of queue {0} was cancelled")]
+    ConsumerCancelled(String),
+
+    #[error("cannot decode json message: {0}")]
+    Json(#[source] serde_json::Error),
+
+    #[error("cannot convert message: {0}")]
+    Record(#[from] JsonRecordError),
+}
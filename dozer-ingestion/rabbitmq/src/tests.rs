@@ -0,0 +1,58 @@
+use dozer_ingestion_connector::dozer_types::{
+    json_record::JsonRecordError,
+    types::{Field, FieldDefinition, FieldType, Record, Schema, SourceDefinition},
+};
+
+use crate::{decoding::map_record, RabbitMQError};
+
+fn schema() -> Schema {
+    let mut schema = Schema::new();
+    schema
+        .field(
+            FieldDefinition::new(
+                "id".to_string(),
+                FieldType::Int,
+                false,
+                SourceDefinition::Dynamic,
+            ),
+            true,
+        )
+        .field(
+            FieldDefinition::new(
+                "name".to_string(),
+                FieldType::String,
+                true,
+                SourceDefinition::Dynamic,
+            ),
+            false,
+        );
+    schema
+}
+
+#[test]
+fn test_map_record() {
+    assert_eq!(
+        map_record(&schema(), br#"{"id": 1, "name": "a", "extra": true}"#).unwrap(),
+        Record::new(vec![Field::Int(1), Field::String("a".to_string())])
+    );
+    assert_eq!(
+        map_record(&schema(), br#"{"id": 2}"#).unwrap(),
+        Record::new(vec![Field::Int(2), Field::Null])
+    );
+    assert!(matches!(
+        map_record(&schema(), br#"{"name": "a"}"#),
+        Err(RabbitMQError::Record(JsonRecordError::Fields(errors))) if errors[0].field == "id"
+    ));
+    assert!(matches!(
+        map_record(&schema(), br#"{"id": "x"}"#),
+        Err(RabbitMQError::Record(JsonRecordError::Fields(errors))) if errors[0].field == "id"
+    ));
+    assert!(matches!(
+        map_record(&schema(), b"[]"),
+        Err(RabbitMQError::Record(JsonRecordError::NotAnObject))
+    ));
+    assert!(matches!(
+        map_record(&schema(), b"{"),
+        Err(RabbitMQError::Json(_))
+    ));
+}
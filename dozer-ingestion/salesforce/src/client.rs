@@ -0,0 +1,312 @@
+use std::time::Duration;
+
+use dozer_ingestion_connector::{
+    dozer_types::{
+        log::debug,
+        models::ingestion_types::{
+            default_salesforce_api_version, default_salesforce_login_url,
+            default_salesforce_max_api_usage_percent, default_salesforce_max_requests_per_minute,
+            SalesforceConfig,
+        },
+        serde::Deserialize,
+        serde_json::{json, Map, Value},
+    },
+    tokio::time::sleep,
+};
+use reqwest::{Method, RequestBuilder, Response};
+
+use crate::{throttle::Throttle, SalesforceError};
+
+const BULK_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const BULK_PAGE_SIZE: u32 = 50_000;
+
+#[derive(Debug, Deserialize)]
+#[serde(crate = "dozer_ingestion_connector::dozer_types::serde")]
+struct TokenResponse {
+    access_token: String,
+    instance_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(crate = "dozer_ingestion_connector::dozer_types::serde")]
+struct DescribeGlobal {
+    sobjects: Vec<SObjectSummary>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(crate = "dozer_ingestion_connector::dozer_types::serde")]
+#[serde(rename_all = "camelCase")]
+struct SObjectSummary {
+    name: String,
+    replicateable: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "dozer_ingestion_connector::dozer_types::serde")]
+pub struct SObjectDescription {
+    pub name: String,
+    pub fields: Vec<SObjectField>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "dozer_ingestion_connector::dozer_types::serde")]
+pub struct SObjectField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub typ: String,
+    pub nillable: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(crate = "dozer_ingestion_connector::dozer_types::serde")]
+struct BulkJob {
+    id: String,
+    state: String,
+}
+
+/// A thin, throttled client over the Salesforce REST and Bulk 2.0 APIs.
+#[derive(Debug)]
+pub struct SalesforceClient {
+    http: reqwest::Client,
+    config: SalesforceConfig,
+    api_version: String,
+    throttle: Throttle,
+    session: Option<Session>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Session {
+    pub access_token: String,
+    pub instance_url: String,
+}
+
+impl SalesforceClient {
+    pub fn new(config: SalesforceConfig) -> Result<Self, SalesforceError> {
+        let http = reqwest::Client::builder().cookie_store(true).build()?;
+        let throttle = Throttle::new(
+            config
+                .max_requests_per_minute
+                .unwrap_or_else(default_salesforce_max_requests_per_minute),
+            config
+                .max_api_usage_percent
+                .unwrap_or_else(default_salesforce_max_api_usage_percent),
+        );
+        let api_version = config
+            .api_version
+            .clone()
+            .unwrap_or_else(default_salesforce_api_version);
+        Ok(Self {
+            http,
+            config,
+            api_version,
+            throttle,
+            session: None,
+        })
+    }
+
+    pub fn http(&self) -> &reqwest::Client {
+        &self.http
+    }
+
+    /// Forgets the access token, so the next request logs in again.
+    pub fn logout(&mut self) {
+        self.session = None;
+    }
+
+    pub fn api_version(&self) -> &str {
+        &self.api_version
+    }
+
+    pub async fn session(&mut self) -> Result<Session, SalesforceError> {
+        if let Some(session) = &self.session {
+            return Ok(session.clone());
+        }
+
+        let login_url = self
+            .config
+            .login_url
+            .clone()
+            .unwrap_or_else(default_salesforce_login_url);
+        let response = self
+            .http
+            .post(format!("{login_url}/services/oauth2/token"))
+            .form(&[
+                ("grant_type", "password"),
+                ("client_id", &self.config.client_id),
+                ("client_secret", &self.config.client_secret),
+                ("username", &self.config.username),
+                ("password", &self.config.password),
+            ])
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(SalesforceError::Authentication(response.text().await?));
+        }
+        let token: TokenResponse = response.json().await?;
+        let session = Session {
+            access_token: token.access_token,
+            instance_url: token.instance_url,
+        };
+        self.session = Some(session.clone());
+        Ok(session)
+    }
+
+    /// Sends a request, logging in again and retrying it once if the access token expired.
+    async fn send(
+        &mut self,
+        method: Method,
+        path: &str,
+        build: impl Fn(RequestBuilder) -> RequestBuilder,
+    ) -> Result<Response, SalesforceError> {
+        let mut logged_in = self.session.is_none();
+        loop {
+            let session = self.session().await?;
+            let url = format!(
+                "{}/services/data/v{}/{path}",
+                session.instance_url, self.api_version
+            );
+            self.throttle.acquire().await;
+            debug!("Salesforce request: {method} {url}");
+            let response = build(
+                self.http
+                    .request(method.clone(), &url)
+                    .bearer_auth(&session.access_token),
+            )
+            .send()
+            .await?;
+            self.throttle
+                .observe_limit_info(
+                    response
+                        .headers()
+                        .get("Sforce-Limit-Info")
+                        .and_then(|value| value.to_str().ok()),
+                )
+                .await;
+
+            let status = response.status();
+            if status == reqwest::StatusCode::UNAUTHORIZED {
+                // The access token expired.
+                self.logout();
+                if !logged_in {
+                    logged_in = true;
+                    continue;
+                }
+            }
+            if !status.is_success() {
+                return Err(SalesforceError::Api {
+                    status,
+                    url,
+                    body: response.text().await.unwrap_or_default(),
+                });
+            }
+            return Ok(response);
+        }
+    }
+
+    pub async fn list_objects(&mut self) -> Result<Vec<String>, SalesforceError> {
+        let describe: DescribeGlobal = self
+            .send(Method::GET, "sobjects", |request| request)
+            .await?
+            .json()
+            .await?;
+        Ok(describe
+            .sobjects
+            .into_iter()
+            .filter(|object| object.replicateable)
+            .map(|object| object.name)
+            .collect())
+    }
+
+    pub async fn describe(&mut self, object: &str) -> Result<SObjectDescription, SalesforceError> {
+        Ok(self
+            .send(
+                Method::GET,
+                &format!("sobjects/{object}/describe"),
+                |request| request,
+            )
+            .await?
+            .json()
+            .await?)
+    }
+
+    /// Fetches the current version of a record, or `None` if it doesn't exist anymore.
+    pub async fn retrieve(
+        &mut self,
+        object: &str,
+        id: &str,
+        fields: &[String],
+    ) -> Result<Option<Map<String, Value>>, SalesforceError> {
+        let fields = fields.join(",");
+        match self
+            .send(Method::GET, &format!("sobjects/{object}/{id}"), |request| {
+                request.query(&[("fields", &fields)])
+            })
+            .await
+        {
+            Ok(response) => Ok(Some(response.json().await?)),
+            Err(SalesforceError::Api { status, .. })
+                if status == reqwest::StatusCode::NOT_FOUND =>
+            {
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Creates a Bulk API 2.0 query job and waits for it to complete. Returns the job id.
+    pub async fn bulk_query(&mut self, soql: &str) -> Result<String, SalesforceError> {
+        let body = json!({
+            "operation": "query",
+            "query": soql,
+            "contentType": "CSV",
+        });
+        let job: BulkJob = self
+            .send(Method::POST, "jobs/query", |request| request.json(&body))
+            .await?
+            .json()
+            .await?;
+
+        loop {
+            let status: BulkJob = self
+                .send(Method::GET, &format!("jobs/query/{}", job.id), |request| {
+                    request
+                })
+                .await?
+                .json()
+                .await?;
+            match status.state.as_str() {
+                "JobComplete" => return Ok(job.id),
+                "Failed" | "Aborted" => {
+                    return Err(SalesforceError::BulkJobFailed(status.id, status.state))
+                }
+                _ => sleep(BULK_POLL_INTERVAL).await,
+            }
+        }
+    }
+
+    /// Fetches one CSV page of a completed query job, along with the locator of the next page.
+    pub async fn bulk_query_results(
+        &mut self,
+        job_id: &str,
+        locator: Option<&str>,
+    ) -> Result<(String, Option<String>), SalesforceError> {
+        let mut query = vec![("maxRecords", BULK_PAGE_SIZE.to_string())];
+        if let Some(locator) = locator {
+            query.push(("locator", locator.to_string()));
+        }
+        let response = self
+            .send(
+                Method::GET,
+                &format!("jobs/query/{job_id}/results"),
+                |request| request.query(&query),
+            )
+            .await?;
+        let next_locator = response
+            .headers()
+            .get("Sforce-Locator")
+            .and_then(|value| value.to_str().ok())
+            .filter(|value| *value != "null")
+            .map(ToOwned::to_owned);
+        Ok((response.text().await?, next_locator))
+    }
+}
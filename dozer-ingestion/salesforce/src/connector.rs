@@ -0,0 +1,486 @@
+use std::collections::HashMap;
+
+use dozer_ingestion_connector::{
+    async_trait,
+    dozer_types::{
+        errors::internal::BoxedError,
+        log::{info, warn},
+        models::ingestion_types::{IngestionMessage, SalesforceConfig, TransactionInfo},
+        node::OpIdentifier,
+        serde_json::{Map, Value},
+        types::{Field, FieldDefinition, FieldType, Operation, Record, Schema, SourceDefinition},
+    },
+    tokio::{self, sync::mpsc::channel},
+    utils::TableNotFound,
+    CdcType, Connector, Ingestor, SourceSchema, SourceSchemaResult, TableIdentifier, TableInfo,
+};
+
+use crate::{
+    client::{SObjectDescription, SObjectField, SalesforceClient},
+    mapping::{map_field_type, parse_field, parse_json_field, TYPES_MAPPING},
+    streaming::{ChangeEvent, ChangeType, StreamingClient, CHANGE_EVENTS_CHANNEL, REPLAY_NEW},
+    SalesforceError,
+};
+
+const ID_FIELD: &str = "Id";
+
+#[derive(Debug)]
+pub struct SalesforceConnector {
+    name: String,
+    config: SalesforceConfig,
+}
+
+/// A table being ingested, with the columns mapped to Dozer types.
+#[derive(Debug, Clone)]
+struct ObjectTable {
+    object: String,
+    columns: Vec<(String, FieldType)>,
+    id_index: usize,
+}
+
+impl ObjectTable {
+    fn new(table: &TableInfo, description: &SObjectDescription) -> Result<Self, SalesforceError> {
+        let mut columns = vec![];
+        for column in &table.column_names {
+            let field = find_field(description, column)?;
+            columns.push((column.clone(), map_type(description, field)?));
+        }
+        let id_index = columns
+            .iter()
+            .position(|(name, _)| name == ID_FIELD)
+            .ok_or_else(|| SalesforceError::NoIdField(table.name.clone()))?;
+        Ok(Self {
+            object: table.name.clone(),
+            columns,
+            id_index,
+        })
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        self.columns.iter().map(|(name, _)| name.clone()).collect()
+    }
+
+    fn pk_only_record(&self, id: &str) -> Record {
+        let mut record = Record::nulls(self.columns.len());
+        record.set_value(self.id_index, Field::String(id.to_string()));
+        record
+    }
+
+    fn record_from_json(&self, object: &Map<String, Value>) -> Result<Record, SalesforceError> {
+        let values = self
+            .columns
+            .iter()
+            .map(|(name, typ)| parse_json_field(*typ, object.get(name).unwrap_or(&Value::Null)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Record::new(values))
+    }
+}
+
+impl SalesforceConnector {
+    pub fn new(name: String, config: SalesforceConfig) -> Self {
+        Self { name, config }
+    }
+
+    fn client(&self) -> Result<SalesforceClient, SalesforceError> {
+        SalesforceClient::new(self.config.clone())
+    }
+
+    async fn list_objects(
+        &self,
+        client: &mut SalesforceClient,
+    ) -> Result<Vec<String>, SalesforceError> {
+        if self.config.objects.is_empty() {
+            client.list_objects().await
+        } else {
+            Ok(self.config.objects.clone())
+        }
+    }
+
+    async fn describe_tables(
+        &self,
+        client: &mut SalesforceClient,
+        tables: &[TableInfo],
+    ) -> Result<Vec<ObjectTable>, SalesforceError> {
+        let mut result = vec![];
+        for table in tables {
+            let description = client.describe(&table.name).await?;
+            result.push(ObjectTable::new(table, &description)?);
+        }
+        Ok(result)
+    }
+}
+
+fn find_field<'a>(
+    description: &'a SObjectDescription,
+    column: &str,
+) -> Result<&'a SObjectField, SalesforceError> {
+    description
+        .fields
+        .iter()
+        .find(|field| field.name == column)
+        .ok_or_else(|| {
+            SalesforceError::ColumnNotFound(description.name.clone(), column.to_string())
+        })
+}
+
+fn map_type(
+    description: &SObjectDescription,
+    field: &SObjectField,
+) -> Result<FieldType, SalesforceError> {
+    map_field_type(&field.typ).ok_or_else(|| {
+        SalesforceError::UnsupportedType(
+            description.name.clone(),
+            field.name.clone(),
+            field.typ.clone(),
+        )
+    })
+}
+
+fn map_schema(
+    description: &SObjectDescription,
+    column_names: &[String],
+) -> Result<SourceSchema, BoxedError> {
+    let mut schema = Schema::new();
+    for column in column_names {
+        let field = find_field(description, column)?;
+        schema.field(
+            FieldDefinition::new(
+                field.name.clone(),
+                map_type(description, field)?,
+                field.nillable,
+                SourceDefinition::Dynamic,
+            ),
+            field.name == ID_FIELD,
+        );
+    }
+    // Change events only identify records by their id, so it must be selected.
+    if schema.primary_index.is_empty() {
+        return Err(SalesforceError::NoIdField(description.name.clone()).into());
+    }
+    // Change events only carry the ids of changed records, see `map_change_event`.
+    Ok(SourceSchema::new(schema, CdcType::OnlyPK))
+}
+
+async fn snapshot_table(
+    client: &mut SalesforceClient,
+    table: &ObjectTable,
+    table_index: usize,
+    ingestor: &Ingestor,
+) -> Result<bool, BoxedError> {
+    let soql = format!(
+        "SELECT {} FROM {}",
+        table.column_names().join(","),
+        table.object
+    );
+    let job_id = client.bulk_query(&soql).await?;
+    let mut locator: Option<String> = None;
+    loop {
+        let (page, next_locator) = client
+            .bulk_query_results(&job_id, locator.as_deref())
+            .await?;
+        let mut reader = csv::Reader::from_reader(page.as_bytes());
+        let headers = reader.headers()?.clone();
+        let positions = table
+            .columns
+            .iter()
+            .map(|(name, _)| {
+                headers
+                    .iter()
+                    .position(|header| header == name)
+                    .ok_or_else(|| {
+                        SalesforceError::ColumnNotFound(table.object.clone(), name.clone())
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for row in reader.records() {
+            let row = row?;
+            let values = table
+                .columns
+                .iter()
+                .zip(&positions)
+                .map(|((_, typ), position)| parse_field(*typ, &row[*position]))
+                .collect::<Result<Vec<_>, _>>()?;
+            let message = IngestionMessage::OperationEvent {
+                table_index,
+                op: Operation::Insert {
+                    new: Record::new(values),
+                },
+                id: None,
+            };
+            if ingestor.handle_message(message).await.is_err() {
+                // If the ingestor is already closed, we don't need to do anything
+                return Ok(false);
+            }
+        }
+        match next_locator {
+            Some(next_locator) => locator = Some(next_locator),
+            None => return Ok(true),
+        }
+    }
+}
+
+/// Maps a change event to operations. Creates and updates re-read the records, because
+/// update events only contain the changed fields.
+async fn map_change_event(
+    client: &mut SalesforceClient,
+    table: &ObjectTable,
+    event: &ChangeEvent,
+) -> Result<Vec<Operation>, SalesforceError> {
+    if event.change_type == ChangeType::GapOverflow {
+        return Err(SalesforceError::GapOverflow(event.object.clone()));
+    }
+    let mut ops = vec![];
+    for id in &event.record_ids {
+        let op = match event.change_type {
+            ChangeType::Delete => Operation::Delete {
+                old: table.pk_only_record(id),
+            },
+            change_type => {
+                let Some(object) = client
+                    .retrieve(&table.object, id, &table.column_names())
+                    .await?
+                else {
+                    warn!(
+                        "Salesforce record {}/{id} disappeared before it could be read",
+                        table.object
+                    );
+                    continue;
+                };
+                let new = table.record_from_json(&object)?;
+                match change_type {
+                    ChangeType::Create | ChangeType::Undelete => Operation::Insert { new },
+                    _ => Operation::Update {
+                        old: table.pk_only_record(id),
+                        new,
+                    },
+                }
+            }
+        };
+        ops.push(op);
+    }
+    Ok(ops)
+}
+
+#[async_trait]
+impl Connector for SalesforceConnector {
+    fn types_mapping() -> Vec<(String, Option<FieldType>)>
+    where
+        Self: Sized,
+    {
+        TYPES_MAPPING
+            .iter()
+            .map(|(name, typ)| (name.to_string(), *typ))
+            .collect()
+    }
+
+    async fn validate_connection(&mut self) -> Result<(), BoxedError> {
+        self.client()?.session().await?;
+        Ok(())
+    }
+
+    async fn list_tables(&mut self) -> Result<Vec<TableIdentifier>, BoxedError> {
+        let mut client = self.client()?;
+        Ok(self
+            .list_objects(&mut client)
+            .await?
+            .into_iter()
+            .map(TableIdentifier::from_table_name)
+            .collect())
+    }
+
+    async fn validate_tables(&mut self, tables: &[TableIdentifier]) -> Result<(), BoxedError> {
+        let mut client = self.client()?;
+        let objects = client.list_objects().await?;
+        for table in tables {
+            if table.schema.is_some() || !objects.contains(&table.name) {
+                return Err(TableNotFound {
+                    schema: table.schema.clone(),
+                    name: table.name.clone(),
+                }
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    async fn list_columns(
+        &mut self,
+        tables: Vec<TableIdentifier>,
+    ) -> Result<Vec<TableInfo>, BoxedError> {
+        let mut client = self.client()?;
+        let mut result = vec![];
+        for table in tables {
+            let description = client.describe(&table.name).await?;
+            let column_names = description
+                .fields
+                .into_iter()
+                .filter(|field| map_field_type(&field.typ).is_some())
+                .map(|field| field.name)
+                .collect();
+            result.push(TableInfo {
+                schema: table.schema,
+                name: table.name,
+                column_names,
+                filter: None,
+            });
+        }
+        Ok(result)
+    }
+
+    async fn get_schemas(
+        &mut self,
+        table_infos: &[TableInfo],
+    ) -> Result<Vec<SourceSchemaResult>, BoxedError> {
+        let mut client = self.client()?;
+        let mut result = vec![];
+        for table in table_infos {
+            let schema = match client.describe(&table.name).await {
+                Ok(description) => map_schema(&description, &table.column_names),
+                Err(SalesforceError::Api { status, .. })
+                    if status == reqwest::StatusCode::NOT_FOUND =>
+                {
+                    Err(TableNotFound {
+                        schema: table.schema.clone(),
+                        name: table.name.clone(),
+                    }
+                    .into())
+                }
+                Err(e) => return Err(e.into()),
+            };
+            result.push(schema);
+        }
+        Ok(result)
+    }
+
+    async fn serialize_state(&self) -> Result<Vec<u8>, BoxedError> {
+        Ok(vec![])
+    }
+
+    async fn start(
+        &mut self,
+        ingestor: &Ingestor,
+        tables: Vec<TableInfo>,
+        last_checkpoint: Option<OpIdentifier>,
+    ) -> Result<(), BoxedError> {
+        let mut client = self.client()?;
+        let tables = self.describe_tables(&mut client, &tables).await?;
+
+        // The change events of all objects are read from a single channel, so they are in one order and the
+        // checkpoint is the replay id of the latest event ingested. When resuming, the snapshot is skipped and the
+        // channel is replayed from the event after the checkpoint. Salesforce only retains change events for 72
+        // hours, so subscribing fails if the checkpoint is older.
+        let resume_after = last_checkpoint.map(|checkpoint| checkpoint.txid as i64);
+
+        // Subscribe before snapshotting, so that changes made during the snapshot are not lost.
+        // They are buffered and applied once the snapshot is done.
+        let mut streaming =
+            StreamingClient::new(self.client()?, resume_after.unwrap_or(REPLAY_NEW));
+        streaming.handshake().await?;
+        let channels = [CHANGE_EVENTS_CHANNEL];
+        for channel in &channels {
+            streaming.subscribe(channel).await?;
+        }
+
+        let (tx, mut rx) = channel::<Result<ChangeEvent, SalesforceError>>(1000);
+        let connector_name = self.name.clone();
+        let streaming_task = tokio::spawn(async move {
+            loop {
+                match streaming.connect().await {
+                    Ok(Some(events)) => {
+                        for event in events {
+                            if tx.send(Ok(event)).await.is_err() {
+                                return;
+                            }
+                        }
+                    }
+                    Ok(None) => {
+                        for channel in &channels {
+                            if let Err(e) = streaming.subscribe(channel).await {
+                                let _ = tx.send(Err(e)).await;
+                                return;
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        let _ = tx.send(Err(e)).await;
+                        return;
+                    }
+                }
+            }
+        });
+
+        if resume_after.is_none() {
+            if ingestor
+                .handle_message(IngestionMessage::TransactionInfo(
+                    TransactionInfo::SnapshottingStarted,
+                ))
+                .await
+                .is_err()
+            {
+                // If the ingestor is already closed, we don't need to do anything
+                streaming_task.abort();
+                return Ok(());
+            }
+            for (table_index, table) in tables.iter().enumerate() {
+                info!(
+                    "[{connector_name}] Snapshotting Salesforce object {}",
+                    table.object
+                );
+                if !snapshot_table(&mut client, table, table_index, ingestor).await? {
+                    streaming_task.abort();
+                    return Ok(());
+                }
+            }
+            if ingestor
+                .handle_message(IngestionMessage::TransactionInfo(
+                    TransactionInfo::SnapshottingDone { id: None },
+                ))
+                .await
+                .is_err()
+            {
+                streaming_task.abort();
+                return Ok(());
+            }
+        }
+
+        let table_indexes = tables
+            .iter()
+            .enumerate()
+            .map(|(index, table)| (table.object.clone(), index))
+            .collect::<HashMap<_, _>>();
+        while let Some(event) = rx.recv().await {
+            let event = event?;
+            if resume_after.is_some_and(|after| event.replay_id <= after) {
+                continue;
+            }
+            let Some(&table_index) = table_indexes.get(&event.object) else {
+                continue;
+            };
+            for op in map_change_event(&mut client, &tables[table_index], &event).await? {
+                if ingestor
+                    .handle_message(IngestionMessage::OperationEvent {
+                        table_index,
+                        op,
+                        id: None,
+                    })
+                    .await
+                    .is_err()
+                {
+                    streaming_task.abort();
+                    return Ok(());
+                }
+            }
+            if ingestor
+                .handle_message(IngestionMessage::TransactionInfo(TransactionInfo::Commit {
+                    id: Some(OpIdentifier::new(event.replay_id as u64, 0)),
+                }))
+                .await
+                .is_err()
+            {
+                streaming_task.abort();
+                return Ok(());
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use dozer_ingestion_connector::dozer_types::{
+    serde_json,
+    thiserror::{self, Error},
+};
+
+mod client;
+pub mod connector;
+mod mapping;
+mod streaming;
+#[cfg(test)]
+mod tests;
+mod throttle;
+
+pub use connector::SalesforceConnector;
+
+#[derive(Error, Debug)]
+pub enum SalesforceError {
+    #[error("http request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("salesforce returned {status} for {url}: {body}")]
+    Api {
+        status: reqwest::StatusCode,
+        url: String,
+        body: String,
+    },
+
+    #[error("authentication failed: {0}")]
+    Authentication(String),
+
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("csv error: {0}")]
+    Csv(#[from] csv::Error),
+
+    #[error("bulk query job {0} ended in state {1}")]
+    BulkJobFailed(String, String),
+
+    #[error("column {1} not found in object {0}")]
+    ColumnNotFound(String, String),
+
+    #[error("column {1} of object {0} has unsupported type {2}")]
+    UnsupportedType(String, String, String),
+
+    #[error("streaming handshake failed: {0}")]
+    Handshake(String),
+
+    #[error("streaming subscription to {0} failed: {1}")]
+    Subscribe(String, String),
+
+    #[error("cannot parse {value:?} as {typ}")]
+    InvalidValue { typ: String, value: String },
+
+    #[error("object {0} has no Id field")]
+    NoIdField(String),
+
+    #[error("change events of object {0} were lost, it must be snapshotted again")]
+    GapOverflow(String),
+}
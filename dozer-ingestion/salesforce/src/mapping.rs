@@ -0,0 +1,99 @@
+use std::str::FromStr;
+
+use base64::{prelude::BASE64_STANDARD, Engine};
+
+use dozer_ingestion_connector::dozer_types::{
+    chrono::{DateTime, NaiveDate},
+    ordered_float::OrderedFloat,
+    rust_decimal::Decimal,
+    serde_json::Value,
+    types::{Field, FieldType},
+};
+
+use crate::SalesforceError;
+
+/// Salesforce field types and their Dozer counterparts. Compound fields (`address`, `location`)
+/// cannot be read through the Bulk API and are not supported.
+pub const TYPES_MAPPING: &[(&str, Option<FieldType>)] = &[
+    ("id", Some(FieldType::String)),
+    ("reference", Some(FieldType::String)),
+    ("string", Some(FieldType::String)),
+    ("picklist", Some(FieldType::String)),
+    ("multipicklist", Some(FieldType::String)),
+    ("combobox", Some(FieldType::String)),
+    ("email", Some(FieldType::String)),
+    ("phone", Some(FieldType::String)),
+    ("url", Some(FieldType::String)),
+    ("encryptedstring", Some(FieldType::String)),
+    ("time", Some(FieldType::String)),
+    ("anyType", Some(FieldType::String)),
+    ("textarea", Some(FieldType::Text)),
+    ("boolean", Some(FieldType::Boolean)),
+    ("int", Some(FieldType::Int)),
+    ("long", Some(FieldType::Int)),
+    ("double", Some(FieldType::Float)),
+    ("percent", Some(FieldType::Float)),
+    ("currency", Some(FieldType::Decimal)),
+    ("date", Some(FieldType::Date)),
+    ("datetime", Some(FieldType::Timestamp)),
+    ("base64", Some(FieldType::Binary)),
+    ("address", None),
+    ("location", None),
+];
+
+pub fn map_field_type(salesforce_type: &str) -> Option<FieldType> {
+    TYPES_MAPPING
+        .iter()
+        .find(|(name, _)| *name == salesforce_type)
+        .and_then(|(_, typ)| *typ)
+}
+
+/// Parses a value as returned in Bulk API CSV results. Empty strings are nulls, and binary values are base64 encoded.
+pub fn parse_field(typ: FieldType, value: &str) -> Result<Field, SalesforceError> {
+    if value.is_empty() {
+        return Ok(Field::Null);
+    }
+    let invalid = || SalesforceError::InvalidValue {
+        typ: typ.to_string(),
+        value: value.to_string(),
+    };
+    Ok(match typ {
+        FieldType::String => Field::String(value.to_string()),
+        FieldType::Text => Field::Text(value.to_string()),
+        FieldType::Boolean => Field::Boolean(value.parse().map_err(|_| invalid())?),
+        FieldType::Int => Field::Int(parse_int(value).ok_or_else(invalid)?),
+        FieldType::Float => Field::Float(OrderedFloat(value.parse().map_err(|_| invalid())?)),
+        FieldType::Decimal => Field::Decimal(Decimal::from_str(value).map_err(|_| invalid())?),
+        FieldType::Date => {
+            Field::Date(NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| invalid())?)
+        }
+        FieldType::Timestamp => Field::Timestamp(
+            DateTime::parse_from_rfc3339(value)
+                .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z"))
+                .map_err(|_| invalid())?,
+        ),
+        FieldType::Binary => Field::Binary(BASE64_STANDARD.decode(value).map_err(|_| invalid())?),
+        _ => return Err(invalid()),
+    })
+}
+
+fn parse_int(value: &str) -> Option<i64> {
+    if let Ok(value) = value.parse() {
+        return Some(value);
+    }
+    // Salesforce reports integers of formula fields as `1.0`. Anything that isn't a whole number in range is invalid.
+    let value = value.parse::<f64>().ok()?;
+    (value.fract() == 0.0 && value >= i64::MIN as f64 && value < i64::MAX as f64)
+        .then_some(value as i64)
+}
+
+/// Parses a value as returned by the REST API.
+pub fn parse_json_field(typ: FieldType, value: &Value) -> Result<Field, SalesforceError> {
+    match value {
+        Value::Null => Ok(Field::Null),
+        Value::String(value) => parse_field(typ, value),
+        Value::Bool(value) => parse_field(typ, &value.to_string()),
+        Value::Number(value) => parse_field(typ, &value.to_string()),
+        Value::Array(_) | Value::Object(_) => parse_field(typ, &value.to_string()),
+    }
+}
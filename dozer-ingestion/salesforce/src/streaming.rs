@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+
+use dozer_ingestion_connector::dozer_types::{
+    log::{debug, warn},
+    serde::Deserialize,
+    serde_json::{json, Value},
+};
+
+use crate::{client::SalesforceClient, SalesforceError};
+
+/// The operation carried by a Change Data Capture event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeType {
+    Create,
+    Update,
+    Delete,
+    Undelete,
+    /// Too many changes were made to the object at once for Salesforce to publish them, so they are lost.
+    GapOverflow,
+}
+
+impl ChangeType {
+    fn parse(change_type: &str) -> Option<Self> {
+        // Gap events (`GAP_CREATE`, ...) carry no field values, but the record ids are still
+        // valid, so they are treated like their regular counterparts.
+        if change_type == "GAP_OVERFLOW" {
+            return Some(ChangeType::GapOverflow);
+        }
+        match change_type.trim_start_matches("GAP_") {
+            "CREATE" => Some(ChangeType::Create),
+            "UPDATE" => Some(ChangeType::Update),
+            "DELETE" => Some(ChangeType::Delete),
+            "UNDELETE" => Some(ChangeType::Undelete),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeEvent {
+    pub object: String,
+    pub change_type: ChangeType,
+    pub record_ids: Vec<String>,
+    /// Position of the event in its channel.
+    pub replay_id: i64,
+}
+
+/// The channel of the change events of all the objects selected for Change Data Capture, in a single order.
+pub const CHANGE_EVENTS_CHANNEL: &str = "/data/ChangeEvents";
+
+/// Subscribes to the events published from now on.
+pub const REPLAY_NEW: i64 = -1;
+
+#[derive(Debug, Deserialize)]
+#[serde(crate = "dozer_ingestion_connector::dozer_types::serde")]
+#[serde(rename_all = "camelCase")]
+struct BayeuxResponse {
+    channel: String,
+    #[serde(default)]
+    successful: Option<bool>,
+    #[serde(default)]
+    client_id: Option<String>,
+    #[serde(default)]
+    error: Option<String>,
+    #[serde(default)]
+    data: Option<Value>,
+}
+
+/// Extracts a change event from the `data` of a Bayeux message.
+pub fn parse_change_event(data: &Value) -> Option<ChangeEvent> {
+    let header = data.get("payload")?.get("ChangeEventHeader")?;
+    let object = header.get("entityName")?.as_str()?.to_string();
+    let change_type = ChangeType::parse(header.get("changeType")?.as_str()?)?;
+    // Overflow events don't list the records that changed.
+    let record_ids = header
+        .get("recordIds")
+        .and_then(Value::as_array)
+        .map(|ids| {
+            ids.iter()
+                .filter_map(|id| id.as_str().map(ToOwned::to_owned))
+                .collect()
+        })
+        .unwrap_or_default();
+    Some(ChangeEvent {
+        object,
+        change_type,
+        record_ids,
+        replay_id: replay_id(data)?,
+    })
+}
+
+fn replay_id(data: &Value) -> Option<i64> {
+    data.get("event")?.get("replayId")?.as_i64()
+}
+
+/// A minimal CometD (Bayeux) long-polling client for the Salesforce Streaming API.
+#[derive(Debug)]
+pub struct StreamingClient {
+    /// Logs in, and again when the access token expires.
+    client: SalesforceClient,
+    client_id: Option<String>,
+    /// Where channels are first subscribed from.
+    replay_from: i64,
+    /// The replay id of the latest event received on each channel, so subscribing again doesn't lose events.
+    replay_ids: HashMap<String, i64>,
+}
+
+impl StreamingClient {
+    pub fn new(client: SalesforceClient, replay_from: i64) -> Self {
+        Self {
+            client,
+            client_id: None,
+            replay_from,
+            replay_ids: HashMap::new(),
+        }
+    }
+
+    /// Sends a message, logging in again and retrying it once if the access token expired.
+    ///
+    /// The server forgets the Bayeux client of an expired session, which [`Self::connect`] handles by handshaking again.
+    async fn post(&mut self, body: Value) -> Result<Vec<BayeuxResponse>, SalesforceError> {
+        let mut logged_in = false;
+        loop {
+            let session = self.client.session().await?;
+            let url = format!(
+                "{}/cometd/{}",
+                session.instance_url,
+                self.client.api_version()
+            );
+            let response = self
+                .client
+                .http()
+                .post(&url)
+                .bearer_auth(&session.access_token)
+                .json(&body)
+                .send()
+                .await?;
+            let status = response.status();
+            if status == reqwest::StatusCode::UNAUTHORIZED && !logged_in {
+                self.client.logout();
+                logged_in = true;
+                continue;
+            }
+            if !status.is_success() {
+                return Err(SalesforceError::Api {
+                    status,
+                    url,
+                    body: response.text().await.unwrap_or_default(),
+                });
+            }
+            return Ok(response.json().await?);
+        }
+    }
+
+    pub async fn handshake(&mut self) -> Result<(), SalesforceError> {
+        let responses = self
+            .post(json!([{
+                "channel": "/meta/handshake",
+                "version": "1.0",
+                "minimumVersion": "1.0",
+                "supportedConnectionTypes": ["long-polling"],
+            }]))
+            .await?;
+        let response = responses
+            .into_iter()
+            .find(|response| response.channel == "/meta/handshake")
+            .ok_or_else(|| SalesforceError::Handshake("no handshake response".to_string()))?;
+        match (response.successful, response.client_id) {
+            (Some(true), Some(client_id)) => {
+                self.client_id = Some(client_id);
+                Ok(())
+            }
+            _ => Err(SalesforceError::Handshake(
+                response.error.unwrap_or_default(),
+            )),
+        }
+    }
+
+    /// Subscribes to a channel, receiving the events after the latest one received on it, or from `replay_from`.
+    pub async fn subscribe(&mut self, channel: &str) -> Result<(), SalesforceError> {
+        let replay_id = self
+            .replay_ids
+            .get(channel)
+            .copied()
+            .unwrap_or(self.replay_from);
+        let responses = self
+            .post(json!([{
+                "channel": "/meta/subscribe",
+                "clientId": self.client_id,
+                "subscription": channel,
+                "ext": { "replay": { channel: replay_id } },
+            }]))
+            .await?;
+        match responses
+            .into_iter()
+            .find(|response| response.channel == "/meta/subscribe")
+        {
+            Some(BayeuxResponse {
+                successful: Some(true),
+                ..
+            }) => Ok(()),
+            Some(response) => Err(SalesforceError::Subscribe(
+                channel.to_string(),
+                response.error.unwrap_or_default(),
+            )),
+            None => Err(SalesforceError::Subscribe(
+                channel.to_string(),
+                "no subscribe response".to_string(),
+            )),
+        }
+    }
+
+    /// Long-polls the server and returns the change events received.
+    ///
+    /// If the server dropped the session, this performs a new handshake and returns no events.
+    /// The caller is responsible for subscribing again in that case, which is signalled by `Ok(None)`.
+    pub async fn connect(&mut self) -> Result<Option<Vec<ChangeEvent>>, SalesforceError> {
+        let responses = self
+            .post(json!([{
+                "channel": "/meta/connect",
+                "clientId": self.client_id,
+                "connectionType": "long-polling",
+            }]))
+            .await?;
+        let mut events = vec![];
+        for response in responses {
+            if response.channel == "/meta/connect" {
+                if response.successful == Some(false) {
+                    warn!(
+                        "Salesforce streaming connection lost: {}. Reconnecting",
+                        response.error.unwrap_or_default()
+                    );
+                    self.handshake().await?;
+                    return Ok(None);
+                }
+                continue;
+            }
+            let Some(data) = response.data.as_ref() else {
+                continue;
+            };
+            if let Some(replay_id) = replay_id(data) {
+                self.replay_ids.insert(response.channel.clone(), replay_id);
+            }
+            match parse_change_event(data) {
+                Some(event) => events.push(event),
+                None => debug!("Ignoring Salesforce message on {}", response.channel),
+            }
+        }
+        Ok(Some(events))
+    }
+}
@@ -0,0 +1,129 @@
+use dozer_ingestion_connector::dozer_types::{
+    chrono::NaiveDate,
+    rust_decimal::Decimal,
+    serde_json::json,
+    types::{Field, FieldType},
+};
+
+use crate::{
+    mapping::{map_field_type, parse_field, parse_json_field},
+    streaming::{parse_change_event, ChangeEvent, ChangeType},
+    throttle::{exceeds_usage, parse_api_usage},
+};
+
+#[test]
+fn test_map_field_type() {
+    assert_eq!(map_field_type("id"), Some(FieldType::String));
+    assert_eq!(map_field_type("currency"), Some(FieldType::Decimal));
+    assert_eq!(map_field_type("datetime"), Some(FieldType::Timestamp));
+    assert_eq!(map_field_type("address"), None);
+    assert_eq!(map_field_type("unknown"), None);
+}
+
+#[test]
+fn test_parse_field() {
+    assert_eq!(parse_field(FieldType::Int, "").unwrap(), Field::Null);
+    assert_eq!(parse_field(FieldType::Int, "42").unwrap(), Field::Int(42));
+    assert_eq!(parse_field(FieldType::Int, "42.0").unwrap(), Field::Int(42));
+    assert!(parse_field(FieldType::Int, "1.9").is_err());
+    assert!(parse_field(FieldType::Int, "1e30").is_err());
+    assert_eq!(
+        parse_field(FieldType::Boolean, "true").unwrap(),
+        Field::Boolean(true)
+    );
+    assert_eq!(
+        parse_field(FieldType::Decimal, "10.25").unwrap(),
+        Field::Decimal(Decimal::new(1025, 2))
+    );
+    assert_eq!(
+        parse_field(FieldType::Date, "2024-02-29").unwrap(),
+        Field::Date(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())
+    );
+    assert!(matches!(
+        parse_field(FieldType::Timestamp, "2024-02-29T10:00:00.000Z").unwrap(),
+        Field::Timestamp(_)
+    ));
+    assert!(matches!(
+        parse_field(FieldType::Timestamp, "2024-02-29T10:00:00.000+0000").unwrap(),
+        Field::Timestamp(_)
+    ));
+    assert!(parse_field(FieldType::Int, "abc").is_err());
+    assert_eq!(
+        parse_field(FieldType::Binary, "aGVsbG8=").unwrap(),
+        Field::Binary(b"hello".to_vec())
+    );
+    assert!(parse_field(FieldType::Binary, "not base64!").is_err());
+}
+
+#[test]
+fn test_parse_json_field() {
+    assert_eq!(
+        parse_json_field(FieldType::Float, &json!(1.5)).unwrap(),
+        Field::Float(1.5.into())
+    );
+    assert_eq!(
+        parse_json_field(FieldType::String, &json!(null)).unwrap(),
+        Field::Null
+    );
+}
+
+#[test]
+fn test_parse_change_event() {
+    let data = json!({
+        "schema": "IeRuaY6cbI_HsV8Rv1Mc5g",
+        "payload": {
+            "ChangeEventHeader": {
+                "entityName": "Account",
+                "recordIds": ["001xx000003DGb2AAG", "001xx000003DGb3AAG"],
+                "changeType": "GAP_UPDATE",
+                "commitTimestamp": 1709200800000_u64,
+            },
+            "Name": "Acme",
+        },
+        "event": { "replayId": 6 },
+    });
+    assert_eq!(
+        parse_change_event(&data),
+        Some(ChangeEvent {
+            object: "Account".to_string(),
+            change_type: ChangeType::Update,
+            record_ids: vec![
+                "001xx000003DGb2AAG".to_string(),
+                "001xx000003DGb3AAG".to_string()
+            ],
+            replay_id: 6,
+        })
+    );
+    assert_eq!(parse_change_event(&json!({ "payload": {} })), None);
+
+    let overflow = json!({
+        "payload": {
+            "ChangeEventHeader": {
+                "entityName": "Account",
+                "changeType": "GAP_OVERFLOW",
+            },
+        },
+        "event": { "replayId": 7 },
+    });
+    assert_eq!(
+        parse_change_event(&overflow),
+        Some(ChangeEvent {
+            object: "Account".to_string(),
+            change_type: ChangeType::GapOverflow,
+            record_ids: vec![],
+            replay_id: 7,
+        })
+    );
+}
+
+#[test]
+fn test_api_usage() {
+    assert_eq!(parse_api_usage("api-usage=25/15000"), Some((25, 15000)));
+    assert_eq!(
+        parse_api_usage("per-app-api-usage=1/100(appName=x); api-usage=25/15000"),
+        Some((25, 15000))
+    );
+    assert_eq!(parse_api_usage("garbage"), None);
+    assert!(!exceeds_usage(25, 15000, 80));
+    assert!(exceeds_usage(12000, 15000, 80));
+}
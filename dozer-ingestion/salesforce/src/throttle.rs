@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use dozer_ingestion_connector::{
+    dozer_types::log::warn,
+    tokio::{
+        sync::Mutex,
+        time::{sleep, sleep_until, Instant},
+    },
+};
+
+const API_USAGE_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Spaces out API calls so that at most `max_requests_per_minute` are made, and backs off when
+/// the org's daily API usage reported in the `Sforce-Limit-Info` header gets close to its limit.
+#[derive(Debug)]
+pub struct Throttle {
+    interval: Duration,
+    max_api_usage_percent: u8,
+    next_request: Mutex<Instant>,
+}
+
+impl Throttle {
+    pub fn new(max_requests_per_minute: u32, max_api_usage_percent: u8) -> Self {
+        Self {
+            interval: Duration::from_secs(60) / max_requests_per_minute.max(1),
+            max_api_usage_percent,
+            next_request: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Waits until the next API call is allowed.
+    pub async fn acquire(&self) {
+        let mut next_request = self.next_request.lock().await;
+        sleep_until(*next_request).await;
+        *next_request = Instant::now() + self.interval;
+    }
+
+    /// Inspects the `Sforce-Limit-Info` header of a response and sleeps if the usage is above the threshold.
+    pub async fn observe_limit_info(&self, limit_info: Option<&str>) {
+        let Some((used, limit)) = limit_info.and_then(parse_api_usage) else {
+            return;
+        };
+        if exceeds_usage(used, limit, self.max_api_usage_percent) {
+            warn!("Salesforce API usage is {used}/{limit}, backing off for {API_USAGE_BACKOFF:?}");
+            sleep(API_USAGE_BACKOFF).await;
+        }
+    }
+}
+
+/// Parses a `Sforce-Limit-Info` header value, e.g. `api-usage=25/15000`.
+pub fn parse_api_usage(limit_info: &str) -> Option<(u64, u64)> {
+    let usage = limit_info
+        .split(';')
+        .find_map(|part| part.trim().strip_prefix("api-usage="))?;
+    let (used, limit) = usage.split_once('/')?;
+    Some((used.trim().parse().ok()?, limit.trim().parse().ok()?))
+}
+
+pub fn exceeds_usage(used: u64, limit: u64, max_api_usage_percent: u8) -> bool {
+    limit > 0 && used * 100 >= limit * max_api_usage_percent as u64
+}
@@ -0,0 +1,355 @@
+use dozer_ingestion_connector::{
+    async_trait,
+    dozer_types::{
+        errors::internal::BoxedError,
+        json_record::json_object_to_record,
+        models::ingestion_types::{default_sheets_poll_interval, AirtableConfig},
+        node::OpIdentifier,
+        serde::Deserialize,
+        serde_json::{Map, Value},
+        types::{FieldDefinition, FieldType, Record, Schema, SourceDefinition},
+    },
+    utils::TableNotFound,
+    CdcType, Connector, Ingestor, SourceSchema, SourceSchemaResult, TableIdentifier, TableInfo,
+};
+use reqwest::Url;
+
+use crate::{
+    http::get_json,
+    polling::{run_polling, ChangeDetector, TableReader},
+    SheetsError,
+};
+
+const AIRTABLE_API_URL: &str = "https://api.airtable.com/v0";
+const PAGE_SIZE: &str = "100";
+
+/// The column holding the Airtable record id, which is the primary key of every table.
+pub const ID_COLUMN: &str = "id";
+
+/// Airtable field types and their Dozer counterparts. Other types (attachments, linked records,
+/// collaborators, ...) are ingested as JSON.
+pub const TYPES_MAPPING: &[(&str, FieldType)] = &[
+    ("singleLineText", FieldType::String),
+    ("email", FieldType::String),
+    ("url", FieldType::String),
+    ("phoneNumber", FieldType::String),
+    ("singleSelect", FieldType::String),
+    ("multilineText", FieldType::Text),
+    ("richText", FieldType::Text),
+    ("number", FieldType::Float),
+    ("currency", FieldType::Float),
+    ("percent", FieldType::Float),
+    ("duration", FieldType::Float),
+    ("rating", FieldType::Int),
+    ("autoNumber", FieldType::Int),
+    ("count", FieldType::Int),
+    ("checkbox", FieldType::Boolean),
+    ("date", FieldType::Date),
+    ("dateTime", FieldType::Timestamp),
+    ("createdTime", FieldType::Timestamp),
+    ("lastModifiedTime", FieldType::Timestamp),
+];
+
+pub fn map_field_type(airtable_type: &str) -> FieldType {
+    TYPES_MAPPING
+        .iter()
+        .find(|(name, _)| *name == airtable_type)
+        .map_or(FieldType::Json, |(_, typ)| *typ)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(crate = "dozer_ingestion_connector::dozer_types::serde")]
+struct BaseSchema {
+    tables: Vec<AirtableTable>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "dozer_ingestion_connector::dozer_types::serde")]
+struct AirtableTable {
+    name: String,
+    fields: Vec<AirtableField>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "dozer_ingestion_connector::dozer_types::serde")]
+struct AirtableField {
+    name: String,
+    #[serde(rename = "type")]
+    typ: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(crate = "dozer_ingestion_connector::dozer_types::serde")]
+struct RecordsPage {
+    records: Vec<AirtableRecord>,
+    #[serde(default)]
+    offset: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(crate = "dozer_ingestion_connector::dozer_types::serde")]
+struct AirtableRecord {
+    id: String,
+    #[serde(default)]
+    fields: Map<String, Value>,
+}
+
+#[derive(Debug)]
+pub struct AirtableConnector {
+    config: AirtableConfig,
+    client: reqwest::Client,
+}
+
+impl AirtableConnector {
+    pub fn new(config: AirtableConfig) -> Self {
+        Self {
+            config,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    fn url(&self, path: &[&str]) -> Url {
+        let mut url = Url::parse(AIRTABLE_API_URL).expect("Airtable API url must be valid");
+        url.path_segments_mut()
+            .expect("Airtable API url must be a base")
+            .extend(path);
+        url
+    }
+
+    async fn tables(&self) -> Result<Vec<AirtableTable>, SheetsError> {
+        let url = self.url(&["meta", "bases", &self.config.base_id, "tables"]);
+        let schema: BaseSchema =
+            get_json(self.client.get(url).bearer_auth(&self.config.api_key)).await?;
+        Ok(schema.tables)
+    }
+
+    async fn find_tables<'a>(
+        &self,
+        tables: impl Iterator<Item = (&'a Option<String>, &'a str)>,
+    ) -> Result<Vec<AirtableTable>, BoxedError> {
+        let all_tables = self.tables().await?;
+        let mut result = vec![];
+        for (schema, name) in tables {
+            let table = all_tables
+                .iter()
+                .find(|table| schema.is_none() && table.name == name)
+                .ok_or_else(|| TableNotFound {
+                    schema: schema.clone(),
+                    name: name.to_string(),
+                })?;
+            result.push(table.clone());
+        }
+        Ok(result)
+    }
+
+    async fn read_records(&self, table: &str) -> Result<Vec<AirtableRecord>, SheetsError> {
+        let url = self.url(&[&self.config.base_id, table]);
+        let mut records = vec![];
+        let mut offset = None;
+        loop {
+            let mut request = self
+                .client
+                .get(url.clone())
+                .bearer_auth(&self.config.api_key)
+                .query(&[("pageSize", PAGE_SIZE)]);
+            if let Some(offset) = &offset {
+                request = request.query(&[("offset", offset)]);
+            }
+            let page: RecordsPage = get_json(request).await?;
+            records.extend(page.records);
+            match page.offset {
+                Some(next) => offset = Some(next),
+                None => return Ok(records),
+            }
+        }
+    }
+}
+
+/// The Dozer type of every requested column.
+fn column_types(table: &AirtableTable, columns: &[String]) -> Result<Vec<FieldType>, SheetsError> {
+    columns
+        .iter()
+        .map(|column| {
+            if column == ID_COLUMN {
+                return Ok(FieldType::String);
+            }
+            table
+                .fields
+                .iter()
+                .find(|field| &field.name == column)
+                .map(|field| map_field_type(&field.typ))
+                .ok_or_else(|| SheetsError::ColumnNotFound(table.name.clone(), column.clone()))
+        })
+        .collect()
+}
+
+fn map_schema(table: &AirtableTable, columns: &[String]) -> Result<SourceSchema, SheetsError> {
+    let types = column_types(table, columns)?;
+    let mut schema = Schema::new();
+    for (column, typ) in columns.iter().zip(types) {
+        let is_primary_key = column == ID_COLUMN;
+        schema.field(
+            FieldDefinition::new(
+                column.clone(),
+                typ,
+                !is_primary_key,
+                SourceDefinition::Dynamic,
+            ),
+            is_primary_key,
+        );
+    }
+    let cdc_type = if schema.primary_index.is_empty() {
+        CdcType::Nothing
+    } else {
+        CdcType::FullChanges
+    };
+    Ok(SourceSchema::new(schema, cdc_type))
+}
+
+/// Converts a record to a record of `schema`. Airtable omits empty fields from responses, so
+/// missing fields are nulls.
+fn map_record(record: AirtableRecord, schema: &Schema) -> Result<Record, SheetsError> {
+    let AirtableRecord { id, mut fields } = record;
+    fields.insert(ID_COLUMN.to_string(), Value::String(id));
+    Ok(json_object_to_record(fields, schema, Default::default())?)
+}
+
+struct AirtableReader<'a> {
+    connector: &'a AirtableConnector,
+    tables: Vec<(String, Schema)>,
+}
+
+#[async_trait]
+impl TableReader for AirtableReader<'_> {
+    async fn read(&self, table_index: usize) -> Result<Vec<Record>, SheetsError> {
+        let (name, schema) = &self.tables[table_index];
+        self.connector
+            .read_records(name)
+            .await?
+            .into_iter()
+            .map(|record| map_record(record, schema))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl Connector for AirtableConnector {
+    fn types_mapping() -> Vec<(String, Option<FieldType>)>
+    where
+        Self: Sized,
+    {
+        TYPES_MAPPING
+            .iter()
+            .map(|(name, typ)| (name.to_string(), Some(*typ)))
+            .collect()
+    }
+
+    async fn validate_connection(&mut self) -> Result<(), BoxedError> {
+        self.tables().await?;
+        Ok(())
+    }
+
+    async fn list_tables(&mut self) -> Result<Vec<TableIdentifier>, BoxedError> {
+        Ok(self
+            .tables()
+            .await?
+            .into_iter()
+            .map(|table| TableIdentifier::from_table_name(table.name))
+            .collect())
+    }
+
+    async fn validate_tables(&mut self, tables: &[TableIdentifier]) -> Result<(), BoxedError> {
+        self.find_tables(
+            tables
+                .iter()
+                .map(|table| (&table.schema, table.name.as_str())),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn list_columns(
+        &mut self,
+        tables: Vec<TableIdentifier>,
+    ) -> Result<Vec<TableInfo>, BoxedError> {
+        let airtable_tables = self
+            .find_tables(
+                tables
+                    .iter()
+                    .map(|table| (&table.schema, table.name.as_str())),
+            )
+            .await?;
+        Ok(tables
+            .into_iter()
+            .zip(airtable_tables)
+            .map(|(table, airtable_table)| TableInfo {
+                schema: table.schema,
+                name: table.name,
+                column_names: std::iter::once(ID_COLUMN.to_string())
+                    .chain(airtable_table.fields.into_iter().map(|field| field.name))
+                    .collect(),
+                filter: None,
+            })
+            .collect())
+    }
+
+    async fn get_schemas(
+        &mut self,
+        table_infos: &[TableInfo],
+    ) -> Result<Vec<SourceSchemaResult>, BoxedError> {
+        let all_tables = self.tables().await?;
+        Ok(table_infos
+            .iter()
+            .map(|table_info| {
+                let table = all_tables
+                    .iter()
+                    .find(|table| table_info.schema.is_none() && table.name == table_info.name)
+                    .ok_or_else(|| TableNotFound {
+                        schema: table_info.schema.clone(),
+                        name: table_info.name.clone(),
+                    })?;
+                Ok(map_schema(table, &table_info.column_names)?)
+            })
+            .collect())
+    }
+
+    async fn serialize_state(&self) -> Result<Vec<u8>, BoxedError> {
+        Ok(vec![])
+    }
+
+    async fn start(
+        &mut self,
+        ingestor: &Ingestor,
+        tables: Vec<TableInfo>,
+        _last_checkpoint: Option<OpIdentifier>,
+    ) -> Result<(), BoxedError> {
+        let airtable_tables = self
+            .find_tables(
+                tables
+                    .iter()
+                    .map(|table| (&table.schema, table.name.as_str())),
+            )
+            .await?;
+        let mut reader_tables = vec![];
+        let mut detectors = vec![];
+        for (table, airtable_table) in tables.into_iter().zip(airtable_tables) {
+            let schema = map_schema(&airtable_table, &table.column_names)?.schema;
+            detectors.push(ChangeDetector::new(
+                table.name.clone(),
+                schema.primary_index.clone(),
+            ));
+            reader_tables.push((table.name, schema));
+        }
+        let reader = AirtableReader {
+            connector: self,
+            tables: reader_tables,
+        };
+        let interval = self
+            .config
+            .poll_interval_seconds
+            .unwrap_or_else(default_sheets_poll_interval);
+        run_polling(&reader, detectors, interval, ingestor)
+            .await
+            .map_err(Into::into)
+    }
+}
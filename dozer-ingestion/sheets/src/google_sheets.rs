@@ -0,0 +1,276 @@
+use dozer_ingestion_connector::{
+    async_trait,
+    dozer_types::{
+        errors::internal::BoxedError,
+        models::ingestion_types::{default_sheets_poll_interval, GoogleSheet, GoogleSheetsConfig},
+        node::OpIdentifier,
+        serde::Deserialize,
+        serde_json::Value,
+        types::{Field, FieldDefinition, FieldType, Record, Schema, SourceDefinition},
+    },
+    utils::TableNotFound,
+    CdcType, Connector, Ingestor, SourceSchema, SourceSchemaResult, TableIdentifier, TableInfo,
+};
+use reqwest::Url;
+
+use crate::{
+    http::get_json,
+    polling::{run_polling, ChangeDetector, TableReader},
+    SheetsError,
+};
+
+const SHEETS_API_URL: &str = "https://sheets.googleapis.com/v4/spreadsheets";
+
+#[derive(Debug, Deserialize)]
+#[serde(crate = "dozer_ingestion_connector::dozer_types::serde")]
+struct ValueRange {
+    #[serde(default)]
+    values: Vec<Vec<Value>>,
+}
+
+#[derive(Debug)]
+pub struct GoogleSheetsConnector {
+    config: GoogleSheetsConfig,
+    client: reqwest::Client,
+}
+
+impl GoogleSheetsConnector {
+    pub fn new(config: GoogleSheetsConfig) -> Self {
+        Self {
+            config,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    fn sheet(&self, name: &str) -> Option<&GoogleSheet> {
+        self.config.sheets.iter().find(|sheet| sheet.name == name)
+    }
+
+    fn find_sheet(
+        &self,
+        schema: &Option<String>,
+        name: &str,
+    ) -> Result<&GoogleSheet, TableNotFound> {
+        self.sheet(name)
+            .filter(|_| schema.is_none())
+            .ok_or_else(|| TableNotFound {
+                schema: schema.clone(),
+                name: name.to_string(),
+            })
+    }
+
+    /// Returns the header row and the data rows of a sheet.
+    async fn read_sheet(
+        &self,
+        sheet: &GoogleSheet,
+    ) -> Result<(Vec<String>, Vec<Vec<Value>>), SheetsError> {
+        let mut url = Url::parse(SHEETS_API_URL).expect("Sheets API url must be valid");
+        url.path_segments_mut()
+            .expect("Sheets API url must be a base")
+            .extend([
+                sheet.spreadsheet_id.as_str(),
+                "values",
+                sheet.range.as_str(),
+            ]);
+        let range: ValueRange = get_json(self.client.get(url).query(&[
+            ("key", self.config.api_key.as_str()),
+            ("majorDimension", "ROWS"),
+            ("valueRenderOption", "UNFORMATTED_VALUE"),
+        ]))
+        .await?;
+
+        let mut rows = range.values.into_iter();
+        let header = rows
+            .next()
+            .ok_or_else(|| SheetsError::NoHeaderRow(sheet.name.clone()))?
+            .iter()
+            .map(cell_to_string)
+            .collect::<Vec<_>>();
+        Ok((header, rows.collect()))
+    }
+}
+
+fn cell_to_string(cell: &Value) -> String {
+    match cell {
+        Value::String(value) => value.clone(),
+        Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
+
+fn cell_to_field(cell: Option<&Value>) -> Field {
+    match cell.map(cell_to_string) {
+        Some(value) if !value.is_empty() => Field::String(value),
+        _ => Field::Null,
+    }
+}
+
+/// Positions of `columns` in the header row.
+fn column_positions(
+    sheet_name: &str,
+    header: &[String],
+    columns: &[String],
+) -> Result<Vec<usize>, SheetsError> {
+    columns
+        .iter()
+        .map(|column| {
+            header
+                .iter()
+                .position(|name| name == column)
+                .ok_or_else(|| SheetsError::ColumnNotFound(sheet_name.to_string(), column.clone()))
+        })
+        .collect()
+}
+
+fn map_schema(sheet: &GoogleSheet, columns: &[String]) -> Result<SourceSchema, SheetsError> {
+    let mut schema = Schema::new();
+    for column in columns {
+        let is_primary_key = sheet.primary_key.contains(column);
+        schema.field(
+            FieldDefinition::new(
+                column.clone(),
+                FieldType::String,
+                !is_primary_key,
+                SourceDefinition::Dynamic,
+            ),
+            is_primary_key,
+        );
+    }
+    if let Some(missing) = sheet
+        .primary_key
+        .iter()
+        .find(|column| !columns.contains(column))
+    {
+        return Err(SheetsError::ColumnNotFound(
+            sheet.name.clone(),
+            missing.clone(),
+        ));
+    }
+    // Without a primary key, rows are only inserted and deleted, see `ChangeDetector`.
+    let cdc_type = if sheet.primary_key.is_empty() {
+        CdcType::Nothing
+    } else {
+        CdcType::FullChanges
+    };
+    Ok(SourceSchema::new(schema, cdc_type))
+}
+
+struct SheetsReader<'a> {
+    connector: &'a GoogleSheetsConnector,
+    tables: Vec<(&'a GoogleSheet, Vec<String>)>,
+}
+
+#[async_trait]
+impl TableReader for SheetsReader<'_> {
+    async fn read(&self, table_index: usize) -> Result<Vec<Record>, SheetsError> {
+        let (sheet, columns) = &self.tables[table_index];
+        let (header, rows) = self.connector.read_sheet(sheet).await?;
+        let positions = column_positions(&sheet.name, &header, columns)?;
+        Ok(rows
+            .iter()
+            .filter(|row| row.iter().any(|cell| !cell_to_string(cell).is_empty()))
+            .map(|row| {
+                Record::new(
+                    positions
+                        .iter()
+                        .map(|position| cell_to_field(row.get(*position)))
+                        .collect(),
+                )
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl Connector for GoogleSheetsConnector {
+    fn types_mapping() -> Vec<(String, Option<FieldType>)>
+    where
+        Self: Sized,
+    {
+        vec![("cell".to_string(), Some(FieldType::String))]
+    }
+
+    async fn validate_connection(&mut self) -> Result<(), BoxedError> {
+        Ok(())
+    }
+
+    async fn list_tables(&mut self) -> Result<Vec<TableIdentifier>, BoxedError> {
+        Ok(self
+            .config
+            .sheets
+            .iter()
+            .map(|sheet| TableIdentifier::from_table_name(sheet.name.clone()))
+            .collect())
+    }
+
+    async fn validate_tables(&mut self, tables: &[TableIdentifier]) -> Result<(), BoxedError> {
+        for table in tables {
+            let sheet = self.find_sheet(&table.schema, &table.name)?;
+            self.read_sheet(sheet).await?;
+        }
+        Ok(())
+    }
+
+    async fn list_columns(
+        &mut self,
+        tables: Vec<TableIdentifier>,
+    ) -> Result<Vec<TableInfo>, BoxedError> {
+        let mut result = vec![];
+        for table in tables {
+            let sheet = self.find_sheet(&table.schema, &table.name)?;
+            let (column_names, _) = self.read_sheet(sheet).await?;
+            result.push(TableInfo {
+                schema: table.schema,
+                name: table.name,
+                column_names,
+                filter: None,
+            });
+        }
+        Ok(result)
+    }
+
+    async fn get_schemas(
+        &mut self,
+        table_infos: &[TableInfo],
+    ) -> Result<Vec<SourceSchemaResult>, BoxedError> {
+        Ok(table_infos
+            .iter()
+            .map(|table| {
+                let sheet = self.find_sheet(&table.schema, &table.name)?;
+                Ok(map_schema(sheet, &table.column_names)?)
+            })
+            .collect())
+    }
+
+    async fn serialize_state(&self) -> Result<Vec<u8>, BoxedError> {
+        Ok(vec![])
+    }
+
+    async fn start(
+        &mut self,
+        ingestor: &Ingestor,
+        tables: Vec<TableInfo>,
+        _last_checkpoint: Option<OpIdentifier>,
+    ) -> Result<(), BoxedError> {
+        let mut reader_tables = vec![];
+        let mut detectors = vec![];
+        for table in tables {
+            let sheet = self.find_sheet(&table.schema, &table.name)?;
+            let primary_index =
+                column_positions(&sheet.name, &table.column_names, &sheet.primary_key)?;
+            detectors.push(ChangeDetector::new(sheet.name.clone(), primary_index));
+            reader_tables.push((sheet, table.column_names));
+        }
+        let reader = SheetsReader {
+            connector: self,
+            tables: reader_tables,
+        };
+        let interval = self
+            .config
+            .poll_interval_seconds
+            .unwrap_or_else(default_sheets_poll_interval);
+        run_polling(&reader, detectors, interval, ingestor)
+            .await
+            .map_err(Into::into)
+    }
+}
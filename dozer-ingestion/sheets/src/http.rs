@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+use dozer_ingestion_connector::{
+    dozer_types::{log::warn, serde::de::DeserializeOwned},
+    tokio::time::sleep,
+};
+use reqwest::{RequestBuilder, StatusCode};
+
+use crate::SheetsError;
+
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(30);
+const MAX_RETRIES: u32 = 5;
+
+/// Sends a request, waiting and retrying when the API reports that the rate limit was hit.
+pub async fn get_json<T: DeserializeOwned>(request: RequestBuilder) -> Result<T, SheetsError> {
+    let mut retries = 0;
+    loop {
+        let attempt = request
+            .try_clone()
+            .expect("GET requests without a streaming body can be cloned");
+        let response = attempt.send().await?;
+        let status = response.status();
+        if status == StatusCode::TOO_MANY_REQUESTS && retries < MAX_RETRIES {
+            let retry_after = retry_after(
+                response
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok()),
+                retries,
+            );
+            warn!(
+                "Rate limited by {}, retrying in {retry_after:?}",
+                response.url()
+            );
+            retries += 1;
+            sleep(retry_after).await;
+            continue;
+        }
+        if !status.is_success() {
+            return Err(SheetsError::Api {
+                status,
+                url: response.url().to_string(),
+                body: response.text().await.unwrap_or_default(),
+            });
+        }
+        return Ok(response.json().await?);
+    }
+}
+
+/// Uses the `Retry-After` header if the server sent one, or backs off exponentially otherwise.
+pub fn retry_after(header: Option<&str>, retries: u32) -> Duration {
+    header
+        .and_then(|value| value.trim().parse().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_RETRY_AFTER * 2u32.pow(retries))
+}
@@ -0,0 +1,36 @@
+use dozer_ingestion_connector::dozer_types::{
+    json_record::JsonRecordError,
+    thiserror::{self, Error},
+};
+
+pub mod airtable;
+pub mod google_sheets;
+mod http;
+mod polling;
+#[cfg(test)]
+mod tests;
+
+pub use airtable::AirtableConnector;
+pub use google_sheets::GoogleSheetsConnector;
+
+#[derive(Error, Debug)]
+pub enum SheetsError {
+    #[error("http request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("{url} returned {status}: {body}")]
+    Api {
+        status: reqwest::StatusCode,
+        url: String,
+        body: String,
+    },
+
+    #[error("sheet {0} has no header row")]
+    NoHeaderRow(String),
+
+    #[error("column {1} not found in header row of {0}")]
+    ColumnNotFound(String, String),
+
+    #[error("cannot convert record: {0}")]
+    Record(#[from] JsonRecordError),
+}
@@ -0,0 +1,138 @@
+use std::{collections::HashMap, time::Duration};
+
+use dozer_ingestion_connector::{
+    async_trait,
+    dozer_types::{
+        log::warn,
+        models::ingestion_types::{IngestionMessage, TransactionInfo},
+        types::{Field, Operation, Record},
+    },
+    tokio::time::sleep,
+    Ingestor,
+};
+
+use crate::SheetsError;
+
+/// Reads the full contents of the polled tables.
+#[async_trait]
+pub trait TableReader: Send + Sync {
+    async fn read(&self, table_index: usize) -> Result<Vec<Record>, SheetsError>;
+}
+
+/// Snapshots all tables, then polls them every `interval` and emits the changes.
+pub async fn run_polling(
+    reader: &impl TableReader,
+    mut detectors: Vec<ChangeDetector>,
+    interval: Duration,
+    ingestor: &Ingestor,
+) -> Result<(), SheetsError> {
+    if ingestor
+        .handle_message(IngestionMessage::TransactionInfo(
+            TransactionInfo::SnapshottingStarted,
+        ))
+        .await
+        .is_err()
+    {
+        // If the ingestor is already closed, we don't need to do anything
+        return Ok(());
+    }
+    let mut snapshotting = true;
+    loop {
+        for (table_index, detector) in detectors.iter_mut().enumerate() {
+            let records = reader.read(table_index).await?;
+            for op in detector.diff(records) {
+                if ingestor
+                    .handle_message(IngestionMessage::OperationEvent {
+                        table_index,
+                        op,
+                        id: None,
+                    })
+                    .await
+                    .is_err()
+                {
+                    return Ok(());
+                }
+            }
+        }
+        let message = if snapshotting {
+            snapshotting = false;
+            TransactionInfo::SnapshottingDone { id: None }
+        } else {
+            TransactionInfo::Commit { id: None }
+        };
+        if ingestor
+            .handle_message(IngestionMessage::TransactionInfo(message))
+            .await
+            .is_err()
+        {
+            return Ok(());
+        }
+        sleep(interval).await;
+    }
+}
+
+/// Detects changes between consecutive polls of a table by comparing rows.
+///
+/// Rows are identified by the primary key columns. Without a primary key, they are identified by the
+/// whole row and how many identical rows precede it, so rows only get inserted and deleted.
+#[derive(Debug, Default)]
+pub struct ChangeDetector {
+    table_name: String,
+    primary_index: Vec<usize>,
+    rows: HashMap<Vec<Field>, Record>,
+}
+
+impl ChangeDetector {
+    pub fn new(table_name: String, primary_index: Vec<usize>) -> Self {
+        Self {
+            table_name,
+            primary_index,
+            rows: HashMap::new(),
+        }
+    }
+
+    fn key(&self, record: &Record) -> Vec<Field> {
+        if !self.primary_index.is_empty() {
+            return record.get_fields_by_indexes(&self.primary_index);
+        }
+        let mut key = record.values.clone();
+        key.push(Field::UInt(0));
+        while self.rows.contains_key(&key) {
+            if let Some(Field::UInt(occurrence)) = key.last_mut() {
+                *occurrence += 1;
+            }
+        }
+        key
+    }
+
+    /// Replaces the known rows with `records` and returns the operations that turn the previous rows into the new ones.
+    ///
+    /// Rows whose primary key is already taken by a previous row of `records` are skipped.
+    pub fn diff(&mut self, records: Vec<Record>) -> Vec<Operation> {
+        let mut previous = std::mem::take(&mut self.rows);
+        let mut ops = vec![];
+        for record in records {
+            let key = self.key(&record);
+            if self.rows.contains_key(&key) {
+                warn!(
+                    "Skipping row of {} with duplicate primary key {:?}",
+                    self.table_name, key
+                );
+                continue;
+            }
+            match previous.remove(&key) {
+                None => ops.push(Operation::Insert {
+                    new: record.clone(),
+                }),
+                Some(old) if old != record => ops.push(Operation::Update {
+                    old,
+                    new: record.clone(),
+                }),
+                Some(_) => (),
+            }
+            self.rows.insert(key, record);
+        }
+        ops.extend(previous.into_values().map(|old| Operation::Delete { old }));
+        ops
+    }
+}
@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use dozer_ingestion_connector::dozer_types::types::{Field, FieldType, Operation, Record};
+
+use crate::{airtable::map_field_type, http::retry_after, polling::ChangeDetector};
+
+fn record(id: i64, name: &str) -> Record {
+    Record::new(vec![Field::Int(id), Field::String(name.to_string())])
+}
+
+#[test]
+fn test_change_detector_with_primary_key() {
+    let mut detector = ChangeDetector::new("table".to_string(), vec![0]);
+    assert_eq!(
+        detector.diff(vec![record(1, "a"), record(2, "b")]),
+        vec![
+            Operation::Insert {
+                new: record(1, "a")
+            },
+            Operation::Insert {
+                new: record(2, "b")
+            },
+        ]
+    );
+
+    assert_eq!(
+        detector.diff(vec![record(1, "a"), record(2, "c"), record(3, "d")]),
+        vec![
+            Operation::Update {
+                old: record(2, "b"),
+                new: record(2, "c")
+            },
+            Operation::Insert {
+                new: record(3, "d")
+            },
+        ]
+    );
+
+    assert_eq!(
+        detector.diff(vec![record(2, "c"), record(3, "d")]),
+        vec![Operation::Delete {
+            old: record(1, "a")
+        }]
+    );
+
+    assert_eq!(detector.diff(vec![record(2, "c"), record(3, "d")]), vec![]);
+}
+
+#[test]
+fn test_change_detector_without_primary_key() {
+    let mut detector = ChangeDetector::new("table".to_string(), vec![]);
+    detector.diff(vec![record(1, "a")]);
+    assert_eq!(
+        detector.diff(vec![record(1, "b")]),
+        vec![
+            Operation::Insert {
+                new: record(1, "b")
+            },
+            Operation::Delete {
+                old: record(1, "a")
+            },
+        ]
+    );
+
+    assert_eq!(
+        detector.diff(vec![record(1, "b"), record(1, "b")]),
+        vec![Operation::Insert {
+            new: record(1, "b")
+        }]
+    );
+    assert_eq!(
+        detector.diff(vec![record(1, "b")]),
+        vec![Operation::Delete {
+            old: record(1, "b")
+        }]
+    );
+}
+
+#[test]
+fn test_change_detector_skips_duplicate_keys() {
+    let mut detector = ChangeDetector::new("table".to_string(), vec![0]);
+    assert_eq!(
+        detector.diff(vec![record(1, "a"), record(1, "b")]),
+        vec![Operation::Insert {
+            new: record(1, "a")
+        }]
+    );
+}
+
+#[test]
+fn test_retry_after() {
+    assert_eq!(retry_after(Some("7"), 0), Duration::from_secs(7));
+    assert_eq!(retry_after(None, 0), Duration::from_secs(30));
+    assert_eq!(retry_after(Some("soon"), 2), Duration::from_secs(120));
+}
+
+#[test]
+fn test_airtable_map_field_type() {
+    assert_eq!(map_field_type("singleLineText"), FieldType::String);
+    assert_eq!(map_field_type("checkbox"), FieldType::Boolean);
+    assert_eq!(map_field_type("dateTime"), FieldType::Timestamp);
+    assert_eq!(map_field_type("multipleAttachments"), FieldType::Json);
+}
@@ -2,12 +2,13 @@ use dozer_ingestion_connector::{
     dozer_types::{
         chrono::{NaiveDate, NaiveDateTime, NaiveTime},
         indexmap::IndexMap,
-        log::debug,
+        log::{debug, warn},
         models::ingestion_types::SnowflakeConfig,
         models::sink_config::snowflake::ConnectionParameters as SnowflakeSinkConfig,
         rust_decimal::Decimal,
         types::*,
     },
+    retry::RetryPolicy,
     CdcType, SourceSchema,
 };
 use odbc::ffi::{SqlDataType, SQL_DATE_STRUCT, SQL_TIMESTAMP_STRUCT};
@@ -164,6 +165,15 @@ pub struct ClientConfig {
 
     pub database: Option<String>,
     pub schema: Option<String>,
+
+    pub retry_policy: RetryPolicy,
+}
+
+impl ClientConfig {
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
 }
 
 pub struct Client<'env> {
@@ -211,7 +221,7 @@ impl<'env> Client<'env> {
             .take(7)
             .map(char::from)
             .collect();
-        let pool = Pool::new(env, conn_string);
+        let pool = Pool::new(env, conn_string, config.retry_policy);
         Self { pool, name }
     }
 
@@ -369,6 +379,7 @@ impl<'env> Client<'env> {
                             typ,
                             nullable: *nullable,
                             source: SourceDefinition::Dynamic,
+                            annotations: Default::default(),
                         });
                     }
                 }
@@ -460,6 +471,7 @@ impl From<SnowflakeConfig> for ClientConfig {
             warehouse,
             database: Some(database),
             schema: Some(schema),
+            retry_policy: RetryPolicy::default(),
         }
     }
 }
@@ -486,14 +498,24 @@ impl From<SnowflakeSinkConfig> for ClientConfig {
             warehouse,
             database: None,
             schema: None,
+            retry_policy: RetryPolicy::default(),
         }
     }
 }
 
+/// Continues the enclosing loop, or the one labeled `$label`, after the delay of `$backoff` if `$operation` fails with a
+/// network error.
 macro_rules! retry {
-    ($operation:expr $(, $label:tt)? $(,)?) => {
+    ($backoff:ident, $operation:expr $(, $label:tt)? $(,)?) => {
         match $operation {
-            Err(err) if is_network_failure(&err) => continue $($label)?,
+            Err(err) if is_network_failure(&err) => match $backoff.next_delay() {
+                Some(delay) => {
+                    warn!("Snowflake network error: {err:?}, retrying in {delay:?}");
+                    std::thread::sleep(delay);
+                    continue $($label)?;
+                }
+                None => Err(err),
+            },
             result => result,
         }
     };
@@ -544,10 +566,11 @@ fn exec_drop(pool: &Pool, query: &str, params: &[OdbcValue]) -> Result<(), Box<D
 }
 
 fn exec_first_exists(pool: &Pool, query: &str) -> Result<bool, Box<DiagnosticRecord>> {
+    let mut backoff = pool.retry_policy().backoff();
     loop {
         let conn = pool.get_conn()?;
         let result = match exec_helper(&conn, query, &[])? {
-            Some(mut data) => retry!(data.fetch())?.is_some(),
+            Some(mut data) => retry!(backoff, data.fetch())?.is_some(),
             None => false,
         };
         conn.return_();
@@ -568,6 +591,7 @@ fn exec_iter(
 
     let mut generator: Gen<ExecIterResult, (), _> = gen!({
         let mut cursor_position = 0u64;
+        let mut backoff = pool.retry_policy().backoff();
         'retry: loop {
             let conn = pool.get_conn().map_err(SnowflakeError::QueryError)?;
             {
@@ -594,12 +618,14 @@ fn exec_iter(
                 }
                 yield_!(Schema(schema.clone()));
 
-                while let Some(cursor) =
-                    retry!(data.fetch(),'retry).map_err(|e| SnowflakeError::QueryError(e.into()))?
+                while let Some(cursor) = retry!(backoff, data.fetch(), 'retry)
+                    .map_err(|e| SnowflakeError::QueryError(e.into()))?
                 {
                     let fields = get_fields_from_cursor(cursor, cols, &schema)?;
                     yield_!(Row(fields));
                     cursor_position += 1;
+                    // The query resumes after the rows fetched so far, so only consecutive failures back off.
+                    backoff.reset();
                 }
             }
             conn.return_();
@@ -671,13 +697,14 @@ fn exec_helper<'a>(
     params: &'a [OdbcValue],
 ) -> Result<Option<odbc::Statement<'a, 'a, Executed, HasResult, AutocommitOn>>, Box<DiagnosticRecord>>
 {
+    let mut backoff = conn.retry_policy().backoff();
     loop {
-        let mut statement = retry!(odbc::Statement::with_parent(conn.deref()))?;
+        let mut statement = retry!(backoff, odbc::Statement::with_parent(conn.deref()))?;
         for (i, param) in params.iter().enumerate() {
             let parameter_index = i as u16 + 1;
             statement = param.bind(statement, parameter_index)?
         }
-        let result = retry!(statement.exec_direct(query))?;
+        let result = retry!(backoff, statement.exec_direct(query))?;
         break match result {
             odbc::ResultSetState::Data(data) => Ok(Some(data)),
             odbc::ResultSetState::NoData(_) => Ok(None),
@@ -5,7 +5,9 @@ use std::{
     rc::Rc,
 };
 
-use dozer_ingestion_connector::{blocking_retry_on_network_failure, dozer_types};
+use dozer_ingestion_connector::{
+    blocking_retry_on_network_failure, dozer_types, retry::RetryPolicy,
+};
 use odbc::{
     odbc_safe::{AutocommitOn, Odbc3},
     Connection, DiagnosticRecord, Environment,
@@ -21,16 +23,25 @@ pub struct Pool<'env> {
 }
 
 impl<'env> Pool<'env> {
-    pub fn new(env: &'env Environment<Odbc3>, conn_string: String) -> Self {
+    pub fn new(
+        env: &'env Environment<Odbc3>,
+        conn_string: String,
+        retry_policy: RetryPolicy,
+    ) -> Self {
         Self {
             inner: Rc::new(RefCell::new(Inner {
                 env,
                 conn_string,
+                retry_policy,
                 connections: Default::default(),
             })),
         }
     }
 
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self.inner.borrow().retry_policy
+    }
+
     pub fn get_conn(&self) -> Result<Conn<'env>, Box<DiagnosticRecord>> {
         let mut inner = self.inner.borrow_mut();
         let inner = inner.deref_mut();
@@ -38,6 +49,7 @@ impl<'env> Pool<'env> {
             conn
         } else {
             blocking_retry_on_network_failure!(
+                inner.retry_policy,
                 "connect_with_connection_string",
                 inner.env.connect_with_connection_string(&inner.conn_string),
                 is_network_failure,
@@ -62,6 +74,7 @@ impl<'env> Pool<'env> {
 struct Inner<'env> {
     env: &'env Environment<Odbc3>,
     conn_string: String,
+    retry_policy: RetryPolicy,
     connections: LinkedList<Connection<'env, AutocommitOn>>,
 }
 
@@ -72,6 +85,10 @@ pub struct Conn<'env> {
 }
 
 impl<'env> Conn<'env> {
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self.pool.retry_policy()
+    }
+
     /// Returns the connection to the pool.
     /// Currently, connections have to be manually returned to the pool
     /// because odbc::Connection does not know if it has been disconnected.
@@ -7,12 +7,15 @@ use dozer_ingestion_connector::{
         node::OpIdentifier,
         types::FieldType,
     },
+    retry::RetryPolicy,
     tokio, Connector, Ingestor, SourceSchema, SourceSchemaResult, TableIdentifier, TableInfo,
 };
 use odbc::create_environment_v3;
 
 use crate::{
-    connection::client::Client, schema_helper::SchemaHelper, stream_consumer::StreamConsumer,
+    connection::client::{Client, ClientConfig},
+    schema_helper::SchemaHelper,
+    stream_consumer::StreamConsumer,
     SnowflakeError, SnowflakeStreamError,
 };
 
@@ -20,11 +23,21 @@ use crate::{
 pub struct SnowflakeConnector {
     name: String,
     config: SnowflakeConfig,
+    retry_policy: RetryPolicy,
 }
 
 impl SnowflakeConnector {
     pub fn new(name: String, config: SnowflakeConfig) -> Self {
-        Self { name, config }
+        Self {
+            name,
+            config,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
     }
 
     async fn get_schemas_async(
@@ -32,7 +45,11 @@ impl SnowflakeConnector {
         table_names: Option<Vec<String>>,
     ) -> Result<Vec<Result<(String, SourceSchema), SnowflakeError>>, SnowflakeError> {
         let config = self.config.clone();
-        spawn_blocking(move || SchemaHelper::get_schema(config, table_names.as_deref())).await
+        let retry_policy = self.retry_policy;
+        spawn_blocking(move || {
+            SchemaHelper::get_schema(config, table_names.as_deref(), retry_policy)
+        })
+        .await
     }
 }
 
@@ -93,6 +110,7 @@ impl Connector for SnowflakeConnector {
                 schema: None,
                 name,
                 column_names,
+                filter: None,
             });
         }
         Ok(result)
@@ -115,6 +133,10 @@ impl Connector for SnowflakeConnector {
             .collect())
     }
 
+    fn sends_op_identifiers(&self) -> bool {
+        true
+    }
+
     async fn serialize_state(&self) -> Result<Vec<u8>, BoxedError> {
         Ok(vec![])
     }
@@ -128,8 +150,18 @@ impl Connector for SnowflakeConnector {
         spawn_blocking({
             let name = self.name.clone();
             let config = self.config.clone();
+            let retry_policy = self.retry_policy;
             let ingestor = ingestor.clone();
-            move || run(name, config, tables, last_checkpoint, ingestor)
+            move || {
+                run(
+                    name,
+                    config,
+                    retry_policy,
+                    tables,
+                    last_checkpoint,
+                    ingestor,
+                )
+            }
         })
         .await
         .map_err(Into::into)
@@ -139,6 +171,7 @@ impl Connector for SnowflakeConnector {
 fn run(
     name: String,
     config: SnowflakeConfig,
+    retry_policy: RetryPolicy,
     tables: Vec<TableInfo>,
     last_checkpoint: Option<OpIdentifier>,
     ingestor: Ingestor,
@@ -148,7 +181,10 @@ fn run(
     let interval = config
         .poll_interval_seconds
         .unwrap_or_else(default_snowflake_poll_interval);
-    let stream_client = Client::new(config.into(), &env);
+    let stream_client = Client::new(
+        ClientConfig::from(config).with_retry_policy(retry_policy),
+        &env,
+    );
 
     let mut consumer = StreamConsumer::new();
     let mut iteration = 0;
@@ -1,11 +1,15 @@
 use dozer_ingestion_connector::{
     dozer_types::{models::ingestion_types::SnowflakeConfig, types::FieldType},
+    retry::RetryPolicy,
     SourceSchema,
 };
 use odbc::create_environment_v3;
 use std::collections::HashMap;
 
-use crate::{connection::client::Client, SnowflakeError, SnowflakeSchemaError};
+use crate::{
+    connection::client::{Client, ClientConfig},
+    SnowflakeError, SnowflakeSchemaError,
+};
 
 pub struct SchemaHelper {}
 
@@ -14,9 +18,13 @@ impl SchemaHelper {
     pub fn get_schema(
         config: SnowflakeConfig,
         table_names: Option<&[String]>,
+        retry_policy: RetryPolicy,
     ) -> Result<Vec<Result<(String, SourceSchema), SnowflakeError>>, SnowflakeError> {
         let env = create_environment_v3().map_err(|e| e.unwrap()).unwrap();
-        let client = Client::new(config.clone().into(), &env);
+        let client = Client::new(
+            ClientConfig::from(config.clone()).with_retry_policy(retry_policy),
+            &env,
+        );
 
         let keys = client.fetch_keys()?;
 
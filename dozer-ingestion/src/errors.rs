@@ -33,6 +33,21 @@ pub enum ConnectorError {
     #[error("javascript feature is not enabled")]
     JavascrtiptFeatureNotEnabled,
 
+    #[error("salesforce feature is not enabled")]
+    SalesforceFeatureNotEnabled,
+
+    #[error("sheets feature is not enabled")]
+    SheetsFeatureNotEnabled,
+
+    #[error("cassandra feature is not enabled")]
+    CassandraFeatureNotEnabled,
+
+    #[error("nats feature is not enabled")]
+    NatsFeatureNotEnabled,
+
+    #[error("rabbitmq feature is not enabled")]
+    RabbitMQFeatureNotEnabled,
+
     #[error("{0} is not supported as a source connector")]
     Unsupported(String),
 }
@@ -1,6 +1,8 @@
 use std::sync::Arc;
 
 use dozer_ingestion_aerospike::connector::AerospikeConnector;
+#[cfg(feature = "cassandra")]
+use dozer_ingestion_cassandra::CassandraConnector;
 #[cfg(feature = "ethereum")]
 use dozer_ingestion_connector::dozer_types::models::ingestion_types::EthProviderConfig;
 use dozer_ingestion_connector::dozer_types::{
@@ -13,6 +15,7 @@ use dozer_ingestion_connector::dozer_types::{
     node::NodeHandle,
     prettytable::Table,
 };
+use dozer_ingestion_connector::retry::RetryPolicy;
 #[cfg(feature = "datafusion")]
 use dozer_ingestion_deltalake::DeltaLakeConnector;
 #[cfg(feature = "ethereum")]
@@ -24,7 +27,9 @@ use dozer_ingestion_javascript::JavaScriptConnector;
 use dozer_ingestion_kafka::connector::KafkaConnector;
 #[cfg(feature = "mongodb")]
 use dozer_ingestion_mongodb::MongodbConnector;
-use dozer_ingestion_mysql::connector::{mysql_connection_opts_from_url, MySQLConnector};
+use dozer_ingestion_mysql::connector::{mysql_connection_opts, MySQLConnector};
+#[cfg(feature = "nats")]
+use dozer_ingestion_nats::NatsConnector;
 #[cfg(feature = "datafusion")]
 use dozer_ingestion_object_store::connector::ObjectStoreConnector;
 use dozer_ingestion_oracle::OracleConnector;
@@ -32,6 +37,12 @@ use dozer_ingestion_postgres::{
     connection::helper::map_connection_config,
     connector::{PostgresConfig, PostgresConnector},
 };
+#[cfg(feature = "rabbitmq")]
+use dozer_ingestion_rabbitmq::RabbitMQConnector;
+#[cfg(feature = "salesforce")]
+use dozer_ingestion_salesforce::SalesforceConnector;
+#[cfg(feature = "sheets")]
+use dozer_ingestion_sheets::{AirtableConnector, GoogleSheetsConnector};
 #[cfg(feature = "snowflake")]
 use dozer_ingestion_snowflake::connector::SnowflakeConnector;
 use dozer_ingestion_webhook::connector::WebhookConnector;
@@ -42,6 +53,9 @@ pub mod errors;
 pub use dozer_ingestion_connector::*;
 
 const DEFAULT_POSTGRES_SNAPSHOT_BATCH_SIZE: u32 = 100_000;
+/// Each snapshot connection is a backend process on the server, and Postgres allows 100 connections by default.
+/// Eight keeps a snapshot well under that while still reading several tables at once.
+const DEFAULT_POSTGRES_SNAPSHOT_CONNECTIONS: u32 = 8;
 
 #[allow(unused_variables)]
 pub fn get_connector(
@@ -51,14 +65,19 @@ pub fn get_connector(
     state: Option<Vec<u8>>,
 ) -> Result<Box<dyn Connector>, ConnectorError> {
     let config = connection.config;
+    let retry_policy = RetryPolicy::of_connection(connection.retry.as_ref());
     match config.clone() {
         ConnectionConfig::Postgres(c) => {
-            let config = map_connection_config(&config)?;
+            let config = map_connection_config(&config)?.with_retry_policy(retry_policy);
             let postgres_config = PostgresConfig {
                 name: connection.name,
                 config,
                 schema: c.schema,
                 batch_size: c.batch_size.unwrap_or(DEFAULT_POSTGRES_SNAPSHOT_BATCH_SIZE) as usize,
+                snapshot_connections: c
+                    .snapshot_connections
+                    .unwrap_or(DEFAULT_POSTGRES_SNAPSHOT_CONNECTIONS)
+                    as usize,
             };
 
             if let Some(dbname) = postgres_config.config.get_dbname() {
@@ -103,15 +122,17 @@ pub fn get_connector(
         ConnectionConfig::Snowflake(snowflake) => {
             let snowflake_config = snowflake;
 
-            Ok(Box::new(SnowflakeConnector::new(
-                connection.name,
-                snowflake_config,
-            )))
+            Ok(Box::new(
+                SnowflakeConnector::new(connection.name, snowflake_config)
+                    .with_retry_policy(retry_policy),
+            ))
         }
         #[cfg(not(feature = "snowflake"))]
         ConnectionConfig::Snowflake(_) => Err(ConnectorError::SnowflakeFeatureNotEnabled),
         #[cfg(feature = "kafka")]
-        ConnectionConfig::Kafka(kafka_config) => Ok(Box::new(KafkaConnector::new(kafka_config))),
+        ConnectionConfig::Kafka(kafka_config) => Ok(Box::new(
+            KafkaConnector::new(kafka_config).with_retry_policy(retry_policy),
+        )),
         #[cfg(not(feature = "kafka"))]
         ConnectionConfig::Kafka(_) => Err(ConnectorError::KafkaFeatureNotEnabled),
         #[cfg(feature = "datafusion")]
@@ -140,12 +161,12 @@ pub fn get_connector(
         #[cfg(not(feature = "mongodb"))]
         ConnectionConfig::MongoDB(_) => Err(ConnectorError::MongodbFeatureNotEnabled),
         ConnectionConfig::MySQL(mysql_config) => {
-            let opts = mysql_connection_opts_from_url(&mysql_config.url)?;
-            Ok(Box::new(MySQLConnector::new(
-                mysql_config.url,
-                opts,
-                mysql_config.server_id,
-            )))
+            let (opts, ssh_tunnel) = mysql_connection_opts(&mysql_config)?;
+            Ok(Box::new(
+                MySQLConnector::new(mysql_config.url, opts, mysql_config.server_id)
+                    .with_ssh_tunnel(ssh_tunnel)
+                    .with_retry_policy(retry_policy),
+            ))
         }
         ConnectionConfig::Webhook(webhook_config) => {
             Ok(Box::new(WebhookConnector::new(webhook_config)))
@@ -157,15 +178,57 @@ pub fn get_connector(
             runtime,
             javascript_config,
         ))),
-        ConnectionConfig::Aerospike(config) => Ok(Box::new(AerospikeConnector::new(
+        ConnectionConfig::Aerospike(config) => Ok(Box::new(
+            AerospikeConnector::new(
+                config,
+                NodeHandle::new(None, connection.name),
+                event_hub.receiver,
+            )
+            .with_retry_policy(retry_policy),
+        )),
+        ConnectionConfig::Oracle(oracle_config) => Ok(Box::new(OracleConnector::new(
+            connection.name,
+            oracle_config,
+        ))),
+        #[cfg(feature = "salesforce")]
+        ConnectionConfig::Salesforce(salesforce_config) => Ok(Box::new(SalesforceConnector::new(
+            connection.name,
+            salesforce_config,
+        ))),
+        #[cfg(not(feature = "salesforce"))]
+        ConnectionConfig::Salesforce(_) => Err(ConnectorError::SalesforceFeatureNotEnabled),
+        #[cfg(feature = "sheets")]
+        ConnectionConfig::GoogleSheets(config) => Ok(Box::new(GoogleSheetsConnector::new(config))),
+        #[cfg(not(feature = "sheets"))]
+        ConnectionConfig::GoogleSheets(_) => Err(ConnectorError::SheetsFeatureNotEnabled),
+        #[cfg(feature = "sheets")]
+        ConnectionConfig::Airtable(config) => Ok(Box::new(AirtableConnector::new(config))),
+        #[cfg(not(feature = "sheets"))]
+        ConnectionConfig::Airtable(_) => Err(ConnectorError::SheetsFeatureNotEnabled),
+        #[cfg(feature = "cassandra")]
+        ConnectionConfig::Cassandra(config) => {
+            Ok(Box::new(CassandraConnector::new(connection.name, config)))
+        }
+        #[cfg(not(feature = "cassandra"))]
+        ConnectionConfig::Cassandra(_) => Err(ConnectorError::CassandraFeatureNotEnabled),
+        #[cfg(feature = "nats")]
+        ConnectionConfig::Nats(config) => Ok(Box::new(NatsConnector::new(
+            connection.name.clone(),
             config,
             NodeHandle::new(None, connection.name),
             event_hub.receiver,
         ))),
-        ConnectionConfig::Oracle(oracle_config) => Ok(Box::new(OracleConnector::new(
-            connection.name,
-            oracle_config,
+        #[cfg(not(feature = "nats"))]
+        ConnectionConfig::Nats(_) => Err(ConnectorError::NatsFeatureNotEnabled),
+        #[cfg(feature = "rabbitmq")]
+        ConnectionConfig::RabbitMQ(config) => Ok(Box::new(RabbitMQConnector::new(
+            connection.name.clone(),
+            config,
+            NodeHandle::new(None, connection.name),
+            event_hub.receiver,
         ))),
+        #[cfg(not(feature = "rabbitmq"))]
+        ConnectionConfig::RabbitMQ(_) => Err(ConnectorError::RabbitMQFeatureNotEnabled),
     }
 }
 
@@ -180,6 +243,12 @@ pub fn get_connector_info_table(connection: &Connection) -> Option<Table> {
         ConnectionConfig::Kafka(config) => Some(config.convert_to_table()),
         ConnectionConfig::S3Storage(config) => Some(config.convert_to_table()),
         ConnectionConfig::LocalStorage(config) => Some(config.convert_to_table()),
+        ConnectionConfig::Salesforce(config) => Some(config.convert_to_table()),
+        ConnectionConfig::GoogleSheets(config) => Some(config.convert_to_table()),
+        ConnectionConfig::Airtable(config) => Some(config.convert_to_table()),
+        ConnectionConfig::Cassandra(config) => Some(config.convert_to_table()),
+        ConnectionConfig::Nats(config) => Some(config.convert_to_table()),
+        ConnectionConfig::RabbitMQ(config) => Some(config.convert_to_table()),
         _ => None,
     }
 }
@@ -217,6 +217,7 @@ pub async fn run_test_suite_basic_cud<T: CudConnectorTest>(runtime: Arc<Runtime>
             .into_iter()
             .map(|field| field.name)
             .collect(),
+        filter: None,
     }];
     let mut schemas = connector.get_schemas(&tables).await.unwrap();
     let actual_schema = schemas.remove(0).unwrap().schema;
@@ -94,6 +94,7 @@ fn create_connector(
                 path: table_name.to_string(),
                 extension: ".parquet".to_string(),
                 marker_extension: None,
+                watch_interval_ms: None,
             }),
             name: table_name,
         }],
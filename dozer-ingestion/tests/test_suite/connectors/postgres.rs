@@ -87,7 +87,7 @@ impl InsertOnlyConnectorTest for PostgresConnectorTest {
 #[async_trait]
 impl CudConnectorTest for PostgresConnectorTest {
     async fn start_cud(&self, operations: Vec<Operation>) {
-        let mut client = connect(self.config.clone()).await.unwrap();
+        let mut client = connect(self.config.clone().into()).await.unwrap();
         let schema_name = self.schema_name.clone();
         let table_name = self.table_name.clone();
         let schema = self.schema.clone();
@@ -134,15 +134,16 @@ async fn create_postgres_server() -> (Client, PostgresConnectorTest, PostgresCon
     let connector = PostgresConnector::new(
         PostgresConfig {
             name: "postgres_connector_test".to_string(),
-            config: config.clone(),
+            config: config.clone().into(),
             schema: None,
             batch_size: 1000,
+            snapshot_connections: 8,
         },
         None,
     )
     .unwrap();
 
-    let client = connect(config.clone()).await.unwrap();
+    let client = connect(config.clone().into()).await.unwrap();
 
     (
         client,
@@ -1,4 +1,4 @@
-use crate::{server::WebhookServer, util::extract_source_schema, Error};
+use crate::{server::WebhookServer, util::endpoint_source_schemas, Error};
 use dozer_ingestion_connector::{
     async_trait,
     dozer_types::{
@@ -24,7 +24,7 @@ impl WebhookConnector {
         let mut result: HashMap<String, SourceSchema> = HashMap::new();
         let config = &self.config;
         for endpoint in &config.endpoints {
-            let schemas = extract_source_schema(endpoint.schema.clone());
+            let schemas = endpoint_source_schemas(endpoint)?;
             for (key, value) in schemas {
                 result.insert(key, value);
             }
@@ -98,6 +98,7 @@ impl Connector for WebhookConnector {
                         schema: table.schema,
                         name: table.name,
                         column_names,
+                        filter: None,
                     })
                 }
                 None => {
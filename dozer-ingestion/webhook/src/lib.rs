@@ -1,6 +1,7 @@
 use std::{net::AddrParseError, path::PathBuf};
 
 use dozer_ingestion_connector::dozer_types::{
+    json_record::JsonRecordError,
     serde_json,
     thiserror::{self, Error},
 };
@@ -20,8 +21,16 @@ pub enum Error {
     AddrParse(#[from] AddrParseError),
     #[error("default adapter cannot handle arrow ingest message")]
     SchemaNotFound(String),
-    #[error("field {0} not found in schema")]
-    FieldNotFound(String),
+    #[error("invalid record: {0}")]
+    Record(#[from] JsonRecordError),
+    #[error("missing signature header {0}")]
+    MissingSignature(String),
+    #[error("invalid signature")]
+    InvalidSignature,
+    #[error("signature timestamp {0} is too far from now")]
+    StaleSignature(u64),
+    #[error("overflow field {field} is declared in the schema of table {table}")]
+    OverflowFieldDeclared { field: String, table: String },
     #[error("actix web start error: {0}")]
     ActixWebStartError(#[from] std::io::Error),
 }
@@ -1,5 +1,5 @@
 use crate::{
-    util::{extract_source_schema, map_record},
+    util::{endpoint_source_schemas, map_record, verify_signature},
     Error,
 };
 use actix_web::{
@@ -8,13 +8,19 @@ use actix_web::{
 };
 use dozer_ingestion_connector::{
     dozer_types::{
-        models::ingestion_types::{IngestionMessage, WebhookConfig, WebhookVerb},
+        models::ingestion_types::{IngestionMessage, WebhookConfig, WebhookSignature, WebhookVerb},
         serde_json,
         types::{Operation, Record},
     },
     Ingestor, SourceSchema, TableInfo,
 };
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc, time::SystemTime};
+
+/// The options of an endpoint, as app data of its resource.
+struct EndpointOptions {
+    overflow_field: Option<String>,
+    signature: Option<WebhookSignature>,
+}
 
 pub(crate) struct WebhookServer {
     config: WebhookConfig,
@@ -31,17 +37,26 @@ impl WebhookServer {
         tables: Vec<TableInfo>,
     ) -> Result<(), Error> {
         let config = self.config.clone();
+        let endpoint_schemas = config
+            .endpoints
+            .iter()
+            .map(endpoint_source_schemas)
+            .collect::<Result<Vec<_>, _>>()?;
         // Clone or extract necessary data from `self`
         let server = HttpServer::new(move || {
             let mut app = App::new();
 
-            for endpoint in config.endpoints.iter() {
+            for (endpoint, source_schema_dict) in config.endpoints.iter().zip(&endpoint_schemas) {
                 let endpoint_data = endpoint.clone();
-                let source_schema_dict = extract_source_schema(endpoint_data.to_owned().schema);
+                let source_schema_dict = source_schema_dict.clone();
                 let tables = tables.clone();
                 let mut app_resource = web::resource(endpoint_data.path)
                     .app_data(web::Data::new(Arc::clone(&ingestor)))
                     .app_data(web::Data::new(source_schema_dict))
+                    .app_data(web::Data::new(EndpointOptions {
+                        overflow_field: endpoint_data.overflow_field,
+                        signature: endpoint_data.signature,
+                    }))
                     .app_data(web::Data::new(tables));
                 for verb in &endpoint.verbs {
                     app_resource = match verb {
@@ -70,10 +85,22 @@ impl WebhookServer {
     fn common_handler(
         tables: Data<Vec<TableInfo>>,
         schema_dict: Data<HashMap<String, SourceSchema>>,
-        info: web::Json<serde_json::Value>,
+        options: Data<EndpointOptions>,
+        req: HttpRequest,
+        body: web::Bytes,
     ) -> actix_web::Result<Vec<(usize, Vec<Record>)>, actix_web::error::Error> {
         let source_schema_dict = schema_dict.get_ref();
-        let info = &info.into_inner();
+        let overflow_field = options.overflow_field.as_deref();
+        if let Some(signature) = &options.signature {
+            let header_value = req
+                .headers()
+                .get(signature.header.as_str())
+                .map(|value| value.as_bytes());
+            verify_signature(signature, header_value, &body, SystemTime::now())
+                .map_err(actix_web::error::ErrorUnauthorized)?;
+        }
+        let info = &serde_json::from_slice::<serde_json::Value>(&body)
+            .map_err(actix_web::error::ErrorBadRequest)?;
 
         let mut result: Vec<(usize, Vec<Record>)> = vec![];
         if let serde_json::Value::Object(object) = info {
@@ -89,7 +116,7 @@ impl WebhookServer {
                             let value = value_element.as_object().ok_or_else(|| {
                                 actix_web::error::ErrorBadRequest("Invalid value")
                             })?;
-                            map_record(value.to_owned(), &schema.schema)
+                            map_record(value.to_owned(), &schema.schema, overflow_field)
                                 .map_err(actix_web::error::ErrorBadRequest)
                         })
                         .collect::<Result<Vec<Record>, _>>(),
@@ -97,7 +124,7 @@ impl WebhookServer {
                         let value = values
                             .as_object()
                             .ok_or_else(|| actix_web::error::ErrorBadRequest("Invalid value"))?;
-                        map_record(value.to_owned(), &schema.schema)
+                        map_record(value.to_owned(), &schema.schema, overflow_field)
                             .map(|e| vec![e])
                             .map_err(|e| actix_web::error::ErrorBadRequest(e.to_string()))
                     }
@@ -117,11 +144,13 @@ impl WebhookServer {
     async fn post_handler(
         ingestor: Data<Arc<Ingestor>>,
         schema_dict: Data<HashMap<String, SourceSchema>>,
+        options: Data<EndpointOptions>,
         tables: Data<Vec<TableInfo>>,
-        info: web::Json<serde_json::Value>,
+        req: HttpRequest,
+        body: web::Bytes,
     ) -> actix_web::Result<impl Responder> {
         let ingestor = ingestor.get_ref();
-        let records = Self::common_handler(tables, schema_dict, info)?;
+        let records = Self::common_handler(tables, schema_dict, options, req, body)?;
 
         for (table_idx, records) in records {
             let op: IngestionMessage = if records.len() == 1 {
@@ -155,11 +184,13 @@ impl WebhookServer {
     async fn delete_handler(
         ingestor: Data<Arc<Ingestor>>,
         schema_dict: Data<HashMap<String, SourceSchema>>,
+        options: Data<EndpointOptions>,
         tables: Data<Vec<TableInfo>>,
-        info: web::Json<serde_json::Value>,
+        req: HttpRequest,
+        body: web::Bytes,
     ) -> actix_web::Result<impl Responder> {
         let ingestor = ingestor.get_ref();
-        let records = Self::common_handler(tables, schema_dict, info)?;
+        let records = Self::common_handler(tables, schema_dict, options, req, body)?;
         for (table_idx, records) in records {
             for record in records {
                 let op: IngestionMessage = IngestionMessage::OperationEvent {
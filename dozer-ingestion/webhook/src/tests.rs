@@ -1,18 +1,26 @@
-use crate::connector::WebhookConnector;
+use crate::{
+    connector::WebhookConnector,
+    util::{endpoint_source_schemas, map_record, verify_signature},
+    Error,
+};
 use dozer_ingestion_connector::{
     dozer_types::{
         json_types::json_from_str,
         models::ingestion_types::{
-            IngestionMessage, WebhookConfig, WebhookConfigSchemas, WebhookEndpoint, WebhookVerb,
+            IngestionMessage, WebhookConfig, WebhookConfigSchemas, WebhookEndpoint,
+            WebhookSignature, WebhookSignatureScheme, WebhookVerb,
         },
         serde_json::{self, json},
-        types::{Field, Record},
+        types::{Field, FieldDefinition, FieldType, Record, Schema, SourceDefinition},
     },
     test_util::{create_test_runtime, spawn_connector_all_tables},
     tokio::runtime::Runtime,
     IngestionIterator,
 };
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 fn ingest_webhook(
     runtime: Arc<Runtime>,
@@ -79,11 +87,15 @@ fn ingest_webhook(
                 path: "/customers".to_string(),
                 verbs: vec![WebhookVerb::POST, WebhookVerb::DELETE],
                 schema: WebhookConfigSchemas::Inline(customer_schema.to_string()),
+                overflow_field: None,
+                signature: None,
             },
             WebhookEndpoint {
                 path: "/users".to_string(),
                 verbs: vec![WebhookVerb::POST, WebhookVerb::DELETE],
                 schema: WebhookConfigSchemas::Inline(user_schema.to_string()),
+                overflow_field: None,
+                signature: None,
             },
         ],
     });
@@ -220,3 +232,106 @@ fn ingest_webhook_delete() {
         panic!("Expected operation event");
     }
 }
+
+#[test]
+fn map_record_overflow_field() {
+    let mut schema = Schema::new();
+    for (name, typ) in [("id", FieldType::Int), ("extra", FieldType::Json)] {
+        schema.field(
+            FieldDefinition::new(name.to_string(), typ, true, SourceDefinition::Dynamic),
+            false,
+        );
+    }
+    let payload = json!({"id": 1, "name": "John", "age": 30});
+    let serde_json::Value::Object(payload) = payload else {
+        unreachable!()
+    };
+
+    let record = map_record(payload.clone(), &schema, Some("extra")).unwrap();
+    assert_eq!(
+        record.values,
+        vec![
+            Field::Int(1),
+            Field::Json(json_from_str(r#"{"name": "John", "age": 30}"#).unwrap()),
+        ]
+    );
+
+    let mut declared = payload;
+    declared.retain(|name, _| name == "id");
+    let record = map_record(declared, &schema, Some("extra")).unwrap();
+    assert_eq!(record.values, vec![Field::Int(1), Field::Null]);
+}
+
+#[test]
+fn overflow_field_must_not_be_declared() {
+    let schema =
+        r#"{"users": {"schema": {"fields": [{"name": "id", "typ": "Int", "nullable": false}]}}}"#;
+    let endpoint = |overflow_field: &str| WebhookEndpoint {
+        path: "/users".to_string(),
+        verbs: vec![WebhookVerb::POST],
+        schema: WebhookConfigSchemas::Inline(schema.to_string()),
+        overflow_field: Some(overflow_field.to_string()),
+        signature: None,
+    };
+
+    let schemas = endpoint_source_schemas(&endpoint("extra")).unwrap();
+    assert_eq!(schemas["users"].schema.fields[1].name, "extra");
+    assert!(matches!(
+        endpoint_source_schemas(&endpoint("id")),
+        Err(Error::OverflowFieldDeclared { field, table }) if field == "id" && table == "users"
+    ));
+}
+
+#[test]
+fn verify_webhook_signature() {
+    let signature = WebhookSignature {
+        header: "X-Hub-Signature-256".to_string(),
+        secret: "key".to_string(),
+        scheme: WebhookSignatureScheme::Hex,
+        tolerance_secs: None,
+    };
+    let body = b"The quick brown fox jumps over the lazy dog";
+    let valid = "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8";
+    let verify = |header_value: Option<&[u8]>, body: &[u8]| {
+        verify_signature(&signature, header_value, body, SystemTime::now())
+    };
+
+    assert!(verify(Some(valid.as_bytes()), body).is_ok());
+    let prefixed = format!("sha256={valid}");
+    assert!(verify(Some(prefixed.as_bytes()), body).is_ok());
+
+    assert!(verify(Some(valid.as_bytes()), b"tampered").is_err());
+    assert!(verify(Some(b"not hex"), body).is_err());
+    assert!(verify(None, body).is_err());
+    assert!(!format!("{signature:?}").contains("key"));
+}
+
+#[test]
+fn verify_timestamped_webhook_signature() {
+    let signature = WebhookSignature {
+        header: "Stripe-Signature".to_string(),
+        secret: "key".to_string(),
+        scheme: WebhookSignatureScheme::Timestamped,
+        tolerance_secs: None,
+    };
+    let body = b"The quick brown fox jumps over the lazy dog";
+    let valid = "2f658d6aef4f246e91cd741bbcded7479e9605f9d41c9e248122a117e0e1765b";
+    let signed_at = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let verify = |header_value: &str, body: &[u8], now: SystemTime| {
+        verify_signature(&signature, Some(header_value.as_bytes()), body, now)
+    };
+
+    let header_value = format!("t=1700000000,v1={valid}");
+    assert!(verify(&header_value, body, signed_at).is_ok());
+    let rolled = format!("t=1700000000,v1={},v1={valid},v0=ignored", "00".repeat(32));
+    assert!(verify(&rolled, body, signed_at + Duration::from_secs(60)).is_ok());
+
+    assert!(verify(&header_value, b"tampered", signed_at).is_err());
+    // The timestamp is signed too.
+    assert!(verify(&format!("t=1700000001,v1={valid}"), body, signed_at).is_err());
+    assert!(verify(&format!("v1={valid}"), body, signed_at).is_err());
+    assert!(matches!(
+        verify(&header_value, body, signed_at + Duration::from_secs(301)),
+        Err(Error::StaleSignature(1_700_000_000))
+    ));
+}
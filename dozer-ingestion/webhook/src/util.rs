@@ -1,17 +1,24 @@
 use crate::Error;
 use dozer_ingestion_connector::{
     dozer_types::{
-        chrono::{self, NaiveDate},
-        json_types::json_from_str,
-        models::ingestion_types::WebhookConfigSchemas,
-        ordered_float::OrderedFloat,
-        rust_decimal::Decimal,
+        json_record::{json_object_to_record, JsonCoercionPolicy},
+        models::ingestion_types::{
+            default_webhook_signature_tolerance_secs, WebhookConfigSchemas, WebhookEndpoint,
+            WebhookSignature, WebhookSignatureScheme,
+        },
         serde_json,
-        types::{Field, FieldType, Record, Schema},
+        types::{FieldDefinition, FieldType, Record, Schema, SourceDefinition},
     },
     SourceSchema,
 };
-use std::{collections::HashMap, fs, path::Path};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::{
+    collections::HashMap,
+    fs,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 pub fn extract_source_schema(input: WebhookConfigSchemas) -> HashMap<String, SourceSchema> {
     match input {
@@ -28,114 +35,134 @@ pub fn extract_source_schema(input: WebhookConfigSchemas) -> HashMap<String, Sou
     }
 }
 
-pub fn map_record(
-    rec: serde_json::map::Map<String, serde_json::Value>,
-    schema: &Schema,
-) -> Result<Record, Error> {
-    let mut values: Vec<Field> = vec![];
-    let fields = schema.fields.clone();
-    for field in fields.into_iter() {
-        let field_name = field.name.clone();
-        let field_value = rec.get(&field_name);
-        if !field.nullable && field_value.is_none() {
-            return Err(Error::FieldNotFound(field_name));
+/// The schemas of `endpoint`, with its overflow field if it has one.
+pub fn endpoint_source_schemas(
+    endpoint: &WebhookEndpoint,
+) -> Result<HashMap<String, SourceSchema>, Error> {
+    let mut schemas = extract_source_schema(endpoint.schema.clone());
+    if let Some(overflow_field) = &endpoint.overflow_field {
+        for (table, source_schema) in schemas.iter_mut() {
+            if source_schema
+                .schema
+                .fields
+                .iter()
+                .any(|field| field.name == *overflow_field)
+            {
+                return Err(Error::OverflowFieldDeclared {
+                    field: overflow_field.clone(),
+                    table: table.clone(),
+                });
+            }
+            source_schema.schema.field(
+                FieldDefinition::new(
+                    overflow_field.clone(),
+                    FieldType::Json,
+                    true,
+                    SourceDefinition::Dynamic,
+                ),
+                false,
+            );
         }
-        match field_value {
-            Some(value) => match field.typ {
-                FieldType::String => {
-                    let str_value: String = serde_json::from_value(value.clone())?;
-                    let field = Field::String(str_value);
-                    values.push(field);
-                }
-                FieldType::Int => {
-                    let i64_value: i64 = serde_json::from_value(value.clone())?;
-                    let field = Field::Int(i64_value);
-                    values.push(field);
-                }
-                FieldType::Float => {
-                    let float_value: f64 = serde_json::from_value(value.clone())?;
-                    let field = Field::Float(OrderedFloat(float_value));
-                    values.push(field);
-                }
-                FieldType::Boolean => {
-                    let bool_value: bool = serde_json::from_value(value.clone())?;
-                    let field = Field::Boolean(bool_value);
-                    values.push(field);
-                }
-                FieldType::Timestamp => {
-                    let i64_value: i64 = serde_json::from_value(value.clone())?;
-                    let timestamp_value = chrono::NaiveDateTime::from_timestamp_millis(i64_value)
-                        .map(|t| {
-                            Field::Timestamp(
-                                chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(
-                                    t,
-                                    chrono::Utc,
-                                )
-                                .into(),
-                            )
-                        })
-                        .unwrap_or(Field::Null);
-                    values.push(timestamp_value);
-                }
-                FieldType::Date => {
-                    let i64_value: NaiveDate = serde_json::from_value(value.clone())?;
-                    let field = Field::Date(i64_value);
-                    values.push(field);
-                }
-                FieldType::UInt => {
-                    let u64_value: u64 = serde_json::from_value(value.clone())?;
-                    let field = Field::UInt(u64_value);
-                    values.push(field);
-                }
-                FieldType::U128 => {
-                    let u128_value: u128 = serde_json::from_value(value.clone())?;
-                    let field = Field::U128(u128_value);
-                    values.push(field);
-                }
-                FieldType::I128 => {
-                    let i128_value: i128 = serde_json::from_value(value.clone())?;
-                    let field = Field::I128(i128_value);
-                    values.push(field);
-                }
-                FieldType::Text => {
-                    let str_value: String = serde_json::from_value(value.clone())?;
-                    let field = Field::Text(str_value);
-                    values.push(field);
-                }
-                FieldType::Binary => {
-                    let str_value: String = serde_json::from_value(value.clone())?;
-                    let field = Field::Binary(str_value.into_bytes());
-                    values.push(field);
-                }
-                FieldType::Decimal => {
-                    let str_value: String = serde_json::from_value(value.clone())?;
-                    let decimal_value: Decimal =
-                        Decimal::from_str_exact(str_value.as_str()).unwrap();
-                    let field = Field::Decimal(decimal_value);
-                    values.push(field);
-                }
-                FieldType::Json => {
-                    let str_value: String = serde_json::to_string(value)?;
-                    let ivalue_str = json_from_str(str_value.as_str()).unwrap();
-                    let field = Field::Json(ivalue_str);
-                    values.push(field);
-                }
-                FieldType::Point => {
-                    values.push(Field::Null);
-                }
-                FieldType::Duration => {
-                    values.push(Field::Null);
+    }
+    Ok(schemas)
+}
+
+/// Checks that `header_value` is the signature of `body`, received at `now`.
+pub fn verify_signature(
+    signature: &WebhookSignature,
+    header_value: Option<&[u8]>,
+    body: &[u8],
+    now: SystemTime,
+) -> Result<(), Error> {
+    let header_value =
+        header_value.ok_or_else(|| Error::MissingSignature(signature.header.clone()))?;
+    match signature.scheme {
+        WebhookSignatureScheme::Hex => {
+            let hex_signature = header_value
+                .strip_prefix(b"sha256=")
+                .unwrap_or(header_value);
+            verify_hmac(&signature.secret, &[body], hex_signature)
+        }
+        WebhookSignatureScheme::Timestamped => {
+            let header_value =
+                std::str::from_utf8(header_value).map_err(|_| Error::InvalidSignature)?;
+            let mut timestamp = None;
+            let mut hex_signatures = vec![];
+            for (key, value) in header_value
+                .split(',')
+                .filter_map(|element| element.trim().split_once('='))
+            {
+                match key {
+                    "t" => timestamp = Some(value),
+                    // There is one signature per secret while the sender rolls its secret.
+                    "v1" => hex_signatures.push(value),
+                    _ => (),
                 }
-            },
-            None => {
-                let field = Field::Null;
-                values.push(field);
+            }
+            let timestamp = timestamp.ok_or(Error::InvalidSignature)?;
+            let seconds = timestamp
+                .parse::<u64>()
+                .map_err(|_| Error::InvalidSignature)?;
+            let now = now
+                .duration_since(UNIX_EPOCH)
+                .expect("now is after the epoch")
+                .as_secs();
+            let tolerance_secs = signature
+                .tolerance_secs
+                .unwrap_or_else(default_webhook_signature_tolerance_secs);
+            if now.abs_diff(seconds) > tolerance_secs {
+                return Err(Error::StaleSignature(seconds));
+            }
+
+            let signed = [timestamp.as_bytes(), b".", body];
+            if hex_signatures.iter().any(|hex_signature| {
+                verify_hmac(&signature.secret, &signed, hex_signature.as_bytes()).is_ok()
+            }) {
+                Ok(())
+            } else {
+                Err(Error::InvalidSignature)
             }
         }
     }
+}
+
+fn verify_hmac(secret: &str, message: &[&[u8]], hex_signature: &[u8]) -> Result<(), Error> {
+    let expected = hex::decode(hex_signature).map_err(|_| Error::InvalidSignature)?;
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take a key of any size");
+    for part in message {
+        mac.update(part);
+    }
+    mac.verify_slice(&expected)
+        .map_err(|_| Error::InvalidSignature)
+}
 
-    Ok(Record {
-        values,
-        lifetime: None,
-    })
+/// Maps the payload `rec` to a record of `schema`. If `overflow_field` is set, the payload fields not in `schema` are
+/// collected in it as a JSON object.
+///
+/// Values are read in their canonical JSON form, see [`json_object_to_record`].
+pub fn map_record(
+    mut rec: serde_json::Map<String, serde_json::Value>,
+    schema: &Schema,
+    overflow_field: Option<&str>,
+) -> Result<Record, Error> {
+    if let Some(overflow_field) = overflow_field {
+        rec.remove(overflow_field);
+        let extra = rec
+            .iter()
+            .filter(|(name, _)| !schema.fields.iter().any(|field| field.name == **name))
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect::<serde_json::Map<_, _>>();
+        let overflow = if extra.is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::Value::Object(extra)
+        };
+        rec.insert(overflow_field.to_string(), overflow);
+    }
+    Ok(json_object_to_record(
+        rec,
+        schema,
+        JsonCoercionPolicy::default(),
+    )?)
 }
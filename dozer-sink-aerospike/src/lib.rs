@@ -583,6 +583,7 @@ mod tests {
             typ,
             nullable: false,
             source: dozer_types::types::SourceDefinition::Dynamic,
+            annotations: Default::default(),
         }
     }
 
@@ -648,6 +649,7 @@ mod tests {
                     typ: FieldType::UInt,
                     nullable: true,
                     source: dozer_types::types::SourceDefinition::Dynamic,
+                    annotations: Default::default(),
                 },
                 false,
             )
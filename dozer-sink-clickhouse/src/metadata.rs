@@ -29,6 +29,7 @@ impl ReplicationMetadata {
                         typ: FieldType::String,
                         nullable: false,
                         source: SourceDefinition::Dynamic,
+                        annotations: Default::default(),
                     },
                     true,
                 )
@@ -38,6 +39,7 @@ impl ReplicationMetadata {
                         typ: FieldType::UInt,
                         nullable: false,
                         source: SourceDefinition::Dynamic,
+                        annotations: Default::default(),
                     },
                     false,
                 )
@@ -31,12 +31,14 @@ fn _get_dozer_schema() -> Schema {
                 typ: FieldType::UInt,
                 nullable: false,
                 source: Default::default(),
+                annotations: Default::default(),
             },
             FieldDefinition {
                 name: "data".to_string(),
                 typ: FieldType::String,
                 nullable: false,
                 source: Default::default(),
+                annotations: Default::default(),
             },
         ],
         primary_index: vec![0],
@@ -464,6 +464,7 @@ impl SinkFactory for OracleSinkFactory {
                 typ: FieldType::UInt,
                 nullable: true,
                 source: dozer_types::types::SourceDefinition::Dynamic,
+                annotations: Default::default(),
             },
             false,
         );
@@ -473,6 +474,7 @@ impl SinkFactory for OracleSinkFactory {
                 typ: FieldType::UInt,
                 nullable: true,
                 source: dozer_types::types::SourceDefinition::Dynamic,
+                annotations: Default::default(),
             },
             false,
         );
@@ -493,6 +495,7 @@ impl SinkFactory for OracleSinkFactory {
                         typ: FieldType::String,
                         nullable: false,
                         source: SourceDefinition::Dynamic,
+                        annotations: Default::default(),
                     },
                     true,
                 )
@@ -502,6 +505,7 @@ impl SinkFactory for OracleSinkFactory {
                         typ: FieldType::UInt,
                         nullable: false,
                         source: SourceDefinition::Dynamic,
+                        annotations: Default::default(),
                     },
                     false,
                 ),
@@ -813,6 +817,7 @@ mod tests {
             typ: FieldType::String,
             nullable: false,
             source: SourceDefinition::Dynamic,
+            annotations: Default::default(),
         }
     }
 }
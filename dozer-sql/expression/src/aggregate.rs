@@ -1,5 +1,8 @@
 use std::fmt::{Display, Formatter};
 
+/// The steps matched by an event are kept as the bits of a `u64`.
+pub const WINDOW_FUNNEL_MAX_STEPS: usize = u64::BITS as usize;
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash, bincode::Encode, bincode::Decode)]
 pub enum AggregateFunctionType {
     Avg,
@@ -11,6 +14,7 @@ pub enum AggregateFunctionType {
     MinAppendOnly,
     MinValue,
     Sum,
+    WindowFunnel,
 }
 
 impl AggregateFunctionType {
@@ -25,6 +29,7 @@ impl AggregateFunctionType {
             "min_append_only" => Some(AggregateFunctionType::MinAppendOnly),
             "min_value" => Some(AggregateFunctionType::MinValue),
             "sum" => Some(AggregateFunctionType::Sum),
+            "window_funnel" => Some(AggregateFunctionType::WindowFunnel),
             _ => None,
         }
     }
@@ -42,6 +47,7 @@ impl Display for AggregateFunctionType {
             AggregateFunctionType::MinAppendOnly => f.write_str("MIN_APPEND_ONLY"),
             AggregateFunctionType::MinValue => f.write_str("MIN_VALUE"),
             AggregateFunctionType::Sum => f.write_str("SUM"),
+            AggregateFunctionType::WindowFunnel => f.write_str("WINDOW_FUNNEL"),
         }
     }
 }
@@ -4,6 +4,7 @@ use crate::aggregate::AggregateFunctionType;
 use crate::conditional::ConditionalExpressionType;
 use crate::datetime::DateTimeFunctionType;
 use crate::error::Error;
+use dozer_types::models::flags::{DecimalRounding, NumericOverflow};
 use dozer_types::models::udf_config::{UdfConfig, UdfType};
 use dozer_types::types::FieldType;
 use dozer_types::{
@@ -34,6 +35,8 @@ pub struct ExpressionBuilder {
     pub aggregations: Vec<Expression>,
     pub offset: usize,
     runtime: Arc<Runtime>,
+    overflow: NumericOverflow,
+    rounding: Option<DecimalRounding>,
 }
 
 impl ExpressionBuilder {
@@ -42,6 +45,8 @@ impl ExpressionBuilder {
             aggregations: Vec::new(),
             offset,
             runtime,
+            overflow: NumericOverflow::default(),
+            rounding: None,
         }
     }
 
@@ -50,9 +55,21 @@ impl ExpressionBuilder {
             aggregations,
             offset,
             runtime,
+            overflow: NumericOverflow::default(),
+            rounding: None,
         }
     }
 
+    pub fn with_numeric_overflow(mut self, overflow: NumericOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    pub fn with_decimal_rounding(mut self, rounding: Option<DecimalRounding>) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
     pub async fn build(
         &mut self,
         parse_aggregations: bool,
@@ -313,6 +330,17 @@ impl ExpressionBuilder {
                 .ok()?;
             arg_expr.push(aggregation);
         }
+        if self.overflow == NumericOverflow::PromoteToDecimal
+            && matches!(
+                aggr,
+                AggregateFunctionType::Sum | AggregateFunctionType::Avg
+            )
+        {
+            arg_expr = arg_expr
+                .into_iter()
+                .map(|arg| promote_to_decimal(arg, schema))
+                .collect();
+        }
         let measure = Expression::AggregateFunction {
             fun: aggr,
             args: arg_expr,
@@ -565,6 +593,11 @@ impl ExpressionBuilder {
                         Err(Error::JavaScriptNotEnabled)
                     }
                 }
+
+                UdfType::Lookup(config) => {
+                    self.parse_lookup_udf(function_name.clone(), config, sql_function, schema, udfs)
+                        .await
+                }
             };
         }
 
@@ -729,6 +762,8 @@ impl ExpressionBuilder {
             left: Box::new(left_op),
             operator,
             right: Box::new(right_op),
+            overflow: self.overflow,
+            rounding: self.rounding,
         })
     }
 
@@ -987,6 +1022,28 @@ impl ExpressionBuilder {
         Ok(Expression::JavaScriptUdf(udf))
     }
 
+    async fn parse_lookup_udf(
+        &mut self,
+        name: String,
+        config: &dozer_types::models::udf_config::LookupConfig,
+        function: &Function,
+        schema: &Schema,
+        udfs: &[UdfConfig],
+    ) -> Result<Expression, Error> {
+        let mut args = vec![];
+        for argument in &function.args {
+            let arg = self
+                .parse_sql_function_arg(false, argument, schema, udfs)
+                .await?;
+            args.push(arg);
+        }
+
+        use crate::lookup::{validate_args, LookupUdf};
+        validate_args(name.clone(), &args)?;
+        let udf = LookupUdf::new(self.runtime.clone(), name, config, args.remove(0))?;
+        Ok(Expression::LookupUdf(udf))
+    }
+
     async fn parse_sql_in_list_operator(
         &mut self,
         parse_aggregations: bool,
@@ -1035,3 +1092,16 @@ pub fn extend_schema_source_def(schema: &Schema, name: &NameOrAlias) -> Schema {
 
     output_schema
 }
+
+/// Casts an integer argument of SUM or AVG to decimal, so the aggregation computes in decimal.
+fn promote_to_decimal(arg: Expression, schema: &Schema) -> Expression {
+    match arg.get_type(schema).map(|typ| typ.return_type) {
+        Ok(FieldType::UInt | FieldType::U128 | FieldType::Int | FieldType::I128) => {
+            Expression::Cast {
+                arg: Box::new(arg),
+                typ: CastOperatorType(FieldType::Decimal),
+            }
+        }
+        _ => arg,
+    }
+}
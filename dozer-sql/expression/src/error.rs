@@ -109,6 +109,9 @@ pub enum Error {
     #[error("JavaScript UDF error: {0}")]
     JavaScript(#[from] crate::javascript::Error),
 
+    #[error("Lookup UDF error: {0}")]
+    Lookup(#[from] crate::lookup::Error),
+
     // Legacy error types.
     #[error("Sql error: {0}")]
     SqlError(#[source] OperationError),
@@ -1,4 +1,4 @@
-use crate::arg_utils::{validate_one_argument, validate_two_arguments};
+use crate::arg_utils::{validate_num_arguments, validate_one_argument, validate_two_arguments};
 use crate::case::evaluate_case;
 use crate::conditional::{get_conditional_expr_type, ConditionalExpressionType};
 use crate::datetime::{get_datetime_function_type, DateTimeFunctionType};
@@ -10,10 +10,11 @@ use crate::scalar::common::{get_scalar_function_type, ScalarFunctionType};
 use crate::scalar::string::{evaluate_trim, validate_trim, TrimType};
 use std::iter::zip;
 
-use super::aggregate::AggregateFunctionType;
+use super::aggregate::{AggregateFunctionType, WINDOW_FUNNEL_MAX_STEPS};
 use super::cast::CastOperatorType;
 use super::in_list::evaluate_in_list;
 use super::scalar::string::{evaluate_like, get_like_operator_type};
+use dozer_types::models::flags::{DecimalRounding, NumericOverflow};
 use dozer_types::types::Record;
 use dozer_types::types::{Field, FieldType, Schema, SourceDefinition};
 
@@ -31,6 +32,8 @@ pub enum Expression {
         left: Box<Expression>,
         operator: BinaryOperatorType,
         right: Box<Expression>,
+        overflow: NumericOverflow,
+        rounding: Option<DecimalRounding>,
     },
     ScalarFunction {
         fun: ScalarFunctionType,
@@ -98,6 +101,7 @@ pub enum Expression {
     },
     #[cfg(feature = "javascript")]
     JavaScriptUdf(crate::javascript::Udf),
+    LookupUdf(crate::lookup::LookupUdf),
 }
 
 impl Expression {
@@ -112,6 +116,7 @@ impl Expression {
                 left,
                 operator,
                 right,
+                ..
             } => {
                 left.to_string(schema)
                     + operator.to_string().as_str()
@@ -278,6 +283,7 @@ impl Expression {
             }
             #[cfg(feature = "javascript")]
             Expression::JavaScriptUdf(udf) => udf.to_string(schema),
+            Expression::LookupUdf(udf) => udf.to_string(schema),
         }
     }
 }
@@ -314,7 +320,9 @@ impl Expression {
                 left,
                 operator,
                 right,
-            } => operator.evaluate(schema, left, right, record),
+                overflow,
+                rounding,
+            } => operator.evaluate(schema, left, right, record, *overflow, *rounding),
             Expression::ScalarFunction { fun, args } => fun.evaluate(schema, args, record),
 
             #[cfg(feature = "python")]
@@ -367,6 +375,7 @@ impl Expression {
             } => evaluate_case(schema, operand, conditions, results, else_result, record),
             #[cfg(feature = "javascript")]
             Expression::JavaScriptUdf(udf) => udf.evaluate(record, schema),
+            Expression::LookupUdf(udf) => udf.evaluate(record, schema),
         }
     }
 
@@ -401,7 +410,9 @@ impl Expression {
                 left,
                 operator,
                 right,
-            } => get_binary_operator_type(left, operator, right, schema),
+                overflow,
+                ..
+            } => get_binary_operator_type(left, operator, right, *overflow, schema),
             Expression::ScalarFunction { fun, args } => get_scalar_function_type(fun, args, schema),
             Expression::ConditionalExpression { fun, args } => {
                 get_conditional_expr_type(fun, args, schema)
@@ -476,6 +487,7 @@ impl Expression {
             )),
             #[cfg(feature = "javascript")]
             Expression::JavaScriptUdf(udf) => Ok(udf.get_type()),
+            Expression::LookupUdf(udf) => Ok(udf.get_type()),
         }
     }
 }
@@ -500,6 +512,7 @@ fn get_binary_operator_type(
     left: &Expression,
     operator: &BinaryOperatorType,
     right: &Expression,
+    overflow: NumericOverflow,
     schema: &Schema,
 ) -> Result<ExpressionType, Error> {
     let left_field_type = left.get_type(schema)?;
@@ -566,6 +579,19 @@ fn get_binary_operator_type(
         | BinaryOperatorType::Mul
         | BinaryOperatorType::Mod => {
             match (left_field_type.return_type, right_field_type.return_type) {
+                (
+                    FieldType::UInt | FieldType::U128 | FieldType::Int | FieldType::I128,
+                    FieldType::UInt | FieldType::U128 | FieldType::Int | FieldType::I128,
+                ) if overflow == NumericOverflow::PromoteToDecimal
+                    && *operator != BinaryOperatorType::Mod =>
+                {
+                    Ok(ExpressionType::new(
+                        FieldType::Decimal,
+                        false,
+                        SourceDefinition::Dynamic,
+                        false,
+                    ))
+                }
                 (FieldType::UInt, FieldType::UInt) => Ok(ExpressionType::new(
                     FieldType::UInt,
                     false,
@@ -735,6 +761,7 @@ fn get_aggregate_function_type(
         AggregateFunctionType::MinAppendOnly => validate_min_append_only(args, schema),
         AggregateFunctionType::MinValue => validate_min_value(args, schema),
         AggregateFunctionType::Sum => validate_sum(args, schema),
+        AggregateFunctionType::WindowFunnel => validate_window_funnel(args, schema),
     }
 }
 
@@ -1061,6 +1088,55 @@ fn validate_max_value(args: &[Expression], schema: &Schema) -> Result<Expression
     ))
 }
 
+/// `WINDOW_FUNNEL(time, window, step_1, step_2, ...)` takes a timestamp, a window in seconds or as a duration, and up to 64 boolean steps.
+fn validate_window_funnel(args: &[Expression], schema: &Schema) -> Result<ExpressionType, Error> {
+    validate_num_arguments(
+        4..WINDOW_FUNNEL_MAX_STEPS + 3,
+        args.len(),
+        AggregateFunctionType::WindowFunnel,
+    )?;
+
+    let time_arg = args[0].get_type(schema)?;
+    if time_arg.return_type != FieldType::Timestamp {
+        return Err(Error::InvalidFunctionArgumentType {
+            function_name: AggregateFunctionType::WindowFunnel.to_string(),
+            argument_index: 0,
+            actual: time_arg.return_type,
+            expected: vec![FieldType::Timestamp],
+        });
+    }
+    let window_arg = args[1].get_type(schema)?;
+    if !matches!(
+        window_arg.return_type,
+        FieldType::UInt | FieldType::Int | FieldType::Duration
+    ) {
+        return Err(Error::InvalidFunctionArgumentType {
+            function_name: AggregateFunctionType::WindowFunnel.to_string(),
+            argument_index: 1,
+            actual: window_arg.return_type,
+            expected: vec![FieldType::UInt, FieldType::Int, FieldType::Duration],
+        });
+    }
+    for (index, step_arg) in args.iter().enumerate().skip(2) {
+        let step_arg = step_arg.get_type(schema)?;
+        if step_arg.return_type != FieldType::Boolean {
+            return Err(Error::InvalidFunctionArgumentType {
+                function_name: AggregateFunctionType::WindowFunnel.to_string(),
+                argument_index: index,
+                actual: step_arg.return_type,
+                expected: vec![FieldType::Boolean],
+            });
+        }
+    }
+
+    Ok(ExpressionType::new(
+        FieldType::UInt,
+        false,
+        SourceDefinition::Dynamic,
+        false,
+    ))
+}
+
 fn validate_min_value(args: &[Expression], schema: &Schema) -> Result<ExpressionType, Error> {
     let (base_arg, arg) = validate_two_arguments(args, schema, AggregateFunctionType::MinValue)?;
 
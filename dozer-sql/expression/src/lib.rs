@@ -12,8 +12,11 @@ mod geo;
 mod in_list;
 mod json_functions;
 mod logical;
+mod lookup;
 mod mathematical;
 pub mod operator;
+pub mod overflow;
+pub mod rounding;
 pub mod scalar;
 
 #[cfg(feature = "javascript")]
@@ -0,0 +1,436 @@
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+use dozer_types::errors::types::DeserializationError;
+use dozer_types::json_types::serde_json_to_json_value;
+use dozer_types::log::warn;
+use dozer_types::models::udf_config::{
+    default_lookup_cache_size, default_lookup_cache_ttl_ms, default_lookup_max_concurrency,
+    default_lookup_max_retries, default_lookup_timeout_ms, LookupConfig,
+};
+use dozer_types::parking_lot::Mutex;
+use dozer_types::retry::RetryPolicy;
+use dozer_types::serde_json;
+use dozer_types::thiserror::{self, Error};
+use dozer_types::types::{Field, FieldType, Record, Schema, SourceDefinition};
+use lru::LruCache;
+use reqwest::StatusCode;
+use tokio::runtime::Runtime;
+use tokio::sync::Semaphore;
+
+use crate::execution::{Expression, ExpressionType};
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Failed to create HTTP client: {0}")]
+    CreateClient(#[source] reqwest::Error),
+    #[error("Lookup of key {key} failed: {source}")]
+    Request {
+        key: String,
+        #[source]
+        source: reqwest::Error,
+    },
+    #[error("Lookup of key {key} returned status {status}")]
+    Status { key: String, status: StatusCode },
+    #[error("Lookup of key {key} returned invalid JSON: {source}")]
+    InvalidJson {
+        key: String,
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("Lookup of key {key} returned an unsupported JSON value: {source}")]
+    UnsupportedJson {
+        key: String,
+        #[source]
+        source: DeserializationError,
+    },
+}
+
+impl Error {
+    /// Whether the request may succeed if it's sent again.
+    fn is_transient(&self) -> bool {
+        match self {
+            Error::Request { .. } => true,
+            Error::Status { status, .. } => {
+                status.is_server_error() || *status == StatusCode::TOO_MANY_REQUESTS
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CachedValue {
+    value: Field,
+    looked_up_at: Instant,
+}
+
+/// The cache and the limit on concurrent requests of a lookup function.
+///
+/// Every processor evaluating the function builds its own `LookupUdf`, so these are registered per function name
+/// to be shared by all of them.
+#[derive(Debug)]
+struct Shared {
+    cache: Mutex<LruCache<String, CachedValue>>,
+    permits: Semaphore,
+}
+
+impl Shared {
+    fn get_or_create(function_name: &str, config: &LookupConfig) -> Arc<Self> {
+        static REGISTRY: OnceLock<Mutex<HashMap<String, Arc<Shared>>>> = OnceLock::new();
+        REGISTRY
+            .get_or_init(Default::default)
+            .lock()
+            .entry(function_name.to_string())
+            .or_insert_with(|| {
+                let cache_size =
+                    NonZeroUsize::new(config.cache_size.unwrap_or_else(default_lookup_cache_size))
+                        .unwrap_or(NonZeroUsize::MIN);
+                let max_concurrency = config
+                    .max_concurrency
+                    .unwrap_or_else(default_lookup_max_concurrency)
+                    .max(1);
+                Arc::new(Self {
+                    cache: Mutex::new(LruCache::new(cache_size)),
+                    permits: Semaphore::new(max_concurrency),
+                })
+            })
+            .clone()
+    }
+}
+
+/// Looks up the value of a key in an external HTTP service, caching the values in memory.
+///
+/// All instances of the same function share the cache and the limit on concurrent requests.
+#[derive(Debug, Clone)]
+pub struct LookupUdf {
+    function_name: String,
+    arg: Box<Expression>,
+    url: String,
+    client: reqwest::Client,
+    tokio_runtime: Arc<Runtime>,
+    shared: Arc<Shared>,
+    cache_ttl: Duration,
+    retry_policy: RetryPolicy,
+}
+
+impl PartialEq for LookupUdf {
+    fn eq(&self, other: &Self) -> bool {
+        self.function_name == other.function_name && self.arg == other.arg
+    }
+}
+
+impl LookupUdf {
+    pub fn new(
+        tokio_runtime: Arc<Runtime>,
+        function_name: String,
+        config: &LookupConfig,
+        arg: Expression,
+    ) -> Result<Self, Error> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(
+                config.timeout_ms.unwrap_or_else(default_lookup_timeout_ms),
+            ))
+            .build()
+            .map_err(Error::CreateClient)?;
+        let shared = Shared::get_or_create(&function_name, config);
+        Ok(Self {
+            function_name,
+            arg: Box::new(arg),
+            url: config.url.clone(),
+            client,
+            tokio_runtime,
+            shared,
+            cache_ttl: Duration::from_millis(
+                config
+                    .cache_ttl_ms
+                    .unwrap_or_else(default_lookup_cache_ttl_ms),
+            ),
+            retry_policy: RetryPolicy {
+                initial_interval: Duration::from_millis(100),
+                max_interval: Duration::from_secs(5),
+                max_retries: Some(
+                    config
+                        .max_retries
+                        .unwrap_or_else(default_lookup_max_retries),
+                ),
+                ..Default::default()
+            },
+        })
+    }
+
+    pub fn get_type(&self) -> ExpressionType {
+        ExpressionType {
+            return_type: FieldType::Json,
+            nullable: true,
+            source: SourceDefinition::Dynamic,
+            is_primary_key: false,
+        }
+    }
+
+    /// Returns `NULL` for a `NULL` key, or if the service responds with `404 Not Found`.
+    ///
+    /// Requests failing with a connection error, a server error or `429 Too Many Requests` are retried.
+    pub fn evaluate(
+        &mut self,
+        record: &Record,
+        schema: &Schema,
+    ) -> Result<Field, crate::error::Error> {
+        let key = match self.arg.evaluate(record, schema)? {
+            Field::Null => return Ok(Field::Null),
+            key => key.to_string(),
+        };
+
+        if let Some(cached) = self.shared.cache.lock().get(&key) {
+            if cached.looked_up_at.elapsed() < self.cache_ttl {
+                return Ok(cached.value.clone());
+            }
+        }
+
+        let value = self.tokio_runtime.block_on(self.look_up(&key))?;
+        self.shared.cache.lock().put(
+            key,
+            CachedValue {
+                value: value.clone(),
+                looked_up_at: Instant::now(),
+            },
+        );
+        Ok(value)
+    }
+
+    async fn look_up(&self, key: &str) -> Result<Field, Error> {
+        let mut backoff = self.retry_policy.backoff();
+        loop {
+            match self.request(key).await {
+                Err(error) if error.is_transient() => match backoff.next_delay() {
+                    Some(delay) => {
+                        warn!("{}: {error}, retrying in {delay:?}", self.function_name);
+                        tokio::time::sleep(delay).await;
+                    }
+                    None => return Err(error),
+                },
+                result => return result,
+            }
+        }
+    }
+
+    async fn request(&self, key: &str) -> Result<Field, Error> {
+        let _permit = self
+            .shared
+            .permits
+            .acquire()
+            .await
+            .expect("semaphore is never closed");
+        let response = self
+            .client
+            .get(&self.url)
+            .query(&[("key", key)])
+            .send()
+            .await
+            .map_err(|source| Error::Request {
+                key: key.to_string(),
+                source,
+            })?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(Field::Null);
+        }
+        if !response.status().is_success() {
+            return Err(Error::Status {
+                key: key.to_string(),
+                status: response.status(),
+            });
+        }
+        let body = response.bytes().await.map_err(|source| Error::Request {
+            key: key.to_string(),
+            source,
+        })?;
+        let value = serde_json::from_slice(&body).map_err(|source| Error::InvalidJson {
+            key: key.to_string(),
+            source,
+        })?;
+        let value = serde_json_to_json_value(value).map_err(|source| Error::UnsupportedJson {
+            key: key.to_string(),
+            source,
+        })?;
+        Ok(Field::Json(value))
+    }
+
+    pub fn to_string(&self, schema: &Schema) -> String {
+        format!("{}({})", self.function_name, self.arg.to_string(schema))
+    }
+}
+
+pub fn validate_args(
+    function_name: String,
+    args: &[Expression],
+) -> Result<(), crate::error::Error> {
+    if args.len() != 1 {
+        return Err(crate::error::Error::InvalidNumberOfArguments {
+            function_name,
+            expected: 1..2,
+            actual: args.len(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use dozer_types::json_types::json;
+    use dozer_types::types::FieldDefinition;
+
+    use super::*;
+
+    /// Serves `{"key": <key>}`, `404` for the key `missing`, and `500` for the first request of the key `flaky`.
+    /// Returns the URL and the number of requests received.
+    fn serve() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        std::thread::spawn(move || {
+            let mut flaky_failed = false;
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut buf = [0; 4096];
+                let len = stream.read(&mut buf).unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
+                let request = String::from_utf8_lossy(&buf[..len]);
+                let key = request
+                    .split_once("key=")
+                    .and_then(|(_, rest)| rest.split(' ').next())
+                    .unwrap_or_default()
+                    .to_string();
+                let (status, body) = match key.as_str() {
+                    "missing" => ("404 Not Found", String::new()),
+                    "flaky" if !flaky_failed => {
+                        flaky_failed = true;
+                        ("500 Internal Server Error", String::new())
+                    }
+                    _ => ("200 OK", format!("{{\"key\": \"{key}\"}}")),
+                };
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+            }
+        });
+        (url, requests)
+    }
+
+    fn lookup(function_name: &str, url: String, cache_size: usize, cache_ttl_ms: u64) -> LookupUdf {
+        let runtime = Arc::new(
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap(),
+        );
+        let config = LookupConfig {
+            url,
+            cache_size: Some(cache_size),
+            cache_ttl_ms: Some(cache_ttl_ms),
+            max_concurrency: None,
+            timeout_ms: None,
+            max_retries: Some(1),
+        };
+        LookupUdf::new(
+            runtime,
+            function_name.to_string(),
+            &config,
+            Expression::Column { index: 0 },
+        )
+        .unwrap()
+    }
+
+    fn evaluate(udf: &mut LookupUdf, key: Field) -> Field {
+        let schema = Schema::default()
+            .field(
+                FieldDefinition::new(
+                    "key".to_string(),
+                    FieldType::String,
+                    true,
+                    SourceDefinition::Dynamic,
+                ),
+                false,
+            )
+            .clone();
+        udf.evaluate(&Record::new(vec![key]), &schema).unwrap()
+    }
+
+    #[test]
+    fn test_lookup_cache_evicts_least_recently_used() {
+        let (url, requests) = serve();
+        let mut udf = lookup("test_lookup_lru", url, 1, 60_000);
+        let a = Field::String("a".to_string());
+        let b = Field::String("b".to_string());
+
+        assert_eq!(
+            evaluate(&mut udf, a.clone()),
+            Field::Json(json!({"key": "a"}))
+        );
+        assert_eq!(
+            evaluate(&mut udf, a.clone()),
+            Field::Json(json!({"key": "a"}))
+        );
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        assert_eq!(evaluate(&mut udf, b), Field::Json(json!({"key": "b"})));
+        assert_eq!(evaluate(&mut udf, a), Field::Json(json!({"key": "a"})));
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_lookup_cache_expires() {
+        let (url, requests) = serve();
+        let mut udf = lookup("test_lookup_ttl", url, 10, 0);
+        let a = Field::String("a".to_string());
+
+        evaluate(&mut udf, a.clone());
+        evaluate(&mut udf, a);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_lookup_cache_is_shared_by_instances() {
+        let (url, requests) = serve();
+        let mut first = lookup("test_lookup_shared", url.clone(), 10, 60_000);
+        let mut second = lookup("test_lookup_shared", url, 10, 60_000);
+        let a = Field::String("a".to_string());
+
+        evaluate(&mut first, a.clone());
+        evaluate(&mut second, a);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_lookup_not_found_and_null_key() {
+        let (url, requests) = serve();
+        let mut udf = lookup("test_lookup_not_found", url, 10, 60_000);
+
+        assert_eq!(
+            evaluate(&mut udf, Field::String("missing".to_string())),
+            Field::Null
+        );
+        assert_eq!(evaluate(&mut udf, Field::Null), Field::Null);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_lookup_retries_server_errors() {
+        let (url, requests) = serve();
+        let mut udf = lookup("test_lookup_retry", url, 10, 60_000);
+
+        assert_eq!(
+            evaluate(&mut udf, Field::String("flaky".to_string())),
+            Field::Json(json!({"key": "flaky"}))
+        );
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+}
@@ -1,27 +1,47 @@
+use dozer_types::models::flags::{DecimalRounding, NumericOverflow};
 use dozer_types::rust_decimal::Decimal;
 use dozer_types::types::Record;
 use dozer_types::types::Schema;
 use dozer_types::types::{DozerDuration, TimeUnit};
 use dozer_types::{chrono, ordered_float::OrderedFloat, types::Field};
 use num_traits::{FromPrimitive, Zero};
-use std::num::Wrapping;
 use std::ops::Neg;
 
 use crate::execution::Expression;
 
 use crate::error::{Error as PipelineError, OperationError};
+use crate::overflow::{int_uint_arithmetic, integer_arithmetic, signed_unsigned_arithmetic};
+use crate::rounding::{result_scale, round_field};
 
 macro_rules! define_math_operator {
-    ($id:ident, $op:expr, $fct:expr, $t: expr) => {
+    ($id:ident, $fields_id:ident, $op:expr, $fct:expr, $t: expr) => {
         pub fn $id(
             schema: &Schema,
             left: &mut Expression,
             right: &mut Expression,
             record: &Record,
+            overflow: NumericOverflow,
+            rounding: Option<DecimalRounding>,
         ) -> Result<Field, PipelineError> {
             let left_p = left.evaluate(&record, schema)?;
             let right_p = right.evaluate(&record, schema)?;
 
+            let Some(rounding) = rounding else {
+                return $fields_id(left_p, right_p, overflow);
+            };
+            let scale = result_scale($op, &left_p, &right_p);
+            Ok(round_field(
+                $fields_id(left_p, right_p, overflow)?,
+                &rounding,
+                scale,
+            ))
+        }
+
+        fn $fields_id(
+            left_p: Field,
+            right_p: Field,
+            overflow: NumericOverflow,
+        ) -> Result<Field, PipelineError> {
             match left_p {
                 Field::Duration(left_v) => match right_p {
                     Field::Duration(right_v) => {
@@ -405,7 +425,7 @@ macro_rules! define_math_operator {
                             }
                             // When it's not division operation
                             "+" | "-" | "*" | "%" => {
-                                Ok(Field::Int($fct(Wrapping(left_v), Wrapping(right_v)).0))
+                                integer_arithmetic($op, left_v, right_v, overflow)
                             }
                             &_ => Err(PipelineError::InvalidTypeComparison(
                                 left_p,
@@ -441,9 +461,9 @@ macro_rules! define_math_operator {
                                 }
                             }
                             // When it's not division operation
-                            "+" | "-" | "*" | "%" => Ok(Field::I128(
-                                $fct(Wrapping(left_v as i128), Wrapping(right_v)).0,
-                            )),
+                            "+" | "-" | "*" | "%" => {
+                                integer_arithmetic($op, left_v as i128, right_v, overflow)
+                            }
                             &_ => Err(PipelineError::InvalidTypeComparison(
                                 left_p,
                                 right_p,
@@ -478,9 +498,12 @@ macro_rules! define_math_operator {
                                 }
                             }
                             // When it's not division operation
-                            "+" | "-" | "*" | "%" => Ok(Field::Int(
-                                $fct(Wrapping(left_v), Wrapping(right_v as i64)).0,
-                            )),
+                            "+" | "-" | "*" | "%" => int_uint_arithmetic(
+                                $op,
+                                i128::from(left_v),
+                                i128::from(right_v),
+                                overflow,
+                            ),
                             &_ => Err(PipelineError::InvalidTypeComparison(
                                 left_p,
                                 right_p,
@@ -515,9 +538,13 @@ macro_rules! define_math_operator {
                                 }
                             }
                             // When it's not division operation
-                            "+" | "-" | "*" | "%" => Ok(Field::I128(
-                                $fct(Wrapping(left_v as i128), Wrapping(right_v as i128)).0,
-                            )),
+                            "+" | "-" | "*" | "%" => signed_unsigned_arithmetic(
+                                $op,
+                                i128::from(left_v),
+                                right_v,
+                                false,
+                                overflow,
+                            ),
                             &_ => Err(PipelineError::InvalidTypeComparison(
                                 left_p,
                                 right_p,
@@ -651,9 +678,9 @@ macro_rules! define_math_operator {
                                 }
                             }
                             // When it's not division operation
-                            "+" | "-" | "*" | "%" => Ok(Field::I128(
-                                $fct(Wrapping(left_v), Wrapping(right_v as i128)).0,
-                            )),
+                            "+" | "-" | "*" | "%" => {
+                                integer_arithmetic($op, left_v, right_v as i128, overflow)
+                            }
                             &_ => Err(PipelineError::InvalidTypeComparison(
                                 left_p,
                                 right_p,
@@ -689,7 +716,7 @@ macro_rules! define_math_operator {
                             }
                             // When it's not division operation
                             "+" | "-" | "*" | "%" => {
-                                Ok(Field::I128($fct(Wrapping(left_v), Wrapping(right_v)).0))
+                                integer_arithmetic($op, left_v, right_v, overflow)
                             }
                             &_ => Err(PipelineError::InvalidTypeComparison(
                                 left_p,
@@ -725,9 +752,9 @@ macro_rules! define_math_operator {
                                 }
                             }
                             // When it's not division operation
-                            "+" | "-" | "*" | "%" => Ok(Field::I128(
-                                $fct(Wrapping(left_v), Wrapping(right_v as i128)).0,
-                            )),
+                            "+" | "-" | "*" | "%" => {
+                                integer_arithmetic($op, left_v, right_v as i128, overflow)
+                            }
                             &_ => Err(PipelineError::InvalidTypeComparison(
                                 left_p,
                                 right_p,
@@ -762,9 +789,9 @@ macro_rules! define_math_operator {
                                 }
                             }
                             // When it's not division operation
-                            "+" | "-" | "*" | "%" => Ok(Field::I128(
-                                $fct(Wrapping(left_v), Wrapping(right_v as i128)).0,
-                            )),
+                            "+" | "-" | "*" | "%" => {
+                                signed_unsigned_arithmetic($op, left_v, right_v, false, overflow)
+                            }
                             &_ => Err(PipelineError::InvalidTypeComparison(
                                 left_p,
                                 right_p,
@@ -876,9 +903,12 @@ macro_rules! define_math_operator {
                                 }
                             }
                             // When it's not division operation
-                            "+" | "-" | "*" | "%" => Ok(Field::Int(
-                                $fct(Wrapping(left_v as i64), Wrapping(right_v)).0,
-                            )),
+                            "+" | "-" | "*" | "%" => int_uint_arithmetic(
+                                $op,
+                                i128::from(left_v),
+                                i128::from(right_v),
+                                overflow,
+                            ),
                             &_ => Err(PipelineError::InvalidTypeComparison(
                                 left_p,
                                 right_p,
@@ -913,9 +943,9 @@ macro_rules! define_math_operator {
                                 }
                             }
                             // When it's not division operation
-                            "+" | "-" | "*" | "%" => Ok(Field::I128(
-                                $fct(Wrapping(left_v as i128), Wrapping(right_v)).0,
-                            )),
+                            "+" | "-" | "*" | "%" => {
+                                integer_arithmetic($op, left_v as i128, right_v, overflow)
+                            }
                             &_ => Err(PipelineError::InvalidTypeComparison(
                                 left_p,
                                 right_p,
@@ -959,7 +989,7 @@ macro_rules! define_math_operator {
                             }
                             // When it's not division operation
                             "+" | "-" | "*" | "%" => {
-                                Ok(Field::UInt($fct(Wrapping(left_v), Wrapping(right_v)).0))
+                                integer_arithmetic($op, left_v, right_v, overflow)
                             }
                             &_ => Err(PipelineError::InvalidTypeComparison(
                                 left_p,
@@ -1003,9 +1033,9 @@ macro_rules! define_math_operator {
                                 }
                             }
                             // When it's not division operation
-                            "+" | "-" | "*" | "%" => Ok(Field::U128(
-                                $fct(Wrapping(left_v as u128), Wrapping(right_v)).0,
-                            )),
+                            "+" | "-" | "*" | "%" => {
+                                integer_arithmetic($op, left_v as u128, right_v, overflow)
+                            }
                             &_ => Err(PipelineError::InvalidTypeComparison(
                                 left_p,
                                 right_p,
@@ -1146,9 +1176,13 @@ macro_rules! define_math_operator {
                                 }
                             }
                             // When it's not division operation
-                            "+" | "-" | "*" | "%" => Ok(Field::I128(
-                                $fct(Wrapping(left_v as i128), Wrapping(right_v as i128)).0,
-                            )),
+                            "+" | "-" | "*" | "%" => signed_unsigned_arithmetic(
+                                $op,
+                                i128::from(right_v),
+                                left_v,
+                                true,
+                                overflow,
+                            ),
                             &_ => Err(PipelineError::InvalidTypeComparison(
                                 left_p,
                                 right_p,
@@ -1183,9 +1217,9 @@ macro_rules! define_math_operator {
                                 }
                             }
                             // When it's not division operation
-                            "+" | "-" | "*" | "%" => Ok(Field::I128(
-                                $fct(Wrapping(left_v as i128), Wrapping(right_v)).0,
-                            )),
+                            "+" | "-" | "*" | "%" => {
+                                signed_unsigned_arithmetic($op, right_v, left_v, true, overflow)
+                            }
                             &_ => Err(PipelineError::InvalidTypeComparison(
                                 left_p,
                                 right_p,
@@ -1228,9 +1262,9 @@ macro_rules! define_math_operator {
                                 }
                             }
                             // When it's not division operation
-                            "+" | "-" | "*" | "%" => Ok(Field::U128(
-                                $fct(Wrapping(left_v), Wrapping(right_v as u128)).0,
-                            )),
+                            "+" | "-" | "*" | "%" => {
+                                integer_arithmetic($op, left_v, right_v as u128, overflow)
+                            }
                             &_ => Err(PipelineError::InvalidTypeComparison(
                                 left_p,
                                 right_p,
@@ -1274,7 +1308,7 @@ macro_rules! define_math_operator {
                             }
                             // When it's not division operation
                             "+" | "-" | "*" | "%" => {
-                                Ok(Field::U128($fct(Wrapping(left_v), Wrapping(right_v)).0))
+                                integer_arithmetic($op, left_v, right_v, overflow)
                             }
                             &_ => Err(PipelineError::InvalidTypeComparison(
                                 left_p,
@@ -1769,11 +1803,11 @@ macro_rules! define_math_operator {
     };
 }
 
-define_math_operator!(evaluate_add, "+", std::ops::Add::add, 0);
-define_math_operator!(evaluate_sub, "-", std::ops::Sub::sub, 0);
-define_math_operator!(evaluate_mul, "*", std::ops::Mul::mul, 0);
-define_math_operator!(evaluate_div, "/", std::ops::Div::div, 1);
-define_math_operator!(evaluate_mod, "%", std::ops::Rem::rem, 0);
+define_math_operator!(evaluate_add, add_fields, "+", std::ops::Add::add, 0);
+define_math_operator!(evaluate_sub, sub_fields, "-", std::ops::Sub::sub, 0);
+define_math_operator!(evaluate_mul, mul_fields, "*", std::ops::Mul::mul, 0);
+define_math_operator!(evaluate_div, div_fields, "/", std::ops::Div::div, 1);
+define_math_operator!(evaluate_mod, mod_fields, "%", std::ops::Rem::rem, 0);
 
 pub fn evaluate_plus(
     schema: &Schema,
@@ -36,31 +36,31 @@ fn test_uint_math() {
         //// left: UInt, right: UInt
         assert_eq!(
             // UInt + UInt = UInt
-            evaluate_add(&Schema::default(), &mut uint1, &mut uint2, &row)
+            evaluate_add(&Schema::default(), &mut uint1, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::UInt((Wrapping(u_num1) + Wrapping(u_num2)).0)
         );
         assert_eq!(
             // UInt - UInt = UInt
-            evaluate_sub(&Schema::default(), &mut uint1, &mut uint2, &row)
+            evaluate_sub(&Schema::default(), &mut uint1, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::UInt((Wrapping(u_num1) - Wrapping(u_num2)).0)
         );
         assert_eq!(
             // UInt * UInt = UInt
-            evaluate_mul(&Schema::default(), &mut uint2, &mut uint1, &row)
+            evaluate_mul(&Schema::default(), &mut uint2, &mut uint1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::UInt((Wrapping(u_num2) * Wrapping(u_num1)).0)
         );
         assert_eq!(
             // UInt / UInt = Float
-            evaluate_div(&Schema::default(), &mut uint2, &mut uint1, &row)
+            evaluate_div(&Schema::default(), &mut uint2, &mut uint1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f64::from_u64(u_num2).unwrap() / f64::from_u64(u_num1).unwrap()))
         );
         assert_eq!(
             // UInt % UInt = UInt
-            evaluate_mod(&Schema::default(), &mut uint1, &mut uint2, &row)
+            evaluate_mod(&Schema::default(), &mut uint1, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::UInt((Wrapping(u_num1) % Wrapping(u_num2)).0)
         );
@@ -68,31 +68,31 @@ fn test_uint_math() {
         //// left: UInt, right: U128
         assert_eq!(
             // UInt + U128 = U128
-            evaluate_add(&Schema::default(), &mut uint1, &mut u128_2, &row)
+            evaluate_add(&Schema::default(), &mut uint1, &mut u128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::U128((Wrapping(u_num1 as u128) + Wrapping(u128_num2)).0)
         );
         assert_eq!(
             // UInt - U128 = U128
-            evaluate_sub(&Schema::default(), &mut uint1, &mut u128_2, &row)
+            evaluate_sub(&Schema::default(), &mut uint1, &mut u128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::U128((Wrapping(u_num1 as u128) - Wrapping(u128_num2)).0)
         );
         assert_eq!(
             // UInt * U128 = U128
-            evaluate_mul(&Schema::default(), &mut uint2, &mut u128_1, &row)
+            evaluate_mul(&Schema::default(), &mut uint2, &mut u128_1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::U128((Wrapping(u_num2 as u128) * Wrapping(u128_num1)).0)
         );
         assert_eq!(
             // UInt / U128 = Float
-            evaluate_div(&Schema::default(), &mut uint2, &mut u128_1, &row)
+            evaluate_div(&Schema::default(), &mut uint2, &mut u128_1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f64::from_u64(u_num2).unwrap() / f64::from_u128(u128_num1).unwrap()))
         );
         assert_eq!(
             // UInt % U128 = U128
-            evaluate_mod(&Schema::default(), &mut uint1, &mut u128_2, &row)
+            evaluate_mod(&Schema::default(), &mut uint1, &mut u128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::U128((Wrapping(u_num1 as u128) % Wrapping(u128_num2)).0)
         );
@@ -100,31 +100,31 @@ fn test_uint_math() {
         //// left: UInt, right: Int
         assert_eq!(
             // UInt + Int = Int
-            evaluate_add(&Schema::default(), &mut uint1, &mut int2, &row)
+            evaluate_add(&Schema::default(), &mut uint1, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Int((Wrapping(u_num1 as i64) + Wrapping(i_num2)).0)
         );
         assert_eq!(
             // UInt - Int = Int
-            evaluate_sub(&Schema::default(), &mut uint1, &mut int2, &row)
+            evaluate_sub(&Schema::default(), &mut uint1, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Int((Wrapping(u_num1 as i64) - Wrapping(i_num2)).0)
         );
         assert_eq!(
             // UInt * Int = Int
-            evaluate_mul(&Schema::default(), &mut uint2, &mut int1, &row)
+            evaluate_mul(&Schema::default(), &mut uint2, &mut int1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Int((Wrapping(u_num2 as i64) * Wrapping(i_num1)).0)
         );
         assert_eq!(
             // UInt / Int = Float
-            evaluate_div(&Schema::default(), &mut uint2, &mut int1, &row)
+            evaluate_div(&Schema::default(), &mut uint2, &mut int1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f64::from_u64(u_num2).unwrap() / f64::from_i64(i_num1).unwrap()))
         );
         assert_eq!(
             // UInt % Int = Int
-            evaluate_mod(&Schema::default(), &mut uint1, &mut int2, &row)
+            evaluate_mod(&Schema::default(), &mut uint1, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Int((Wrapping(u_num1 as i64) % Wrapping(i_num2)).0)
         );
@@ -132,31 +132,31 @@ fn test_uint_math() {
         //// left: UInt, right: I128
         assert_eq!(
             // UInt + I128 = I128
-            evaluate_add(&Schema::default(), &mut uint1, &mut i128_2, &row)
+            evaluate_add(&Schema::default(), &mut uint1, &mut i128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(u_num1 as i128) + Wrapping(i128_num2)).0)
         );
         assert_eq!(
             // UInt - I128 = I128
-            evaluate_sub(&Schema::default(), &mut uint1, &mut i128_2, &row)
+            evaluate_sub(&Schema::default(), &mut uint1, &mut i128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(u_num1 as i128) - Wrapping(i128_num2)).0)
         );
         assert_eq!(
             // UInt * I128 = I128
-            evaluate_mul(&Schema::default(), &mut uint2, &mut i128_1, &row)
+            evaluate_mul(&Schema::default(), &mut uint2, &mut i128_1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(u_num2 as i128) * Wrapping(i128_num1)).0)
         );
         assert_eq!(
             // UInt / I128 = Float
-            evaluate_div(&Schema::default(), &mut uint2, &mut i128_1, &row)
+            evaluate_div(&Schema::default(), &mut uint2, &mut i128_1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f64::from_u64(u_num2).unwrap() / f64::from_i128(i128_num1).unwrap()))
         );
         assert_eq!(
             // UInt % I128 = I128
-            evaluate_mod(&Schema::default(), &mut uint1, &mut i128_2, &row)
+            evaluate_mod(&Schema::default(), &mut uint1, &mut i128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(u_num1 as i128) % Wrapping(i128_num2)).0)
         );
@@ -164,26 +164,26 @@ fn test_uint_math() {
         //// left: UInt, right: Float
         assert_eq!(
             // UInt + Float = Float
-            evaluate_add(&Schema::default(), &mut uint1, &mut float2, &row)
+            evaluate_add(&Schema::default(), &mut uint1, &mut float2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f64::from_u64(u_num1).unwrap() + f_num2))
         );
         assert_eq!(
             // UInt - Float = Float
-            evaluate_sub(&Schema::default(), &mut uint1, &mut float2, &row)
+            evaluate_sub(&Schema::default(), &mut uint1, &mut float2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f64::from_u64(u_num1).unwrap() - f_num2))
         );
         assert_eq!(
             // UInt * Float = Float
-            evaluate_mul(&Schema::default(), &mut uint2, &mut float1, &row)
+            evaluate_mul(&Schema::default(), &mut uint2, &mut float1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f64::from_u64(u_num2).unwrap() * f_num1))
         );
         if *float1 != Literal(Field::Float(OrderedFloat(0_f64))) {
             assert_eq!(
                 // UInt / Float = Float
-                evaluate_div(&Schema::default(), &mut uint2, &mut float1, &row)
+                evaluate_div(&Schema::default(), &mut uint2, &mut float1, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_u64(u_num2).unwrap() / f_num1))
             );
@@ -191,7 +191,7 @@ fn test_uint_math() {
         if *float2 != Literal(Field::Float(OrderedFloat(0_f64))) {
             assert_eq!(
                 // UInt % Float = Float
-                evaluate_mod(&Schema::default(), &mut uint1, &mut float2, &row)
+                evaluate_mod(&Schema::default(), &mut uint1, &mut float2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_u64(u_num1).unwrap() % f_num2))
             );
@@ -200,18 +200,18 @@ fn test_uint_math() {
         //// left: UInt, right: Decimal
         assert_eq!(
             // UInt + Decimal = Decimal
-            evaluate_add(&Schema::default(), &mut uint1, &mut dec2, &row)
+            evaluate_add(&Schema::default(), &mut uint1, &mut dec2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Decimal(Decimal::from_u64(u_num1).unwrap() + d_num2.0)
         );
         assert_eq!(
             // UInt - Decimal = Decimal
-            evaluate_sub(&Schema::default(), &mut uint1, &mut dec2, &row)
+            evaluate_sub(&Schema::default(), &mut uint1, &mut dec2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Decimal(Decimal::from_u64(u_num1).unwrap() - d_num2.0)
         );
         // UInt * Decimal = Decimal
-        let res = evaluate_mul(&Schema::default(), &mut uint2, &mut dec1, &row);
+        let res = evaluate_mul(&Schema::default(), &mut uint2, &mut dec1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
              assert_eq!(
                 res.unwrap(), Field::Decimal(Decimal::from_u64(u_num2).unwrap().checked_mul(d_num1.0).unwrap())
@@ -224,7 +224,7 @@ fn test_uint_math() {
             ));
         }
         // UInt / Decimal = Decimal
-        let res = evaluate_div(&Schema::default(), &mut uint2, &mut dec1, &row);
+        let res = evaluate_div(&Schema::default(), &mut uint2, &mut dec1, &row, NumericOverflow::Wrap, None);
         if d_num1.0 == Decimal::new(0, 0) {
             assert!(res.is_err());
             assert!(matches!(
@@ -244,7 +244,7 @@ fn test_uint_math() {
             ));
         }
         // UInt % Decimal = Decimal
-        let res = evaluate_mod(&Schema::default(), &mut uint2, &mut dec1, &row);
+        let res = evaluate_mod(&Schema::default(), &mut uint2, &mut dec1, &row, NumericOverflow::Wrap, None);
         if d_num1.0 == Decimal::new(0, 0) {
             assert!(res.is_err());
             assert!(matches!(
@@ -267,31 +267,31 @@ fn test_uint_math() {
         //// left: UInt, right: Null
         assert_eq!(
             // UInt + Null = Null
-            evaluate_add(&Schema::default(), &mut uint1, &mut null, &row)
+            evaluate_add(&Schema::default(), &mut uint1, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // UInt - Null = Null
-            evaluate_sub(&Schema::default(), &mut uint1, &mut null, &row)
+            evaluate_sub(&Schema::default(), &mut uint1, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // UInt * Null = Null
-            evaluate_mul(&Schema::default(), &mut uint2, &mut null, &row)
+            evaluate_mul(&Schema::default(), &mut uint2, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // UInt / Null = Null
-            evaluate_div(&Schema::default(), &mut uint2, &mut null, &row)
+            evaluate_div(&Schema::default(), &mut uint2, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // UInt % Null = Null
-            evaluate_mod(&Schema::default(), &mut uint1, &mut null, &row)
+            evaluate_mod(&Schema::default(), &mut uint1, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
@@ -321,31 +321,31 @@ fn test_u128_math() {
         //// left: U128, right: UInt
         assert_eq!(
             // U128 + UInt = U128
-            evaluate_add(&Schema::default(), &mut u128_1, &mut uint2, &row)
+            evaluate_add(&Schema::default(), &mut u128_1, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::U128((Wrapping(u128_num1) + Wrapping(u_num2 as u128)).0)
         );
         assert_eq!(
             // U128 - UInt = U128
-            evaluate_sub(&Schema::default(), &mut u128_1, &mut uint2, &row)
+            evaluate_sub(&Schema::default(), &mut u128_1, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::U128((Wrapping(u128_num1) - Wrapping(u_num2 as u128)).0)
         );
         assert_eq!(
             // U128 * UInt = U128
-            evaluate_mul(&Schema::default(), &mut u128_2, &mut uint1, &row)
+            evaluate_mul(&Schema::default(), &mut u128_2, &mut uint1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::U128((Wrapping(u128_num2) * Wrapping(u_num1 as u128)).0)
         );
         assert_eq!(
             // U128 / UInt = Float
-            evaluate_div(&Schema::default(), &mut u128_2, &mut uint1, &row)
+            evaluate_div(&Schema::default(), &mut u128_2, &mut uint1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f64::from_u128(u128_num2).unwrap() / f64::from_u64(u_num1).unwrap()))
         );
         assert_eq!(
             // U128 % UInt = U128
-            evaluate_mod(&Schema::default(), &mut u128_1, &mut uint2, &row)
+            evaluate_mod(&Schema::default(), &mut u128_1, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::U128((Wrapping(u128_num1) % Wrapping(u_num2 as u128)).0)
         );
@@ -353,31 +353,31 @@ fn test_u128_math() {
         //// left: U128, right: U128
         assert_eq!(
             // U128 + U128 = U128
-            evaluate_add(&Schema::default(), &mut u128_1, &mut u128_2, &row)
+            evaluate_add(&Schema::default(), &mut u128_1, &mut u128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::U128((Wrapping(u128_num1) + Wrapping(u128_num2)).0)
         );
         assert_eq!(
             // U128 - U128 = U128
-            evaluate_sub(&Schema::default(), &mut u128_1, &mut u128_2, &row)
+            evaluate_sub(&Schema::default(), &mut u128_1, &mut u128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::U128((Wrapping(u128_num1) - Wrapping(u128_num2)).0)
         );
         assert_eq!(
             // U128 * U128 = U128
-            evaluate_mul(&Schema::default(), &mut u128_2, &mut u128_1, &row)
+            evaluate_mul(&Schema::default(), &mut u128_2, &mut u128_1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::U128((Wrapping(u128_num2) * Wrapping(u128_num1)).0)
         );
         assert_eq!(
             // U128 / U128 = Float
-            evaluate_div(&Schema::default(), &mut u128_2, &mut u128_1, &row)
+            evaluate_div(&Schema::default(), &mut u128_2, &mut u128_1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f64::from_u128(u128_num2).unwrap() / f64::from_u128(u128_num1).unwrap()))
         );
         assert_eq!(
             // U128 % U128 = U128
-            evaluate_mod(&Schema::default(), &mut u128_1, &mut u128_2, &row)
+            evaluate_mod(&Schema::default(), &mut u128_1, &mut u128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::U128((Wrapping(u128_num1) % Wrapping(u128_num2)).0)
         );
@@ -385,31 +385,31 @@ fn test_u128_math() {
         //// left: U128, right: Int
         assert_eq!(
             // U128 + Int = I128
-            evaluate_add(&Schema::default(), &mut u128_1, &mut int2, &row)
+            evaluate_add(&Schema::default(), &mut u128_1, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(u128_num1 as i128) + Wrapping(i_num2 as i128)).0)
         );
         assert_eq!(
             // U128 - Int = I128
-            evaluate_sub(&Schema::default(), &mut u128_1, &mut int2, &row)
+            evaluate_sub(&Schema::default(), &mut u128_1, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(u128_num1 as i128) - Wrapping(i_num2 as i128)).0)
         );
         assert_eq!(
             // U128 * Int = I128
-            evaluate_mul(&Schema::default(), &mut u128_2, &mut int1, &row)
+            evaluate_mul(&Schema::default(), &mut u128_2, &mut int1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(u128_num2 as i128) * Wrapping(i_num1 as i128)).0)
         );
         assert_eq!(
             // U128 / Int = Float
-            evaluate_div(&Schema::default(), &mut u128_2, &mut int1, &row)
+            evaluate_div(&Schema::default(), &mut u128_2, &mut int1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f64::from_u128(u128_num2).unwrap() / f64::from_i64(i_num1).unwrap()))
         );
         assert_eq!(
             // U128 % Int = I128
-            evaluate_mod(&Schema::default(), &mut u128_1, &mut int2, &row)
+            evaluate_mod(&Schema::default(), &mut u128_1, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(u128_num1 as i128) % Wrapping(i_num2 as i128)).0)
         );
@@ -417,103 +417,103 @@ fn test_u128_math() {
         //// left: U128, right: I128
         assert_eq!(
             // U128 + I128 = I128
-            evaluate_add(&Schema::default(), &mut u128_1, &mut i128_2, &row)
+            evaluate_add(&Schema::default(), &mut u128_1, &mut i128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(u128_num1 as i128) + Wrapping(i128_num2)).0)
         );
         assert_eq!(
             // U128 - I128 = I128
-            evaluate_sub(&Schema::default(), &mut u128_1, &mut i128_2, &row)
+            evaluate_sub(&Schema::default(), &mut u128_1, &mut i128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(u128_num1 as i128) - Wrapping(i128_num2)).0)
         );
         assert_eq!(
             // U128 * I128 = I128
-            evaluate_mul(&Schema::default(), &mut u128_2, &mut i128_1, &row)
+            evaluate_mul(&Schema::default(), &mut u128_2, &mut i128_1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(u128_num2 as i128) * Wrapping(i128_num1)).0)
         );
         assert_eq!(
             // U128 / I128 = Float
-            evaluate_div(&Schema::default(), &mut u128_2, &mut i128_1, &row)
+            evaluate_div(&Schema::default(), &mut u128_2, &mut i128_1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f64::from_u128(u128_num2).unwrap() / f64::from_i128(i128_num1).unwrap()))
         );
         assert_eq!(
             // U128 % I128 = I128
-            evaluate_mod(&Schema::default(), &mut u128_1, &mut i128_2, &row)
+            evaluate_mod(&Schema::default(), &mut u128_1, &mut i128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(u128_num1 as i128) % Wrapping(i128_num2)).0)
         );
 
         //// left: U128, right: Float
-        let res = evaluate_add(&Schema::default(), &mut u128_1, &mut float2, &row);
+        let res = evaluate_add(&Schema::default(), &mut u128_1, &mut float2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // U128 + Float = Float
-                evaluate_add(&Schema::default(), &mut u128_1, &mut float2, &row)
+                evaluate_add(&Schema::default(), &mut u128_1, &mut float2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_u128(u128_num1).unwrap() + f_num2))
             );
         }
-        let res = evaluate_sub(&Schema::default(), &mut u128_1, &mut float2, &row);
+        let res = evaluate_sub(&Schema::default(), &mut u128_1, &mut float2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // U128 - Float = Float
-                evaluate_sub(&Schema::default(), &mut u128_1, &mut float2, &row)
+                evaluate_sub(&Schema::default(), &mut u128_1, &mut float2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_u128(u128_num1).unwrap() - f_num2))
             );
         }
-        let res = evaluate_mul(&Schema::default(), &mut u128_2, &mut float1, &row);
+        let res = evaluate_mul(&Schema::default(), &mut u128_2, &mut float1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // U128 * Float = Float
-                evaluate_mul(&Schema::default(), &mut u128_2, &mut float1, &row)
+                evaluate_mul(&Schema::default(), &mut u128_2, &mut float1, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_u128(u128_num2).unwrap() * f_num1))
             );
         }
-        let res = evaluate_div(&Schema::default(), &mut u128_2, &mut float1, &row);
+        let res = evaluate_div(&Schema::default(), &mut u128_2, &mut float1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // U128 / Float = Float
-                evaluate_div(&Schema::default(), &mut u128_2, &mut float1, &row)
+                evaluate_div(&Schema::default(), &mut u128_2, &mut float1, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_u128(u128_num2).unwrap() / f_num1))
             );
         }
-        let res = evaluate_mod(&Schema::default(), &mut u128_1, &mut float2, &row);
+        let res = evaluate_mod(&Schema::default(), &mut u128_1, &mut float2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // U128 % Float = Float
-                evaluate_mod(&Schema::default(), &mut u128_1, &mut float2, &row)
+                evaluate_mod(&Schema::default(), &mut u128_1, &mut float2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_u128(u128_num1).unwrap() % f_num2))
             );
         }
 
         //// left: U128, right: Decimal
-        let res = evaluate_add(&Schema::default(), &mut u128_1, &mut dec2, &row);
+        let res = evaluate_add(&Schema::default(), &mut u128_1, &mut dec2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // U128 + Decimal = Decimal
-                evaluate_add(&Schema::default(), &mut u128_1, &mut dec2, &row)
+                evaluate_add(&Schema::default(), &mut u128_1, &mut dec2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Decimal(Decimal::from_u128(u128_num1).unwrap() + d_num2.0)
             );
         }
-        let res = evaluate_sub(&Schema::default(), &mut u128_1, &mut dec2, &row);
+        let res = evaluate_sub(&Schema::default(), &mut u128_1, &mut dec2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // U128 - Decimal = Decimal
-                evaluate_sub(&Schema::default(), &mut u128_1, &mut dec2, &row)
+                evaluate_sub(&Schema::default(), &mut u128_1, &mut dec2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Decimal(Decimal::from_u128(u128_num1).unwrap() - d_num2.0)
             );
         }
         // U128 * Decimal = Decimal
-        let res = evaluate_mul(&Schema::default(), &mut u128_2, &mut dec1, &row);
+        let res = evaluate_mul(&Schema::default(), &mut u128_2, &mut dec1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
              assert_eq!(
                 res.unwrap(), Field::Decimal(Decimal::from_u128(u128_num2).unwrap().checked_mul(d_num1.0).unwrap())
@@ -528,7 +528,7 @@ fn test_u128_math() {
             }
         }
         // U128 / Decimal = Decimal
-        let res = evaluate_div(&Schema::default(), &mut u128_2, &mut dec1, &row);
+        let res = evaluate_div(&Schema::default(), &mut u128_2, &mut dec1, &row, NumericOverflow::Wrap, None);
         if d_num1.0 == Decimal::new(0, 0) {
             assert!(res.is_err());
             if !matches!(res, Err(PipelineError::UnableToCast(_, _))) {
@@ -552,7 +552,7 @@ fn test_u128_math() {
             }
         }
         // U128 % Decimal = Decimal
-        let res = evaluate_mod(&Schema::default(), &mut u128_1, &mut dec1, &row);
+        let res = evaluate_mod(&Schema::default(), &mut u128_1, &mut dec1, &row, NumericOverflow::Wrap, None);
         if d_num1.0 == Decimal::new(0, 0) {
             assert!(res.is_err());
             if !matches!(res, Err(PipelineError::UnableToCast(_, _))) {
@@ -579,31 +579,31 @@ fn test_u128_math() {
         //// left: U128, right: Null
         assert_eq!(
             // U128 + Null = Null
-            evaluate_add(&Schema::default(), &mut u128_1, &mut null, &row)
+            evaluate_add(&Schema::default(), &mut u128_1, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // U128 - Null = Null
-            evaluate_sub(&Schema::default(), &mut u128_1, &mut null, &row)
+            evaluate_sub(&Schema::default(), &mut u128_1, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // U128 * Null = Null
-            evaluate_mul(&Schema::default(), &mut u128_2, &mut null, &row)
+            evaluate_mul(&Schema::default(), &mut u128_2, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // U128 / Null = Null
-            evaluate_div(&Schema::default(), &mut u128_2, &mut null, &row)
+            evaluate_div(&Schema::default(), &mut u128_2, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // U128 % Null = Null
-            evaluate_mod(&Schema::default(), &mut u128_1, &mut null, &row)
+            evaluate_mod(&Schema::default(), &mut u128_1, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
@@ -633,31 +633,31 @@ fn test_int_math() {
         //// left: Int, right: UInt
         assert_eq!(
             // Int + UInt = Int
-            evaluate_add(&Schema::default(), &mut int1, &mut uint2, &row)
+            evaluate_add(&Schema::default(), &mut int1, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Int((Wrapping(i_num1) + Wrapping(u_num2 as i64)).0)
         );
         assert_eq!(
             // Int - UInt = Int
-            evaluate_sub(&Schema::default(), &mut int1, &mut uint2, &row)
+            evaluate_sub(&Schema::default(), &mut int1, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Int((Wrapping(i_num1) - Wrapping(u_num2 as i64)).0)
         );
         assert_eq!(
             // Int * UInt = Int
-            evaluate_mul(&Schema::default(), &mut int2, &mut uint1, &row)
+            evaluate_mul(&Schema::default(), &mut int2, &mut uint1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Int((Wrapping(i_num2) * Wrapping(u_num1 as i64)).0)
         );
         assert_eq!(
             // Int / UInt = Float
-            evaluate_div(&Schema::default(), &mut int2, &mut uint1, &row)
+            evaluate_div(&Schema::default(), &mut int2, &mut uint1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f64::from_i64(i_num2).unwrap() / f64::from_u64(u_num1).unwrap()))
         );
         assert_eq!(
             // Int % UInt = Int
-            evaluate_mod(&Schema::default(), &mut int1, &mut uint2, &row)
+            evaluate_mod(&Schema::default(), &mut int1, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Int((Wrapping(i_num1) % Wrapping(u_num2 as i64)).0)
         );
@@ -665,33 +665,33 @@ fn test_int_math() {
         //// left: Int, right: U128
         assert_eq!(
             // Int + U128 = I128
-            evaluate_add(&Schema::default(), &mut int1, &mut u128_2, &row)
+            evaluate_add(&Schema::default(), &mut int1, &mut u128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i_num1 as i128) + Wrapping(u128_num2 as i128)).0)
         );
         assert_eq!(
             // Int - U128 = I128
-            evaluate_sub(&Schema::default(), &mut int1, &mut u128_2, &row)
+            evaluate_sub(&Schema::default(), &mut int1, &mut u128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i_num1 as i128) - Wrapping(u128_num2 as i128)).0)
         );
         assert_eq!(
             // Int * U128 = I128
-            evaluate_mul(&Schema::default(), &mut int2, &mut u128_1, &row)
+            evaluate_mul(&Schema::default(), &mut int2, &mut u128_1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i_num2 as i128) * Wrapping(u128_num1 as i128)).0)
         );
-        let res = evaluate_div(&Schema::default(), &mut int2, &mut u128_1, &row);
+        let res = evaluate_div(&Schema::default(), &mut int2, &mut u128_1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Int / U128 = Float
-                evaluate_div(&Schema::default(), &mut int2, &mut u128_1, &row).unwrap_or_else(|e| panic!("{}", e.to_string())),
+                evaluate_div(&Schema::default(), &mut int2, &mut u128_1, &row, NumericOverflow::Wrap, None).unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_i128(i_num2 as i128).unwrap() / f64::from_i128(u128_num1 as i128).unwrap()))
             );
         }
         assert_eq!(
             // Int % U128 = I128
-            evaluate_mod(&Schema::default(), &mut int1, &mut u128_2, &row)
+            evaluate_mod(&Schema::default(), &mut int1, &mut u128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i_num1 as i128) % Wrapping(u128_num2 as i128)).0)
         );
@@ -699,31 +699,31 @@ fn test_int_math() {
         //// left: Int, right: Int
         assert_eq!(
             // Int + Int = Int
-            evaluate_add(&Schema::default(), &mut int1, &mut int2, &row)
+            evaluate_add(&Schema::default(), &mut int1, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Int((Wrapping(i_num1) + Wrapping(i_num2)).0)
         );
         assert_eq!(
             // Int - Int = Int
-            evaluate_sub(&Schema::default(), &mut int1, &mut int2, &row)
+            evaluate_sub(&Schema::default(), &mut int1, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Int((Wrapping(i_num1) - Wrapping(i_num2)).0)
         );
         assert_eq!(
             // Int * Int = Int
-            evaluate_mul(&Schema::default(), &mut int2, &mut int1, &row)
+            evaluate_mul(&Schema::default(), &mut int2, &mut int1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Int((Wrapping(i_num2) * Wrapping(i_num1)).0)
         );
         assert_eq!(
             // Int / Int = Float
-            evaluate_div(&Schema::default(), &mut int2, &mut int1, &row)
+            evaluate_div(&Schema::default(), &mut int2, &mut int1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f64::from_i64(i_num2).unwrap() / f64::from_i64(i_num1).unwrap()))
         );
         assert_eq!(
             // Int % Int = Int
-            evaluate_mod(&Schema::default(), &mut int1, &mut int2, &row)
+            evaluate_mod(&Schema::default(), &mut int1, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Int((Wrapping(i_num1) % Wrapping(i_num2)).0)
         );
@@ -731,34 +731,34 @@ fn test_int_math() {
         //// left: Int, right: I128
         assert_eq!(
             // Int + I128 = I128
-            evaluate_add(&Schema::default(), &mut int1, &mut i128_2, &row)
+            evaluate_add(&Schema::default(), &mut int1, &mut i128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i_num1 as i128) + Wrapping(i128_num2)).0)
         );
         assert_eq!(
             // Int - I128 = I128
-            evaluate_sub(&Schema::default(), &mut int1, &mut i128_2, &row)
+            evaluate_sub(&Schema::default(), &mut int1, &mut i128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i_num1 as i128) - Wrapping(i128_num2)).0)
         );
         assert_eq!(
             // Int * I128 = I128
-            evaluate_mul(&Schema::default(), &mut int2, &mut i128_1, &row)
+            evaluate_mul(&Schema::default(), &mut int2, &mut i128_1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i_num2 as i128) * Wrapping(i128_num1)).0)
         );
-        let res = evaluate_div(&Schema::default(), &mut int2, &mut i128_1, &row);
+        let res = evaluate_div(&Schema::default(), &mut int2, &mut i128_1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Int / I128 = Float
-                evaluate_div(&Schema::default(), &mut int2, &mut i128_1, &row)
+                evaluate_div(&Schema::default(), &mut int2, &mut i128_1, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_i64(i_num2).unwrap() / f64::from_i128(i128_num1).unwrap()))
             );
         }
         assert_eq!(
             // Int % I128 = I128
-            evaluate_mod(&Schema::default(), &mut int1, &mut i128_2, &row)
+            evaluate_mod(&Schema::default(), &mut int1, &mut i128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i_num1 as i128) % Wrapping(i128_num2)).0)
         );
@@ -766,26 +766,26 @@ fn test_int_math() {
         //// left: Int, right: Float
         assert_eq!(
             // Int + Float = Float
-            evaluate_add(&Schema::default(), &mut int1, &mut float2, &row)
+            evaluate_add(&Schema::default(), &mut int1, &mut float2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f64::from_i64(i_num1).unwrap() + f_num2))
         );
         assert_eq!(
             // Int - Float = Float
-            evaluate_sub(&Schema::default(), &mut int1, &mut float2, &row)
+            evaluate_sub(&Schema::default(), &mut int1, &mut float2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f64::from_i64(i_num1).unwrap() - f_num2))
         );
         assert_eq!(
             // Int * Float = Float
-            evaluate_mul(&Schema::default(), &mut int2, &mut float1, &row)
+            evaluate_mul(&Schema::default(), &mut int2, &mut float1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f64::from_i64(i_num2).unwrap() * f_num1))
         );
         if *float1 != Literal(Field::Float(OrderedFloat(0_f64))) {
             assert_eq!(
                 // Int / Float = Float
-                evaluate_div(&Schema::default(), &mut int2, &mut float1, &row)
+                evaluate_div(&Schema::default(), &mut int2, &mut float1, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_i64(i_num2).unwrap() / f_num1))
             );
@@ -793,7 +793,7 @@ fn test_int_math() {
         if *float2 != Literal(Field::Float(OrderedFloat(0_f64))) {
             assert_eq!(
                 // Int % Float = Float
-                evaluate_mod(&Schema::default(), &mut int1, &mut float2, &row)
+                evaluate_mod(&Schema::default(), &mut int1, &mut float2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_i64(i_num1).unwrap() % f_num2))
             );
@@ -802,18 +802,18 @@ fn test_int_math() {
         //// left: Int, right: Decimal
         assert_eq!(
             // Int + Decimal = Decimal
-            evaluate_add(&Schema::default(), &mut int1, &mut dec2, &row)
+            evaluate_add(&Schema::default(), &mut int1, &mut dec2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Decimal(Decimal::from_i64(i_num1).unwrap() + d_num2.0)
         );
         assert_eq!(
             // Int - Decimal = Decimal
-            evaluate_sub(&Schema::default(), &mut int1, &mut dec2, &row)
+            evaluate_sub(&Schema::default(), &mut int1, &mut dec2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Decimal(Decimal::from_i64(i_num1).unwrap() - d_num2.0)
         );
         // Int * Decimal = Decimal
-        let res = evaluate_mul(&Schema::default(), &mut int2, &mut dec1, &row);
+        let res = evaluate_mul(&Schema::default(), &mut int2, &mut dec1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
              assert_eq!(
                 res.unwrap(), Field::Decimal(Decimal::from_i64(i_num2).unwrap().checked_mul(d_num1.0).unwrap())
@@ -826,7 +826,7 @@ fn test_int_math() {
             ));
         }
         // Int / Decimal = Decimal
-        let res = evaluate_div(&Schema::default(), &mut int2, &mut dec1, &row);
+        let res = evaluate_div(&Schema::default(), &mut int2, &mut dec1, &row, NumericOverflow::Wrap, None);
         if d_num1.0 == Decimal::new(0, 0) {
             assert!(res.is_err());
             assert!(matches!(
@@ -846,7 +846,7 @@ fn test_int_math() {
             ));
         }
         // Int % Decimal = Decimal
-        let res = evaluate_mod(&Schema::default(), &mut int1, &mut dec2, &row);
+        let res = evaluate_mod(&Schema::default(), &mut int1, &mut dec2, &row, NumericOverflow::Wrap, None);
         if d_num1.0 == Decimal::new(0, 0) {
             assert!(res.is_err());
             assert!(matches!(
@@ -869,31 +869,31 @@ fn test_int_math() {
         //// left: Int, right: Null
         assert_eq!(
             // Int + Null = Null
-            evaluate_add(&Schema::default(), &mut int1, &mut null, &row)
+            evaluate_add(&Schema::default(), &mut int1, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Int - Null = Null
-            evaluate_sub(&Schema::default(), &mut int1, &mut null, &row)
+            evaluate_sub(&Schema::default(), &mut int1, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Int * Null = Null
-            evaluate_mul(&Schema::default(), &mut int2, &mut null, &row)
+            evaluate_mul(&Schema::default(), &mut int2, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Int / Null = Null
-            evaluate_div(&Schema::default(), &mut int2, &mut null, &row)
+            evaluate_div(&Schema::default(), &mut int2, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Int % Null = Null
-            evaluate_mod(&Schema::default(), &mut int1, &mut null, &row)
+            evaluate_mod(&Schema::default(), &mut int1, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
@@ -923,34 +923,34 @@ fn test_i128_math() {
         //// left: I128, right: UInt
         assert_eq!(
             // I128 + UInt = I128
-            evaluate_add(&Schema::default(), &mut i128_1, &mut uint2, &row)
+            evaluate_add(&Schema::default(), &mut i128_1, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i128_num1) + Wrapping(u_num2 as i128)).0)
         );
         assert_eq!(
             // I128 - UInt = I128
-            evaluate_sub(&Schema::default(), &mut i128_1, &mut uint2, &row)
+            evaluate_sub(&Schema::default(), &mut i128_1, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i128_num1) - Wrapping(u_num2 as i128)).0)
         );
         assert_eq!(
             // I128 * UInt = I128
-            evaluate_mul(&Schema::default(), &mut i128_2, &mut uint1, &row)
+            evaluate_mul(&Schema::default(), &mut i128_2, &mut uint1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i128_num2) * Wrapping(u_num1 as i128)).0)
         );
-        let res = evaluate_div(&Schema::default(), &mut i128_2, &mut uint1, &row);
+        let res = evaluate_div(&Schema::default(), &mut i128_2, &mut uint1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // I128 / UInt = Float
-                evaluate_div(&Schema::default(), &mut i128_2, &mut uint1, &row)
+                evaluate_div(&Schema::default(), &mut i128_2, &mut uint1, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_i128(i128_num2).unwrap() / f64::from_u64(u_num1).unwrap()))
             );
         }
         assert_eq!(
             // I128 % UInt = I128
-            evaluate_mod(&Schema::default(), &mut i128_1, &mut uint2, &row)
+            evaluate_mod(&Schema::default(), &mut i128_1, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i128_num1) % Wrapping(u_num2 as i128)).0)
         );
@@ -958,33 +958,33 @@ fn test_i128_math() {
         //// left: I128, right: U128
         assert_eq!(
             // I128 + U128 = I128
-            evaluate_add(&Schema::default(), &mut i128_1, &mut u128_2, &row)
+            evaluate_add(&Schema::default(), &mut i128_1, &mut u128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i128_num1) + Wrapping(u128_num2 as i128)).0)
         );
         assert_eq!(
             // I128 - U128 = I128
-            evaluate_sub(&Schema::default(), &mut i128_1, &mut u128_2, &row)
+            evaluate_sub(&Schema::default(), &mut i128_1, &mut u128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i128_num1) - Wrapping(u128_num2 as i128)).0)
         );
         assert_eq!(
             // I128 * U128 = I128
-            evaluate_mul(&Schema::default(), &mut i128_2, &mut u128_1, &row)
+            evaluate_mul(&Schema::default(), &mut i128_2, &mut u128_1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i128_num2) * Wrapping(u128_num1 as i128)).0)
         );
-        let res = evaluate_div(&Schema::default(), &mut i128_2, &mut u128_1, &row);
+        let res = evaluate_div(&Schema::default(), &mut i128_2, &mut u128_1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // I128 / U128 = Float
-                evaluate_div(&Schema::default(), &mut i128_2, &mut u128_1, &row).unwrap_or_else(|e| panic!("{}", e.to_string())),
+                evaluate_div(&Schema::default(), &mut i128_2, &mut u128_1, &row, NumericOverflow::Wrap, None).unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_i128(i128_num2).unwrap() / f64::from_i128(u128_num1 as i128).unwrap()))
             );
         }
         assert_eq!(
             // I128 % U128 = I128
-            evaluate_mod(&Schema::default(), &mut i128_1, &mut u128_2, &row)
+            evaluate_mod(&Schema::default(), &mut i128_1, &mut u128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i128_num1) % Wrapping(u128_num2 as i128)).0)
         );
@@ -992,34 +992,34 @@ fn test_i128_math() {
         //// left: I128, right: Int
         assert_eq!(
             // I128 + Int = I128
-            evaluate_add(&Schema::default(), &mut i128_1, &mut int2, &row)
+            evaluate_add(&Schema::default(), &mut i128_1, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i128_num1) + Wrapping(i_num2 as i128)).0)
         );
         assert_eq!(
             // I128 - Int = I128
-            evaluate_sub(&Schema::default(), &mut i128_1, &mut int2, &row)
+            evaluate_sub(&Schema::default(), &mut i128_1, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i128_num1) - Wrapping(i_num2 as i128)).0)
         );
         assert_eq!(
             // I128 * Int = I128
-            evaluate_mul(&Schema::default(), &mut i128_2, &mut int1, &row)
+            evaluate_mul(&Schema::default(), &mut i128_2, &mut int1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i128_num2) * Wrapping(i_num1 as i128)).0)
         );
-        let res = evaluate_div(&Schema::default(), &mut i128_2, &mut int1, &row);
+        let res = evaluate_div(&Schema::default(), &mut i128_2, &mut int1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // I128 / Int = Float
-                evaluate_div(&Schema::default(), &mut i128_2, &mut int1, &row)
+                evaluate_div(&Schema::default(), &mut i128_2, &mut int1, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_i128(i128_num2).unwrap() / f64::from_i64(i_num1).unwrap()))
             );
         }
         assert_eq!(
             // I128 % Int = I128
-            evaluate_mod(&Schema::default(), &mut i128_1, &mut int2, &row)
+            evaluate_mod(&Schema::default(), &mut i128_1, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i128_num1) % Wrapping(i_num2 as i128)).0)
         );
@@ -1027,106 +1027,106 @@ fn test_i128_math() {
         //// left: I128, right: I128
         assert_eq!(
             // I128 + I128 = I128
-            evaluate_add(&Schema::default(), &mut i128_1, &mut i128_2, &row)
+            evaluate_add(&Schema::default(), &mut i128_1, &mut i128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i128_num1) + Wrapping(i128_num2)).0)
         );
         assert_eq!(
             // I128 - I128 = I128
-            evaluate_sub(&Schema::default(), &mut i128_1, &mut i128_2, &row)
+            evaluate_sub(&Schema::default(), &mut i128_1, &mut i128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i128_num1) - Wrapping(i128_num2)).0)
         );
         assert_eq!(
             // I128 * I128 = I128
-            evaluate_mul(&Schema::default(), &mut i128_2, &mut i128_1, &row)
+            evaluate_mul(&Schema::default(), &mut i128_2, &mut i128_1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i128_num2) * Wrapping(i128_num1)).0)
         );
-        let res = evaluate_div(&Schema::default(), &mut i128_2, &mut i128_1, &row);
+        let res = evaluate_div(&Schema::default(), &mut i128_2, &mut i128_1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // I128 / I128 = Float
-                evaluate_div(&Schema::default(), &mut i128_2, &mut i128_1, &row)
+                evaluate_div(&Schema::default(), &mut i128_2, &mut i128_1, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_i128(i128_num2).unwrap() / f64::from_i128(i128_num1).unwrap()))
             );
         }
         assert_eq!(
             // I128 % I128 = I128
-            evaluate_mod(&Schema::default(), &mut i128_1, &mut i128_2, &row)
+            evaluate_mod(&Schema::default(), &mut i128_1, &mut i128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::I128((Wrapping(i128_num1) % Wrapping(i128_num2)).0)
         );
 
         //// left: I128, right: Float
-        let res = evaluate_add(&Schema::default(), &mut i128_1, &mut float2, &row);
+        let res = evaluate_add(&Schema::default(), &mut i128_1, &mut float2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // I128 + Float = Float
-                evaluate_add(&Schema::default(), &mut i128_1, &mut float2, &row)
+                evaluate_add(&Schema::default(), &mut i128_1, &mut float2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_i128(i128_num1).unwrap() + f_num2))
             );
         }
-        let res = evaluate_sub(&Schema::default(), &mut i128_1, &mut float2, &row);
+        let res = evaluate_sub(&Schema::default(), &mut i128_1, &mut float2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // I128 - Float = Float
-                evaluate_sub(&Schema::default(), &mut i128_1, &mut float2, &row)
+                evaluate_sub(&Schema::default(), &mut i128_1, &mut float2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_i128(i128_num1).unwrap() - f_num2))
             );
         }
-        let res = evaluate_mul(&Schema::default(), &mut i128_2, &mut float1, &row);
+        let res = evaluate_mul(&Schema::default(), &mut i128_2, &mut float1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // I128 * Float = Float
-                evaluate_mul(&Schema::default(), &mut i128_2, &mut float1, &row)
+                evaluate_mul(&Schema::default(), &mut i128_2, &mut float1, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_i128(i128_num2).unwrap() * f_num1))
             );
         }
-        let res = evaluate_div(&Schema::default(), &mut i128_2, &mut float1, &row);
+        let res = evaluate_div(&Schema::default(), &mut i128_2, &mut float1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // I128 / Float = Float
-                evaluate_div(&Schema::default(), &mut i128_2, &mut float1, &row)
+                evaluate_div(&Schema::default(), &mut i128_2, &mut float1, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_i128(i128_num2).unwrap() / f_num1))
             );
         }
-        let res = evaluate_mod(&Schema::default(), &mut i128_1, &mut float2, &row);
+        let res = evaluate_mod(&Schema::default(), &mut i128_1, &mut float2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // I128 % Float = Float
-                evaluate_mod(&Schema::default(), &mut i128_1, &mut float2, &row)
+                evaluate_mod(&Schema::default(), &mut i128_1, &mut float2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f64::from_i128(i128_num1).unwrap() % f_num2))
             );
         }
 
         //// left: I128, right: Decimal
-        let res = evaluate_add(&Schema::default(), &mut i128_1, &mut dec2, &row);
+        let res = evaluate_add(&Schema::default(), &mut i128_1, &mut dec2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // I128 + Decimal = Decimal
-                evaluate_add(&Schema::default(), &mut i128_1, &mut dec2, &row)
+                evaluate_add(&Schema::default(), &mut i128_1, &mut dec2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Decimal(Decimal::from_i128(i128_num1).unwrap() + d_num2.0)
             );
         }
-        let res = evaluate_sub(&Schema::default(), &mut i128_1, &mut dec2, &row);
+        let res = evaluate_sub(&Schema::default(), &mut i128_1, &mut dec2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // I128 - Decimal = Decimal
-                evaluate_sub(&Schema::default(), &mut i128_1, &mut dec2, &row)
+                evaluate_sub(&Schema::default(), &mut i128_1, &mut dec2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Decimal(Decimal::from_i128(i128_num1).unwrap() - d_num2.0)
             );
         }
         // I128 * Decimal = Decimal
-        let res = evaluate_mul(&Schema::default(), &mut i128_2, &mut dec1, &row);
+        let res = evaluate_mul(&Schema::default(), &mut i128_2, &mut dec1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
              assert_eq!(
                 res.unwrap(), Field::Decimal(Decimal::from_i128(i128_num2).unwrap().checked_mul(d_num1.0).unwrap())
@@ -1141,7 +1141,7 @@ fn test_i128_math() {
             }
         }
         // I128 / Decimal = Decimal
-        let res = evaluate_div(&Schema::default(), &mut i128_2, &mut dec1, &row);
+        let res = evaluate_div(&Schema::default(), &mut i128_2, &mut dec1, &row, NumericOverflow::Wrap, None);
         if d_num1.0 == Decimal::new(0, 0) {
             assert!(res.is_err());
             if !matches!(res, Err(PipelineError::UnableToCast(_, _))) {
@@ -1165,7 +1165,7 @@ fn test_i128_math() {
             }
         }
         // I128 % Decimal = Decimal
-        let res = evaluate_mod(&Schema::default(), &mut i128_1, &mut dec2, &row);
+        let res = evaluate_mod(&Schema::default(), &mut i128_1, &mut dec2, &row, NumericOverflow::Wrap, None);
         if d_num1.0 == Decimal::new(0, 0) {
             assert!(res.is_err());
             if !matches!(res, Err(PipelineError::UnableToCast(_, _))) {
@@ -1192,31 +1192,31 @@ fn test_i128_math() {
         //// left: I128, right: Null
         assert_eq!(
             // I128 + Null = Null
-            evaluate_add(&Schema::default(), &mut i128_1, &mut null, &row)
+            evaluate_add(&Schema::default(), &mut i128_1, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // I128 - Null = Null
-            evaluate_sub(&Schema::default(), &mut i128_1, &mut null, &row)
+            evaluate_sub(&Schema::default(), &mut i128_1, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // I128 * Null = Null
-            evaluate_mul(&Schema::default(), &mut i128_2, &mut null, &row)
+            evaluate_mul(&Schema::default(), &mut i128_2, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // I128 / Null = Null
-            evaluate_div(&Schema::default(), &mut i128_2, &mut null, &row)
+            evaluate_div(&Schema::default(), &mut i128_2, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // I128 % Null = Null
-            evaluate_mod(&Schema::default(), &mut i128_1, &mut null, &row)
+            evaluate_mod(&Schema::default(), &mut i128_1, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
@@ -1246,77 +1246,77 @@ fn test_float_math() {
         //// left: Float, right: UInt
         assert_eq!(
             // Float + UInt = Float
-            evaluate_add(&Schema::default(), &mut float1, &mut uint2, &row)
+            evaluate_add(&Schema::default(), &mut float1, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f_num1) + OrderedFloat(f64::from_u64(u_num2).unwrap()))
         );
         assert_eq!(
             // Float - UInt = Float
-            evaluate_sub(&Schema::default(), &mut float1, &mut uint2, &row)
+            evaluate_sub(&Schema::default(), &mut float1, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f_num1) - OrderedFloat(f64::from_u64(u_num2).unwrap()))
         );
         assert_eq!(
             // Float * UInt = Float
-            evaluate_mul(&Schema::default(), &mut float2, &mut uint1, &row)
+            evaluate_mul(&Schema::default(), &mut float2, &mut uint1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f_num2) * OrderedFloat(f64::from_u64(u_num1).unwrap()))
         );
         assert_eq!(
             // Float / UInt = Float
-            evaluate_div(&Schema::default(), &mut float2, &mut uint1, &row)
+            evaluate_div(&Schema::default(), &mut float2, &mut uint1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f_num2) / OrderedFloat(f64::from_u64(u_num1).unwrap()))
         );
         assert_eq!(
             // Float % UInt = Float
-            evaluate_mod(&Schema::default(), &mut float1, &mut uint2, &row)
+            evaluate_mod(&Schema::default(), &mut float1, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f_num1) % OrderedFloat(f64::from_u64(u_num2).unwrap()))
         );
 
         //// left: Float, right: U128
-        let res = evaluate_add(&Schema::default(), &mut float1, &mut u128_2, &row);
+        let res = evaluate_add(&Schema::default(), &mut float1, &mut u128_2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
            assert_eq!(
                 // Float + U128 = Float
-                evaluate_add(&Schema::default(), &mut float1, &mut u128_2, &row)
+                evaluate_add(&Schema::default(), &mut float1, &mut u128_2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f_num1) + OrderedFloat(f64::from_u128(u128_num2).unwrap()))
             );
         }
-        let res = evaluate_sub(&Schema::default(), &mut float1, &mut u128_2, &row);
+        let res = evaluate_sub(&Schema::default(), &mut float1, &mut u128_2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Float - U128 = Float
-                evaluate_sub(&Schema::default(), &mut float1, &mut u128_2, &row)
+                evaluate_sub(&Schema::default(), &mut float1, &mut u128_2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f_num1) - OrderedFloat(f64::from_u128(u128_num2).unwrap()))
             );
         }
-        let res = evaluate_mul(&Schema::default(), &mut float2, &mut u128_1, &row);
+        let res = evaluate_mul(&Schema::default(), &mut float2, &mut u128_1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Float * U128 = Float
-                evaluate_mul(&Schema::default(), &mut float2, &mut u128_1, &row)
+                evaluate_mul(&Schema::default(), &mut float2, &mut u128_1, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f_num2) * OrderedFloat(f64::from_u128(u128_num1).unwrap()))
             );
         }
-        let res = evaluate_div(&Schema::default(), &mut float2, &mut u128_1, &row);
+        let res = evaluate_div(&Schema::default(), &mut float2, &mut u128_1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Float / U128 = Float
-                evaluate_div(&Schema::default(), &mut float2, &mut u128_1, &row)
+                evaluate_div(&Schema::default(), &mut float2, &mut u128_1, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f_num2) / OrderedFloat(f64::from_u128(u128_num1).unwrap()))
             );
         }
-        let res = evaluate_mod(&Schema::default(), &mut float1, &mut u128_2, &row);
+        let res = evaluate_mod(&Schema::default(), &mut float1, &mut u128_2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Float % U128 = Float
-                evaluate_mod(&Schema::default(), &mut float1, &mut u128_2, &row)
+                evaluate_mod(&Schema::default(), &mut float1, &mut u128_2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f_num1) % OrderedFloat(f64::from_u128(u128_num2).unwrap()))
             );
@@ -1325,77 +1325,77 @@ fn test_float_math() {
         //// left: Float, right: Int
         assert_eq!(
             // Float + Int = Float
-            evaluate_add(&Schema::default(), &mut float1, &mut int2, &row)
+            evaluate_add(&Schema::default(), &mut float1, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f_num1) + OrderedFloat(f64::from_i64(i_num2).unwrap()))
         );
         assert_eq!(
             // Float - Int = Float
-            evaluate_sub(&Schema::default(), &mut float1, &mut int2, &row)
+            evaluate_sub(&Schema::default(), &mut float1, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f_num1) - OrderedFloat(f64::from_i64(i_num2).unwrap()))
         );
         assert_eq!(
             // Float * Int = Float
-            evaluate_mul(&Schema::default(), &mut float2, &mut int1, &row)
+            evaluate_mul(&Schema::default(), &mut float2, &mut int1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f_num2) * OrderedFloat(f64::from_i64(i_num1).unwrap()))
         );
         assert_eq!(
             // Float / Int = Float
-            evaluate_div(&Schema::default(), &mut float2, &mut int1, &row)
+            evaluate_div(&Schema::default(), &mut float2, &mut int1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f_num2) / OrderedFloat(f64::from_i64(i_num1).unwrap()))
         );
         assert_eq!(
             // Float % Int = Float
-            evaluate_mod(&Schema::default(), &mut float1, &mut int2, &row)
+            evaluate_mod(&Schema::default(), &mut float1, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f_num1) % OrderedFloat(f64::from_i64(i_num2).unwrap()))
         );
 
         //// left: Float, right: I128
-        let res = evaluate_add(&Schema::default(), &mut float1, &mut i128_2, &row);
+        let res = evaluate_add(&Schema::default(), &mut float1, &mut i128_2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Float + I128 = Float
-                evaluate_add(&Schema::default(), &mut float1, &mut i128_2, &row)
+                evaluate_add(&Schema::default(), &mut float1, &mut i128_2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f_num1) + OrderedFloat(f64::from_i128(i128_num2).unwrap()))
             );
         }
-        let res = evaluate_sub(&Schema::default(), &mut float1, &mut i128_2, &row);
+        let res = evaluate_sub(&Schema::default(), &mut float1, &mut i128_2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Float - I128 = Float
-                evaluate_sub(&Schema::default(), &mut float1, &mut i128_2, &row)
+                evaluate_sub(&Schema::default(), &mut float1, &mut i128_2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f_num1) - OrderedFloat(f64::from_i128(i128_num2).unwrap()))
             );
         }
-        let res = evaluate_mul(&Schema::default(), &mut float2, &mut i128_1, &row);
+        let res = evaluate_mul(&Schema::default(), &mut float2, &mut i128_1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Float * I128 = Float
-                evaluate_mul(&Schema::default(), &mut float2, &mut i128_1, &row)
+                evaluate_mul(&Schema::default(), &mut float2, &mut i128_1, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f_num2) * OrderedFloat(f64::from_i128(i128_num1).unwrap()))
             );
         }
-        let res = evaluate_div(&Schema::default(), &mut float2, &mut i128_1, &row);
+        let res = evaluate_div(&Schema::default(), &mut float2, &mut i128_1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Float / I128 = Float
-                evaluate_div(&Schema::default(), &mut float2, &mut i128_1, &row)
+                evaluate_div(&Schema::default(), &mut float2, &mut i128_1, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f_num2) / OrderedFloat(f64::from_i128(i128_num1).unwrap()))
             );
         }
-        let res = evaluate_mod(&Schema::default(), &mut float1, &mut i128_2, &row);
+        let res = evaluate_mod(&Schema::default(), &mut float1, &mut i128_2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Float % I128 = Float
-                evaluate_mod(&Schema::default(), &mut float1, &mut i128_2, &row)
+                evaluate_mod(&Schema::default(), &mut float1, &mut i128_2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f_num1) % OrderedFloat(f64::from_i128(i128_num2).unwrap()))
             );
@@ -1404,26 +1404,26 @@ fn test_float_math() {
         //// left: Float, right: Float
         assert_eq!(
             // Float + Float = Float
-            evaluate_add(&Schema::default(), &mut float1, &mut float2, &row)
+            evaluate_add(&Schema::default(), &mut float1, &mut float2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f_num1 + f_num2))
         );
         assert_eq!(
             // Float - Float = Float
-            evaluate_sub(&Schema::default(), &mut float1, &mut float2, &row)
+            evaluate_sub(&Schema::default(), &mut float1, &mut float2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f_num1 - f_num2))
         );
         assert_eq!(
             // Float * Float = Float
-            evaluate_mul(&Schema::default(), &mut float2, &mut float1, &row)
+            evaluate_mul(&Schema::default(), &mut float2, &mut float1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Float(OrderedFloat(f_num2 * f_num1))
         );
         if *float1 != Literal(Field::Float(OrderedFloat(0_f64))) {
             assert_eq!(
                 // Float / Float = Float
-                evaluate_div(&Schema::default(), &mut float2, &mut float1, &row)
+                evaluate_div(&Schema::default(), &mut float2, &mut float1, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f_num2 / f_num1))
             );
@@ -1431,7 +1431,7 @@ fn test_float_math() {
         if *float2 != Literal(Field::Float(OrderedFloat(0_f64))) {
             assert_eq!(
                 // Float % Float = Float
-                evaluate_mod(&Schema::default(), &mut float1, &mut float2, &row)
+                evaluate_mod(&Schema::default(), &mut float1, &mut float2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Float(OrderedFloat(f_num1 % f_num2))
             );
@@ -1443,18 +1443,18 @@ fn test_float_math() {
         if d_val1.is_some() && d_val2.is_some() {
             assert_eq!(
                 // Float + Decimal = Decimal
-                evaluate_add(&Schema::default(), &mut float1, &mut dec2, &row)
+                evaluate_add(&Schema::default(), &mut float1, &mut dec2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Decimal(d_val1.unwrap() + d_num2.0)
             );
             assert_eq!(
                 // Float - Decimal = Decimal
-                evaluate_sub(&Schema::default(), &mut float1, &mut dec2, &row)
+                evaluate_sub(&Schema::default(), &mut float1, &mut dec2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Decimal(d_val1.unwrap() - d_num2.0)
             );
             // Float * Decimal = Decimal
-            let res = evaluate_mul(&Schema::default(), &mut float2, &mut dec1, &row);
+            let res = evaluate_mul(&Schema::default(), &mut float2, &mut dec1, &row, NumericOverflow::Wrap, None);
             if res.is_ok() {
                  assert_eq!(
                     res.unwrap(), Field::Decimal(d_val2.unwrap().checked_mul(d_num1.0).unwrap())
@@ -1467,7 +1467,7 @@ fn test_float_math() {
                 ));
             }
             // Float / Decimal = Decimal
-            let res = evaluate_div(&Schema::default(), &mut float2, &mut dec1, &row);
+            let res = evaluate_div(&Schema::default(), &mut float2, &mut dec1, &row, NumericOverflow::Wrap, None);
             if d_num1.0 == Decimal::new(0, 0) {
                 assert!(res.is_err());
                 assert!(matches!(
@@ -1487,7 +1487,7 @@ fn test_float_math() {
                 ));
             }
             // Float % Decimal = Decimal
-            let res = evaluate_mod(&Schema::default(), &mut float1, &mut dec2, &row);
+            let res = evaluate_mod(&Schema::default(), &mut float1, &mut dec2, &row, NumericOverflow::Wrap, None);
             if d_num1.0 == Decimal::new(0, 0) {
                 assert!(res.is_err());
                 assert!(matches!(
@@ -1511,31 +1511,31 @@ fn test_float_math() {
         //// left: Float, right: Null
         assert_eq!(
             // Float + Null = Null
-            evaluate_add(&Schema::default(), &mut float1, &mut null, &row)
+            evaluate_add(&Schema::default(), &mut float1, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Float - Null = Null
-            evaluate_sub(&Schema::default(), &mut float1, &mut null, &row)
+            evaluate_sub(&Schema::default(), &mut float1, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Float * Null = Null
-            evaluate_mul(&Schema::default(), &mut float2, &mut null, &row)
+            evaluate_mul(&Schema::default(), &mut float2, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Float / Null = Null
-            evaluate_div(&Schema::default(), &mut float2, &mut null, &row)
+            evaluate_div(&Schema::default(), &mut float2, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Float % Null = Null
-            evaluate_mod(&Schema::default(), &mut float1, &mut null, &row)
+            evaluate_mod(&Schema::default(), &mut float1, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
@@ -1565,18 +1565,18 @@ fn test_decimal_math() {
         //// left: Decimal, right: UInt
         assert_eq!(
             // Decimal + UInt = Decimal
-            evaluate_add(&Schema::default(), &mut dec1, &mut uint2, &row)
+            evaluate_add(&Schema::default(), &mut dec1, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Decimal(d_num1.0 + Decimal::from(u_num2))
         );
         assert_eq!(
             // Decimal - UInt = Decimal
-            evaluate_sub(&Schema::default(), &mut dec1, &mut uint2, &row)
+            evaluate_sub(&Schema::default(), &mut dec1, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Decimal(d_num1.0 - Decimal::from(u_num2))
         );
         // Decimal * UInt = Decimal
-        let res = evaluate_mul(&Schema::default(), &mut dec2, &mut uint1, &row);
+        let res = evaluate_mul(&Schema::default(), &mut dec2, &mut uint1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
              assert_eq!(
                 res.unwrap(), Field::Decimal(d_num2.0 * Decimal::from(u_num1))
@@ -1589,7 +1589,7 @@ fn test_decimal_math() {
             ));
         }
         // Decimal / UInt = Decimal
-        let res = evaluate_div(&Schema::default(), &mut dec2, &mut uint1, &row);
+        let res = evaluate_div(&Schema::default(), &mut dec2, &mut uint1, &row, NumericOverflow::Wrap, None);
         if d_num1.0 == Decimal::new(0, 0) {
             assert!(res.is_err());
             assert!(matches!(
@@ -1609,7 +1609,7 @@ fn test_decimal_math() {
             ));
         }
         // Decimal % UInt = Decimal
-        let res = evaluate_mod(&Schema::default(), &mut dec1, &mut uint2, &row);
+        let res = evaluate_mod(&Schema::default(), &mut dec1, &mut uint2, &row, NumericOverflow::Wrap, None);
         if d_num1.0 == Decimal::new(0, 0) {
             assert!(res.is_err());
             assert!(matches!(
@@ -1630,26 +1630,26 @@ fn test_decimal_math() {
         }
 
         //// left: Decimal, right: U128
-        let res = evaluate_add(&Schema::default(), &mut dec1, &mut u128_2, &row);
+        let res = evaluate_add(&Schema::default(), &mut dec1, &mut u128_2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Decimal + U128 = Decimal
-                evaluate_add(&Schema::default(), &mut dec1, &mut u128_2, &row)
+                evaluate_add(&Schema::default(), &mut dec1, &mut u128_2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Decimal(d_num1.0 + Decimal::from_u128(u128_num2).unwrap())
             );
         }
-        let res = evaluate_sub(&Schema::default(), &mut dec1, &mut u128_2, &row);
+        let res = evaluate_sub(&Schema::default(), &mut dec1, &mut u128_2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Decimal - U128 = Decimal
-                evaluate_sub(&Schema::default(), &mut dec1, &mut u128_2, &row)
+                evaluate_sub(&Schema::default(), &mut dec1, &mut u128_2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Decimal(d_num1.0 - Decimal::from_u128(u128_num2).unwrap())
             );
         }
         // Decimal * U128 = Decimal
-        let res = evaluate_mul(&Schema::default(), &mut dec2, &mut u128_1, &row);
+        let res = evaluate_mul(&Schema::default(), &mut dec2, &mut u128_1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
              assert_eq!(
                 res.unwrap(), Field::Decimal(d_num2.0 * Decimal::from_u128(u128_num1).unwrap())
@@ -1664,7 +1664,7 @@ fn test_decimal_math() {
             }
         }
         // Decimal / U128 = Decimal
-        let res = evaluate_div(&Schema::default(), &mut dec2, &mut u128_1, &row);
+        let res = evaluate_div(&Schema::default(), &mut dec2, &mut u128_1, &row, NumericOverflow::Wrap, None);
         if d_num1.0 == Decimal::new(0, 0) {
             assert!(res.is_err());
             if !matches!(res, Err(PipelineError::UnableToCast(_, _))) {
@@ -1688,7 +1688,7 @@ fn test_decimal_math() {
             }
         }
         // Decimal % U128 = Decimal
-        let res = evaluate_mod(&Schema::default(), &mut dec1, &mut u128_2, &row);
+        let res = evaluate_mod(&Schema::default(), &mut dec1, &mut u128_2, &row, NumericOverflow::Wrap, None);
         if d_num1.0 == Decimal::new(0, 0) {
             assert!(res.is_err());
             if !matches!(res, Err(PipelineError::UnableToCast(_, _))) {
@@ -1715,80 +1715,80 @@ fn test_decimal_math() {
         //// left: Decimal, right: Int
         assert_eq!(
             // Decimal + Int = Decimal
-            evaluate_add(&Schema::default(), &mut dec1, &mut int2, &row)
+            evaluate_add(&Schema::default(), &mut dec1, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Decimal(d_num1.0 + Decimal::from(i_num2))
         );
         assert_eq!(
             // Decimal - Int = Decimal
-            evaluate_sub(&Schema::default(), &mut dec1, &mut int2, &row)
+            evaluate_sub(&Schema::default(), &mut dec1, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Decimal(d_num1.0 - Decimal::from(i_num2))
         );
-        let res = evaluate_mul(&Schema::default(), &mut dec2, &mut int1, &row);
+        let res = evaluate_mul(&Schema::default(), &mut dec2, &mut int1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Decimal * Int = Decimal
-                evaluate_mul(&Schema::default(), &mut dec2, &mut int1, &row)
+                evaluate_mul(&Schema::default(), &mut dec2, &mut int1, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Decimal(d_num2.0 * Decimal::from(i_num1))
             );
         }
         assert_eq!(
             // Decimal / Int = Decimal
-            evaluate_div(&Schema::default(), &mut dec2, &mut int1, &row)
+            evaluate_div(&Schema::default(), &mut dec2, &mut int1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Decimal(d_num2.0 / Decimal::from(i_num1))
         );
         assert_eq!(
             // Decimal % Int = Decimal
-            evaluate_mod(&Schema::default(), &mut dec1, &mut int2, &row)
+            evaluate_mod(&Schema::default(), &mut dec1, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Decimal(d_num1.0 % Decimal::from(i_num2))
         );
 
         //// left: Decimal, right: I128
-        let res = evaluate_add(&Schema::default(), &mut dec1, &mut i128_2, &row);
+        let res = evaluate_add(&Schema::default(), &mut dec1, &mut i128_2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Decimal + I128 = Decimal
-                evaluate_add(&Schema::default(), &mut dec1, &mut i128_2, &row)
+                evaluate_add(&Schema::default(), &mut dec1, &mut i128_2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Decimal(d_num1.0 + Decimal::from_i128(i128_num2).unwrap())
             );
         }
-        let res = evaluate_sub(&Schema::default(), &mut dec1, &mut i128_2, &row);
+        let res = evaluate_sub(&Schema::default(), &mut dec1, &mut i128_2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Decimal - I128 = Decimal
-                evaluate_sub(&Schema::default(), &mut dec1, &mut i128_2, &row)
+                evaluate_sub(&Schema::default(), &mut dec1, &mut i128_2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Decimal(d_num1.0 - Decimal::from_i128(i128_num2).unwrap())
             );
         }
-        let res = evaluate_mul(&Schema::default(), &mut dec2, &mut i128_1, &row);
+        let res = evaluate_mul(&Schema::default(), &mut dec2, &mut i128_1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Decimal * I128 = Decimal
-                evaluate_mul(&Schema::default(), &mut dec2, &mut i128_1, &row)
+                evaluate_mul(&Schema::default(), &mut dec2, &mut i128_1, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Decimal(d_num2.0 * Decimal::from_i128(i128_num1).unwrap())
             );
         }
-        let res = evaluate_div(&Schema::default(), &mut dec2, &mut i128_1, &row);
+        let res = evaluate_div(&Schema::default(), &mut dec2, &mut i128_1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Decimal / I128 = Decimal
-                evaluate_div(&Schema::default(), &mut dec2, &mut i128_1, &row)
+                evaluate_div(&Schema::default(), &mut dec2, &mut i128_1, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Decimal(d_num2.0 / Decimal::from_i128(i128_num1).unwrap())
             );
         }
-        let res = evaluate_mod(&Schema::default(), &mut dec1, &mut i128_2, &row);
+        let res = evaluate_mod(&Schema::default(), &mut dec1, &mut i128_2, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
             assert_eq!(
                 // Decimal % I128 = Decimal
-                evaluate_mod(&Schema::default(), &mut dec1, &mut i128_2, &row)
+                evaluate_mod(&Schema::default(), &mut dec1, &mut i128_2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Decimal(d_num1.0 % Decimal::from_i128(i128_num2).unwrap())
             );
@@ -1800,18 +1800,18 @@ fn test_decimal_math() {
         if d_val1.is_some() && d_val2.is_some() && d_val1.unwrap() != Decimal::new(0, 0) && d_val2.unwrap() != Decimal::new(0, 0) {
             assert_eq!(
                 // Decimal + Float = Decimal
-                evaluate_add(&Schema::default(), &mut dec1, &mut float2, &row)
+                evaluate_add(&Schema::default(), &mut dec1, &mut float2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Decimal(d_num1.0 + d_val2.unwrap())
             );
             assert_eq!(
                 // Decimal - Float = Decimal
-                evaluate_sub(&Schema::default(), &mut dec1, &mut float2, &row)
+                evaluate_sub(&Schema::default(), &mut dec1, &mut float2, &row, NumericOverflow::Wrap, None)
                     .unwrap_or_else(|e| panic!("{}", e.to_string())),
                 Field::Decimal(d_num1.0 - d_val2.unwrap())
             );
             // Decimal * Float = Decimal
-            let res = evaluate_mul(&Schema::default(), &mut dec2, &mut float1, &row);
+            let res = evaluate_mul(&Schema::default(), &mut dec2, &mut float1, &row, NumericOverflow::Wrap, None);
             if res.is_ok() {
                  assert_eq!(
                     res.unwrap(), Field::Decimal(d_num2.0 * d_val1.unwrap())
@@ -1824,7 +1824,7 @@ fn test_decimal_math() {
                 ));
             }
             // Decimal / Float = Decimal
-            let res = evaluate_div(&Schema::default(), &mut dec2, &mut float1, &row);
+            let res = evaluate_div(&Schema::default(), &mut dec2, &mut float1, &row, NumericOverflow::Wrap, None);
             if d_num1.0 == Decimal::new(0, 0) {
                 assert!(res.is_err());
                 assert!(matches!(
@@ -1844,7 +1844,7 @@ fn test_decimal_math() {
                 ));
             }
             // Decimal % Float = Decimal
-            let res = evaluate_mod(&Schema::default(), &mut dec1, &mut float2, &row);
+            let res = evaluate_mod(&Schema::default(), &mut dec1, &mut float2, &row, NumericOverflow::Wrap, None);
             if d_num1.0 == Decimal::new(0, 0) {
                 assert!(res.is_err());
                 assert!(matches!(
@@ -1869,18 +1869,18 @@ fn test_decimal_math() {
         //// left: Decimal, right: Decimal
         assert_eq!(
             // Decimal + Decimal = Decimal
-            evaluate_add(&Schema::default(), &mut dec1, &mut dec2, &row)
+            evaluate_add(&Schema::default(), &mut dec1, &mut dec2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Decimal(d_num1.0 + d_num2.0)
         );
         assert_eq!(
             // Decimal - Decimal = Decimal
-            evaluate_sub(&Schema::default(), &mut dec1, &mut dec2, &row)
+            evaluate_sub(&Schema::default(), &mut dec1, &mut dec2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Decimal(d_num1.0 - d_num2.0)
         );
         // Decimal * Decimal = Decimal
-        let res = evaluate_mul(&Schema::default(), &mut dec2, &mut dec1, &row);
+        let res = evaluate_mul(&Schema::default(), &mut dec2, &mut dec1, &row, NumericOverflow::Wrap, None);
         if res.is_ok() {
              assert_eq!(
                 res.unwrap(), Field::Decimal(d_num2.0 * d_num1.0)
@@ -1893,7 +1893,7 @@ fn test_decimal_math() {
             ));
         }
         // Decimal / Decimal = Decimal
-        let res = evaluate_div(&Schema::default(), &mut dec2, &mut dec1, &row);
+        let res = evaluate_div(&Schema::default(), &mut dec2, &mut dec1, &row, NumericOverflow::Wrap, None);
         if d_num1.0 == Decimal::new(0, 0) {
             assert!(res.is_err());
             assert!(matches!(
@@ -1913,7 +1913,7 @@ fn test_decimal_math() {
             ));
         }
         // Decimal % Decimal = Decimal
-        let res = evaluate_mod(&Schema::default(), &mut dec1, &mut dec2, &row);
+        let res = evaluate_mod(&Schema::default(), &mut dec1, &mut dec2, &row, NumericOverflow::Wrap, None);
         if d_num1.0 == Decimal::new(0, 0) {
             assert!(res.is_err());
             assert!(matches!(
@@ -1936,31 +1936,31 @@ fn test_decimal_math() {
         //// left: Decimal, right: Null
         assert_eq!(
             // Decimal + Null = Null
-            evaluate_add(&Schema::default(), &mut dec1, &mut null, &row)
+            evaluate_add(&Schema::default(), &mut dec1, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Decimal - Null = Null
-            evaluate_sub(&Schema::default(), &mut dec1, &mut null, &row)
+            evaluate_sub(&Schema::default(), &mut dec1, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Decimal * Null = Null
-            evaluate_mul(&Schema::default(), &mut dec2, &mut null, &row)
+            evaluate_mul(&Schema::default(), &mut dec2, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Decimal / Null = Null
-            evaluate_div(&Schema::default(), &mut dec2, &mut null, &row)
+            evaluate_div(&Schema::default(), &mut dec2, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Decimal % Null = Null
-            evaluate_mod(&Schema::default(), &mut dec1, &mut null, &row)
+            evaluate_mod(&Schema::default(), &mut dec1, &mut null, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
@@ -1990,31 +1990,31 @@ fn test_null_math() {
         //// left: Null, right: UInt
         assert_eq!(
             // Null + UInt = Null
-            evaluate_add(&Schema::default(), &mut null, &mut uint2, &row)
+            evaluate_add(&Schema::default(), &mut null, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Null - UInt = Null
-            evaluate_sub(&Schema::default(), &mut null, &mut uint2, &row)
+            evaluate_sub(&Schema::default(), &mut null, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Null * UInt = Null
-            evaluate_mul(&Schema::default(), &mut null, &mut uint1, &row)
+            evaluate_mul(&Schema::default(), &mut null, &mut uint1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Decimal / UInt = Null
-            evaluate_div(&Schema::default(), &mut null, &mut uint1, &row)
+            evaluate_div(&Schema::default(), &mut null, &mut uint1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Decimal % UInt = Null
-            evaluate_mod(&Schema::default(), &mut null, &mut uint2, &row)
+            evaluate_mod(&Schema::default(), &mut null, &mut uint2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
@@ -2022,31 +2022,31 @@ fn test_null_math() {
         //// left: Null, right: U128
         assert_eq!(
             // Null + U128 = Null
-            evaluate_add(&Schema::default(), &mut null, &mut u128_2, &row)
+            evaluate_add(&Schema::default(), &mut null, &mut u128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Null - U128 = Null
-            evaluate_sub(&Schema::default(), &mut null, &mut u128_2, &row)
+            evaluate_sub(&Schema::default(), &mut null, &mut u128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Null * U128 = Null
-            evaluate_mul(&Schema::default(), &mut null, &mut u128_1, &row)
+            evaluate_mul(&Schema::default(), &mut null, &mut u128_1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Decimal / U128 = Null
-            evaluate_div(&Schema::default(), &mut null, &mut u128_1, &row)
+            evaluate_div(&Schema::default(), &mut null, &mut u128_1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Decimal % U128 = Null
-            evaluate_mod(&Schema::default(), &mut null, &mut u128_2, &row)
+            evaluate_mod(&Schema::default(), &mut null, &mut u128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
@@ -2054,31 +2054,31 @@ fn test_null_math() {
         //// left: Null, right: Int
         assert_eq!(
             // Null + Int = Null
-            evaluate_add(&Schema::default(), &mut null, &mut int2, &row)
+            evaluate_add(&Schema::default(), &mut null, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Null - Int = Null
-            evaluate_sub(&Schema::default(), &mut null, &mut int2, &row)
+            evaluate_sub(&Schema::default(), &mut null, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Null * Int = Null
-            evaluate_mul(&Schema::default(), &mut null, &mut int1, &row)
+            evaluate_mul(&Schema::default(), &mut null, &mut int1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Decimal / Int = Null
-            evaluate_div(&Schema::default(), &mut null, &mut int1, &row)
+            evaluate_div(&Schema::default(), &mut null, &mut int1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Decimal % Int = Null
-            evaluate_mod(&Schema::default(), &mut null, &mut int2, &row)
+            evaluate_mod(&Schema::default(), &mut null, &mut int2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
@@ -2086,31 +2086,31 @@ fn test_null_math() {
         //// left: Null, right: I128
         assert_eq!(
             // Null + I128 = Null
-            evaluate_add(&Schema::default(), &mut null, &mut i128_2, &row)
+            evaluate_add(&Schema::default(), &mut null, &mut i128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Null - I128 = Null
-            evaluate_sub(&Schema::default(), &mut null, &mut i128_2, &row)
+            evaluate_sub(&Schema::default(), &mut null, &mut i128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Null * I128 = Null
-            evaluate_mul(&Schema::default(), &mut null, &mut i128_1, &row)
+            evaluate_mul(&Schema::default(), &mut null, &mut i128_1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Decimal / I128 = Null
-            evaluate_div(&Schema::default(), &mut null, &mut i128_1, &row)
+            evaluate_div(&Schema::default(), &mut null, &mut i128_1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Decimal % I128 = Null
-            evaluate_mod(&Schema::default(), &mut null, &mut i128_2, &row)
+            evaluate_mod(&Schema::default(), &mut null, &mut i128_2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
@@ -2118,31 +2118,31 @@ fn test_null_math() {
         //// left: Null, right: Float
         assert_eq!(
             // Null + Float = Null
-            evaluate_add(&Schema::default(), &mut null, &mut float2, &row)
+            evaluate_add(&Schema::default(), &mut null, &mut float2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Null - Float = Null
-            evaluate_sub(&Schema::default(), &mut null, &mut float2, &row)
+            evaluate_sub(&Schema::default(), &mut null, &mut float2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Null * Float = Null
-            evaluate_mul(&Schema::default(), &mut null, &mut float1, &row)
+            evaluate_mul(&Schema::default(), &mut null, &mut float1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Decimal / Float = Null
-            evaluate_div(&Schema::default(), &mut null, &mut float1, &row)
+            evaluate_div(&Schema::default(), &mut null, &mut float1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Decimal % Float = Null
-            evaluate_mod(&Schema::default(), &mut null, &mut float2, &row)
+            evaluate_mod(&Schema::default(), &mut null, &mut float2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
@@ -2150,31 +2150,31 @@ fn test_null_math() {
         //// left: Null, right: Decimal
         assert_eq!(
             // Null + Decimal = Null
-            evaluate_add(&Schema::default(), &mut null, &mut dec2, &row)
+            evaluate_add(&Schema::default(), &mut null, &mut dec2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Null - Decimal = Null
-            evaluate_sub(&Schema::default(), &mut null, &mut dec2, &row)
+            evaluate_sub(&Schema::default(), &mut null, &mut dec2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Null * Decimal = Null
-            evaluate_mul(&Schema::default(), &mut null, &mut dec1, &row)
+            evaluate_mul(&Schema::default(), &mut null, &mut dec1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Decimal / Decimal = Null
-            evaluate_div(&Schema::default(), &mut null, &mut dec1, &row)
+            evaluate_div(&Schema::default(), &mut null, &mut dec1, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Decimal % Decimal = Null
-            evaluate_mod(&Schema::default(), &mut null, &mut dec2, &row)
+            evaluate_mod(&Schema::default(), &mut null, &mut dec2, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
@@ -2183,31 +2183,31 @@ fn test_null_math() {
         let mut null_clone = null.clone();
         assert_eq!(
             // Null + Null = Null
-            evaluate_add(&Schema::default(), &mut null, &mut null_clone, &row)
+            evaluate_add(&Schema::default(), &mut null, &mut null_clone, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Null - Null = Null
-            evaluate_sub(&Schema::default(), &mut null, &mut null_clone, &row)
+            evaluate_sub(&Schema::default(), &mut null, &mut null_clone, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Null * Null = Null
-            evaluate_mul(&Schema::default(), &mut null, &mut null_clone, &row)
+            evaluate_mul(&Schema::default(), &mut null, &mut null_clone, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Decimal / Null = Null
-            evaluate_div(&Schema::default(), &mut null, &mut null_clone, &row)
+            evaluate_div(&Schema::default(), &mut null, &mut null_clone, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
         assert_eq!(
             // Decimal % Null = Null
-            evaluate_mod(&Schema::default(), &mut null, &mut null_clone, &row)
+            evaluate_mod(&Schema::default(), &mut null, &mut null_clone, &row, NumericOverflow::Wrap, None)
                 .unwrap_or_else(|e| panic!("{}", e.to_string())),
             Field::Null
         );
@@ -2247,6 +2247,8 @@ fn test_timestamp_difference() {
         &mut Expression::Column { index: 0 },
         &mut Expression::Column { index: 1 },
         &record,
+        NumericOverflow::Wrap,
+        None,
     )
     .unwrap();
     assert_eq!(
@@ -2262,6 +2264,8 @@ fn test_timestamp_difference() {
         &mut Expression::Column { index: 1 },
         &mut Expression::Column { index: 0 },
         &record,
+        NumericOverflow::Wrap,
+        None,
     );
     assert!(result.is_err());
 }
@@ -2289,7 +2293,14 @@ fn test_duration_math(d1: u64, d2: u64, dt1: ArbitraryDateTime) {
     )));
 
     // Duration + Duration = Duration
-    let result = evaluate_add(&Schema::default(), &mut dur1, &mut dur2, &row);
+    let result = evaluate_add(
+        &Schema::default(),
+        &mut dur1,
+        &mut dur2,
+        &row,
+        NumericOverflow::Wrap,
+        None,
+    );
     let sum = std::time::Duration::from_nanos(d1).checked_add(std::time::Duration::from_nanos(d2));
     if result.is_ok() && sum.is_some() {
         assert_eq!(
@@ -2298,7 +2309,14 @@ fn test_duration_math(d1: u64, d2: u64, dt1: ArbitraryDateTime) {
         );
     }
     // Duration - Duration = Duration
-    let result = evaluate_sub(&Schema::default(), &mut dur1, &mut dur2, &row);
+    let result = evaluate_sub(
+        &Schema::default(),
+        &mut dur1,
+        &mut dur2,
+        &row,
+        NumericOverflow::Wrap,
+        None,
+    );
     let diff = std::time::Duration::from_nanos(d1).checked_sub(std::time::Duration::from_nanos(d2));
     if result.is_ok() && diff.is_some() {
         assert_eq!(
@@ -2307,33 +2325,96 @@ fn test_duration_math(d1: u64, d2: u64, dt1: ArbitraryDateTime) {
         );
     }
     // Duration * Duration = Error
-    let result = evaluate_mul(&Schema::default(), &mut dur1, &mut dur2, &row);
+    let result = evaluate_mul(
+        &Schema::default(),
+        &mut dur1,
+        &mut dur2,
+        &row,
+        NumericOverflow::Wrap,
+        None,
+    );
     assert!(result.is_err());
     // Duration / Duration = Error
-    let result = evaluate_div(&Schema::default(), &mut dur1, &mut dur2, &row);
+    let result = evaluate_div(
+        &Schema::default(),
+        &mut dur1,
+        &mut dur2,
+        &row,
+        NumericOverflow::Wrap,
+        None,
+    );
     assert!(result.is_err());
     // Duration % Duration = Error
-    let result = evaluate_mod(&Schema::default(), &mut dur1, &mut dur2, &row);
+    let result = evaluate_mod(
+        &Schema::default(),
+        &mut dur1,
+        &mut dur2,
+        &row,
+        NumericOverflow::Wrap,
+        None,
+    );
     assert!(result.is_err());
 
     // Duration + Timestamp = Error
-    let result = evaluate_add(&Schema::default(), &mut dur1, &mut v, &row);
+    let result = evaluate_add(
+        &Schema::default(),
+        &mut dur1,
+        &mut v,
+        &row,
+        NumericOverflow::Wrap,
+        None,
+    );
     assert!(result.is_err());
     // Duration - Timestamp = Error
-    let result = evaluate_sub(&Schema::default(), &mut dur1, &mut v, &row);
+    let result = evaluate_sub(
+        &Schema::default(),
+        &mut dur1,
+        &mut v,
+        &row,
+        NumericOverflow::Wrap,
+        None,
+    );
     assert!(result.is_err());
     // Duration * Timestamp = Error
-    let result = evaluate_mul(&Schema::default(), &mut dur1, &mut v, &row);
+    let result = evaluate_mul(
+        &Schema::default(),
+        &mut dur1,
+        &mut v,
+        &row,
+        NumericOverflow::Wrap,
+        None,
+    );
     assert!(result.is_err());
     // Duration / Timestamp = Error
-    let result = evaluate_div(&Schema::default(), &mut dur1, &mut v, &row);
+    let result = evaluate_div(
+        &Schema::default(),
+        &mut dur1,
+        &mut v,
+        &row,
+        NumericOverflow::Wrap,
+        None,
+    );
     assert!(result.is_err());
     // Duration % Timestamp = Error
-    let result = evaluate_mod(&Schema::default(), &mut dur1, &mut v, &row);
+    let result = evaluate_mod(
+        &Schema::default(),
+        &mut dur1,
+        &mut v,
+        &row,
+        NumericOverflow::Wrap,
+        None,
+    );
     assert!(result.is_err());
 
     // Timestamp + Duration = Timestamp
-    let result = evaluate_add(&Schema::default(), &mut v, &mut dur1, &row);
+    let result = evaluate_add(
+        &Schema::default(),
+        &mut v,
+        &mut dur1,
+        &row,
+        NumericOverflow::Wrap,
+        None,
+    );
     let sum = dt1
         .0
         .checked_add_signed(chrono::Duration::nanoseconds(d1 as i64));
@@ -2341,7 +2422,14 @@ fn test_duration_math(d1: u64, d2: u64, dt1: ArbitraryDateTime) {
         assert_eq!(result.unwrap(), Field::Timestamp(sum.unwrap()));
     }
     // Timestamp - Duration = Timestamp
-    let result = evaluate_sub(&Schema::default(), &mut v, &mut dur2, &row);
+    let result = evaluate_sub(
+        &Schema::default(),
+        &mut v,
+        &mut dur2,
+        &row,
+        NumericOverflow::Wrap,
+        None,
+    );
     let diff = dt1
         .0
         .checked_sub_signed(chrono::Duration::nanoseconds(d2 as i64));
@@ -2349,13 +2437,34 @@ fn test_duration_math(d1: u64, d2: u64, dt1: ArbitraryDateTime) {
         assert_eq!(result.unwrap(), Field::Timestamp(diff.unwrap()));
     }
     // Timestamp * Duration = Error
-    let result = evaluate_mul(&Schema::default(), &mut v, &mut dur1, &row);
+    let result = evaluate_mul(
+        &Schema::default(),
+        &mut v,
+        &mut dur1,
+        &row,
+        NumericOverflow::Wrap,
+        None,
+    );
     assert!(result.is_err());
     // Timestamp / Duration = Error
-    let result = evaluate_div(&Schema::default(), &mut v, &mut dur1, &row);
+    let result = evaluate_div(
+        &Schema::default(),
+        &mut v,
+        &mut dur1,
+        &row,
+        NumericOverflow::Wrap,
+        None,
+    );
     assert!(result.is_err());
     // Timestamp % Duration = Error
-    let result = evaluate_mod(&Schema::default(), &mut v, &mut dur1, &row);
+    let result = evaluate_mod(
+        &Schema::default(),
+        &mut v,
+        &mut dur1,
+        &row,
+        NumericOverflow::Wrap,
+        None,
+    );
     assert!(result.is_err());
 }
 
@@ -2378,82 +2487,172 @@ fn test_decimal() {
 
     // left: Int, right: Decimal
     assert_eq!(
-        evaluate_add(&Schema::default(), &mut int1, dec1.as_mut(), &row)
-            .unwrap_or_else(|e| panic!("{}", e.to_string())),
+        evaluate_add(
+            &Schema::default(),
+            &mut int1,
+            dec1.as_mut(),
+            &row,
+            NumericOverflow::Wrap
+        )
+        .unwrap_or_else(|e| panic!("{}", e.to_string())),
         Field::Decimal(Decimal::from_i64(2_i64).unwrap())
     );
     assert_eq!(
-        evaluate_sub(&Schema::default(), &mut int1, dec1.as_mut(), &row)
-            .unwrap_or_else(|e| panic!("{}", e.to_string())),
+        evaluate_sub(
+            &Schema::default(),
+            &mut int1,
+            dec1.as_mut(),
+            &row,
+            NumericOverflow::Wrap
+        )
+        .unwrap_or_else(|e| panic!("{}", e.to_string())),
         Field::Decimal(Decimal::from_i64(0_i64).unwrap())
     );
     assert_eq!(
-        evaluate_mul(&Schema::default(), &mut int2, dec1.as_mut(), &row)
-            .unwrap_or_else(|e| panic!("{}", e.to_string())),
+        evaluate_mul(
+            &Schema::default(),
+            &mut int2,
+            dec1.as_mut(),
+            &row,
+            NumericOverflow::Wrap
+        )
+        .unwrap_or_else(|e| panic!("{}", e.to_string())),
         Field::Decimal(Decimal::from_i64(2_i64).unwrap())
     );
     assert_eq!(
-        evaluate_div(&Schema::default(), &mut int1, dec2.as_mut(), &row)
-            .unwrap_or_else(|e| panic!("{}", e.to_string())),
+        evaluate_div(
+            &Schema::default(),
+            &mut int1,
+            dec2.as_mut(),
+            &row,
+            NumericOverflow::Wrap
+        )
+        .unwrap_or_else(|e| panic!("{}", e.to_string())),
         Field::Decimal(Decimal::from_f64(0.5).unwrap())
     );
     assert_eq!(
-        evaluate_mod(&Schema::default(), &mut int1, dec1.as_mut(), &row)
-            .unwrap_or_else(|e| panic!("{}", e.to_string())),
+        evaluate_mod(
+            &Schema::default(),
+            &mut int1,
+            dec1.as_mut(),
+            &row,
+            NumericOverflow::Wrap
+        )
+        .unwrap_or_else(|e| panic!("{}", e.to_string())),
         Field::Decimal(Decimal::from_i64(0_i64).unwrap())
     );
 
     // left: UInt, right: Decimal
     assert_eq!(
-        evaluate_add(&Schema::default(), &mut uint1, dec1.as_mut(), &row)
-            .unwrap_or_else(|e| panic!("{}", e.to_string())),
+        evaluate_add(
+            &Schema::default(),
+            &mut uint1,
+            dec1.as_mut(),
+            &row,
+            NumericOverflow::Wrap
+        )
+        .unwrap_or_else(|e| panic!("{}", e.to_string())),
         Field::Decimal(Decimal::from_i64(2_i64).unwrap())
     );
     assert_eq!(
-        evaluate_sub(&Schema::default(), &mut uint1, dec1.as_mut(), &row)
-            .unwrap_or_else(|e| panic!("{}", e.to_string())),
+        evaluate_sub(
+            &Schema::default(),
+            &mut uint1,
+            dec1.as_mut(),
+            &row,
+            NumericOverflow::Wrap
+        )
+        .unwrap_or_else(|e| panic!("{}", e.to_string())),
         Field::Decimal(Decimal::from_i64(0_i64).unwrap())
     );
     assert_eq!(
-        evaluate_mul(&Schema::default(), &mut uint2, dec1.as_mut(), &row)
-            .unwrap_or_else(|e| panic!("{}", e.to_string())),
+        evaluate_mul(
+            &Schema::default(),
+            &mut uint2,
+            dec1.as_mut(),
+            &row,
+            NumericOverflow::Wrap
+        )
+        .unwrap_or_else(|e| panic!("{}", e.to_string())),
         Field::Decimal(Decimal::from_i64(2_i64).unwrap())
     );
     assert_eq!(
-        evaluate_div(&Schema::default(), &mut uint1, dec2.as_mut(), &row)
-            .unwrap_or_else(|e| panic!("{}", e.to_string())),
+        evaluate_div(
+            &Schema::default(),
+            &mut uint1,
+            dec2.as_mut(),
+            &row,
+            NumericOverflow::Wrap
+        )
+        .unwrap_or_else(|e| panic!("{}", e.to_string())),
         Field::Decimal(Decimal::from_f64(0.5).unwrap())
     );
     assert_eq!(
-        evaluate_mod(&Schema::default(), &mut uint1, dec1.as_mut(), &row)
-            .unwrap_or_else(|e| panic!("{}", e.to_string())),
+        evaluate_mod(
+            &Schema::default(),
+            &mut uint1,
+            dec1.as_mut(),
+            &row,
+            NumericOverflow::Wrap
+        )
+        .unwrap_or_else(|e| panic!("{}", e.to_string())),
         Field::Decimal(Decimal::from_i64(0_i64).unwrap())
     );
 
     // left: Float, right: Decimal
     assert_eq!(
-        evaluate_add(&Schema::default(), &mut float1, dec1.as_mut(), &row)
-            .unwrap_or_else(|e| panic!("{}", e.to_string())),
+        evaluate_add(
+            &Schema::default(),
+            &mut float1,
+            dec1.as_mut(),
+            &row,
+            NumericOverflow::Wrap
+        )
+        .unwrap_or_else(|e| panic!("{}", e.to_string())),
         Field::Decimal(Decimal::from_i64(2_i64).unwrap())
     );
     assert_eq!(
-        evaluate_sub(&Schema::default(), &mut float1, dec1.as_mut(), &row)
-            .unwrap_or_else(|e| panic!("{}", e.to_string())),
+        evaluate_sub(
+            &Schema::default(),
+            &mut float1,
+            dec1.as_mut(),
+            &row,
+            NumericOverflow::Wrap
+        )
+        .unwrap_or_else(|e| panic!("{}", e.to_string())),
         Field::Decimal(Decimal::from_i64(0_i64).unwrap())
     );
     assert_eq!(
-        evaluate_mul(&Schema::default(), &mut float2, dec1.as_mut(), &row)
-            .unwrap_or_else(|e| panic!("{}", e.to_string())),
+        evaluate_mul(
+            &Schema::default(),
+            &mut float2,
+            dec1.as_mut(),
+            &row,
+            NumericOverflow::Wrap
+        )
+        .unwrap_or_else(|e| panic!("{}", e.to_string())),
         Field::Decimal(Decimal::from_i64(2_i64).unwrap())
     );
     assert_eq!(
-        evaluate_div(&Schema::default(), &mut float1, dec2.as_mut(), &row)
-            .unwrap_or_else(|e| panic!("{}", e.to_string())),
+        evaluate_div(
+            &Schema::default(),
+            &mut float1,
+            dec2.as_mut(),
+            &row,
+            NumericOverflow::Wrap
+        )
+        .unwrap_or_else(|e| panic!("{}", e.to_string())),
         Field::Decimal(Decimal::from_f64(0.5).unwrap())
     );
     assert_eq!(
-        evaluate_mod(&Schema::default(), &mut float1, dec1.as_mut(), &row)
-            .unwrap_or_else(|e| panic!("{}", e.to_string())),
+        evaluate_mod(
+            &Schema::default(),
+            &mut float1,
+            dec1.as_mut(),
+            &row,
+            NumericOverflow::Wrap
+        )
+        .unwrap_or_else(|e| panic!("{}", e.to_string())),
         Field::Decimal(Decimal::from_i64(0_i64).unwrap())
     );
 }
@@ -3,6 +3,7 @@ use crate::error::Error;
 use crate::execution::Expression;
 use crate::logical::*;
 use crate::mathematical::*;
+use dozer_types::models::flags::{DecimalRounding, NumericOverflow};
 use dozer_types::types::Record;
 use dozer_types::types::{Field, Schema};
 use std::fmt::{Display, Formatter};
@@ -88,6 +89,8 @@ impl BinaryOperatorType {
         left: &mut Expression,
         right: &mut Expression,
         record: &Record,
+        overflow: NumericOverflow,
+        rounding: Option<DecimalRounding>,
     ) -> Result<Field, Error> {
         match self {
             BinaryOperatorType::Eq => evaluate_eq(schema, left, right, record),
@@ -100,11 +103,21 @@ impl BinaryOperatorType {
             BinaryOperatorType::And => evaluate_and(schema, left, right, record),
             BinaryOperatorType::Or => evaluate_or(schema, left, right, record),
 
-            BinaryOperatorType::Add => evaluate_add(schema, left, right, record),
-            BinaryOperatorType::Sub => evaluate_sub(schema, left, right, record),
-            BinaryOperatorType::Mul => evaluate_mul(schema, left, right, record),
-            BinaryOperatorType::Div => evaluate_div(schema, left, right, record),
-            BinaryOperatorType::Mod => evaluate_mod(schema, left, right, record),
+            BinaryOperatorType::Add => {
+                evaluate_add(schema, left, right, record, overflow, rounding)
+            }
+            BinaryOperatorType::Sub => {
+                evaluate_sub(schema, left, right, record, overflow, rounding)
+            }
+            BinaryOperatorType::Mul => {
+                evaluate_mul(schema, left, right, record, overflow, rounding)
+            }
+            BinaryOperatorType::Div => {
+                evaluate_div(schema, left, right, record, overflow, rounding)
+            }
+            BinaryOperatorType::Mod => {
+                evaluate_mod(schema, left, right, record, overflow, rounding)
+            }
         }
     }
 }
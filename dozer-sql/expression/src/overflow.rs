@@ -0,0 +1,386 @@
+use dozer_types::models::flags::NumericOverflow;
+use dozer_types::rust_decimal::Decimal;
+use dozer_types::types::Field;
+use num_traits::FromPrimitive;
+
+use crate::error::{Error, OperationError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArithmeticOp {
+    Add,
+    Sub,
+    Mul,
+    Rem,
+}
+
+impl ArithmeticOp {
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "+" => Some(Self::Add),
+            "-" => Some(Self::Sub),
+            "*" => Some(Self::Mul),
+            "%" => Some(Self::Rem),
+            _ => None,
+        }
+    }
+
+    fn overflow_error(self) -> OperationError {
+        match self {
+            Self::Add => OperationError::AdditionOverflow,
+            Self::Sub => OperationError::SubtractionOverflow,
+            Self::Mul => OperationError::MultiplicationOverflow,
+            Self::Rem => OperationError::ModuloByZeroOrOverflow,
+        }
+    }
+}
+
+/// An integer type whose arithmetic is subject to a [`NumericOverflow`] policy.
+pub trait OverflowInteger: Copy + std::fmt::Display {
+    fn is_zero(self) -> bool;
+    fn checked(self, op: ArithmeticOp, rhs: Self) -> Option<Self>;
+    fn saturating(self, op: ArithmeticOp, rhs: Self) -> Self;
+    fn wrapping(self, op: ArithmeticOp, rhs: Self) -> Self;
+    fn to_decimal(self) -> Option<Decimal>;
+    fn into_field(self) -> Field;
+}
+
+macro_rules! impl_overflow_integer {
+    ($t:ty, $variant:ident, $from:ident) => {
+        impl OverflowInteger for $t {
+            fn is_zero(self) -> bool {
+                self == 0
+            }
+
+            fn checked(self, op: ArithmeticOp, rhs: Self) -> Option<Self> {
+                match op {
+                    ArithmeticOp::Add => self.checked_add(rhs),
+                    ArithmeticOp::Sub => self.checked_sub(rhs),
+                    ArithmeticOp::Mul => self.checked_mul(rhs),
+                    ArithmeticOp::Rem => self.checked_rem(rhs),
+                }
+            }
+
+            fn saturating(self, op: ArithmeticOp, rhs: Self) -> Self {
+                match op {
+                    ArithmeticOp::Add => self.saturating_add(rhs),
+                    ArithmeticOp::Sub => self.saturating_sub(rhs),
+                    ArithmeticOp::Mul => self.saturating_mul(rhs),
+                    ArithmeticOp::Rem => self.wrapping_rem(rhs),
+                }
+            }
+
+            fn wrapping(self, op: ArithmeticOp, rhs: Self) -> Self {
+                match op {
+                    ArithmeticOp::Add => self.wrapping_add(rhs),
+                    ArithmeticOp::Sub => self.wrapping_sub(rhs),
+                    ArithmeticOp::Mul => self.wrapping_mul(rhs),
+                    ArithmeticOp::Rem => self.wrapping_rem(rhs),
+                }
+            }
+
+            fn to_decimal(self) -> Option<Decimal> {
+                Decimal::$from(self)
+            }
+
+            fn into_field(self) -> Field {
+                Field::$variant(self)
+            }
+        }
+    };
+}
+
+impl_overflow_integer!(i64, Int, from_i64);
+impl_overflow_integer!(u64, UInt, from_u64);
+impl_overflow_integer!(i128, I128, from_i128);
+impl_overflow_integer!(u128, U128, from_u128);
+
+/// Applies `op` to two integers of the same type. `PromoteToDecimal` is handled like `Error`, because the result must fit in `T`.
+///
+/// The remainder can only overflow for `MIN % -1`, whose result is 0 under every policy.
+pub fn integer_op<T: OverflowInteger>(
+    op: ArithmeticOp,
+    left: T,
+    right: T,
+    overflow: NumericOverflow,
+) -> Result<T, Error> {
+    if op == ArithmeticOp::Rem {
+        if right.is_zero() {
+            return Err(Error::SqlError(OperationError::ModuloByZeroOrOverflow));
+        }
+        return Ok(left.wrapping(op, right));
+    }
+    match overflow {
+        NumericOverflow::Error | NumericOverflow::PromoteToDecimal => left
+            .checked(op, right)
+            .ok_or(Error::SqlError(op.overflow_error())),
+        NumericOverflow::Saturate => Ok(left.saturating(op, right)),
+        NumericOverflow::Wrap => Ok(left.wrapping(op, right)),
+    }
+}
+
+/// Evaluates the integer arithmetic operator `symbol`. Under `PromoteToDecimal`, additions, subtractions and multiplications compute in decimal.
+pub fn integer_arithmetic<T: OverflowInteger>(
+    symbol: &str,
+    left: T,
+    right: T,
+    overflow: NumericOverflow,
+) -> Result<Field, Error> {
+    let Some(op) = ArithmeticOp::from_symbol(symbol) else {
+        return Err(Error::InvalidType(left.into_field(), symbol.to_string()));
+    };
+    if overflow == NumericOverflow::PromoteToDecimal && op != ArithmeticOp::Rem {
+        let left = to_decimal(left)?;
+        let right = to_decimal(right)?;
+        let result = match op {
+            ArithmeticOp::Add => left.checked_add(right),
+            ArithmeticOp::Sub => left.checked_sub(right),
+            ArithmeticOp::Mul => left.checked_mul(right),
+            ArithmeticOp::Rem => left.checked_rem(right),
+        };
+        return result
+            .map(Field::Decimal)
+            .ok_or(Error::SqlError(op.overflow_error()));
+    }
+    integer_op(op, left, right, overflow).map(OverflowInteger::into_field)
+}
+
+/// Evaluates the integer arithmetic operator `symbol` on an `i64` and a `u64`, given as `i128` so the result is exact before it's narrowed to `Int`.
+pub fn int_uint_arithmetic(
+    symbol: &str,
+    left: i128,
+    right: i128,
+    overflow: NumericOverflow,
+) -> Result<Field, Error> {
+    let Some(op) = ArithmeticOp::from_symbol(symbol) else {
+        return Err(Error::InvalidType(Field::I128(left), symbol.to_string()));
+    };
+    if overflow == NumericOverflow::PromoteToDecimal && op != ArithmeticOp::Rem {
+        return integer_arithmetic(symbol, left, right, overflow);
+    }
+    // The product of an `i64` and a `u64` fits in `i128`, so this can't overflow.
+    let result = integer_op(op, left, right, NumericOverflow::Error)?;
+    let result = match i64::try_from(result) {
+        Ok(result) => result,
+        Err(_) => match overflow {
+            NumericOverflow::Error | NumericOverflow::PromoteToDecimal => {
+                return Err(Error::SqlError(op.overflow_error()))
+            }
+            NumericOverflow::Saturate if result > 0 => i64::MAX,
+            NumericOverflow::Saturate => i64::MIN,
+            NumericOverflow::Wrap => result as i64,
+        },
+    };
+    Ok(Field::Int(result))
+}
+
+/// Evaluates the integer arithmetic operator `symbol` on an `i128` and a `u128`, with an `I128` result. `unsigned_left` tells which of them is the left operand.
+pub fn signed_unsigned_arithmetic(
+    symbol: &str,
+    signed: i128,
+    unsigned: u128,
+    unsigned_left: bool,
+    overflow: NumericOverflow,
+) -> Result<Field, Error> {
+    if let Ok(converted) = i128::try_from(unsigned) {
+        let (left, right) = if unsigned_left {
+            (converted, signed)
+        } else {
+            (signed, converted)
+        };
+        return integer_arithmetic(symbol, left, right, overflow);
+    }
+    let Some(op) = ArithmeticOp::from_symbol(symbol) else {
+        return Err(Error::InvalidType(
+            if unsigned_left {
+                Field::U128(unsigned)
+            } else {
+                Field::I128(signed)
+            },
+            symbol.to_string(),
+        ));
+    };
+    if op == ArithmeticOp::Rem && unsigned_left && signed == 0 {
+        return Err(Error::SqlError(OperationError::ModuloByZeroOrOverflow));
+    }
+    if overflow == NumericOverflow::PromoteToDecimal && op != ArithmeticOp::Rem {
+        return Err(Error::UnableToCast(
+            unsigned.to_string(),
+            "Decimal".to_string(),
+        ));
+    }
+    let result = match large_unsigned_op(op, signed, unsigned, unsigned_left) {
+        Ok(result) => result,
+        Err(bound) => match overflow {
+            NumericOverflow::Error | NumericOverflow::PromoteToDecimal => {
+                return Err(Error::SqlError(op.overflow_error()))
+            }
+            NumericOverflow::Saturate => bound,
+            // Addition, subtraction and multiplication wrap the same when the operand wraps first.
+            NumericOverflow::Wrap if unsigned_left => (unsigned as i128).wrapping(op, signed),
+            NumericOverflow::Wrap => signed.wrapping(op, unsigned as i128),
+        },
+    };
+    Ok(Field::I128(result))
+}
+
+/// Applies `op` to `signed` and an `unsigned` above `i128::MAX`, returning the bound it overflows past if the result doesn't fit in `i128`.
+fn large_unsigned_op(
+    op: ArithmeticOp,
+    signed: i128,
+    unsigned: u128,
+    unsigned_left: bool,
+) -> Result<i128, i128> {
+    // `unsigned` is 2^127 + `excess`, and 2^127 + x is `x - i128::MIN` for a negative x.
+    let excess = (unsigned - i128::MAX as u128 - 1) as i128;
+    match (op, unsigned_left) {
+        (ArithmeticOp::Add, _) => signed
+            .checked_add(excess)
+            .filter(|sum| *sum < 0)
+            .map(|sum| sum - i128::MIN)
+            .ok_or(i128::MAX),
+        (ArithmeticOp::Sub, false) => signed
+            .checked_sub(excess)
+            .filter(|difference| *difference >= 0)
+            .map(|difference| difference + i128::MIN)
+            .ok_or(i128::MIN),
+        (ArithmeticOp::Sub, true) => excess
+            .checked_sub(signed)
+            .filter(|difference| *difference < 0)
+            .map(|difference| difference - i128::MIN)
+            .ok_or(i128::MAX),
+        (ArithmeticOp::Mul, _) => match signed {
+            0 => Ok(0),
+            -1 if excess == 0 => Ok(i128::MIN),
+            signed if signed > 0 => Err(i128::MAX),
+            _ => Err(i128::MIN),
+        },
+        // `signed` is at most 2^127 in magnitude, so it's its own remainder unless it's -2^127 and `unsigned` is 2^127.
+        (ArithmeticOp::Rem, false) => Ok(if signed.unsigned_abs() == unsigned {
+            0
+        } else {
+            signed
+        }),
+        (ArithmeticOp::Rem, true) => Ok((unsigned % signed.unsigned_abs()) as i128),
+    }
+}
+
+fn to_decimal<T: OverflowInteger>(value: T) -> Result<Decimal, Error> {
+    value
+        .to_decimal()
+        .ok_or_else(|| Error::UnableToCast(value.to_string(), "Decimal".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer_arithmetic_overflow() {
+        assert!(matches!(
+            integer_arithmetic("+", i64::MAX, 1, NumericOverflow::Error),
+            Err(Error::SqlError(OperationError::AdditionOverflow))
+        ));
+        assert_eq!(
+            integer_arithmetic("+", i64::MAX, 1, NumericOverflow::Saturate).unwrap(),
+            Field::Int(i64::MAX)
+        );
+        assert_eq!(
+            integer_arithmetic("-", 0_u64, 1, NumericOverflow::Saturate).unwrap(),
+            Field::UInt(0)
+        );
+        assert_eq!(
+            integer_arithmetic("+", i64::MAX, 1, NumericOverflow::Wrap).unwrap(),
+            Field::Int(i64::MIN)
+        );
+        assert_eq!(
+            integer_arithmetic("*", i64::MAX, 2, NumericOverflow::PromoteToDecimal).unwrap(),
+            Field::Decimal(Decimal::from(i64::MAX) * Decimal::from(2))
+        );
+        assert_eq!(
+            integer_arithmetic("%", 7_i64, 2, NumericOverflow::PromoteToDecimal).unwrap(),
+            Field::Int(1)
+        );
+        assert!(matches!(
+            integer_arithmetic("%", 7_i64, 0, NumericOverflow::Wrap),
+            Err(Error::SqlError(OperationError::ModuloByZeroOrOverflow))
+        ));
+        assert_eq!(
+            integer_arithmetic("%", i64::MIN, -1, NumericOverflow::Error).unwrap(),
+            Field::Int(0)
+        );
+    }
+
+    #[test]
+    fn test_int_uint_arithmetic() {
+        let big = i128::from(u64::MAX);
+        assert_eq!(
+            int_uint_arithmetic("+", i64::MIN.into(), big, NumericOverflow::Error).unwrap(),
+            Field::Int(i64::MAX)
+        );
+        assert!(matches!(
+            int_uint_arithmetic("+", 0, big, NumericOverflow::Error),
+            Err(Error::SqlError(OperationError::AdditionOverflow))
+        ));
+        assert_eq!(
+            int_uint_arithmetic("-", 0, big, NumericOverflow::Saturate).unwrap(),
+            Field::Int(i64::MIN)
+        );
+        assert_eq!(
+            int_uint_arithmetic("+", 0, big, NumericOverflow::Wrap).unwrap(),
+            Field::Int(-1)
+        );
+        assert_eq!(
+            int_uint_arithmetic("%", -7, big, NumericOverflow::Error).unwrap(),
+            Field::Int(-7)
+        );
+        assert_eq!(
+            int_uint_arithmetic("+", 1, big, NumericOverflow::PromoteToDecimal).unwrap(),
+            Field::Decimal(Decimal::from(u64::MAX) + Decimal::ONE)
+        );
+    }
+
+    #[test]
+    fn test_signed_unsigned_arithmetic() {
+        let big = i128::MAX as u128 + 1;
+        assert_eq!(
+            signed_unsigned_arithmetic("+", -1, big, false, NumericOverflow::Error).unwrap(),
+            Field::I128(i128::MAX)
+        );
+        assert!(matches!(
+            signed_unsigned_arithmetic("+", 0, big, false, NumericOverflow::Error),
+            Err(Error::SqlError(OperationError::AdditionOverflow))
+        ));
+        assert_eq!(
+            signed_unsigned_arithmetic("-", 0, big, false, NumericOverflow::Error).unwrap(),
+            Field::I128(i128::MIN)
+        );
+        assert_eq!(
+            signed_unsigned_arithmetic("-", 1, big, true, NumericOverflow::Error).unwrap(),
+            Field::I128(i128::MAX)
+        );
+        assert_eq!(
+            signed_unsigned_arithmetic("*", -1, big, false, NumericOverflow::Error).unwrap(),
+            Field::I128(i128::MIN)
+        );
+        assert_eq!(
+            signed_unsigned_arithmetic("*", 2, big, false, NumericOverflow::Saturate).unwrap(),
+            Field::I128(i128::MAX)
+        );
+        assert_eq!(
+            signed_unsigned_arithmetic("+", 0, u128::MAX, true, NumericOverflow::Wrap).unwrap(),
+            Field::I128(-1)
+        );
+        assert_eq!(
+            signed_unsigned_arithmetic("%", 10, u128::MAX, true, NumericOverflow::Error).unwrap(),
+            Field::I128((u128::MAX % 10) as i128)
+        );
+        assert_eq!(
+            signed_unsigned_arithmetic("%", i128::MIN, big, false, NumericOverflow::Error).unwrap(),
+            Field::I128(0)
+        );
+        assert_eq!(
+            signed_unsigned_arithmetic("+", 1, 2, true, NumericOverflow::Error).unwrap(),
+            Field::I128(3)
+        );
+    }
+}
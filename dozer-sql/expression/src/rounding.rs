@@ -0,0 +1,109 @@
+use dozer_types::models::flags::{DecimalRounding, RoundingMode};
+use dozer_types::rust_decimal::{Decimal, RoundingStrategy};
+use dozer_types::types::Field;
+
+/// The largest scale a `Decimal` can have.
+const MAX_SCALE: u32 = 28;
+
+/// The scale the result of the arithmetic operator `op` keeps, if both operands have a scale.
+pub fn result_scale(op: &str, left: &Field, right: &Field) -> Option<u32> {
+    let left = field_scale(left)?;
+    let right = field_scale(right)?;
+    match op {
+        "*" => Some((left + right).min(MAX_SCALE)),
+        _ => Some(left.max(right)),
+    }
+}
+
+fn field_scale(field: &Field) -> Option<u32> {
+    match field {
+        Field::Decimal(value) => Some(value.scale()),
+        Field::UInt(_) | Field::U128(_) | Field::Int(_) | Field::I128(_) => Some(0),
+        _ => None,
+    }
+}
+
+/// Rounds `value` to the configured scale, or to `scale` if none is configured.
+pub fn round_decimal(value: Decimal, rounding: &DecimalRounding, scale: Option<u32>) -> Decimal {
+    let Some(scale) = rounding.scale.or(scale) else {
+        return value;
+    };
+    let mut rounded = value.round_dp_with_strategy(scale, strategy(rounding.mode));
+    if rounded.scale() < scale {
+        rounded.rescale(scale);
+    }
+    rounded
+}
+
+/// Rounds `field` if it's a decimal.
+pub fn round_field(field: Field, rounding: &DecimalRounding, scale: Option<u32>) -> Field {
+    match field {
+        Field::Decimal(value) => Field::Decimal(round_decimal(value, rounding, scale)),
+        field => field,
+    }
+}
+
+fn strategy(mode: RoundingMode) -> RoundingStrategy {
+    match mode {
+        RoundingMode::HalfEven => RoundingStrategy::MidpointNearestEven,
+        RoundingMode::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+        RoundingMode::Truncate => RoundingStrategy::ToZero,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn decimal(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    fn rounding(mode: RoundingMode, scale: Option<u32>) -> DecimalRounding {
+        DecimalRounding { mode, scale }
+    }
+
+    #[test]
+    fn test_round_decimal() {
+        let half_even = rounding(RoundingMode::HalfEven, Some(2));
+        assert_eq!(
+            round_decimal(decimal("2.345"), &half_even, None),
+            decimal("2.34")
+        );
+        let half_up = rounding(RoundingMode::HalfUp, Some(2));
+        assert_eq!(
+            round_decimal(decimal("2.345"), &half_up, None),
+            decimal("2.35")
+        );
+        let truncate = rounding(RoundingMode::Truncate, Some(2));
+        assert_eq!(
+            round_decimal(decimal("-2.349"), &truncate, None),
+            decimal("-2.34")
+        );
+
+        let rescaled = round_decimal(decimal("2"), &half_even, None);
+        assert_eq!(rescaled.scale(), 2);
+
+        let unscaled = rounding(RoundingMode::HalfEven, None);
+        assert_eq!(
+            round_decimal(decimal("2.345"), &unscaled, Some(1)),
+            decimal("2.3")
+        );
+        assert_eq!(
+            round_decimal(decimal("2.345"), &unscaled, None),
+            decimal("2.345")
+        );
+    }
+
+    #[test]
+    fn test_result_scale() {
+        let left = Field::Decimal(decimal("1.25"));
+        let right = Field::Decimal(decimal("3.1"));
+        assert_eq!(result_scale("+", &left, &right), Some(2));
+        assert_eq!(result_scale("*", &left, &right), Some(3));
+        assert_eq!(result_scale("/", &left, &Field::Int(3)), Some(2));
+        assert_eq!(result_scale("/", &left, &Field::Null), None);
+    }
+}
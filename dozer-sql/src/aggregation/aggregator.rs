@@ -5,16 +5,18 @@ use crate::aggregation::count::CountAggregator;
 use crate::aggregation::max::MaxAggregator;
 use crate::aggregation::min::MinAggregator;
 use crate::aggregation::sum::SumAggregator;
+use crate::aggregation::window_funnel::WindowFunnelAggregator;
 use crate::calculate_err;
 use crate::errors::PipelineError;
 use dozer_types::chrono::{DateTime, FixedOffset, NaiveDate};
+use dozer_types::models::flags::{DecimalRounding, NumericOverflow};
 use dozer_types::ordered_float::OrderedFloat;
 use dozer_types::rust_decimal::Decimal;
 
 use enum_dispatch::enum_dispatch;
 use std::collections::BTreeMap;
 
-use dozer_sql_expression::aggregate::AggregateFunctionType;
+use dozer_sql_expression::aggregate::{AggregateFunctionType, WINDOW_FUNNEL_MAX_STEPS};
 use dozer_sql_expression::execution::Expression;
 
 use crate::aggregation::max_append_only::MaxAppendOnlyAggregator;
@@ -46,6 +48,7 @@ pub enum AggregatorEnum {
     MaxValueAggregator,
     SumAggregator,
     CountAggregator,
+    WindowFunnelAggregator,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
@@ -59,6 +62,7 @@ pub enum AggregatorType {
     MinAppendOnly,
     MinValue,
     Sum,
+    WindowFunnel,
 }
 
 #[derive(Debug, bincode::Encode, bincode::Decode)]
@@ -250,13 +254,18 @@ impl Display for AggregatorType {
             AggregatorType::MinAppendOnly => f.write_str("min_append_only"),
             AggregatorType::MinValue => f.write_str("min_value"),
             AggregatorType::Sum => f.write_str("sum"),
+            AggregatorType::WindowFunnel => f.write_str("window_funnel"),
         }
     }
 }
 
-pub fn get_aggregator_from_aggregator_type(typ: AggregatorType) -> AggregatorEnum {
+pub fn get_aggregator_from_aggregator_type(
+    typ: AggregatorType,
+    overflow: NumericOverflow,
+    rounding: Option<DecimalRounding>,
+) -> AggregatorEnum {
     match typ {
-        AggregatorType::Avg => AvgAggregator::new().into(),
+        AggregatorType::Avg => AvgAggregator::new(overflow, rounding).into(),
         AggregatorType::Count => CountAggregator::new().into(),
         AggregatorType::Max => MaxAggregator::new().into(),
         AggregatorType::MaxAppendOnly => MaxAppendOnlyAggregator::new().into(),
@@ -264,7 +273,8 @@ pub fn get_aggregator_from_aggregator_type(typ: AggregatorType) -> AggregatorEnu
         AggregatorType::Min => MinAggregator::new().into(),
         AggregatorType::MinAppendOnly => MinAppendOnlyAggregator::new().into(),
         AggregatorType::MinValue => MinValueAggregator::new().into(),
-        AggregatorType::Sum => SumAggregator::new().into(),
+        AggregatorType::Sum => SumAggregator::new(overflow, rounding).into(),
+        AggregatorType::WindowFunnel => WindowFunnelAggregator::new().into(),
     }
 }
 
@@ -405,6 +415,16 @@ pub fn get_aggregator_type_from_aggregation_expression(
                 .clone()],
             AggregatorType::Count,
         )),
+        Expression::AggregateFunction {
+            fun: AggregateFunctionType::WindowFunnel,
+            args,
+        } => {
+            let steps = args.len().saturating_sub(2);
+            if steps > WINDOW_FUNNEL_MAX_STEPS {
+                return Err(PipelineError::TooManyWindowFunnelSteps(steps));
+            }
+            Ok((args.clone(), AggregatorType::WindowFunnel))
+        }
         _ => Err(PipelineError::InvalidFunction(e.to_string(schema))),
     }
 }
@@ -4,7 +4,9 @@ use crate::errors::PipelineError;
 use crate::errors::PipelineError::InvalidValue;
 use dozer_sql_expression::aggregate::AggregateFunctionType::Avg;
 use dozer_sql_expression::num_traits::FromPrimitive;
+use dozer_sql_expression::rounding::round_decimal;
 use dozer_types::arrow::datatypes::ArrowNativeTypeOp;
+use dozer_types::models::flags::{DecimalRounding, NumericOverflow};
 use dozer_types::ordered_float::OrderedFloat;
 use dozer_types::rust_decimal::Decimal;
 
@@ -17,10 +19,14 @@ pub struct AvgAggregator {
     current_state: SumState,
     current_count: u64,
     return_type: Option<FieldType>,
+    #[bincode(with_serde)]
+    overflow: NumericOverflow,
+    #[bincode(with_serde)]
+    rounding: Option<DecimalRounding>,
 }
 
 impl AvgAggregator {
-    pub fn new() -> Self {
+    pub fn new(overflow: NumericOverflow, rounding: Option<DecimalRounding>) -> Self {
         Self {
             current_state: SumState {
                 int_state: 0_i64,
@@ -33,6 +39,8 @@ impl AvgAggregator {
             },
             current_count: 0_u64,
             return_type: None,
+            overflow,
+            rounding,
         }
     }
 }
@@ -54,6 +62,8 @@ impl Aggregator for AvgAggregator {
             &mut self.current_state,
             &mut self.current_count,
             self.return_type,
+            self.overflow,
+            self.rounding.as_ref(),
             true,
         )
     }
@@ -65,6 +75,8 @@ impl Aggregator for AvgAggregator {
             &mut self.current_state,
             &mut self.current_count,
             self.return_type,
+            self.overflow,
+            self.rounding.as_ref(),
             false,
         )
     }
@@ -75,9 +87,11 @@ fn get_average(
     current_sum: &mut SumState,
     current_count: &mut u64,
     return_type: Option<FieldType>,
+    overflow: NumericOverflow,
+    rounding: Option<&DecimalRounding>,
     decr: bool,
 ) -> Result<Field, PipelineError> {
-    let sum = get_sum(field, current_sum, return_type, decr)?;
+    let sum = get_sum(field, current_sum, return_type, overflow, decr)?;
 
     match return_type {
         Some(typ) => match typ {
@@ -126,7 +140,11 @@ fn get_average(
                     .to_decimal()
                     .ok_or(InvalidValue(sum.to_string()))
                     .unwrap();
-                Ok(Field::Decimal(d_sum.div(Decimal::from(*current_count))))
+                let avg = d_sum.div(Decimal::from(*current_count));
+                Ok(Field::Decimal(match rounding {
+                    Some(rounding) => round_decimal(avg, rounding, Some(d_sum.scale())),
+                    None => avg,
+                }))
             }
             FieldType::Duration => {
                 if *current_count == 0 {
@@ -0,0 +1,489 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use dozer_types::models::flags::AggregationEmission;
+use dozer_types::types::{Field, Operation, Record, Schema};
+
+use crate::utils::record_hashtable_key::RecordKey;
+
+/// A change to the result of the group `key`.
+#[derive(Debug)]
+pub struct GroupChange {
+    pub key: RecordKey,
+    /// The end of the window the group belongs to, if the aggregation is over a window.
+    pub window_end: Option<Field>,
+    pub op: Operation,
+}
+
+/// The net effect of several changes to the result of a group.
+#[derive(Debug)]
+struct NetChange {
+    emitted: Option<Record>,
+    current: Option<Record>,
+}
+
+impl NetChange {
+    fn new(op: Operation) -> Self {
+        match op {
+            Operation::Insert { new } => Self {
+                emitted: None,
+                current: Some(new),
+            },
+            Operation::Delete { old } => Self {
+                emitted: Some(old),
+                current: None,
+            },
+            Operation::Update { old, new } => Self {
+                emitted: Some(old),
+                current: Some(new),
+            },
+            Operation::BatchInsert { new } => Self {
+                emitted: None,
+                current: new.into_iter().last(),
+            },
+        }
+    }
+
+    fn push(&mut self, op: Operation) {
+        match op {
+            Operation::Insert { new } | Operation::Update { new, .. } => self.current = Some(new),
+            Operation::Delete { .. } => self.current = None,
+            Operation::BatchInsert { new } => {
+                if let Some(new) = new.into_iter().last() {
+                    self.current = Some(new);
+                }
+            }
+        }
+    }
+
+    fn into_operation(self) -> Option<Operation> {
+        match (self.emitted, self.current) {
+            (None, Some(new)) => Some(Operation::Insert { new }),
+            (Some(old), None) => Some(Operation::Delete { old }),
+            (Some(old), Some(new)) if old != new => Some(Operation::Update { old, new }),
+            _ => None,
+        }
+    }
+}
+
+/// Decides when the changes to the results of an aggregation are emitted, according to an [`AggregationEmission`].
+#[derive(Debug)]
+pub enum Emitter {
+    OnChange,
+    OnWindowClose(WindowEmitter),
+    Periodic(PeriodicEmitter),
+}
+
+impl Emitter {
+    /// `windowed` is whether the input comes from TUMBLE or HOP, which append the window start and end to the records.
+    /// Other aggregations emit on change, even if `emission` is `OnWindowClose`.
+    pub fn new(emission: AggregationEmission, windowed: bool, input_schema: &Schema) -> Self {
+        match emission {
+            AggregationEmission::OnChange => Self::OnChange,
+            AggregationEmission::OnWindowClose => {
+                let fields = input_schema.fields.len();
+                if windowed && fields >= 2 {
+                    Self::OnWindowClose(WindowEmitter::new(fields - 2, fields - 1))
+                } else {
+                    Self::OnChange
+                }
+            }
+            AggregationEmission::Periodic { interval_ms } => {
+                Self::Periodic(PeriodicEmitter::new(Duration::from_millis(interval_ms)))
+            }
+        }
+    }
+
+    /// The end of the window `record` belongs to, if it's needed to emit its changes.
+    pub fn window_end(&self, record: &Record) -> Option<Field> {
+        match self {
+            Self::OnWindowClose(emitter) => Some(record.values[emitter.window_end].clone()),
+            _ => None,
+        }
+    }
+
+    /// The latest start of the windows of the records inserted by `input`, if it's needed to emit the changes they cause.
+    pub fn window_start(&self, input: &Operation) -> Option<Field> {
+        let Self::OnWindowClose(emitter) = self else {
+            return None;
+        };
+        match input {
+            Operation::Insert { new } | Operation::Update { new, .. } => {
+                Some(new.values[emitter.window_start].clone())
+            }
+            Operation::BatchInsert { new } => new
+                .iter()
+                .map(|record| &record.values[emitter.window_start])
+                .max()
+                .cloned(),
+            Operation::Delete { .. } => None,
+        }
+    }
+
+    /// Returns the operations to emit after an input whose latest window starts at `window_start` has been aggregated into `changes`.
+    pub fn emit(
+        &mut self,
+        window_start: Option<Field>,
+        changes: Vec<GroupChange>,
+        now: Instant,
+    ) -> Vec<Operation> {
+        let mut output = vec![];
+        match self {
+            Self::OnChange => output.extend(changes.into_iter().map(|change| change.op)),
+            Self::OnWindowClose(emitter) => {
+                for change in changes {
+                    emitter.push(change, &mut output);
+                }
+                if let Some(window_start) = window_start {
+                    emitter.advance(window_start, &mut output);
+                }
+            }
+            Self::Periodic(emitter) => {
+                emitter.flush_due(now, &mut output);
+                for change in changes {
+                    emitter.push(change, now, &mut output);
+                }
+            }
+        }
+        output
+    }
+
+    /// How long to wait for input before calling [`Emitter::flush`].
+    ///
+    /// Windows only close as the event time of the input advances, so they are never flushed without input.
+    pub fn flush_interval(&self) -> Option<Duration> {
+        match self {
+            Self::OnChange | Self::OnWindowClose(_) => None,
+            Self::Periodic(emitter) => Some(emitter.interval),
+        }
+    }
+
+    /// Returns the operations that are due without more input: the groups whose interval is over.
+    pub fn flush(&mut self, now: Instant) -> Vec<Operation> {
+        let mut output = vec![];
+        match self {
+            Self::OnChange | Self::OnWindowClose(_) => (),
+            Self::Periodic(emitter) => emitter.flush_due(now, &mut output),
+        }
+        output
+    }
+}
+
+#[derive(Debug)]
+pub struct WindowEmitter {
+    window_start: usize,
+    window_end: usize,
+    /// The latest window start aggregated. Windows ending at or before it are closed.
+    watermark: Option<Field>,
+    pending: HashMap<RecordKey, NetChange>,
+    /// The groups with pending changes, by the end of their window.
+    closing: BTreeMap<Field, Vec<RecordKey>>,
+}
+
+impl WindowEmitter {
+    fn new(window_start: usize, window_end: usize) -> Self {
+        Self {
+            window_start,
+            window_end,
+            watermark: None,
+            pending: HashMap::new(),
+            closing: BTreeMap::new(),
+        }
+    }
+
+    fn is_closed(&self, window_end: &Field) -> bool {
+        self.watermark
+            .as_ref()
+            .map_or(false, |watermark| window_end <= watermark)
+    }
+
+    fn push(&mut self, change: GroupChange, output: &mut Vec<Operation>) {
+        let Some(window_end) = change.window_end else {
+            output.push(change.op);
+            return;
+        };
+        if self.is_closed(&window_end) {
+            output.push(change.op);
+            return;
+        }
+        match self.pending.get_mut(&change.key) {
+            Some(pending) => pending.push(change.op),
+            None => {
+                self.closing
+                    .entry(window_end)
+                    .or_default()
+                    .push(change.key.clone());
+                self.pending.insert(change.key, NetChange::new(change.op));
+            }
+        }
+    }
+
+    /// Moves the watermark to `window_start`, emitting the groups of the windows that closed.
+    fn advance(&mut self, window_start: Field, output: &mut Vec<Operation>) {
+        if self.is_closed(&window_start) {
+            return;
+        }
+        self.watermark = Some(window_start.clone());
+
+        while let Some(entry) = self.closing.first_entry() {
+            if *entry.key() > window_start {
+                break;
+            }
+            for key in entry.remove() {
+                if let Some(op) = self
+                    .pending
+                    .remove(&key)
+                    .and_then(NetChange::into_operation)
+                {
+                    output.push(op);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PeriodicEmitter {
+    interval: Duration,
+    /// The groups emitted less than `interval` ago, with their changes since.
+    throttled: HashMap<RecordKey, Option<NetChange>>,
+    /// When the groups stop being throttled, in order.
+    deadlines: VecDeque<(Instant, RecordKey)>,
+}
+
+impl PeriodicEmitter {
+    fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            throttled: HashMap::new(),
+            deadlines: VecDeque::new(),
+        }
+    }
+
+    fn throttle(&mut self, key: RecordKey, now: Instant) {
+        self.throttled.insert(key.clone(), None);
+        self.deadlines.push_back((now + self.interval, key));
+    }
+
+    fn push(&mut self, change: GroupChange, now: Instant, output: &mut Vec<Operation>) {
+        match self.throttled.get_mut(&change.key) {
+            Some(Some(pending)) => pending.push(change.op),
+            Some(pending) => *pending = Some(NetChange::new(change.op)),
+            None => {
+                output.push(change.op);
+                self.throttle(change.key, now);
+            }
+        }
+    }
+
+    /// Emits the changes of the groups whose interval is over.
+    fn flush_due(&mut self, now: Instant, output: &mut Vec<Operation>) {
+        while let Some((deadline, _)) = self.deadlines.front() {
+            if *deadline > now {
+                break;
+            }
+            let (_, key) = self.deadlines.pop_front().expect("deadline exists");
+            if let Some(op) = self
+                .throttled
+                .remove(&key)
+                .flatten()
+                .and_then(NetChange::into_operation)
+            {
+                output.push(op);
+                self.throttle(key, now);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dozer_types::chrono::DateTime;
+    use dozer_types::types::{FieldDefinition, FieldType, SourceDefinition};
+
+    use super::*;
+
+    fn key(name: &str) -> RecordKey {
+        RecordKey::Accurate(vec![Field::String(name.to_string())])
+    }
+
+    fn result(value: i64) -> Record {
+        Record::new(vec![Field::Int(value)])
+    }
+
+    fn change(name: &str, window_end: Option<i64>, op: Operation) -> GroupChange {
+        GroupChange {
+            key: key(name),
+            window_end: window_end.map(Field::Int),
+            op,
+        }
+    }
+
+    fn update(old: i64, new: i64) -> Operation {
+        Operation::Update {
+            old: result(old),
+            new: result(new),
+        }
+    }
+
+    #[test]
+    fn on_window_close_falls_back_to_on_change() {
+        let emitter = Emitter::new(
+            AggregationEmission::OnWindowClose,
+            false,
+            &Schema::default(),
+        );
+        assert!(matches!(emitter, Emitter::OnChange));
+    }
+
+    #[test]
+    fn on_window_close_emits_net_change() {
+        let mut schema = Schema::default();
+        for name in ["window_start", "window_end"] {
+            schema.field(
+                FieldDefinition::new(
+                    name.to_string(),
+                    FieldType::Int,
+                    false,
+                    SourceDefinition::Dynamic,
+                ),
+                false,
+            );
+        }
+        let mut emitter = Emitter::new(AggregationEmission::OnWindowClose, true, &schema);
+        let input = Record::new(vec![Field::Int(0), Field::Int(10)]);
+        assert_eq!(
+            emitter.window_start(&Operation::Insert { new: input.clone() }),
+            Some(Field::Int(0))
+        );
+        assert_eq!(emitter.window_end(&input), Some(Field::Int(10)));
+        let now = Instant::now();
+
+        let output = emitter.emit(
+            Some(Field::Int(0)),
+            vec![change("a", Some(10), Operation::Insert { new: result(1) })],
+            now,
+        );
+        assert!(output.is_empty());
+        let output = emitter.emit(
+            Some(Field::Int(0)),
+            vec![change("a", Some(10), update(1, 2))],
+            now,
+        );
+        assert!(output.is_empty());
+
+        // A record of the next window closes the first one.
+        let output = emitter.emit(
+            Some(Field::Int(10)),
+            vec![change("b", Some(20), Operation::Insert { new: result(5) })],
+            now,
+        );
+        assert_eq!(output, vec![Operation::Insert { new: result(2) }]);
+
+        // Late changes are emitted as corrections.
+        let output = emitter.emit(
+            Some(Field::Int(0)),
+            vec![change("a", Some(10), update(2, 3))],
+            now,
+        );
+        assert_eq!(output, vec![update(2, 3)]);
+    }
+
+    #[test]
+    fn periodic_emits_at_most_once_per_interval() {
+        let interval = Duration::from_secs(1);
+        let mut emitter = Emitter::new(
+            AggregationEmission::Periodic {
+                interval_ms: interval.as_millis() as u64,
+            },
+            false,
+            &Schema::default(),
+        );
+        let start = Instant::now();
+
+        let output = emitter.emit(
+            None,
+            vec![change("a", None, Operation::Insert { new: result(1) })],
+            start,
+        );
+        assert_eq!(output, vec![Operation::Insert { new: result(1) }]);
+
+        let output = emitter.emit(None, vec![change("a", None, update(1, 2))], start);
+        assert!(output.is_empty());
+        let output = emitter.emit(None, vec![change("a", None, update(2, 3))], start);
+        assert!(output.is_empty());
+
+        // Changes that cancel out aren't emitted.
+        let output = emitter.emit(None, vec![change("b", None, update(7, 8))], start);
+        assert_eq!(output, vec![update(7, 8)]);
+        let output = emitter.emit(None, vec![change("b", None, update(8, 9))], start);
+        assert!(output.is_empty());
+        let output = emitter.emit(None, vec![change("b", None, update(9, 8))], start);
+        assert!(output.is_empty());
+
+        let output = emitter.emit(None, vec![], start + interval);
+        assert_eq!(output, vec![update(1, 3)]);
+    }
+
+    #[test]
+    fn flush_emits_without_input() {
+        let interval = Duration::from_secs(1);
+        let mut emitter = Emitter::new(
+            AggregationEmission::Periodic {
+                interval_ms: interval.as_millis() as u64,
+            },
+            false,
+            &Schema::default(),
+        );
+        assert_eq!(emitter.flush_interval(), Some(interval));
+        let start = Instant::now();
+
+        emitter.emit(
+            None,
+            vec![change("a", None, Operation::Insert { new: result(1) })],
+            start,
+        );
+        let output = emitter.emit(None, vec![change("a", None, update(1, 2))], start);
+        assert!(output.is_empty());
+        assert!(emitter.flush(start).is_empty());
+        assert_eq!(emitter.flush(start + interval), vec![update(1, 2)]);
+    }
+
+    #[test]
+    fn on_window_close_follows_event_time() {
+        let mut schema = Schema::default();
+        for name in ["window_start", "window_end"] {
+            schema.field(
+                FieldDefinition::new(
+                    name.to_string(),
+                    FieldType::Timestamp,
+                    false,
+                    SourceDefinition::Dynamic,
+                ),
+                false,
+            );
+        }
+        let mut emitter = Emitter::new(AggregationEmission::OnWindowClose, true, &schema);
+        let window_start = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z").unwrap();
+        let window_end = DateTime::parse_from_rfc3339("2024-01-01T00:01:00Z").unwrap();
+        let now = Instant::now();
+        assert_eq!(emitter.flush_interval(), None);
+
+        let output = emitter.emit(
+            Some(Field::Timestamp(window_start)),
+            vec![GroupChange {
+                key: key("a"),
+                window_end: Some(Field::Timestamp(window_end)),
+                op: Operation::Insert { new: result(1) },
+            }],
+            now,
+        );
+        assert!(output.is_empty());
+        // Replayed data can be far behind the wall-clock time, so waiting doesn't close the window.
+        assert!(emitter.flush(now + Duration::from_secs(3600)).is_empty());
+        assert_eq!(
+            emitter.emit(Some(Field::Timestamp(window_end)), vec![], now),
+            vec![Operation::Insert { new: result(1) }]
+        );
+    }
+}
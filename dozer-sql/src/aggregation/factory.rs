@@ -8,6 +8,7 @@ use dozer_core::{
 };
 use dozer_sql_expression::sqlparser::ast::{Expr, SelectItem};
 use dozer_types::errors::internal::BoxedError;
+use dozer_types::models::flags::{AggregationEmission, DecimalRounding, NumericOverflow};
 use dozer_types::models::udf_config::UdfConfig;
 use dozer_types::parking_lot::Mutex;
 use dozer_types::tonic::async_trait;
@@ -23,6 +24,11 @@ pub struct AggregationProcessorFactory {
     group_by: Vec<Expr>,
     having: Option<Expr>,
     enable_probabilistic_optimizations: bool,
+    emission: AggregationEmission,
+    /// Whether the input comes directly from TUMBLE or HOP.
+    windowed: bool,
+    overflow: NumericOverflow,
+    rounding: Option<DecimalRounding>,
     udfs: Vec<UdfConfig>,
     runtime: Arc<Runtime>,
 
@@ -31,12 +37,17 @@ pub struct AggregationProcessorFactory {
 }
 
 impl AggregationProcessorFactory {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: String,
         projection: Vec<SelectItem>,
         group_by: Vec<Expr>,
         having: Option<Expr>,
         enable_probabilistic_optimizations: bool,
+        emission: AggregationEmission,
+        windowed: bool,
+        overflow: NumericOverflow,
+        rounding: Option<DecimalRounding>,
         udfs: Vec<UdfConfig>,
         runtime: Arc<Runtime>,
     ) -> Self {
@@ -46,6 +57,10 @@ impl AggregationProcessorFactory {
             group_by,
             having,
             enable_probabilistic_optimizations,
+            emission,
+            windowed,
+            overflow,
+            rounding,
             udfs,
             runtime,
             type_name: Mutex::new(None),
@@ -53,8 +68,13 @@ impl AggregationProcessorFactory {
     }
 
     async fn get_planner(&self, input_schema: Schema) -> Result<CommonPlanner, PipelineError> {
-        let mut projection_planner =
-            CommonPlanner::new(input_schema, self.udfs.as_slice(), self.runtime.clone());
+        let mut projection_planner = CommonPlanner::new(
+            input_schema,
+            self.udfs.as_slice(),
+            self.runtime.clone(),
+            self.overflow,
+            self.rounding,
+        );
         projection_planner
             .plan(
                 self.projection.clone(),
@@ -133,6 +153,10 @@ impl ProcessorFactory for AggregationProcessorFactory {
                 input_schema.clone(),
                 planner.post_aggregation_schema,
                 self.enable_probabilistic_optimizations,
+                self.emission,
+                self.windowed,
+                self.overflow,
+                self.rounding,
             )?)
         };
         Ok(processor)
@@ -1,6 +1,7 @@
 pub mod aggregator;
 pub mod avg;
 pub mod count;
+mod emission;
 pub mod factory;
 pub mod max;
 pub mod max_value;
@@ -9,6 +10,7 @@ pub mod min_value;
 pub mod processor;
 pub mod sum;
 mod tests;
+pub mod window_funnel;
 
 pub mod max_append_only;
 pub mod min_append_only;
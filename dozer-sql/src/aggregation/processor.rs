@@ -1,6 +1,7 @@
 #![allow(clippy::too_many_arguments)]
 
 use crate::aggregation::aggregator::Aggregator;
+use crate::aggregation::emission::{Emitter, GroupChange};
 use crate::errors::PipelineError;
 use crate::utils::record_hashtable_key::{get_record_hash, RecordKey};
 use dozer_core::channels::ProcessorChannelForwarder;
@@ -9,8 +10,10 @@ use dozer_core::DEFAULT_PORT_HANDLE;
 use dozer_sql_expression::execution::Expression;
 use dozer_types::bincode;
 use dozer_types::errors::internal::BoxedError;
+use dozer_types::models::flags::{AggregationEmission, DecimalRounding, NumericOverflow};
 use dozer_types::types::{Field, FieldType, Operation, Record, Schema, TableOperation};
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 use crate::aggregation::aggregator::{
     get_aggregator_from_aggregator_type, get_aggregator_type_from_aggregation_expression,
@@ -28,10 +31,15 @@ struct AggregationState {
 }
 
 impl AggregationState {
-    pub fn new(types: &[AggregatorType], ret_types: &[FieldType]) -> Self {
+    pub fn new(
+        types: &[AggregatorType],
+        ret_types: &[FieldType],
+        overflow: NumericOverflow,
+        rounding: Option<DecimalRounding>,
+    ) -> Self {
         let mut states: Vec<AggregatorEnum> = Vec::new();
         for (idx, typ) in types.iter().enumerate() {
-            let mut aggr = get_aggregator_from_aggregator_type(*typ);
+            let mut aggr = get_aggregator_from_aggregator_type(*typ, overflow, rounding);
             aggr.init(ret_types[idx]);
             states.push(aggr);
         }
@@ -59,6 +67,9 @@ pub struct AggregationProcessor {
     default_segment_key: RecordKey,
     having_eval_schema: Schema,
     accurate_keys: bool,
+    emitter: Emitter,
+    overflow: NumericOverflow,
+    rounding: Option<DecimalRounding>,
 }
 
 enum AggregatorOperation {
@@ -77,6 +88,10 @@ impl AggregationProcessor {
         input_schema: Schema,
         aggregation_schema: Schema,
         enable_probabilistic_optimizations: bool,
+        emission: AggregationEmission,
+        windowed: bool,
+        overflow: NumericOverflow,
+        rounding: Option<DecimalRounding>,
     ) -> Result<Self, BoxedError> {
         let mut aggr_types = Vec::new();
         let mut aggr_measures = Vec::new();
@@ -94,6 +109,7 @@ impl AggregationProcessor {
         having_eval_schema_fields.extend(aggregation_schema.fields.clone());
 
         let accurate_keys = !enable_probabilistic_optimizations;
+        let emitter = Emitter::new(emission, windowed, &input_schema);
 
         Ok(Self {
             _id: id,
@@ -119,6 +135,9 @@ impl AggregationProcessor {
                 primary_index: vec![],
             },
             accurate_keys,
+            emitter,
+            overflow,
+            rounding,
         })
     }
 
@@ -180,17 +199,14 @@ impl AggregationProcessor {
         Ok(new_fields)
     }
 
-    fn agg_delete(&mut self, old: &mut Record) -> Result<Vec<Operation>, PipelineError> {
+    fn agg_delete(
+        &mut self,
+        old: &mut Record,
+        key: &RecordKey,
+    ) -> Result<Vec<Operation>, PipelineError> {
         let mut out_rec_delete: Vec<Field> = Vec::with_capacity(self.measures.len());
         let mut out_rec_insert: Vec<Field> = Vec::with_capacity(self.measures.len());
 
-        let key = if !self.dimensions.is_empty() {
-            Some(self.get_key(old)?)
-        } else {
-            None
-        };
-        let key = key.as_ref().unwrap_or(&self.default_segment_key);
-
         let curr_state_opt = self.states.get_mut(key);
         assert!(
             curr_state_opt.is_some(),
@@ -260,19 +276,19 @@ impl AggregationProcessor {
         Ok(res)
     }
 
-    fn agg_insert(&mut self, new: &mut Record) -> Result<Vec<Operation>, PipelineError> {
+    fn agg_insert(
+        &mut self,
+        new: &mut Record,
+        key: RecordKey,
+    ) -> Result<Vec<Operation>, PipelineError> {
         let mut out_rec_delete: Vec<Field> = Vec::with_capacity(self.measures.len());
         let mut out_rec_insert: Vec<Field> = Vec::with_capacity(self.measures.len());
 
-        let key = if !self.dimensions.is_empty() {
-            self.get_key(new)?
-        } else {
-            self.default_segment_key.clone()
-        };
-
         let curr_state = self.states.entry(key).or_insert(AggregationState::new(
             &self.measures_types,
             &self.measures_return_types,
+            self.overflow,
+            self.rounding,
         ));
 
         let new_values = Self::calc_and_fill_measures(
@@ -415,12 +431,12 @@ impl AggregationProcessor {
         &mut self,
         old: &mut Record,
         new: &mut Record,
-        key: RecordKey,
+        key: &RecordKey,
     ) -> Result<Vec<Operation>, PipelineError> {
         let mut out_rec_delete: Vec<Field> = Vec::with_capacity(self.measures.len());
         let mut out_rec_insert: Vec<Field> = Vec::with_capacity(self.measures.len());
 
-        let curr_state_opt = self.states.get_mut(&key);
+        let curr_state_opt = self.states.get_mut(key);
         assert!(
             curr_state_opt.is_some(),
             "Unable to find aggregator state during UPDATE operation"
@@ -518,42 +534,81 @@ impl AggregationProcessor {
         Ok(output_record)
     }
 
-    pub fn aggregate(&mut self, mut op: Operation) -> Result<Vec<Operation>, PipelineError> {
+    pub fn aggregate(&mut self, op: Operation) -> Result<Vec<Operation>, PipelineError> {
+        Ok(self
+            .aggregate_by_group(op)?
+            .into_iter()
+            .map(|change| change.op)
+            .collect())
+    }
+
+    fn aggregate_by_group(&mut self, mut op: Operation) -> Result<Vec<GroupChange>, PipelineError> {
         match op {
-            Operation::Insert { ref mut new } => Ok(self.agg_insert(new)?),
-            Operation::Delete { ref mut old } => Ok(self.agg_delete(old)?),
+            Operation::Insert { ref mut new } => {
+                let key = self.get_group_key(new)?;
+                let window_end = self.emitter.window_end(new);
+                let ops = self.agg_insert(new, key.clone())?;
+                Ok(Self::group_changes(ops, key, window_end))
+            }
+            Operation::Delete { ref mut old } => {
+                let key = self.get_group_key(old)?;
+                let window_end = self.emitter.window_end(old);
+                let ops = self.agg_delete(old, &key)?;
+                Ok(Self::group_changes(ops, key, window_end))
+            }
             Operation::Update {
                 ref mut old,
                 ref mut new,
             } => {
-                let (old_record_hash, new_record_hash) = if self.dimensions.is_empty() {
-                    (
-                        self.default_segment_key.clone(),
-                        self.default_segment_key.clone(),
-                    )
-                } else {
-                    (self.get_key(old)?, self.get_key(new)?)
-                };
-
-                if old_record_hash == new_record_hash {
-                    Ok(self.agg_update(old, new, old_record_hash)?)
+                let old_key = self.get_group_key(old)?;
+                let new_key = self.get_group_key(new)?;
+                let old_window_end = self.emitter.window_end(old);
+                let new_window_end = self.emitter.window_end(new);
+
+                if old_key == new_key {
+                    let ops = self.agg_update(old, new, &old_key)?;
+                    Ok(Self::group_changes(ops, old_key, old_window_end))
                 } else {
                     let mut r = Vec::with_capacity(2);
-                    r.extend(self.agg_delete(old)?);
-                    r.extend(self.agg_insert(new)?);
+                    let ops = self.agg_delete(old, &old_key)?;
+                    r.extend(Self::group_changes(ops, old_key, old_window_end));
+                    let ops = self.agg_insert(new, new_key.clone())?;
+                    r.extend(Self::group_changes(ops, new_key, new_window_end));
                     Ok(r)
                 }
             }
             Operation::BatchInsert { new } => {
                 let mut result = vec![];
                 for record in new {
-                    result.extend(self.aggregate(Operation::Insert { new: record })?);
+                    result.extend(self.aggregate_by_group(Operation::Insert { new: record })?);
                 }
                 Ok(result)
             }
         }
     }
 
+    fn group_changes(
+        ops: Vec<Operation>,
+        key: RecordKey,
+        window_end: Option<Field>,
+    ) -> Vec<GroupChange> {
+        ops.into_iter()
+            .map(|op| GroupChange {
+                key: key.clone(),
+                window_end: window_end.clone(),
+                op,
+            })
+            .collect()
+    }
+
+    fn get_group_key(&mut self, record: &Record) -> Result<RecordKey, PipelineError> {
+        if self.dimensions.is_empty() {
+            Ok(self.default_segment_key.clone())
+        } else {
+            self.get_key(record)
+        }
+    }
+
     fn get_key(&mut self, record: &Record) -> Result<RecordKey, PipelineError> {
         let mut key = Vec::<Field>::with_capacity(self.dimensions.len());
         for dimension in self.dimensions.iter_mut() {
@@ -577,7 +632,21 @@ impl Processor for AggregationProcessor {
         op: TableOperation,
         fw: &mut dyn ProcessorChannelForwarder,
     ) -> Result<(), BoxedError> {
-        let ops = self.aggregate(op.op)?;
+        let window_start = self.emitter.window_start(&op.op);
+        let changes = self.aggregate_by_group(op.op)?;
+        let ops = self.emitter.emit(window_start, changes, Instant::now());
+        for output_op in ops {
+            fw.send(TableOperation::without_id(output_op, DEFAULT_PORT_HANDLE));
+        }
+        Ok(())
+    }
+
+    fn flush_interval(&self) -> Option<Duration> {
+        self.emitter.flush_interval()
+    }
+
+    fn flush(&mut self, fw: &mut dyn ProcessorChannelForwarder) -> Result<(), BoxedError> {
+        let ops = self.emitter.flush(Instant::now());
         for output_op in ops {
             fw.send(TableOperation::without_id(output_op, DEFAULT_PORT_HANDLE));
         }
@@ -2,7 +2,11 @@ use crate::aggregation::aggregator::Aggregator;
 use crate::calculate_err_field;
 use crate::errors::PipelineError;
 use dozer_sql_expression::aggregate::AggregateFunctionType::Sum;
+use dozer_sql_expression::error::{Error, OperationError};
 use dozer_sql_expression::num_traits::FromPrimitive;
+use dozer_sql_expression::overflow::{integer_op, ArithmeticOp};
+use dozer_sql_expression::rounding::round_field;
+use dozer_types::models::flags::{DecimalRounding, NumericOverflow};
 use dozer_types::ordered_float::OrderedFloat;
 use dozer_types::rust_decimal::Decimal;
 
@@ -12,6 +16,10 @@ use dozer_types::types::{DozerDuration, Field, FieldType, TimeUnit};
 pub struct SumAggregator {
     current_state: SumState,
     return_type: Option<FieldType>,
+    #[bincode(with_serde)]
+    overflow: NumericOverflow,
+    #[bincode(with_serde)]
+    rounding: Option<DecimalRounding>,
 }
 
 #[derive(Debug, bincode::Encode, bincode::Decode)]
@@ -27,7 +35,7 @@ pub struct SumState {
 }
 
 impl SumAggregator {
-    pub fn new() -> Self {
+    pub fn new(overflow: NumericOverflow, rounding: Option<DecimalRounding>) -> Self {
         Self {
             current_state: SumState {
                 int_state: 0_i64,
@@ -39,6 +47,18 @@ impl SumAggregator {
                 duration_state: std::time::Duration::new(0, 0),
             },
             return_type: None,
+            overflow,
+            rounding,
+        }
+    }
+}
+
+impl SumAggregator {
+    /// Sums of decimals are exact, so they're only rounded to a configured scale.
+    fn round(&self, sum: Field) -> Field {
+        match &self.rounding {
+            Some(rounding) => round_field(sum, rounding, None),
+            None => sum,
         }
     }
 }
@@ -54,11 +74,25 @@ impl Aggregator for SumAggregator {
     }
 
     fn delete(&mut self, old: &[Field]) -> Result<Field, PipelineError> {
-        get_sum(old, &mut self.current_state, self.return_type, true)
+        let sum = get_sum(
+            old,
+            &mut self.current_state,
+            self.return_type,
+            self.overflow,
+            true,
+        )?;
+        Ok(self.round(sum))
     }
 
     fn insert(&mut self, new: &[Field]) -> Result<Field, PipelineError> {
-        get_sum(new, &mut self.current_state, self.return_type, false)
+        let sum = get_sum(
+            new,
+            &mut self.current_state,
+            self.return_type,
+            self.overflow,
+            false,
+        )?;
+        Ok(self.round(sum))
     }
 }
 
@@ -66,63 +100,45 @@ pub fn get_sum(
     fields: &[Field],
     current_state: &mut SumState,
     return_type: Option<FieldType>,
+    overflow: NumericOverflow,
     decr: bool,
 ) -> Result<Field, PipelineError> {
+    let op = if decr {
+        ArithmeticOp::Sub
+    } else {
+        ArithmeticOp::Add
+    };
     match return_type {
         Some(typ) => match typ {
             FieldType::UInt => {
-                if decr {
-                    for field in fields {
-                        let val = calculate_err_field!(field.to_uint(), Sum, field);
-                        current_state.uint_state -= val;
-                    }
-                } else {
-                    for field in fields {
-                        let val = calculate_err_field!(field.to_uint(), Sum, field);
-                        current_state.uint_state += val;
-                    }
+                for field in fields {
+                    let val = calculate_err_field!(field.to_uint(), Sum, field);
+                    current_state.uint_state =
+                        integer_op(op, current_state.uint_state, val, overflow)?;
                 }
                 Ok(Field::UInt(current_state.uint_state))
             }
             FieldType::U128 => {
-                if decr {
-                    for field in fields {
-                        let val = calculate_err_field!(field.to_u128(), Sum, field);
-                        current_state.u128_state -= val;
-                    }
-                } else {
-                    for field in fields {
-                        let val = calculate_err_field!(field.to_u128(), Sum, field);
-                        current_state.u128_state += val;
-                    }
+                for field in fields {
+                    let val = calculate_err_field!(field.to_u128(), Sum, field);
+                    current_state.u128_state =
+                        integer_op(op, current_state.u128_state, val, overflow)?;
                 }
                 Ok(Field::U128(current_state.u128_state))
             }
             FieldType::Int => {
-                if decr {
-                    for field in fields {
-                        let val = calculate_err_field!(field.to_int(), Sum, field);
-                        current_state.int_state -= val;
-                    }
-                } else {
-                    for field in fields {
-                        let val = calculate_err_field!(field.to_int(), Sum, field);
-                        current_state.int_state += val;
-                    }
+                for field in fields {
+                    let val = calculate_err_field!(field.to_int(), Sum, field);
+                    current_state.int_state =
+                        integer_op(op, current_state.int_state, val, overflow)?;
                 }
                 Ok(Field::Int(current_state.int_state))
             }
             FieldType::I128 => {
-                if decr {
-                    for field in fields {
-                        let val = calculate_err_field!(field.to_i128(), Sum, field);
-                        current_state.i128_state -= val;
-                    }
-                } else {
-                    for field in fields {
-                        let val = calculate_err_field!(field.to_i128(), Sum, field);
-                        current_state.i128_state += val;
-                    }
+                for field in fields {
+                    let val = calculate_err_field!(field.to_i128(), Sum, field);
+                    current_state.i128_state =
+                        integer_op(op, current_state.i128_state, val, overflow)?;
                 }
                 Ok(Field::I128(current_state.i128_state))
             }
@@ -141,16 +157,10 @@ pub fn get_sum(
                 Ok(Field::Float(OrderedFloat::from(current_state.float_state)))
             }
             FieldType::Decimal => {
-                if decr {
-                    for field in fields {
-                        let val = calculate_err_field!(field.to_decimal(), Sum, field);
-                        current_state.decimal_state -= val;
-                    }
-                } else {
-                    for field in fields {
-                        let val = calculate_err_field!(field.to_decimal(), Sum, field);
-                        current_state.decimal_state += val;
-                    }
+                for field in fields {
+                    let val = calculate_err_field!(field.to_decimal(), Sum, field);
+                    current_state.decimal_state =
+                        decimal_op(op, current_state.decimal_state, val, overflow)?;
                 }
                 Ok(Field::Decimal(current_state.decimal_state))
             }
@@ -187,3 +197,25 @@ pub fn get_sum(
         ))),
     }
 }
+
+/// Decimals can't wrap around, so `Wrap` is handled like `Error`.
+fn decimal_op(
+    op: ArithmeticOp,
+    left: Decimal,
+    right: Decimal,
+    overflow: NumericOverflow,
+) -> Result<Decimal, PipelineError> {
+    let result = match (op, overflow) {
+        (ArithmeticOp::Sub, NumericOverflow::Saturate) => Some(left.saturating_sub(right)),
+        (_, NumericOverflow::Saturate) => Some(left.saturating_add(right)),
+        (ArithmeticOp::Sub, _) => left.checked_sub(right),
+        (_, _) => left.checked_add(right),
+    };
+    result.ok_or_else(|| {
+        PipelineError::Expression(Error::SqlError(if op == ArithmeticOp::Sub {
+            OperationError::SubtractionOverflow
+        } else {
+            OperationError::AdditionOverflow
+        }))
+    })
+}
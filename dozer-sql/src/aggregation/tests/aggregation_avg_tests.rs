@@ -1,3 +1,5 @@
+use crate::aggregation::aggregator::Aggregator;
+use crate::aggregation::avg::AvgAggregator;
 use crate::aggregation::tests::aggregation_tests_utils::{
     delete_exp, delete_field, get_decimal_div_field, get_decimal_field, get_duration_div_field,
     get_duration_field, init_input_schema, init_processor, insert_exp, insert_field, update_exp,
@@ -7,6 +9,9 @@ use crate::aggregation::tests::aggregation_tests_utils::{
 };
 use crate::output;
 use dozer_core::DEFAULT_PORT_HANDLE;
+use dozer_types::models::flags::{DecimalRounding, RoundingMode};
+use dozer_types::rust_decimal;
+use dozer_types::types::Field;
 use dozer_types::types::FieldType::{Decimal, Duration, Float, Int, UInt};
 use std::collections::HashMap;
 
@@ -1021,3 +1026,34 @@ fn test_avg_aggregation_duration_null() {
     exp = vec![delete_exp(ITALY, &get_duration_field(0))];
     assert_eq!(out, exp);
 }
+
+#[test]
+fn test_avg_aggregation_decimal_rounding() {
+    let avg_with = |mode| {
+        let mut aggregator = AvgAggregator::new(
+            Default::default(),
+            Some(DecimalRounding { mode, scale: None }),
+        );
+        aggregator.init(Decimal);
+        aggregator
+            .insert(&[Field::Decimal(rust_decimal::Decimal::new(100, 2))])
+            .unwrap();
+        aggregator
+            .insert(&[Field::Decimal(rust_decimal::Decimal::new(105, 2))])
+            .unwrap()
+    };
+
+    // The average is 1.025, rounded to the scale of the sum.
+    assert_eq!(
+        avg_with(RoundingMode::HalfEven),
+        Field::Decimal(rust_decimal::Decimal::new(102, 2))
+    );
+    assert_eq!(
+        avg_with(RoundingMode::HalfUp),
+        Field::Decimal(rust_decimal::Decimal::new(103, 2))
+    );
+    assert_eq!(
+        avg_with(RoundingMode::Truncate),
+        Field::Decimal(rust_decimal::Decimal::new(102, 2))
+    );
+}
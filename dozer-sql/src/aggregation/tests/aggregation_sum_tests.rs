@@ -1,3 +1,5 @@
+use crate::aggregation::aggregator::Aggregator;
+use crate::aggregation::sum::SumAggregator;
 use crate::aggregation::tests::aggregation_tests_utils::{
     delete_exp, delete_field, get_decimal_field, get_duration_field, init_input_schema,
     init_processor, insert_exp, insert_field, update_exp, update_field, FIELD_0_FLOAT, FIELD_0_INT,
@@ -8,6 +10,8 @@ use crate::aggregation::tests::aggregation_tests_utils::{
 };
 use crate::output;
 use dozer_core::DEFAULT_PORT_HANDLE;
+use dozer_types::models::flags::NumericOverflow;
+use dozer_types::types::Field;
 use dozer_types::types::FieldType::{Decimal, Duration, Float, Int, UInt};
 use std::collections::HashMap;
 
@@ -947,3 +951,23 @@ fn test_sum_aggregation_duration_null() {
     exp = vec![delete_exp(ITALY, &get_duration_field(0))];
     assert_eq!(out, exp);
 }
+
+#[test]
+fn test_sum_aggregation_int_overflow() {
+    let sum_with = |overflow| {
+        let mut aggregator = SumAggregator::new(overflow, None);
+        aggregator.init(Int);
+        aggregator.insert(&[Field::Int(i64::MAX)]).unwrap();
+        aggregator.insert(&[Field::Int(1)])
+    };
+
+    assert!(sum_with(NumericOverflow::Error).is_err());
+    assert_eq!(
+        sum_with(NumericOverflow::Saturate).unwrap(),
+        Field::Int(i64::MAX)
+    );
+    assert_eq!(
+        sum_with(NumericOverflow::Wrap).unwrap(),
+        Field::Int(i64::MIN)
+    );
+}
@@ -72,7 +72,13 @@ fn test_planner_with_aggregator() {
         .clone();
 
     let runtime = create_test_runtime();
-    let mut projection_planner = CommonPlanner::new(schema.clone(), &[], runtime.clone());
+    let mut projection_planner = CommonPlanner::new(
+        schema.clone(),
+        &[],
+        runtime.clone(),
+        Default::default(),
+        None,
+    );
     let statement = get_select(sql).unwrap();
 
     runtime
@@ -92,6 +98,10 @@ fn test_planner_with_aggregator() {
         schema,
         projection_planner.post_aggregation_schema,
         false,
+        Default::default(),
+        false,
+        Default::default(),
+        None,
     )
     .unwrap();
 
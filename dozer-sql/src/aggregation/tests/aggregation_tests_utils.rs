@@ -24,7 +24,13 @@ pub(crate) fn init_processor(
         .unwrap_or_else(|| panic!("Error getting Input Schema"));
 
     let runtime = create_test_runtime();
-    let mut projection_planner = CommonPlanner::new(input_schema.clone(), &[], runtime.clone());
+    let mut projection_planner = CommonPlanner::new(
+        input_schema.clone(),
+        &[],
+        runtime.clone(),
+        Default::default(),
+        None,
+    );
     let statement = get_select(sql).unwrap();
 
     runtime
@@ -44,6 +50,10 @@ pub(crate) fn init_processor(
         input_schema.clone(),
         projection_planner.post_aggregation_schema,
         false,
+        Default::default(),
+        false,
+        Default::default(),
+        None,
     )
     .unwrap_or_else(|e| panic!("{}", e.to_string()));
 
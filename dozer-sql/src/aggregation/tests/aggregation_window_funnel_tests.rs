@@ -0,0 +1,208 @@
+use crate::aggregation::aggregator::get_aggregator_type_from_aggregation_expression;
+use crate::aggregation::tests::aggregation_tests_utils::{get_ts_field, init_processor};
+use crate::errors::PipelineError;
+use crate::output;
+use dozer_core::DEFAULT_PORT_HANDLE;
+use dozer_sql_expression::aggregate::AggregateFunctionType;
+use dozer_sql_expression::execution::Expression;
+use dozer_types::types::{
+    Field, FieldDefinition, FieldType, Operation, Record, Schema, SourceDefinition,
+};
+use std::collections::HashMap;
+
+const SQL: &str =
+    "SELECT UserId, WINDOW_FUNNEL(EventTime, 60, Event = 'view', Event = 'cart', Event = 'buy') \
+    FROM Events GROUP BY UserId";
+
+fn init_events_schema() -> Schema {
+    let mut schema = Schema::default();
+    for (name, typ) in [
+        ("UserId", FieldType::Int),
+        ("Event", FieldType::String),
+        ("EventTime", FieldType::Timestamp),
+    ] {
+        schema.field(
+            FieldDefinition::new(name.to_string(), typ, false, SourceDefinition::Dynamic),
+            false,
+        );
+    }
+    schema
+}
+
+fn event(user_id: i64, event: &str, seconds: i64) -> Record {
+    Record::new(vec![
+        Field::Int(user_id),
+        Field::String(event.to_string()),
+        get_ts_field(seconds * 1000),
+    ])
+}
+
+fn funnel(user_id: i64, steps: u64) -> Record {
+    Record::new(vec![Field::Int(user_id), Field::UInt(steps)])
+}
+
+#[test]
+fn test_window_funnel() {
+    let mut processor = init_processor(
+        SQL,
+        HashMap::from([(DEFAULT_PORT_HANDLE, init_events_schema())]),
+    )
+    .unwrap();
+
+    let out = output!(
+        processor,
+        Operation::Insert {
+            new: event(1, "view", 0)
+        }
+    );
+    assert_eq!(out, vec![Operation::Insert { new: funnel(1, 1) }]);
+
+    // Buying before adding to the cart doesn't advance the funnel.
+    let out = output!(
+        processor,
+        Operation::Insert {
+            new: event(1, "buy", 10)
+        }
+    );
+    assert_eq!(
+        out,
+        vec![Operation::Update {
+            old: funnel(1, 1),
+            new: funnel(1, 1),
+        }]
+    );
+
+    let out = output!(
+        processor,
+        Operation::Insert {
+            new: event(1, "cart", 20)
+        }
+    );
+    assert_eq!(
+        out,
+        vec![Operation::Update {
+            old: funnel(1, 1),
+            new: funnel(1, 2),
+        }]
+    );
+
+    // Too late to complete the funnel started at 0.
+    let out = output!(
+        processor,
+        Operation::Insert {
+            new: event(1, "buy", 61)
+        }
+    );
+    assert_eq!(
+        out,
+        vec![Operation::Update {
+            old: funnel(1, 2),
+            new: funnel(1, 2),
+        }]
+    );
+
+    let out = output!(
+        processor,
+        Operation::Insert {
+            new: event(1, "buy", 30)
+        }
+    );
+    assert_eq!(
+        out,
+        vec![Operation::Update {
+            old: funnel(1, 2),
+            new: funnel(1, 3),
+        }]
+    );
+
+    let out = output!(
+        processor,
+        Operation::Delete {
+            old: event(1, "cart", 20)
+        }
+    );
+    assert_eq!(
+        out,
+        vec![Operation::Update {
+            old: funnel(1, 3),
+            new: funnel(1, 1),
+        }]
+    );
+}
+
+#[test]
+fn test_window_funnel_prunes_old_events() {
+    let mut processor = init_processor(
+        SQL,
+        HashMap::from([(DEFAULT_PORT_HANDLE, init_events_schema())]),
+    )
+    .unwrap();
+
+    output!(
+        processor,
+        Operation::Insert {
+            new: event(1, "view", 0)
+        }
+    );
+    output!(
+        processor,
+        Operation::Insert {
+            new: event(1, "cart", 20)
+        }
+    );
+
+    // More than twice the window later, the first events are pruned but the steps they reached are kept.
+    let out = output!(
+        processor,
+        Operation::Insert {
+            new: event(1, "view", 200)
+        }
+    );
+    assert_eq!(
+        out,
+        vec![Operation::Update {
+            old: funnel(1, 2),
+            new: funnel(1, 2),
+        }]
+    );
+
+    // Pruned events can't be deleted, and events that early are ignored.
+    let out = output!(
+        processor,
+        Operation::Delete {
+            old: event(1, "cart", 20)
+        }
+    );
+    assert_eq!(
+        out,
+        vec![Operation::Update {
+            old: funnel(1, 2),
+            new: funnel(1, 2),
+        }]
+    );
+    let out = output!(
+        processor,
+        Operation::Insert {
+            new: event(1, "buy", 30)
+        }
+    );
+    assert_eq!(
+        out,
+        vec![Operation::Update {
+            old: funnel(1, 2),
+            new: funnel(1, 2),
+        }]
+    );
+}
+
+#[test]
+fn test_window_funnel_too_many_steps() {
+    let expression = Expression::AggregateFunction {
+        fun: AggregateFunctionType::WindowFunnel,
+        args: vec![Expression::Literal(Field::Boolean(true)); 67],
+    };
+    assert!(matches!(
+        get_aggregator_type_from_aggregation_expression(&expression, &init_events_schema()),
+        Err(PipelineError::TooManyWindowFunnelSteps(65))
+    ));
+}
@@ -0,0 +1,176 @@
+use crate::aggregation::aggregator::Aggregator;
+use crate::calculate_err_field;
+use crate::errors::PipelineError;
+use dozer_sql_expression::aggregate::AggregateFunctionType::WindowFunnel;
+use dozer_sql_expression::aggregate::WINDOW_FUNNEL_MAX_STEPS;
+use dozer_types::types::{Field, FieldType};
+use std::collections::BTreeMap;
+
+/// `WINDOW_FUNNEL(time, window, step_1, ..., step_n)` returns how many steps of the funnel a group went through, in order,
+/// with all of them happening within `window` of the first one. `window` is either a duration or a number of seconds.
+///
+/// Each record is an event at `time`, matching the steps whose condition is true. Other events may happen between the steps.
+///
+/// Events more than twice the window before the latest one are pruned, keeping the steps reached by the sequences they start.
+/// Inserting or deleting events that early doesn't change the result any more.
+#[derive(Debug, bincode::Encode, bincode::Decode)]
+pub struct WindowFunnelAggregator {
+    /// The number of events by their time in microseconds and the steps they match, as a bit mask.
+    events: BTreeMap<(i64, u64), u64>,
+    /// The window in microseconds, read from the first event.
+    window: Option<i64>,
+    steps: usize,
+    return_type: Option<FieldType>,
+    /// The time of the latest event inserted, in microseconds.
+    latest: Option<i64>,
+    /// The steps reached by the sequences starting with pruned events.
+    pruned_steps: u64,
+}
+
+impl WindowFunnelAggregator {
+    pub fn new() -> Self {
+        Self {
+            events: BTreeMap::new(),
+            window: None,
+            steps: 0,
+            return_type: None,
+            latest: None,
+            pruned_steps: 0,
+        }
+    }
+
+    /// The time before which events are pruned.
+    fn horizon(&self) -> Option<i64> {
+        Some(self.latest?.saturating_sub(self.window?.saturating_mul(2)))
+    }
+
+    /// Returns the time and the steps matched by the event `fields`, or `None` if it has no time.
+    fn event(&mut self, fields: &[Field]) -> Result<Option<(i64, u64)>, PipelineError> {
+        let time = match fields.first() {
+            Some(Field::Timestamp(time)) => time.timestamp_micros(),
+            Some(Field::Null) => return Ok(None),
+            other => {
+                return Err(PipelineError::InvalidFunctionArgument(
+                    WindowFunnel.to_string(),
+                    other.cloned().unwrap_or(Field::Null),
+                    0,
+                ))
+            }
+        };
+        if self.window.is_none() {
+            self.window = Some(window_micros(fields.get(1).unwrap_or(&Field::Null))?);
+            self.steps = fields.len().saturating_sub(2);
+            if self.steps > WINDOW_FUNNEL_MAX_STEPS {
+                return Err(PipelineError::TooManyWindowFunnelSteps(self.steps));
+            }
+        }
+        let steps = fields
+            .iter()
+            .skip(2)
+            .enumerate()
+            .filter(|(_, matched)| **matched == Field::Boolean(true))
+            .fold(0_u64, |steps, (step, _)| steps | (1 << step));
+        Ok(Some((time, steps)))
+    }
+
+    /// The number of steps reached by the sequences starting before `starts_before`, if any,
+    /// tracking for each step the latest start of a sequence that reached it.
+    fn steps_reached(&self, starts_before: Option<i64>) -> u64 {
+        let window = self.window.unwrap_or(0);
+        let mut starts: Vec<Option<i64>> = vec![None; self.steps];
+        for &(time, steps) in self.events.keys() {
+            // Advance the furthest sequences first, so an event advances each one step at most.
+            for step in (1..starts.len()).rev() {
+                if steps & (1 << step) == 0 {
+                    continue;
+                }
+                if let Some(start) = starts[step - 1] {
+                    if time - start <= window {
+                        starts[step] = starts[step].max(Some(start));
+                    }
+                }
+            }
+            if steps & 1 == 1
+                && !starts.is_empty()
+                && starts_before.map_or(true, |before| time < before)
+            {
+                starts[0] = Some(time);
+            }
+        }
+        starts
+            .iter()
+            .rposition(Option::is_some)
+            .map_or(0, |step| step as u64 + 1)
+    }
+
+    /// Prunes the events before the horizon once no event can be inserted in a sequence with them.
+    fn prune(&mut self) {
+        let Some(horizon) = self.horizon() else {
+            return;
+        };
+        if self
+            .events
+            .first_key_value()
+            .map_or(true, |((time, _), _)| *time >= horizon)
+        {
+            return;
+        }
+        self.pruned_steps = self.pruned_steps.max(self.steps_reached(Some(horizon)));
+        self.events = self.events.split_off(&(horizon, 0));
+    }
+
+    fn get_funnel(&self) -> Result<Field, PipelineError> {
+        let steps = self.pruned_steps.max(self.steps_reached(None));
+        match self.return_type {
+            Some(FieldType::UInt) | None => Ok(Field::UInt(steps)),
+            Some(FieldType::Int) => Ok(Field::Int(steps as i64)),
+            Some(typ) => Err(PipelineError::InvalidReturnType(format!(
+                "Not supported return type {typ} for {WindowFunnel}"
+            ))),
+        }
+    }
+}
+
+fn window_micros(window: &Field) -> Result<i64, PipelineError> {
+    let micros = match window {
+        Field::UInt(seconds) => (*seconds as i64).checked_mul(1_000_000),
+        Field::Int(seconds) if *seconds >= 0 => seconds.checked_mul(1_000_000),
+        Field::Duration(duration) => i64::try_from(duration.0.as_micros()).ok(),
+        _ => None,
+    };
+    Ok(calculate_err_field!(micros, WindowFunnel, window))
+}
+
+impl Aggregator for WindowFunnelAggregator {
+    fn init(&mut self, return_type: FieldType) {
+        self.return_type = Some(return_type);
+    }
+
+    fn update(&mut self, old: &[Field], new: &[Field]) -> Result<Field, PipelineError> {
+        self.delete(old)?;
+        self.insert(new)
+    }
+
+    fn delete(&mut self, old: &[Field]) -> Result<Field, PipelineError> {
+        if let Some(event) = self.event(old)? {
+            if let Some(count) = self.events.get_mut(&event) {
+                *count -= 1;
+                if *count == 0 {
+                    self.events.remove(&event);
+                }
+            }
+        }
+        self.get_funnel()
+    }
+
+    fn insert(&mut self, new: &[Field]) -> Result<Field, PipelineError> {
+        if let Some(event) = self.event(new)? {
+            if self.horizon().map_or(true, |horizon| event.0 >= horizon) {
+                *self.events.entry(event).or_insert(0) += 1;
+                self.latest = self.latest.max(Some(event.0));
+                self.prune();
+            }
+        }
+        self.get_funnel()
+    }
+}
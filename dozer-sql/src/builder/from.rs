@@ -57,6 +57,7 @@ fn insert_table_to_pipeline(
         Ok(ConnectionInfo {
             input_nodes: connection_info.input_nodes,
             output_node: (product_processor_name, DEFAULT_PORT_HANDLE),
+            windowed: connection_info.windowed,
         })
     } else {
         insert_table_processor_to_pipeline(relation, pipeline, pipeline_idx, query_context)
@@ -103,6 +104,7 @@ fn insert_table_processor_to_pipeline(
     Ok(ConnectionInfo {
         input_nodes,
         output_node: (processor_name, DEFAULT_PORT_HANDLE),
+        windowed: false,
     })
 }
 
@@ -92,6 +92,7 @@ pub fn insert_join_to_pipeline(
         left_join_source = JoinSource::Join(ConnectionInfo {
             input_nodes: input_nodes.clone(),
             output_node: (join_processor_name, DEFAULT_PORT_HANDLE),
+            windowed: false,
         });
     }
 
@@ -253,6 +253,7 @@ fn select_to_pipeline(
 
     let input_nodes = connection_info.input_nodes;
     let output_node = connection_info.output_node;
+    let windowed = connection_info.windowed;
 
     let gen_agg_name = format!("agg--{}", query_ctx.get_next_processor_id());
 
@@ -276,6 +277,15 @@ fn select_to_pipeline(
         }
     }
 
+    let output_table_name = if let Some(into) = &select.into {
+        Some(into.name.to_string())
+    } else {
+        table_info.override_name.clone()
+    };
+    let emission = pipeline
+        .flags()
+        .aggregation_emission_of(output_table_name.as_deref().unwrap_or(&table_info.name.0));
+
     let aggregation = AggregationProcessorFactory::new(
         gen_agg_name.clone(),
         select.projection,
@@ -286,6 +296,10 @@ fn select_to_pipeline(
             .enable_probabilistic_optimizations
             .in_aggregations
             .unwrap_or(false),
+        emission,
+        windowed,
+        pipeline.flags().numeric_overflow,
+        pipeline.flags().decimal_rounding,
         query_ctx.udfs.clone(),
         query_ctx.runtime.clone(),
     );
@@ -299,6 +313,8 @@ fn select_to_pipeline(
             selection,
             query_ctx.udfs.clone(),
             query_ctx.runtime.clone(),
+            pipeline.flags().numeric_overflow,
+            pipeline.flags().decimal_rounding,
         );
 
         pipeline.add_processor(Box::new(selection), gen_selection_name.clone());
@@ -333,12 +349,6 @@ fn select_to_pipeline(
         },
     );
 
-    let output_table_name = if let Some(into) = select.into {
-        Some(into.name.to_string())
-    } else {
-        table_info.override_name.clone()
-    };
-
     if is_top_select && output_table_name.is_none() {
         return Err(PipelineError::MissingIntoClause);
     }
@@ -556,6 +566,8 @@ fn get_from_source(
 struct ConnectionInfo {
     input_nodes: Vec<(String, String, PortHandle)>,
     output_node: (String, PortHandle),
+    /// Whether the output comes from TUMBLE or HOP, so ends with the window start and end.
+    windowed: bool,
 }
 
 mod common;
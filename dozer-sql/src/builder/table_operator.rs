@@ -97,8 +97,9 @@ pub fn insert_table_operator_processor_to_pipeline(
     pipeline_idx: usize,
     query_context: &mut QueryContext,
 ) -> Result<ConnectionInfo, PipelineError> {
+    let name = operator.name.to_uppercase();
     let (processor_name, processor): (_, Box<dyn ProcessorFactory>) =
-        if operator.name.to_uppercase() == "TTL" {
+        if name == "TTL" || name == "PATTERN" {
             let processor_name = generate_name("TOP", &operator, query_context);
             let processor = Box::new(TableOperatorProcessorFactory::new(
                 processor_name.clone(),
@@ -107,8 +108,7 @@ pub fn insert_table_operator_processor_to_pipeline(
                 query_context.runtime.clone(),
             ));
             (processor_name, processor)
-        } else if operator.name.to_uppercase() == "TUMBLE" || operator.name.to_uppercase() == "HOP"
-        {
+        } else if name == "TUMBLE" || name == "HOP" {
             let processor_name = generate_name("WIN", &operator, query_context);
             let processor = Box::new(WindowProcessorFactory::new(
                 processor_name.clone(),
@@ -133,6 +133,8 @@ pub fn insert_table_operator_processor_to_pipeline(
         ));
     };
 
+    // TTL passes the records of a window through unchanged.
+    let mut windowed = name == "TUMBLE" || name == "HOP";
     let source_name = match table {
         TableOperatorArg::Argument(argument) => get_source_name(&operator.name, &argument)?,
         TableOperatorArg::Descriptor(descriptor) => {
@@ -142,6 +144,7 @@ pub fn insert_table_operator_processor_to_pipeline(
                 pipeline_idx,
                 query_context,
             )?;
+            windowed |= name == "TTL" && connection_info.windowed;
             connection_info.output_node.0
         }
     };
@@ -173,6 +176,7 @@ pub fn insert_table_operator_processor_to_pipeline(
     Ok(ConnectionInfo {
         input_nodes,
         output_node: (processor_name, DEFAULT_PORT_HANDLE),
+        windowed,
     })
 }
 
@@ -1,6 +1,7 @@
 #![allow(clippy::enum_variant_names)]
 
 use dozer_core::node::PortHandle;
+use dozer_sql_expression::aggregate::WINDOW_FUNNEL_MAX_STEPS;
 use dozer_types::chrono::RoundingError;
 use dozer_types::errors::internal::BoxedError;
 use dozer_types::errors::types::{DeserializationError, TypeError};
@@ -46,6 +47,8 @@ pub enum PipelineError {
     InvalidFunctionArgument(String, Field, usize),
     #[error("Not enough arguments for function {0}()")]
     NotEnoughArguments(String),
+    #[error("WINDOW_FUNNEL() supports at most {WINDOW_FUNNEL_MAX_STEPS} steps, got {0}")]
+    TooManyWindowFunnelSteps(usize),
     #[error("Missing INTO clause for top-level SELECT statement")]
     MissingIntoClause,
     #[error("Duplicate INTO table name found: {0:?}")]
@@ -314,4 +317,10 @@ pub enum TableOperatorError {
 
     #[error("TTL input must evaluate to timestamp, but it evaluates to {0}")]
     InvalidTtlInputType(Field),
+
+    #[error("PATTERN time must evaluate to timestamp, but it evaluates to {0}")]
+    InvalidPatternTimeType(Field),
+
+    #[error("PATTERN needs at least two steps, but {0} are given")]
+    NotEnoughPatternSteps(usize),
 }
@@ -94,6 +94,8 @@ fn test_column_execution() {
         left: Box::new(Expression::Literal(Field::Boolean(true))),
         operator: BinaryOperatorType::And,
         right: Box::new(Expression::Literal(Field::Boolean(false))),
+        overflow: Default::default(),
+        rounding: None,
     };
     assert_eq!(
         e.evaluate(&record, &schema)
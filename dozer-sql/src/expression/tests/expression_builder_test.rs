@@ -315,7 +315,9 @@ fn test_3_nested_aggr_function_and_sum() {
                 fun: ScalarFunctionType::Round,
                 args: vec![Expression::Column { index: 2 }]
             }),
-            right: Box::new(Expression::Column { index: 3 })
+            right: Box::new(Expression::Column { index: 3 }),
+            overflow: Default::default(),
+            rounding: None,
         }
     );
 }
@@ -383,9 +385,13 @@ fn test_3_nested_aggr_function_and_sum_3() {
                     fun: ScalarFunctionType::Round,
                     args: vec![Expression::Column { index: 2 }]
                 }),
-                right: Box::new(Expression::Column { index: 3 })
+                right: Box::new(Expression::Column { index: 3 }),
+                overflow: Default::default(),
+                rounding: None,
             }),
-            right: Box::new(Expression::Column { index: 0 })
+            right: Box::new(Expression::Column { index: 0 }),
+            overflow: Default::default(),
+            rounding: None,
         }
     );
 }
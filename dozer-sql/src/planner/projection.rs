@@ -6,6 +6,7 @@ use crate::errors::PipelineError;
 use dozer_sql_expression::builder::ExpressionBuilder;
 use dozer_sql_expression::execution::Expression;
 use dozer_sql_expression::sqlparser::ast::{Expr, Ident, SelectItem};
+use dozer_types::models::flags::{DecimalRounding, NumericOverflow};
 use dozer_types::models::udf_config::UdfConfig;
 use dozer_types::types::{FieldDefinition, Schema};
 use tokio::runtime::Runtime;
@@ -28,6 +29,8 @@ pub struct CommonPlanner<'a> {
     pub projection_output: Vec<Expression>,
     pub udfs: &'a [UdfConfig],
     pub runtime: Arc<Runtime>,
+    overflow: NumericOverflow,
+    rounding: Option<DecimalRounding>,
 }
 
 impl<'a> CommonPlanner<'_> {
@@ -79,7 +82,9 @@ impl<'a> CommonPlanner<'_> {
             let mut builder = ExpressionBuilder::new(
                 self.input_schema.fields.len() + self.aggregation_output.len(),
                 self.runtime.clone(),
-            );
+            )
+            .with_numeric_overflow(self.overflow)
+            .with_decimal_rounding(self.rounding);
             let projection_expression = builder
                 .build(true, &expr, &self.input_schema, self.udfs)
                 .await?;
@@ -118,7 +123,9 @@ impl<'a> CommonPlanner<'_> {
             let mut builder = ExpressionBuilder::new(
                 self.input_schema.fields.len() + self.aggregation_output.len(),
                 self.runtime.clone(),
-            );
+            )
+            .with_numeric_overflow(self.overflow)
+            .with_decimal_rounding(self.rounding);
             let projection_expression = builder
                 .build(true, &expr, &self.input_schema, self.udfs)
                 .await?;
@@ -150,7 +157,9 @@ impl<'a> CommonPlanner<'_> {
             self.input_schema.fields.len(),
             self.aggregation_output.clone(),
             self.runtime.clone(),
-        );
+        )
+        .with_numeric_overflow(self.overflow)
+        .with_decimal_rounding(self.rounding);
         let having_expression = builder
             .build(true, &expr, &self.input_schema, self.udfs)
             .await?;
@@ -182,7 +191,9 @@ impl<'a> CommonPlanner<'_> {
             let mut builder = ExpressionBuilder::new(
                 self.input_schema.fields.len() + self.aggregation_output.len(),
                 self.runtime.clone(),
-            );
+            )
+            .with_numeric_overflow(self.overflow)
+            .with_decimal_rounding(self.rounding);
             let groupby_expression = builder
                 .build(false, &expr, &self.input_schema, self.udfs)
                 .await?;
@@ -232,6 +243,8 @@ impl<'a> CommonPlanner<'_> {
         input_schema: Schema,
         udfs: &'a [UdfConfig],
         runtime: Arc<Runtime>,
+        overflow: NumericOverflow,
+        rounding: Option<DecimalRounding>,
     ) -> CommonPlanner<'a> {
         CommonPlanner {
             input_schema: input_schema.clone(),
@@ -243,6 +256,8 @@ impl<'a> CommonPlanner<'_> {
             projection_output: Vec::new(),
             udfs,
             runtime,
+            overflow,
+            rounding,
         }
     }
 }
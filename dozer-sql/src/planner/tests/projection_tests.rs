@@ -40,7 +40,8 @@ fn test_basic_projection() {
         .to_owned();
 
     let runtime = create_test_runtime();
-    let mut projection_planner = CommonPlanner::new(schema, &[], runtime.clone());
+    let mut projection_planner =
+        CommonPlanner::new(schema, &[], runtime.clone(), Default::default(), None);
     let statement = get_select(sql).unwrap();
 
     runtime
@@ -125,7 +126,9 @@ fn test_basic_projection() {
         Some(Expression::BinaryOperator {
             operator: BinaryOperatorType::Gt,
             left: Box::new(Expression::Column { index: 2 }),
-            right: Box::new(Expression::Column { index: 3 })
+            right: Box::new(Expression::Column { index: 3 }),
+            overflow: Default::default(),
+            rounding: None,
         })
     );
 }
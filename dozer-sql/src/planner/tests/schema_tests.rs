@@ -45,7 +45,8 @@ fn test_schema_index_partial_group_by() {
         .to_owned();
 
     let runtime = create_test_runtime();
-    let mut projection_planner = CommonPlanner::new(schema, &[], runtime.clone());
+    let mut projection_planner =
+        CommonPlanner::new(schema, &[], runtime.clone(), Default::default(), None);
     let statement = get_select(sql).unwrap();
 
     runtime
@@ -105,7 +106,8 @@ fn test_schema_index_full_group_by() {
         .to_owned();
 
     let runtime = create_test_runtime();
-    let mut projection_planner = CommonPlanner::new(schema, &[], runtime.clone());
+    let mut projection_planner =
+        CommonPlanner::new(schema, &[], runtime.clone(), Default::default(), None);
     let statement = get_select(sql).unwrap();
 
     runtime
@@ -147,6 +147,7 @@ mod tests {
                         connection: "test".into(),
                         name: table_name.into(),
                     },
+                    annotations: Default::default(),
                 },
                 true,
             )
@@ -159,6 +160,7 @@ mod tests {
                         connection: "test".into(),
                         name: table_name.into(),
                     },
+                    annotations: Default::default(),
                 },
                 false,
             );
@@ -112,12 +112,17 @@ impl ProcessorFactory for ProjectionProcessorFactory {
         for e in select_expr.iter() {
             let field_name = e.0.clone();
             let field_type = e.1.get_type(input_schema)?;
-            fields.push(FieldDefinition::new(
+            let mut field = FieldDefinition::new(
                 field_name,
                 field_type.return_type,
                 field_type.nullable,
                 field_type.source,
-            ));
+            );
+            // Selected columns keep their annotations, computed ones have none.
+            if let Expression::Column { index } = &e.1 {
+                field.annotations = input_schema.fields[*index].annotations.clone();
+            }
+            fields.push(field);
         }
         output_schema.fields = fields;
 
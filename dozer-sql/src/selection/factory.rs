@@ -8,6 +8,7 @@ use dozer_core::{
 };
 use dozer_sql_expression::builder::ExpressionBuilder;
 use dozer_sql_expression::sqlparser::ast::Expr as SqlExpr;
+use dozer_types::models::flags::{DecimalRounding, NumericOverflow};
 use dozer_types::{errors::internal::BoxedError, types::Schema};
 use dozer_types::{models::udf_config::UdfConfig, tonic::async_trait};
 use tokio::runtime::Runtime;
@@ -20,6 +21,8 @@ pub struct SelectionProcessorFactory {
     id: String,
     udfs: Vec<UdfConfig>,
     runtime: Arc<Runtime>,
+    overflow: NumericOverflow,
+    rounding: Option<DecimalRounding>,
 }
 
 impl SelectionProcessorFactory {
@@ -29,12 +32,16 @@ impl SelectionProcessorFactory {
         statement: SqlExpr,
         udf_config: Vec<UdfConfig>,
         runtime: Arc<Runtime>,
+        overflow: NumericOverflow,
+        rounding: Option<DecimalRounding>,
     ) -> Self {
         Self {
             statement,
             id,
             udfs: udf_config,
             runtime,
+            overflow,
+            rounding,
         }
     }
 }
@@ -77,6 +84,8 @@ impl ProcessorFactory for SelectionProcessorFactory {
             .ok_or(PipelineError::InvalidPortHandle(DEFAULT_PORT_HANDLE))?;
 
         match ExpressionBuilder::new(schema.fields.len(), self.runtime.clone())
+            .with_numeric_overflow(self.overflow)
+            .with_decimal_rounding(self.rounding)
             .build(false, &self.statement, schema, &self.udfs)
             .await
         {
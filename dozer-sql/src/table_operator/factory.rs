@@ -22,6 +22,7 @@ use crate::{
 use super::{
     lifetime::LifetimeTableOperator,
     operator::{TableOperator, TableOperatorType},
+    pattern::PatternTableOperator,
     processor::TableOperatorProcessor,
 };
 
@@ -142,6 +143,10 @@ pub(crate) async fn operator_from_descriptor(
     if &descriptor.name.to_uppercase() == "TTL" {
         let operator = lifetime_from_descriptor(descriptor, schema, udfs, runtime).await?;
 
+        Ok(Some(operator.into()))
+    } else if &descriptor.name.to_uppercase() == "PATTERN" {
+        let operator = pattern_from_descriptor(descriptor, schema, udfs, runtime).await?;
+
         Ok(Some(operator.into()))
     } else {
         Err(PipelineError::InternalError(descriptor.name.clone().into()))
@@ -201,6 +206,67 @@ async fn lifetime_from_descriptor(
     Ok(operator)
 }
 
+async fn pattern_from_descriptor(
+    descriptor: &TableOperatorDescriptor,
+    schema: &Schema,
+    udfs: &[UdfConfig],
+    runtime: Arc<Runtime>,
+) -> Result<PatternTableOperator, TableOperatorError> {
+    let mut args = vec![];
+    for arg in descriptor.args.iter().skip(1) {
+        match arg {
+            TableOperatorArg::Argument(argument) => args.push(argument),
+            TableOperatorArg::Descriptor(_) => {
+                return Err(TableOperatorError::InvalidReference(
+                    descriptor.name.to_owned(),
+                    format!("{:?}", arg),
+                ))
+            }
+        }
+    }
+    let [key_arg, time_arg, within_arg, step_args @ ..] = args.as_slice() else {
+        return Err(TableOperatorError::MissingArgument(
+            descriptor.name.to_owned(),
+        ));
+    };
+    if step_args.len() < 2 {
+        return Err(TableOperatorError::NotEnoughPatternSteps(step_args.len()));
+    }
+
+    let key = get_expression(
+        descriptor.name.to_owned(),
+        key_arg,
+        schema,
+        udfs,
+        runtime.clone(),
+    )
+    .await?;
+    let time = get_expression(
+        descriptor.name.to_owned(),
+        time_arg,
+        schema,
+        udfs,
+        runtime.clone(),
+    )
+    .await?;
+    let within = get_interval(descriptor.name.to_owned(), within_arg)?;
+    let mut steps = vec![];
+    for step_arg in step_args {
+        steps.push(
+            get_expression(
+                descriptor.name.to_owned(),
+                step_arg,
+                schema,
+                udfs,
+                runtime.clone(),
+            )
+            .await?,
+        );
+    }
+
+    Ok(PatternTableOperator::new(key, time, within, steps))
+}
+
 fn get_interval(
     function_name: String,
     interval_arg: &FunctionArg,
@@ -1,5 +1,6 @@
 pub(crate) mod factory;
 mod lifetime;
 mod operator;
+mod pattern;
 mod processor;
 mod tests;
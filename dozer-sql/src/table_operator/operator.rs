@@ -1,4 +1,5 @@
 use crate::table_operator::lifetime::LifetimeTableOperator;
+use crate::table_operator::pattern::PatternTableOperator;
 use dozer_types::types::{Record, Schema};
 use enum_dispatch::enum_dispatch;
 
@@ -12,6 +13,14 @@ pub trait TableOperator: Send + Sync {
         record: &Record,
         schema: &Schema,
     ) -> Result<Vec<Record>, TableOperatorError>;
+    /// Returns the records to delete when `record` is deleted.
+    fn retract(
+        &mut self,
+        record: &Record,
+        schema: &Schema,
+    ) -> Result<Vec<Record>, TableOperatorError> {
+        self.execute(record, schema)
+    }
     fn get_output_schema(&self, schema: &Schema) -> Result<Schema, TableOperatorError>;
 }
 
@@ -19,4 +28,5 @@ pub trait TableOperator: Send + Sync {
 #[derive(Debug)]
 pub enum TableOperatorType {
     LifetimeTableOperator,
+    PatternTableOperator,
 }
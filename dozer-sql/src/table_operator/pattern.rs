@@ -0,0 +1,219 @@
+use std::collections::{BTreeSet, HashMap};
+
+use dozer_sql_expression::execution::Expression;
+use dozer_types::chrono::{self, DateTime, FixedOffset};
+use dozer_types::types::{Field, FieldDefinition, FieldType, Record, Schema, SourceDefinition};
+
+use crate::errors::TableOperatorError;
+
+use super::operator::TableOperator;
+
+pub const MATCH_START: &str = "match_start";
+
+/// Detects, per key, records matching a sequence of steps in order, with the whole sequence happening within `within`.
+/// Other records may occur between the steps.
+///
+/// A match is emitted as the record completing it, with an extra `match_start` field holding the time of its first step.
+/// Once a key matches, its partial matches are discarded, so a record belongs to at most one match.
+///
+/// Records are expected in time order. A record earlier than the latest record of its key with partial matches,
+/// or more than `within` earlier than the latest record of any key, is ignored.
+#[derive(Debug)]
+pub struct PatternTableOperator {
+    key: Expression,
+    time: Expression,
+    within: chrono::Duration,
+    steps: Vec<Expression>,
+    partial_matches: HashMap<Field, PartialMatches>,
+    /// The keys with partial matches by their earliest start, so expired ones are discarded whichever key the records have.
+    expirations: BTreeSet<(DateTime<FixedOffset>, Field)>,
+    /// The time of the latest record.
+    latest: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug)]
+struct PartialMatches {
+    /// The latest start of a partial match by the number of steps matched.
+    starts: Vec<Option<DateTime<FixedOffset>>>,
+    /// The time of the latest record of the key.
+    latest: DateTime<FixedOffset>,
+}
+
+impl PartialMatches {
+    fn earliest_start(&self) -> Option<DateTime<FixedOffset>> {
+        self.starts.iter().flatten().min().copied()
+    }
+}
+
+impl PatternTableOperator {
+    pub fn new(
+        key: Expression,
+        time: Expression,
+        within: std::time::Duration,
+        steps: Vec<Expression>,
+    ) -> Self {
+        Self {
+            key,
+            time,
+            within: chrono::Duration::from_std(within).unwrap_or(chrono::Duration::max_value()),
+            steps,
+            partial_matches: HashMap::new(),
+            expirations: BTreeSet::new(),
+            latest: None,
+        }
+    }
+
+    /// Discards the partial matches that started before `cutoff`.
+    fn expire(&mut self, cutoff: DateTime<FixedOffset>) {
+        while let Some((start, key)) = self.expirations.first().cloned() {
+            if start >= cutoff {
+                break;
+            }
+            self.expirations.pop_first();
+            let Some(matches) = self.partial_matches.get_mut(&key) else {
+                continue;
+            };
+            for start in matches.starts.iter_mut() {
+                if start.map_or(false, |start| start < cutoff) {
+                    *start = None;
+                }
+            }
+            match matches.earliest_start() {
+                Some(start) => {
+                    self.expirations.insert((start, key));
+                }
+                None => {
+                    self.partial_matches.remove(&key);
+                }
+            }
+        }
+    }
+
+    #[cfg(test)]
+    pub fn num_partial_matches(&self) -> usize {
+        self.partial_matches.len()
+    }
+
+    fn matches_step(
+        &mut self,
+        step: usize,
+        record: &Record,
+        schema: &Schema,
+    ) -> Result<bool, TableOperatorError> {
+        let value = self.steps[step]
+            .evaluate(record, schema)
+            .map_err(|err| TableOperatorError::InternalError(Box::new(err)))?;
+        Ok(value == Field::Boolean(true))
+    }
+}
+
+impl TableOperator for PatternTableOperator {
+    fn get_name(&self) -> String {
+        "PATTERN".to_owned()
+    }
+
+    fn execute(
+        &mut self,
+        record: &Record,
+        schema: &Schema,
+    ) -> Result<Vec<Record>, TableOperatorError> {
+        let key = self
+            .key
+            .evaluate(record, schema)
+            .map_err(|err| TableOperatorError::InternalError(Box::new(err)))?;
+        let time = match self
+            .time
+            .evaluate(record, schema)
+            .map_err(|err| TableOperatorError::InternalError(Box::new(err)))?
+        {
+            Field::Timestamp(time) => time,
+            other => return Err(TableOperatorError::InvalidPatternTimeType(other)),
+        };
+
+        let latest = self.latest.map_or(time, |latest| latest.max(time));
+        self.latest = Some(latest);
+        if let Some(cutoff) = latest.checked_sub_signed(self.within) {
+            if time < cutoff {
+                return Ok(vec![]);
+            }
+            self.expire(cutoff);
+        }
+
+        let matched = (0..self.steps.len())
+            .map(|step| self.matches_step(step, record, schema))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let steps = self.steps.len();
+        let matches = self
+            .partial_matches
+            .entry(key.clone())
+            .or_insert_with(|| PartialMatches {
+                starts: vec![None; steps],
+                latest: time,
+            });
+        if time < matches.latest {
+            return Ok(vec![]);
+        }
+        matches.latest = time;
+        let earliest_start = matches.earliest_start();
+        if let Some(start) = earliest_start {
+            self.expirations.remove(&(start, key.clone()));
+        }
+        let partial_matches = &mut matches.starts;
+
+        // Advance the furthest partial matches first, so a record advances each one step at most.
+        for progress in (1..steps).rev() {
+            let Some(start) = partial_matches[progress] else {
+                continue;
+            };
+            if !matched[progress] {
+                continue;
+            }
+            partial_matches[progress] = None;
+            if progress + 1 == steps {
+                self.partial_matches.remove(&key);
+                let mut output = record.clone();
+                output.values.push(Field::Timestamp(start));
+                return Ok(vec![output]);
+            }
+            let next = &mut partial_matches[progress + 1];
+            *next = (*next).max(Some(start));
+        }
+        if matched[0] {
+            partial_matches[1] = Some(time);
+        }
+        match matches.earliest_start() {
+            Some(start) => {
+                self.expirations.insert((start, key));
+            }
+            None => {
+                self.partial_matches.remove(&key);
+            }
+        }
+
+        Ok(vec![])
+    }
+
+    /// Emitted matches aren't retracted, and retracted records don't take part in matches.
+    fn retract(
+        &mut self,
+        _record: &Record,
+        _schema: &Schema,
+    ) -> Result<Vec<Record>, TableOperatorError> {
+        Ok(vec![])
+    }
+
+    fn get_output_schema(&self, schema: &Schema) -> Result<Schema, TableOperatorError> {
+        let mut output_schema = schema.clone();
+        output_schema.field(
+            FieldDefinition::new(
+                MATCH_START.to_string(),
+                FieldType::Timestamp,
+                false,
+                SourceDefinition::Dynamic,
+            ),
+            false,
+        );
+        Ok(output_schema)
+    }
+}
@@ -40,7 +40,7 @@ impl Processor for TableOperatorProcessor {
             Operation::Delete { ref old } => {
                 let records = self
                     .operator
-                    .execute(old, &self.input_schema)
+                    .retract(old, &self.input_schema)
                     .map_err(PipelineError::TableOperatorError)?;
                 for record in records {
                     fw.send(TableOperation::without_id(
@@ -64,7 +64,7 @@ impl Processor for TableOperatorProcessor {
             Operation::Update { ref old, ref new } => {
                 let old_records = self
                     .operator
-                    .execute(old, &self.input_schema)
+                    .retract(old, &self.input_schema)
                     .map_err(PipelineError::TableOperatorError)?;
                 for record in old_records {
                     fw.send(TableOperation::without_id(
@@ -6,7 +6,11 @@ use dozer_types::{
     types::{Field, FieldDefinition, FieldType, Lifetime, Record, Schema, SourceDefinition},
 };
 
-use crate::table_operator::{lifetime::LifetimeTableOperator, operator::TableOperator};
+use dozer_sql_expression::operator::BinaryOperatorType;
+
+use crate::table_operator::{
+    lifetime::LifetimeTableOperator, operator::TableOperator, pattern::PatternTableOperator,
+};
 
 #[test]
 fn test_lifetime() {
@@ -59,3 +63,107 @@ fn test_lifetime() {
 
     assert_eq!(lifetime_record, &expected_record);
 }
+
+#[test]
+fn test_pattern() {
+    let schema = Schema::default()
+        .field(
+            FieldDefinition::new(
+                "user".to_string(),
+                FieldType::Int,
+                false,
+                SourceDefinition::Dynamic,
+            ),
+            false,
+        )
+        .field(
+            FieldDefinition::new(
+                "event".to_string(),
+                FieldType::String,
+                false,
+                SourceDefinition::Dynamic,
+            ),
+            false,
+        )
+        .field(
+            FieldDefinition::new(
+                "ts".to_string(),
+                FieldType::Timestamp,
+                false,
+                SourceDefinition::Dynamic,
+            ),
+            false,
+        )
+        .to_owned();
+
+    let step = |event: &str| Expression::BinaryOperator {
+        left: Box::new(Expression::Column { index: 1 }),
+        operator: BinaryOperatorType::Eq,
+        right: Box::new(Expression::Literal(Field::String(event.to_string()))),
+        overflow: Default::default(),
+        rounding: None,
+    };
+    let mut table_operator = PatternTableOperator::new(
+        Expression::Column { index: 0 },
+        Expression::Column { index: 2 },
+        Duration::from_secs(60 * 60),
+        vec![
+            step("login"),
+            step("failed_payment"),
+            step("support_ticket"),
+        ],
+    );
+    assert_eq!(
+        table_operator
+            .get_output_schema(&schema)
+            .unwrap()
+            .fields
+            .len(),
+        4
+    );
+
+    let record = |user: i64, event: &str, time: &str| {
+        Record::new(vec![
+            Field::Int(user),
+            Field::String(event.to_string()),
+            Field::Timestamp(DateTime::parse_from_rfc3339(time).unwrap()),
+        ])
+    };
+    let mut execute = |input: Record| table_operator.execute(&input, &schema).unwrap();
+
+    assert!(execute(record(1, "login", "2020-01-01T00:00:00Z")).is_empty());
+    assert!(execute(record(2, "failed_payment", "2020-01-01T00:05:00Z")).is_empty());
+    assert!(execute(record(1, "browse", "2020-01-01T00:10:00Z")).is_empty());
+    assert!(execute(record(1, "login", "2020-01-01T00:30:00Z")).is_empty());
+    assert!(execute(record(1, "failed_payment", "2020-01-01T00:40:00Z")).is_empty());
+
+    // The first login is too early, but the sequence from the second one is within the hour.
+    let matches = execute(record(1, "support_ticket", "2020-01-01T01:20:00Z"));
+    assert_eq!(matches.len(), 1);
+    assert_eq!(
+        matches[0].values[3],
+        Field::Timestamp(DateTime::parse_from_rfc3339("2020-01-01T00:30:00Z").unwrap())
+    );
+
+    // A match discards the partial matches of its key.
+    assert!(execute(record(1, "support_ticket", "2020-01-01T01:25:00Z")).is_empty());
+    // Other keys don't take part in the match.
+    assert!(execute(record(2, "failed_payment", "2020-01-01T01:30:00Z")).is_empty());
+    assert!(execute(record(2, "support_ticket", "2020-01-01T01:31:00Z")).is_empty());
+
+    // Records of a key earlier than its latest one are ignored.
+    assert!(execute(record(3, "login", "2020-01-01T01:40:00Z")).is_empty());
+    assert!(execute(record(3, "failed_payment", "2020-01-01T01:45:00Z")).is_empty());
+    assert!(execute(record(3, "support_ticket", "2020-01-01T01:41:00Z")).is_empty());
+    // Records more than the window earlier than the latest one are ignored.
+    assert!(execute(record(4, "login", "2020-01-01T00:30:00Z")).is_empty());
+    assert_eq!(
+        execute(record(3, "support_ticket", "2020-01-01T01:50:00Z"))[0].values[3],
+        Field::Timestamp(DateTime::parse_from_rfc3339("2020-01-01T01:40:00Z").unwrap())
+    );
+
+    // Partial matches older than the window are discarded, whichever key the later records have.
+    assert!(execute(record(5, "login", "2020-01-01T02:00:00Z")).is_empty());
+    assert!(execute(record(6, "browse", "2020-01-01T03:01:00Z")).is_empty());
+    assert_eq!(table_operator.num_partial_matches(), 0);
+}
@@ -28,6 +28,7 @@ pub fn get_schema(columns: &[rusqlite::Column]) -> Schema {
                 },
                 nullable: true,
                 source: SourceDefinition::Dynamic,
+                annotations: Default::default(),
             }
         })
         .collect();
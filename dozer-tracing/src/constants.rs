@@ -4,6 +4,8 @@ pub const DOZER_METER_NAME: &str = "dozer.meter";
 // Metrics
 pub const SINK_OPERATION_COUNTER_NAME: &str = "sink_operation";
 pub const PIPELINE_LATENCY_GAUGE_NAME: &str = "pipeline_latency";
+pub const SINK_FLUSH_LATENCY_GAUGE_NAME: &str = "sink_flush_latency";
+pub const SINK_INVALID_RECORD_COUNTER_NAME: &str = "sink_invalid_record";
 
 pub const SOURCE_OPERATION_COUNTER_NAME: &str = "source_operation";
 
@@ -3,15 +3,17 @@ use std::time::Duration;
 
 use dozer_types::log::{debug, error};
 use dozer_types::models::telemetry::{
-    TelemetryConfig, TelemetryMetricsConfig, TelemetryTraceConfig, XRayConfig,
+    default_otlp_sample_percent, default_otlp_timeout_in_seconds, OtlpConfig, TelemetryConfig,
+    TelemetryMetricsConfig, TelemetryTraceConfig, XRayConfig,
 };
-use dozer_types::tracing::{self, Metadata, Subscriber};
+use dozer_types::tracing::Subscriber;
 use opentelemetry::{global, KeyValue};
 use opentelemetry_aws::trace::XrayIdGenerator;
 use opentelemetry_otlp::{ExportConfig, WithExportConfig};
 use opentelemetry_sdk::metrics::SdkMeterProvider;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
 use opentelemetry_sdk::trace::{self};
-use opentelemetry_sdk::{self, Resource};
+use opentelemetry_sdk::{self, runtime, Resource};
 use prometheus::Registry;
 use tracing_opentelemetry::OpenTelemetryLayer;
 use tracing_subscriber::layer::SubscriberExt;
@@ -138,10 +140,13 @@ fn create_subscriber(
     let layers = telemetry_config
         .trace
         .as_ref()
-        .map(|TelemetryTraceConfig::XRay(config)| {
-            get_xray_tracer(app_name, config).with_filter(filter::filter_fn(
-                |metadata: &Metadata| metadata.level() == &tracing::Level::ERROR,
-            ))
+        .map(|trace_config| match trace_config {
+            TelemetryTraceConfig::XRay(config) => {
+                get_xray_tracer(app_name, config).with_filter(filter::LevelFilter::ERROR)
+            }
+            TelemetryTraceConfig::Otlp(config) => {
+                get_otlp_tracer(app_name, config).with_filter(filter::LevelFilter::INFO)
+            }
         });
 
     let stdout_is_tty = stdout().is_terminal();
@@ -163,6 +168,56 @@ fn get_xray_tracer<S>(
     app_name: &str,
     config: &XRayConfig,
 ) -> OpenTelemetryLayer<S, opentelemetry_sdk::trace::Tracer>
+where
+    S: for<'span> tracing_subscriber::registry::LookupSpan<'span>
+        + dozer_types::tracing::Subscriber,
+{
+    get_tracer(
+        app_name,
+        &config.endpoint,
+        Duration::from_secs(config.timeout_in_seconds),
+        trace::config().with_id_generator(XrayIdGenerator::default()),
+    )
+}
+
+fn get_otlp_tracer<S>(
+    app_name: &str,
+    config: &OtlpConfig,
+) -> OpenTelemetryLayer<S, opentelemetry_sdk::trace::Tracer>
+where
+    S: for<'span> tracing_subscriber::registry::LookupSpan<'span>
+        + dozer_types::tracing::Subscriber,
+{
+    // Lets handlers continue the traces of their callers, from the `traceparent` header.
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    let timeout = Duration::from_secs(
+        config
+            .timeout_in_seconds
+            .unwrap_or_else(default_otlp_timeout_in_seconds),
+    );
+    let sample_ratio = config
+        .sample_percent
+        .unwrap_or_else(default_otlp_sample_percent)
+        .min(100) as f64
+        / 100.0;
+    get_tracer(
+        app_name,
+        &config.endpoint,
+        timeout,
+        trace::config().with_sampler(trace::Sampler::ParentBased(Box::new(
+            trace::Sampler::TraceIdRatioBased(sample_ratio),
+        ))),
+    )
+}
+
+/// Exports spans over OTLP/gRPC in batches, from a task of the current tokio runtime.
+fn get_tracer<S>(
+    app_name: &str,
+    endpoint: &str,
+    timeout: Duration,
+    trace_config: trace::Config,
+) -> OpenTelemetryLayer<S, opentelemetry_sdk::trace::Tracer>
 where
     S: for<'span> tracing_subscriber::registry::LookupSpan<'span>
         + dozer_types::tracing::Subscriber,
@@ -170,24 +225,20 @@ where
     let otlp_exporter = opentelemetry_otlp::new_exporter()
         .tonic()
         .with_export_config(ExportConfig {
-            endpoint: config.endpoint.clone(),
+            endpoint: endpoint.to_string(),
             protocol: opentelemetry_otlp::Protocol::Grpc,
-            timeout: Duration::from_secs(config.timeout_in_seconds),
+            timeout,
         })
-        .with_timeout(Duration::from_secs(3));
+        .with_timeout(timeout);
 
     let tracer = opentelemetry_otlp::new_pipeline()
         .tracing()
         .with_exporter(otlp_exporter)
-        .with_trace_config(
-            trace::config()
-                .with_id_generator(XrayIdGenerator::default())
-                .with_resource(Resource::new(vec![KeyValue::new(
-                    "service.name",
-                    app_name.to_string(),
-                )])),
-        )
-        .install_simple()
+        .with_trace_config(trace_config.with_resource(Resource::new(vec![KeyValue::new(
+            "service.name",
+            app_name.to_string(),
+        )])))
+        .install_batch(runtime::Tokio)
         .expect("Failed to install OpenTelemetry tracer.");
     tracing_opentelemetry::layer().with_tracer(tracer)
 }
@@ -67,6 +67,8 @@ pub enum DeserializationError {
     MissingFieldInPostgresConfig(String),
     #[error("{0} is mismatching in Postgres configuration")]
     MismatchingFieldInPostgresConfig(String),
+    #[error("Unable to read Postgres password file {0}: {1}")]
+    UnableToReadPasswordFile(String, #[source] std::io::Error),
 }
 
 #[derive(Debug, Error)]
@@ -154,6 +154,9 @@ pub mod conversions {
                 typ: field_type_to_internal_type(f.typ) as i32,
                 name: f.name,
                 nullable: f.nullable,
+                description: f.annotations.description,
+                pii: f.annotations.pii.map(|pii| pii.to_string()),
+                unit: f.annotations.unit,
             })
             .collect()
     }
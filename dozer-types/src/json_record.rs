@@ -0,0 +1,304 @@
+//! Conversion between JSON objects and [`Record`]s.
+//!
+//! A record is represented as a JSON object keyed by field name. Values are converted with
+//! [`json_value_to_field`] and [`field_to_json_value`], so every field type has exactly one
+//! canonical JSON form. How lenient the conversion from JSON is, is set by a [`JsonCoercionPolicy`].
+
+use std::fmt::{self, Display, Formatter};
+
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::errors::types::TypeError;
+use crate::helper::json_value_to_field;
+use crate::json_types::{field_to_json_value, json_value_to_serde_json};
+use crate::types::{Field, FieldType, Record, Schema};
+
+/// How JSON objects are converted to records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonCoercionPolicy {
+    /// Fields missing from the object are null. Otherwise they are errors. Missing non-nullable fields are always errors.
+    pub missing_as_null: bool,
+    /// Keys of the object that are not fields of the schema are errors. Otherwise they are ignored.
+    pub deny_unknown_fields: bool,
+    /// JSON strings are parsed for integer, float and boolean fields, so `"42"` is accepted for an `Int` field.
+    /// Decimal, timestamp, date and 128 bit integer fields are always read from strings.
+    pub parse_strings: bool,
+}
+
+impl JsonCoercionPolicy {
+    /// Missing fields are errors, unknown fields are errors and strings are never parsed.
+    pub const STRICT: Self = Self {
+        missing_as_null: false,
+        deny_unknown_fields: true,
+        parse_strings: false,
+    };
+
+    /// Missing fields are null, unknown fields are ignored and strings are parsed.
+    pub const LENIENT: Self = Self {
+        missing_as_null: true,
+        deny_unknown_fields: false,
+        parse_strings: true,
+    };
+}
+
+impl Default for JsonCoercionPolicy {
+    /// Missing fields are null, unknown fields are ignored and strings are not parsed.
+    fn default() -> Self {
+        Self {
+            missing_as_null: true,
+            deny_unknown_fields: false,
+            parse_strings: false,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum JsonFieldErrorKind {
+    #[error("missing value")]
+    Missing,
+    #[error("not a field of the schema")]
+    Unknown,
+    #[error(transparent)]
+    Conversion(#[from] TypeError),
+}
+
+/// The error converting one field of a record.
+#[derive(Debug, Error)]
+#[error("field {field}: {kind}")]
+pub struct JsonFieldError {
+    pub field: String,
+    #[source]
+    pub kind: JsonFieldErrorKind,
+}
+
+#[derive(Debug, Error)]
+pub enum JsonRecordError {
+    #[error("expected a JSON object")]
+    NotAnObject,
+    #[error("{}", FieldErrors(.0))]
+    Fields(Vec<JsonFieldError>),
+}
+
+struct FieldErrors<'a>(&'a [JsonFieldError]);
+
+impl Display for FieldErrors<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (index, error) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts a JSON object to a record of `schema`.
+pub fn json_to_record(
+    value: Value,
+    schema: &Schema,
+    policy: JsonCoercionPolicy,
+) -> Result<Record, JsonRecordError> {
+    match value {
+        Value::Object(object) => json_object_to_record(object, schema, policy),
+        _ => Err(JsonRecordError::NotAnObject),
+    }
+}
+
+/// Converts a JSON object to a record of `schema`, reporting the errors of all the fields that can't be converted.
+pub fn json_object_to_record(
+    mut object: Map<String, Value>,
+    schema: &Schema,
+    policy: JsonCoercionPolicy,
+) -> Result<Record, JsonRecordError> {
+    let mut values = Vec::with_capacity(schema.fields.len());
+    let mut errors = vec![];
+    for field in &schema.fields {
+        let result = match object.remove(&field.name) {
+            Some(value) => json_field(value, field.typ, field.nullable, policy).map_err(Into::into),
+            None if field.nullable && policy.missing_as_null => Ok(Field::Null),
+            None => Err(JsonFieldErrorKind::Missing),
+        };
+        match result {
+            Ok(value) => values.push(value),
+            Err(kind) => errors.push(JsonFieldError {
+                field: field.name.clone(),
+                kind,
+            }),
+        }
+    }
+    if policy.deny_unknown_fields {
+        errors.extend(object.into_iter().map(|(field, _)| JsonFieldError {
+            field,
+            kind: JsonFieldErrorKind::Unknown,
+        }));
+    }
+
+    if errors.is_empty() {
+        Ok(Record::new(values))
+    } else {
+        Err(JsonRecordError::Fields(errors))
+    }
+}
+
+fn json_field(
+    value: Value,
+    typ: FieldType,
+    nullable: bool,
+    policy: JsonCoercionPolicy,
+) -> Result<Field, TypeError> {
+    match value {
+        Value::String(s)
+            if policy.parse_strings
+                && matches!(
+                    typ,
+                    FieldType::UInt | FieldType::Int | FieldType::Float | FieldType::Boolean
+                ) =>
+        {
+            Field::from_str(s.trim(), typ, nullable)
+        }
+        value => json_value_to_field(value, typ, nullable),
+    }
+}
+
+/// Converts a record of `schema` to a JSON object.
+pub fn record_to_json(record: &Record, schema: &Schema) -> Map<String, Value> {
+    schema
+        .fields
+        .iter()
+        .zip(&record.values)
+        .map(|(definition, field)| {
+            (
+                definition.name.clone(),
+                json_value_to_serde_json(&field_to_json_value(field.clone())),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::types::{FieldDefinition, SourceDefinition};
+
+    use super::*;
+
+    fn schema() -> Schema {
+        let mut schema = Schema::new();
+        schema
+            .field(
+                FieldDefinition::new(
+                    "id".to_string(),
+                    FieldType::Int,
+                    false,
+                    SourceDefinition::Dynamic,
+                ),
+                true,
+            )
+            .field(
+                FieldDefinition::new(
+                    "name".to_string(),
+                    FieldType::String,
+                    true,
+                    SourceDefinition::Dynamic,
+                ),
+                false,
+            )
+            .field(
+                FieldDefinition::new(
+                    "price".to_string(),
+                    FieldType::Decimal,
+                    true,
+                    SourceDefinition::Dynamic,
+                ),
+                false,
+            );
+        schema
+    }
+
+    fn field_errors(result: Result<Record, JsonRecordError>) -> Vec<String> {
+        let Err(JsonRecordError::Fields(errors)) = result else {
+            panic!("expected field errors, got {result:?}");
+        };
+        errors.into_iter().map(|error| error.field).collect()
+    }
+
+    #[test]
+    fn default_policy() {
+        let record = json_to_record(
+            json!({"id": 1, "price": "1.50", "extra": true}),
+            &schema(),
+            Default::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            record,
+            Record::new(vec![
+                Field::Int(1),
+                Field::Null,
+                Field::Decimal("1.50".parse().unwrap()),
+            ])
+        );
+        assert_eq!(
+            field_errors(json_to_record(
+                json!({"id": "1", "name": 2}),
+                &schema(),
+                Default::default()
+            )),
+            vec!["id", "name"]
+        );
+        assert_eq!(
+            field_errors(json_to_record(
+                json!({"id": null}),
+                &schema(),
+                Default::default()
+            )),
+            vec!["id"]
+        );
+        assert!(matches!(
+            json_to_record(json!([1]), &schema(), Default::default()),
+            Err(JsonRecordError::NotAnObject)
+        ));
+    }
+
+    #[test]
+    fn strict_and_lenient_policies() {
+        assert_eq!(
+            field_errors(json_to_record(
+                json!({"id": 1, "extra": true}),
+                &schema(),
+                JsonCoercionPolicy::STRICT
+            )),
+            vec!["name", "price", "extra"]
+        );
+        assert_eq!(
+            json_to_record(
+                json!({"id": " 7 ", "extra": true}),
+                &schema(),
+                JsonCoercionPolicy::LENIENT
+            )
+            .unwrap(),
+            Record::new(vec![Field::Int(7), Field::Null, Field::Null])
+        );
+    }
+
+    #[test]
+    fn record_json_roundtrip() {
+        let record = Record::new(vec![
+            Field::Int(1),
+            Field::String("a".to_string()),
+            Field::Null,
+        ]);
+        let object = record_to_json(&record, &schema());
+        assert_eq!(
+            Value::Object(object.clone()),
+            json!({"id": 1, "name": "a", "price": null})
+        );
+        assert_eq!(
+            json_object_to_record(object, &schema(), JsonCoercionPolicy::STRICT).unwrap(),
+            record
+        );
+    }
+}
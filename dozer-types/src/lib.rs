@@ -5,9 +5,12 @@ pub mod errors;
 pub mod event;
 pub mod field_type;
 pub mod helper;
+pub mod json_record;
 pub mod json_types;
 pub mod models;
 pub mod node;
+pub mod proto_record;
+pub mod retry;
 #[cfg(test)]
 mod tests;
 pub mod types;
@@ -32,6 +35,7 @@ pub use log;
 pub use ordered_float;
 pub use parking_lot;
 pub use prost;
+pub use prost_reflect;
 pub use tonic;
 #[macro_use]
 pub extern crate prettytable;
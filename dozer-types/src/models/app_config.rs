@@ -1,6 +1,8 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use super::equal_default;
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Clone, PartialEq, Eq, Default)]
 #[serde(deny_unknown_fields)]
 pub struct AppConfig {
@@ -15,6 +17,129 @@ pub struct AppConfig {
     /// The event hub's queue capacity. Events that are not processed will be dropped.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub event_hub_capacity: Option<usize>,
+
+    /// Resource quotas of the app. CPU and cache disk usage aren't covered, they're limited by the container or the cache the app runs with; Default: None
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quotas: Option<AppQuotas>,
+
+    /// How operations from different sources are merged into the pipeline; Default: Arrival
+    #[serde(default, skip_serializing_if = "equal_default")]
+    pub merge_ordering: MergeOrdering,
+
+    /// Faults injected into the pipeline to test recovery. Only applied when dozer is built with the `chaos` feature; Default: None
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chaos: Option<ChaosConfig>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone, PartialEq, Eq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct AppQuotas {
+    /// Maximum number of operations ingested per second, across all the sources of the app
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_ingestion_rate: Option<u64>,
+
+    /// Maximum size in bytes of the app's home directory, which holds its pipeline state
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_state_size: Option<u64>,
+
+    /// What happens when a quota is exceeded; Default: Throttle
+    #[serde(default, skip_serializing_if = "equal_default")]
+    pub on_exceeded: QuotaAction,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(deny_unknown_fields)]
+pub enum QuotaAction {
+    /// Ingestion waits until the app is back under its ingestion rate quota. The state doesn't shrink while ingestion waits, so exceeding the state size quota pauses ingestion instead.
+    #[default]
+    Throttle,
+    /// A warning is logged and ingestion continues.
+    Alert,
+    /// An error is logged and ingestion stops until the app is restarted.
+    Pause,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(deny_unknown_fields)]
+pub enum MergeOrdering {
+    /// Operations are processed in the order they arrive from the sources.
+    #[default]
+    Arrival,
+    /// Operations of the sources with an `event_time` column are processed in the order of their event time, so replays produce the same results. An operation is held until every other such source has sent a later one or has sent nothing for `idle_timeout_ms`, or until an operation `watermark_ms` later has been received. Event times are timestamps, or integers in milliseconds.
+    EventTime {
+        watermark_ms: u64,
+        /// Milliseconds without messages after which a source no longer holds back the others; Default: 1000
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        idle_timeout_ms: Option<u64>,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone, PartialEq, Eq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ChaosConfig {
+    /// Sources that fail as if their connector lost its connection
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub connector_disconnects: Vec<ConnectorDisconnect>,
+
+    /// Processors that sleep before processing an operation
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub slow_processors: Vec<SlowProcessor>,
+
+    /// Sinks whose commits fail
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sink_commit_errors: Vec<SinkCommitError>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorDisconnect {
+    /// Name of the connection; Default: all connections
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection: Option<String>,
+
+    /// When the source fails, counted in operations
+    pub schedule: FaultSchedule,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct SlowProcessor {
+    /// Name of the processor node; Default: all processors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processor: Option<String>,
+
+    /// How long the processor sleeps
+    pub delay_ms: u64,
+
+    /// When the processor sleeps, counted in operations
+    pub schedule: FaultSchedule,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct SinkCommitError {
+    /// Name of the sink node; Default: all sinks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sink: Option<String>,
+
+    /// When the commit fails, counted in commits
+    pub schedule: FaultSchedule,
+}
+
+/// When a fault is injected, in events counted by the node it is injected in.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone, Copy, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct FaultSchedule {
+    /// Number of events before the fault is first injected
+    pub after: u64,
+
+    /// Number of events between injections; Default: the fault is injected once
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub every: Option<u64>,
+
+    /// Maximum number of injections; Default: unlimited when `every` is set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub times: Option<u64>,
 }
 
 pub fn default_app_buffer_size() -> u32 {
@@ -28,3 +153,7 @@ pub fn default_error_threshold() -> u32 {
 pub fn default_event_hub_capacity() -> usize {
     100
 }
+
+pub fn default_event_time_idle_timeout_ms() -> u64 {
+    1000
+}
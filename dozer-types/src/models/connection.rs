@@ -1,6 +1,8 @@
 use crate::models::ingestion_types::{
-    ConfigSchemas, DeltaLakeConfig, EthConfig, GrpcConfig, JavaScriptConfig, KafkaConfig,
-    LocalStorage, MongodbConfig, MySQLConfig, S3Storage, SnowflakeConfig, WebhookConfig, SECRET,
+    AirtableConfig, CassandraConfig, ConfigSchemas, DeltaLakeConfig, EthConfig, GoogleSheetsConfig,
+    GrpcConfig, JavaScriptConfig, KafkaConfig, LocalStorage, MongodbConfig, MySQLConfig,
+    NatsConfig, RabbitMQConfig, S3Storage, SalesforceConfig, SnowflakeConfig, SshTunnelConfig,
+    TlsConfig, WebhookConfig, SECRET,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -9,7 +11,7 @@ use std::str::FromStr;
 use crate::errors::types::DeserializationError;
 use crate::errors::types::DeserializationError::{
     InvalidConnectionUrl, MismatchingFieldInPostgresConfig, MissingFieldInPostgresConfig,
-    UnableToParseConnectionUrl, UnknownSslMode,
+    UnableToParseConnectionUrl, UnableToReadPasswordFile, UnknownSslMode,
 };
 use prettytable::Table;
 use tokio_postgres::config::{Host, SslMode};
@@ -26,6 +28,37 @@ pub trait SchemaExample {
 pub struct Connection {
     pub config: ConnectionConfig,
     pub name: String,
+    /// How operations failing with a network error are retried
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryConfig>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Hash, JsonSchema, Default)]
+#[serde(deny_unknown_fields)]
+pub struct RetryConfig {
+    /// Milliseconds before the first retry, doubled after each retry; Default: 1000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_interval_ms: Option<u64>,
+
+    /// Longest time in milliseconds between two retries; Default: 60000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_interval_ms: Option<u64>,
+
+    /// Number of retries before the operation fails; Default: 10
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_retries: Option<u32>,
+}
+
+pub fn default_retry_initial_interval_ms() -> u64 {
+    1_000
+}
+
+pub fn default_retry_max_interval_ms() -> u64 {
+    60_000
+}
+
+pub fn default_retry_max_retries() -> u32 {
+    10
 }
 
 /// Configuration for a Postgres connection
@@ -39,6 +72,10 @@ pub struct PostgresConfig {
     /// The password to use for authentication
     pub password: Option<String>,
 
+    /// A file holding the password, read again on every connection so a rotated password is picked up on reconnect. Overrides password
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_file: Option<String>,
+
     /// The host to connect to (IP or DNS name)
     pub host: Option<String>,
 
@@ -63,6 +100,18 @@ pub struct PostgresConfig {
     /// The snapshot batch size
     #[serde(skip_serializing_if = "Option::is_none")]
     pub batch_size: Option<u32>,
+
+    /// The maximum number of connections tables are snapshotted over in parallel (default: 8)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_connections: Option<u32>,
+
+    /// The SSH tunnel to connect through
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssh_tunnel: Option<SshTunnelConfig>,
+
+    /// The TLS certificates to connect with, when sslmode is prefer or require
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsConfig>,
 }
 
 impl SchemaExample for PostgresConfig {
@@ -106,7 +155,10 @@ impl PostgresConfig {
     pub fn replenish(&self) -> Result<PostgresConfigReplenished, DeserializationError> {
         Ok(PostgresConfigReplenished {
             user: self.lookup("user")?,
-            password: self.lookup("password")?,
+            password: match &self.password_file {
+                Some(path) => read_password_file(path)?,
+                None => self.lookup("password")?,
+            },
             host: self.lookup("host")?,
             port: u32::from_str(self.lookup("port")?.as_str())
                 .map_err(UnableToParseConnectionUrl)?,
@@ -198,6 +250,12 @@ impl PostgresConfig {
     }
 }
 
+pub fn read_password_file(path: &str) -> Result<String, DeserializationError> {
+    std::fs::read_to_string(path)
+        .map(|password| password.trim_end_matches(['\r', '\n']).to_string())
+        .map_err(|e| UnableToReadPasswordFile(path.to_string(), e))
+}
+
 fn get_sslmode(mode: String) -> Result<SslMode, DeserializationError> {
     match mode.as_str() {
         "disable" | "Disable" => Ok(SslMode::Disable),
@@ -293,6 +351,24 @@ pub enum ConnectionConfig {
 
     Oracle(OracleConfig),
     Aerospike(AerospikeConnection),
+
+    /// In yaml, present as tag: `!Salesforce`
+    Salesforce(SalesforceConfig),
+
+    /// In yaml, present as tag: `!GoogleSheets`
+    GoogleSheets(GoogleSheetsConfig),
+
+    /// In yaml, present as tag: `!Airtable`
+    Airtable(AirtableConfig),
+
+    /// In yaml, present as tag: `!Cassandra`
+    Cassandra(CassandraConfig),
+
+    /// In yaml, present as tag: `!Nats`
+    Nats(NatsConfig),
+
+    /// In yaml, present as tag: `!RabbitMQ`
+    RabbitMQ(RabbitMQConfig),
 }
 
 impl ConnectionConfig {
@@ -312,6 +388,12 @@ impl ConnectionConfig {
             ConnectionConfig::Webhook(_) => "webhook".to_string(),
             ConnectionConfig::Oracle(_) => "oracle".to_string(),
             ConnectionConfig::Aerospike(_) => "aerospike".to_string(),
+            ConnectionConfig::Salesforce(_) => "salesforce".to_string(),
+            ConnectionConfig::GoogleSheets(_) => "googlesheets".to_string(),
+            ConnectionConfig::Airtable(_) => "airtable".to_string(),
+            ConnectionConfig::Cassandra(_) => "cassandra".to_string(),
+            ConnectionConfig::Nats(_) => "nats".to_string(),
+            ConnectionConfig::RabbitMQ(_) => "rabbitmq".to_string(),
         }
     }
 }
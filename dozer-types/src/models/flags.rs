@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -28,6 +30,22 @@ pub struct Flags {
 
     /// app checkpoints can be used to resume execution of a query.; Default: false
     pub enable_app_checkpoints: Option<bool>,
+
+    /// when aggregations emit their results.; Default: OnChange
+    #[serde(default, skip_serializing_if = "equal_default")]
+    pub aggregation_emission: AggregationEmission,
+
+    /// when the aggregations of single queries emit their results, by the name of the table the query outputs (its INTO table, or its CTE or subquery alias). Overrides aggregation_emission.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub aggregation_emissions: BTreeMap<String, AggregationEmission>,
+
+    /// how integer arithmetic, SUM and AVG handle overflow.; Default: Error
+    #[serde(default, skip_serializing_if = "equal_default")]
+    pub numeric_overflow: NumericOverflow,
+
+    /// how decimal arithmetic, SUM and AVG round their results. If not set, results are not rounded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decimal_rounding: Option<DecimalRounding>,
 }
 
 pub fn default_dynamic() -> bool {
@@ -50,6 +68,56 @@ pub struct EnableProbabilisticOptimizations {
     pub in_aggregations: Option<bool>,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Default, PartialEq, Eq, Clone, Copy)]
+#[serde(deny_unknown_fields)]
+pub enum AggregationEmission {
+    /// Every change to a group is emitted.
+    #[default]
+    OnChange,
+    /// The result of a group is emitted once its window closes, that is once a record of a later window is aggregated, so it only depends on event time and replays emit the same results. Changes arriving after that are emitted as corrections. Only applies to aggregations directly over TUMBLE or HOP.
+    OnWindowClose,
+    /// The result of a group is emitted at most once every `interval_ms`. The changes in between are emitted as one final correction once the interval is over, even if no more records arrive.
+    Periodic { interval_ms: u64 },
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Default, PartialEq, Eq, Clone, Copy)]
+#[serde(deny_unknown_fields)]
+pub enum NumericOverflow {
+    /// An overflow fails the pipeline.
+    #[default]
+    Error,
+    /// The result is clamped to the bounds of its type.
+    Saturate,
+    /// Integer arithmetic, SUM and AVG compute in decimal, which has 28 digits of precision. An overflow of the decimal fails the pipeline.
+    PromoteToDecimal,
+    /// The result wraps around, in two's complement.
+    Wrap,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Default, PartialEq, Eq, Clone, Copy)]
+#[serde(deny_unknown_fields)]
+pub struct DecimalRounding {
+    /// how the digits beyond the scale are rounded.; Default: HalfEven
+    #[serde(default, skip_serializing_if = "equal_default")]
+    pub mode: RoundingMode,
+
+    /// the number of decimal places of the results. If not set, results keep the scale of their operands: the larger one for addition, subtraction, division and modulo, their sum for multiplication, and the scale of the sum for AVG.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scale: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Default, PartialEq, Eq, Clone, Copy)]
+#[serde(deny_unknown_fields)]
+pub enum RoundingMode {
+    /// Rounds to the nearest value, and ties to the even neighbour (banker's rounding).
+    #[default]
+    HalfEven,
+    /// Rounds to the nearest value, and ties away from zero.
+    HalfUp,
+    /// Drops the extra digits, rounding towards zero.
+    Truncate,
+}
+
 pub fn default_push_events() -> bool {
     true
 }
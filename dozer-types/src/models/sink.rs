@@ -99,6 +99,73 @@ pub struct LogReaderOptions {
 pub struct Sink {
     pub name: String,
     pub config: SinkConfig,
+
+    /// How deletes coming from upstream are applied to this sink; Default: Propagate
+    #[serde(default, skip_serializing_if = "equal_default")]
+    pub on_delete: SinkDeletePolicy,
+
+    /// Rules every inserted or updated record must satisfy before reaching the sink
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub validation: Vec<ValidationRule>,
+
+    /// What happens to records violating a validation rule; Default: Skip
+    #[serde(default, skip_serializing_if = "equal_default")]
+    pub on_invalid: InvalidRecordPolicy,
+
+    /// Longest time in milliseconds operations wait in the sink before being flushed; Default: set by the sink
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_commit_interval: Option<u64>,
+
+    /// Number of operations after which the sink is flushed at the next commit; Default: set by the sink
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_commit_batch: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Clone, Copy, Default)]
+#[serde(deny_unknown_fields)]
+pub enum SinkDeletePolicy {
+    /// Deletes are forwarded to the sink as-is.
+    #[default]
+    Propagate,
+    /// Deletes are dropped, the sink keeps the last known record. Inserting its key again updates it.
+    Ignore,
+    /// Deletes are turned into updates setting the `__deleted` column to true. Inserting the key again updates the row and sets it back to false.
+    MarkDeleted,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ValidationRule {
+    /// The field checked, named as in the sink input.
+    pub field: String,
+    pub check: ValidationCheck,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub enum ValidationCheck {
+    /// The value is not null.
+    NotNull,
+    /// The value is a number greater than or equal to zero. Nulls pass.
+    NonNegative,
+    /// The value is a string matching `pattern`. Nulls pass.
+    Regex { pattern: String },
+    /// The value, formatted as a string, is one of `values`. Nulls pass.
+    OneOf { values: Vec<String> },
+    /// The value is a string of at most `length` characters. Nulls pass.
+    MaxLength { length: usize },
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub enum InvalidRecordPolicy {
+    /// The operation is dropped and a warning is logged.
+    #[default]
+    Skip,
+    /// The pipeline fails.
+    Fail,
+    /// The operation is dropped and the record is appended to `path` as a JSON line, with its violations in a `__violations` field.
+    DeadLetter { path: String },
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Clone)]
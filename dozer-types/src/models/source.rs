@@ -2,6 +2,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::equal_default;
+use crate::types::{FieldAnnotations, PiiCategory};
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Default, Eq, PartialEq, Clone)]
 #[serde(deny_unknown_fields)]
@@ -26,6 +27,22 @@ pub struct Source {
     #[serde(default, skip_serializing_if = "equal_default")]
     /// setting for how to refresh the data; Default: RealTime
     pub refresh_config: RefreshConfig,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// renames and type conversions applied to the columns before they enter the pipeline; Type: FieldMapping[]
+    pub mappings: Vec<FieldMapping>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// primary key generated for tables without a natural one
+    pub key: Option<KeyStrategy>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// column holding the time of the source event, after renaming. Used to order operations across sources when `app.merge_ordering` is `EventTime`; Type: String
+    pub event_time: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// SQL condition on the source columns, pushed down to the source so only matching rows are ingested. Only supported by the Postgres connector; Type: String
+    pub filter: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Clone, Default)]
@@ -37,3 +54,76 @@ pub enum RefreshConfig {
     #[default]
     RealTime,
 }
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct FieldMapping {
+    /// name of the column in the source table; Type: String
+    pub field: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// name of the column in the pipeline; Type: String
+    pub rename: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// conversion applied to the values of the column
+    pub coerce: Option<FieldCoercion>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// description of the column, shown in the generated APIs; Type: String
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// whether the column holds personal data, and of which kind
+    pub pii: Option<PiiCategory>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// unit of the values of the column, e.g. ms or USD; Type: String
+    pub unit: Option<String>,
+}
+
+impl FieldMapping {
+    pub fn annotations(&self) -> FieldAnnotations {
+        FieldAnnotations {
+            description: self.description.clone(),
+            pii: self.pii,
+            unit: self.unit.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub enum FieldCoercion {
+    /// Integer milliseconds since the Unix epoch to `Timestamp`.
+    EpochMillisToTimestamp,
+    /// Integer seconds since the Unix epoch to `Timestamp`.
+    EpochSecondsToTimestamp,
+    /// String to `Int`.
+    StringToInt,
+    /// String to `Float`.
+    StringToFloat,
+    /// String to `Decimal`. The thousands separator is removed and the decimal separator replaced by `.` before parsing.
+    StringToDecimal {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        thousands_separator: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        decimal_separator: Option<String>,
+    },
+    /// String to `Timestamp`, parsed with a chrono format string. Timestamps without an offset are taken as UTC.
+    StringToTimestamp { format: String },
+    /// String to `Date`, parsed with a chrono format string.
+    StringToDate { format: String },
+}
+
+/// How the primary key of a table without a natural one is generated.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub enum KeyStrategy {
+    /// The values of `fields`, named as in the pipeline. Records with the same values are deduplicated.
+    Fields { fields: Vec<String> },
+    /// A UUIDv7 assigned at ingestion, in a `__key` column. Only for append-only tables.
+    Uuid,
+    /// The position of the operation in the source, in `__txid`, `__seq_in_tx` and `__index` columns. Only for append-only tables of connectors that identify every operation.
+    SourceOffset,
+}
@@ -12,6 +12,7 @@ pub struct TelemetryConfig {
 #[derive(Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Clone)]
 pub enum TelemetryTraceConfig {
     XRay(XRayConfig),
+    Otlp(OtlpConfig),
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Default, PartialEq, Eq, Clone)]
@@ -34,6 +35,29 @@ pub struct XRayConfig {
     pub timeout_in_seconds: u64,
 }
 
+/// Exports the spans at info level and above to an OpenTelemetry collector, over OTLP/gRPC.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct OtlpConfig {
+    pub endpoint: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_in_seconds: Option<u64>,
+
+    /// Percentage of the traces that are exported. Traces started by an incoming request carrying a trace context follow the
+    /// sampling decision of the caller.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_percent: Option<u32>,
+}
+
+pub fn default_otlp_timeout_in_seconds() -> u64 {
+    3
+}
+
+pub fn default_otlp_sample_percent() -> u32 {
+    100
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Clone)]
 #[serde(deny_unknown_fields)]
 pub enum TelemetryMetricsConfig {
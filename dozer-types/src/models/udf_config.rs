@@ -16,6 +16,7 @@ pub struct UdfConfig {
 pub enum UdfType {
     Onnx(OnnxConfig),
     JavaScript(JavaScriptConfig),
+    Lookup(LookupConfig),
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Clone)]
@@ -31,3 +32,49 @@ pub struct JavaScriptConfig {
     /// path to the module file
     pub module: String,
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LookupConfig {
+    /// URL of the service. The key is sent as the `key` query parameter, and the service returns a JSON value
+    pub url: String,
+
+    /// Maximum number of keys whose value is cached; Default: 10000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_size: Option<usize>,
+
+    /// How long a cached value is used before it's looked up again, in milliseconds; Default: 60000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_ttl_ms: Option<u64>,
+
+    /// Maximum number of requests in flight to the service; Default: 16
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrency: Option<usize>,
+
+    /// Request timeout in milliseconds; Default: 5000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+    /// Number of times a failed request is retried before the lookup fails; Default: 3
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_retries: Option<u32>,
+}
+
+pub fn default_lookup_cache_size() -> usize {
+    10_000
+}
+
+pub fn default_lookup_cache_ttl_ms() -> u64 {
+    60_000
+}
+
+pub fn default_lookup_max_concurrency() -> usize {
+    16
+}
+
+pub fn default_lookup_timeout_ms() -> u64 {
+    5_000
+}
+
+pub fn default_lookup_max_retries() -> u32 {
+    3
+}
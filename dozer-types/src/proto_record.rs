@@ -0,0 +1,621 @@
+//! Conversion between protobuf messages and [`Record`]s, driven by message descriptors.
+//!
+//! Fields are matched by name. Scalars convert to the closest field type, `google.protobuf.Timestamp` and
+//! `google.protobuf.Duration` convert to timestamps and durations, enums convert to their value names,
+//! and any other message, repeated or map field converts to its canonical protobuf JSON as a `Json` field.
+//!
+//! Values of the typed `dozer.types.Value` message, which gRPC ingestion receives, convert with [`grpc_value_to_field`].
+
+use std::borrow::Cow;
+use std::time::Duration;
+
+use chrono::{SecondsFormat, TimeZone, Utc};
+use geo::Point;
+use ordered_float::OrderedFloat;
+use prost_reflect::{
+    DynamicMessage, FieldDescriptor, Kind, MessageDescriptor, SerializeOptions, Value,
+};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde_json::{Map, Value as JsonValue};
+use thiserror::Error;
+
+use crate::grpc_types::types::{value as grpc_value, Value as GrpcValue};
+use crate::json_types::{json_value_to_serde_json, prost_to_json_value, serde_json_to_json_value};
+use crate::types::{
+    DozerDuration, DozerPoint, Field, FieldDefinition, FieldType, Record, Schema, SourceDefinition,
+    TimeUnit, DATE_FORMAT,
+};
+
+const TIMESTAMP: &str = "google.protobuf.Timestamp";
+const DURATION: &str = "google.protobuf.Duration";
+
+#[derive(Debug, Error)]
+pub enum ProtoRecordError {
+    #[error("field {0} is not in message {1}")]
+    FieldNotFound(String, String),
+    #[error("cannot convert field {field} of protobuf type {kind} to {typ}")]
+    FromProto {
+        field: String,
+        kind: String,
+        typ: FieldType,
+    },
+    #[error("cannot convert value {value} of field {field} to protobuf type {kind}")]
+    ToProto {
+        field: String,
+        value: Field,
+        kind: String,
+    },
+    #[error("cannot decode message: {0}")]
+    Decode(#[from] prost::DecodeError),
+    #[error("cannot convert json field: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// The schema of records converted from messages of `descriptor`. Fields with presence are nullable.
+pub fn schema_from_descriptor(descriptor: &MessageDescriptor) -> Schema {
+    let mut schema = Schema::new();
+    for field in descriptor.fields() {
+        schema.field(
+            FieldDefinition::new(
+                field.name().to_string(),
+                field_type(&field),
+                field.supports_presence(),
+                SourceDefinition::Dynamic,
+            ),
+            false,
+        );
+    }
+    schema
+}
+
+fn field_type(field: &FieldDescriptor) -> FieldType {
+    if field.is_list() || field.is_map() {
+        return FieldType::Json;
+    }
+    match field.kind() {
+        Kind::Int32
+        | Kind::Int64
+        | Kind::Sint32
+        | Kind::Sint64
+        | Kind::Sfixed32
+        | Kind::Sfixed64 => FieldType::Int,
+        Kind::Uint32 | Kind::Uint64 | Kind::Fixed32 | Kind::Fixed64 => FieldType::UInt,
+        Kind::Float | Kind::Double => FieldType::Float,
+        Kind::Bool => FieldType::Boolean,
+        Kind::String | Kind::Enum(_) => FieldType::String,
+        Kind::Bytes => FieldType::Binary,
+        Kind::Message(message) => match message.full_name() {
+            TIMESTAMP => FieldType::Timestamp,
+            DURATION => FieldType::Duration,
+            _ => FieldType::Json,
+        },
+    }
+}
+
+fn kind_name(kind: &Kind) -> String {
+    match kind {
+        Kind::Message(message) => message.full_name().to_string(),
+        Kind::Enum(enumeration) => enumeration.full_name().to_string(),
+        kind => format!("{kind:?}").to_lowercase(),
+    }
+}
+
+/// Decodes an encoded message of `descriptor` to a record of `schema`.
+pub fn decode_record(
+    descriptor: &MessageDescriptor,
+    bytes: &[u8],
+    schema: &Schema,
+) -> Result<Record, ProtoRecordError> {
+    let message = DynamicMessage::decode(descriptor.clone(), bytes)?;
+    message_to_record(&message, schema)
+}
+
+/// Converts a message to a record of `schema`. Fields without presence that are not set take their default value.
+pub fn message_to_record(
+    message: &DynamicMessage,
+    schema: &Schema,
+) -> Result<Record, ProtoRecordError> {
+    let descriptor = message.descriptor();
+    // Only computed if the schema has JSON fields.
+    let mut json = None;
+    let mut values = Vec::with_capacity(schema.fields.len());
+    for definition in &schema.fields {
+        let field = descriptor
+            .get_field_by_name(&definition.name)
+            .ok_or_else(|| {
+                ProtoRecordError::FieldNotFound(
+                    definition.name.clone(),
+                    descriptor.full_name().to_string(),
+                )
+            })?;
+        if field.supports_presence() && !message.has_field(&field) {
+            values.push(Field::Null);
+            continue;
+        }
+
+        let error = || ProtoRecordError::FromProto {
+            field: definition.name.clone(),
+            kind: kind_name(&field.kind()),
+            typ: definition.typ,
+        };
+        let value = if definition.typ == FieldType::Json {
+            let json = match &mut json {
+                Some(json) => json,
+                None => json.insert(message_to_json(message)?),
+            };
+            let value = json.remove(field.json_name()).unwrap_or(JsonValue::Null);
+            Field::Json(serde_json_to_json_value(value).map_err(|_| error())?)
+        } else {
+            proto_to_field(&field, message.get_field(&field), definition.typ).ok_or_else(error)?
+        };
+        values.push(value);
+    }
+    Ok(Record::new(values))
+}
+
+fn message_to_json(message: &DynamicMessage) -> Result<Map<String, JsonValue>, serde_json::Error> {
+    let options = SerializeOptions::new().skip_default_fields(false);
+    match message.serialize_with_options(serde_json::value::Serializer, &options)? {
+        JsonValue::Object(object) => Ok(object),
+        _ => Ok(Map::new()),
+    }
+}
+
+fn proto_to_field(field: &FieldDescriptor, value: Cow<Value>, typ: FieldType) -> Option<Field> {
+    let integer = match value.as_ref() {
+        Value::I32(n) => Some(i128::from(*n)),
+        Value::I64(n) => Some(i128::from(*n)),
+        Value::U32(n) => Some(i128::from(*n)),
+        Value::U64(n) => Some(i128::from(*n)),
+        _ => None,
+    };
+    if let Some(n) = integer {
+        return match typ {
+            FieldType::Int => i64::try_from(n).ok().map(Field::Int),
+            FieldType::UInt => u64::try_from(n).ok().map(Field::UInt),
+            FieldType::I128 => Some(Field::I128(n)),
+            FieldType::U128 => u128::try_from(n).ok().map(Field::U128),
+            FieldType::Float => Some(Field::Float(OrderedFloat(n as f64))),
+            FieldType::Decimal => Decimal::try_from_i128_with_scale(n, 0)
+                .ok()
+                .map(Field::Decimal),
+            _ => None,
+        };
+    }
+
+    match (value.into_owned(), typ) {
+        (Value::F32(n), FieldType::Float) => Some(Field::Float(OrderedFloat(n.into()))),
+        (Value::F64(n), FieldType::Float) => Some(Field::Float(OrderedFloat(n))),
+        (Value::F32(n), FieldType::Decimal) => Decimal::try_from(n).ok().map(Field::Decimal),
+        (Value::F64(n), FieldType::Decimal) => Decimal::try_from(n).ok().map(Field::Decimal),
+        (Value::Bool(b), FieldType::Boolean) => Some(Field::Boolean(b)),
+        (Value::String(s), FieldType::String) => Some(Field::String(s)),
+        (Value::String(s), FieldType::Text) => Some(Field::Text(s)),
+        (Value::String(s), typ) => Field::from_str(&s, typ, false).ok(),
+        (Value::Bytes(b), FieldType::Binary) => Some(Field::Binary(b.to_vec())),
+        (Value::EnumNumber(n), FieldType::Int) => Some(Field::Int(n.into())),
+        (Value::EnumNumber(n), FieldType::String | FieldType::Text) => {
+            let name = field.kind().as_enum()?.get_value(n)?.name().to_string();
+            Some(if typ == FieldType::String {
+                Field::String(name)
+            } else {
+                Field::Text(name)
+            })
+        }
+        (Value::Message(message), FieldType::Timestamp)
+            if message.descriptor().full_name() == TIMESTAMP =>
+        {
+            let (seconds, nanos) = seconds_and_nanos(&message)?;
+            Utc.timestamp_opt(seconds, u32::try_from(nanos).ok()?)
+                .single()
+                .map(|timestamp| Field::Timestamp(timestamp.fixed_offset()))
+        }
+        (Value::Message(message), FieldType::Duration)
+            if message.descriptor().full_name() == DURATION =>
+        {
+            let (seconds, nanos) = seconds_and_nanos(&message)?;
+            let duration = Duration::new(u64::try_from(seconds).ok()?, u32::try_from(nanos).ok()?);
+            Some(Field::Duration(DozerDuration(
+                duration,
+                TimeUnit::Nanoseconds,
+            )))
+        }
+        _ => None,
+    }
+}
+
+fn seconds_and_nanos(message: &DynamicMessage) -> Option<(i64, i32)> {
+    let seconds = message.get_field_by_name("seconds")?.as_i64()?;
+    let nanos = message.get_field_by_name("nanos")?.as_i32()?;
+    Some((seconds, nanos))
+}
+
+/// Converts a record of `schema` to a message of `descriptor`. Null fields are left unset.
+pub fn record_to_message(
+    record: &Record,
+    schema: &Schema,
+    descriptor: &MessageDescriptor,
+) -> Result<DynamicMessage, ProtoRecordError> {
+    let mut message = DynamicMessage::new(descriptor.clone());
+    let mut json = Map::new();
+    let mut json_fields = vec![];
+    for (definition, value) in schema.fields.iter().zip(&record.values) {
+        let field = descriptor
+            .get_field_by_name(&definition.name)
+            .ok_or_else(|| {
+                ProtoRecordError::FieldNotFound(
+                    definition.name.clone(),
+                    descriptor.full_name().to_string(),
+                )
+            })?;
+        match value {
+            Field::Null => (),
+            Field::Json(value) => {
+                json.insert(
+                    field.json_name().to_string(),
+                    json_value_to_serde_json(value),
+                );
+                json_fields.push(field);
+            }
+            value => {
+                let proto = field_to_proto(value, &field.kind())
+                    .filter(|_| !field.is_list() && !field.is_map())
+                    .ok_or_else(|| ProtoRecordError::ToProto {
+                        field: definition.name.clone(),
+                        value: value.clone(),
+                        kind: kind_name(&field.kind()),
+                    })?;
+                message.set_field(&field, proto);
+            }
+        }
+    }
+
+    // JSON fields are parsed with the canonical protobuf JSON mapping, then moved to the message.
+    if !json.is_empty() {
+        let parsed = DynamicMessage::deserialize(descriptor.clone(), JsonValue::Object(json))?;
+        for field in json_fields {
+            message.set_field(&field, parsed.get_field(&field).into_owned());
+        }
+    }
+    Ok(message)
+}
+
+fn field_to_proto(value: &Field, kind: &Kind) -> Option<Value> {
+    let integer = match value {
+        Field::Int(n) => Some(i128::from(*n)),
+        Field::UInt(n) => Some(i128::from(*n)),
+        Field::I128(n) => Some(*n),
+        Field::U128(n) => i128::try_from(*n).ok(),
+        _ => None,
+    };
+    if let Some(n) = integer {
+        return match kind {
+            Kind::Int32 | Kind::Sint32 | Kind::Sfixed32 => i32::try_from(n).ok().map(Value::I32),
+            Kind::Int64 | Kind::Sint64 | Kind::Sfixed64 => i64::try_from(n).ok().map(Value::I64),
+            Kind::Uint32 | Kind::Fixed32 => u32::try_from(n).ok().map(Value::U32),
+            Kind::Uint64 | Kind::Fixed64 => u64::try_from(n).ok().map(Value::U64),
+            Kind::Float => Some(Value::F32(n as f32)),
+            Kind::Double => Some(Value::F64(n as f64)),
+            Kind::String => Some(Value::String(n.to_string())),
+            Kind::Enum(enumeration) => {
+                let number = i32::try_from(n).ok()?;
+                enumeration.get_value(number)?;
+                Some(Value::EnumNumber(number))
+            }
+            _ => None,
+        };
+    }
+
+    match (value, kind) {
+        (Field::Float(n), Kind::Float) => Some(Value::F32(n.0 as f32)),
+        (Field::Float(n), Kind::Double) => Some(Value::F64(n.0)),
+        (Field::Decimal(n), Kind::Float) => n.to_f32().map(Value::F32),
+        (Field::Decimal(n), Kind::Double) => n.to_f64().map(Value::F64),
+        (Field::Boolean(b), Kind::Bool) => Some(Value::Bool(*b)),
+        (Field::String(s) | Field::Text(s), Kind::Enum(enumeration)) => enumeration
+            .get_value_by_name(s)
+            .map(|value| Value::EnumNumber(value.number())),
+        (Field::Binary(b), Kind::Bytes) => Some(Value::Bytes(b.clone().into())),
+        (Field::Timestamp(timestamp), Kind::Message(message))
+            if message.full_name() == TIMESTAMP =>
+        {
+            let nanos = i32::try_from(timestamp.timestamp_subsec_nanos()).ok()?;
+            Some(seconds_and_nanos_message(
+                message,
+                timestamp.timestamp(),
+                nanos,
+            ))
+        }
+        (Field::Duration(duration), Kind::Message(message)) if message.full_name() == DURATION => {
+            let seconds = i64::try_from(duration.0.as_secs()).ok()?;
+            let nanos = i32::try_from(duration.0.subsec_nanos()).ok()?;
+            Some(seconds_and_nanos_message(message, seconds, nanos))
+        }
+        (value, Kind::String) => Some(Value::String(match value {
+            Field::String(s) | Field::Text(s) => s.clone(),
+            Field::Timestamp(timestamp) => timestamp.to_rfc3339_opts(SecondsFormat::Millis, true),
+            Field::Date(date) => date.format(DATE_FORMAT).to_string(),
+            Field::Float(_) | Field::Decimal(_) | Field::Boolean(_) => value.to_string(),
+            _ => return None,
+        })),
+        _ => None,
+    }
+}
+
+fn seconds_and_nanos_message(descriptor: &MessageDescriptor, seconds: i64, nanos: i32) -> Value {
+    let mut message = DynamicMessage::new(descriptor.clone());
+    message.set_field_by_name("seconds", Value::I64(seconds));
+    message.set_field_by_name("nanos", Value::I32(nanos));
+    Value::Message(message)
+}
+
+/// Converts a `dozer.types.Value` to a field of type `typ`, the inverse of
+/// [`field_to_grpc`](crate::grpc_types::conversions::field_to_grpc). Unset values are null.
+/// Returns `None` if the value doesn't convert to `typ`.
+pub fn grpc_value_to_field(value: GrpcValue, typ: FieldType) -> Option<Field> {
+    let Some(value) = value.value else {
+        return Some(Field::Null);
+    };
+    match (value, typ) {
+        (grpc_value::Value::UintValue(n), FieldType::UInt) => Some(Field::UInt(n)),
+        (grpc_value::Value::IntValue(n), FieldType::Int) => Some(Field::Int(n)),
+        (grpc_value::Value::FloatValue(n), FieldType::Float) => Some(Field::Float(OrderedFloat(n))),
+        (grpc_value::Value::BoolValue(b), FieldType::Boolean) => Some(Field::Boolean(b)),
+        (grpc_value::Value::StringValue(s), FieldType::String) => Some(Field::String(s)),
+        (grpc_value::Value::StringValue(s), FieldType::Text) => Some(Field::Text(s)),
+        (
+            grpc_value::Value::Uint128Value(s)
+            | grpc_value::Value::Int128Value(s)
+            | grpc_value::Value::DateValue(s)
+            | grpc_value::Value::StringValue(s),
+            FieldType::U128 | FieldType::I128 | FieldType::Date,
+        ) => Field::from_str(&s, typ, false).ok(),
+        (grpc_value::Value::BytesValue(b), FieldType::Binary) => Some(Field::Binary(b)),
+        (grpc_value::Value::DecimalValue(d), FieldType::Decimal) => Some(Field::Decimal(
+            Decimal::from_parts(d.lo, d.mid, d.hi, d.negative, d.scale),
+        )),
+        (grpc_value::Value::TimestampValue(t), FieldType::Timestamp) => Utc
+            .timestamp_opt(t.seconds, u32::try_from(t.nanos).ok()?)
+            .single()
+            .map(|timestamp| Field::Timestamp(timestamp.fixed_offset())),
+        (grpc_value::Value::PointValue(p), FieldType::Point) => Some(Field::Point(DozerPoint(
+            Point::new(OrderedFloat(p.x), OrderedFloat(p.y)),
+        ))),
+        (grpc_value::Value::DurationValue(d), FieldType::Duration) => {
+            let nanos = u64::try_from(d.value.parse::<u128>().ok()?).ok()?;
+            Some(Field::Duration(DozerDuration(
+                Duration::from_nanos(nanos),
+                d.time_unit.parse().ok()?,
+            )))
+        }
+        (grpc_value::Value::JsonValue(v), FieldType::Json) => {
+            Some(Field::Json(prost_to_json_value(v)))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+    use prost_reflect::DescriptorPool;
+    use prost_types::field_descriptor_proto::{Label, Type};
+    use prost_types::{
+        DescriptorProto, EnumDescriptorProto, EnumValueDescriptorProto, FieldDescriptorProto,
+        FileDescriptorProto, FileDescriptorSet,
+    };
+
+    use crate::grpc_types::conversions::field_to_grpc;
+    use crate::json_types::json;
+
+    use super::*;
+
+    fn proto_field(
+        name: &str,
+        number: i32,
+        typ: Type,
+        type_name: Option<&str>,
+        label: Label,
+        proto3_optional: bool,
+    ) -> FieldDescriptorProto {
+        FieldDescriptorProto {
+            name: Some(name.to_string()),
+            number: Some(number),
+            label: Some(label as i32),
+            r#type: Some(typ as i32),
+            type_name: type_name.map(ToString::to_string),
+            json_name: Some(name.to_string()),
+            oneof_index: proto3_optional.then_some(0),
+            proto3_optional: proto3_optional.then_some(true),
+            ..Default::default()
+        }
+    }
+
+    fn descriptor() -> MessageDescriptor {
+        let timestamp = FileDescriptorProto {
+            name: Some("google/protobuf/timestamp.proto".to_string()),
+            package: Some("google.protobuf".to_string()),
+            message_type: vec![DescriptorProto {
+                name: Some("Timestamp".to_string()),
+                field: vec![
+                    proto_field("seconds", 1, Type::Int64, None, Label::Optional, false),
+                    proto_field("nanos", 2, Type::Int32, None, Label::Optional, false),
+                ],
+                ..Default::default()
+            }],
+            syntax: Some("proto3".to_string()),
+            ..Default::default()
+        };
+        let order = FileDescriptorProto {
+            name: Some("order.proto".to_string()),
+            package: Some("shop".to_string()),
+            dependency: vec!["google/protobuf/timestamp.proto".to_string()],
+            message_type: vec![DescriptorProto {
+                name: Some("Order".to_string()),
+                field: vec![
+                    proto_field("id", 1, Type::Uint64, None, Label::Optional, false),
+                    proto_field("note", 2, Type::String, None, Label::Optional, true),
+                    proto_field(
+                        "status",
+                        3,
+                        Type::Enum,
+                        Some(".shop.Status"),
+                        Label::Optional,
+                        false,
+                    ),
+                    proto_field(
+                        "created",
+                        4,
+                        Type::Message,
+                        Some(".google.protobuf.Timestamp"),
+                        Label::Optional,
+                        false,
+                    ),
+                    proto_field("tags", 5, Type::String, None, Label::Repeated, false),
+                ],
+                oneof_decl: vec![prost_types::OneofDescriptorProto {
+                    name: Some("_note".to_string()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            enum_type: vec![EnumDescriptorProto {
+                name: Some("Status".to_string()),
+                value: ["PENDING", "SHIPPED"]
+                    .into_iter()
+                    .enumerate()
+                    .map(|(number, name)| EnumValueDescriptorProto {
+                        name: Some(name.to_string()),
+                        number: Some(number as i32),
+                        ..Default::default()
+                    })
+                    .collect(),
+                ..Default::default()
+            }],
+            syntax: Some("proto3".to_string()),
+            ..Default::default()
+        };
+        DescriptorPool::from_file_descriptor_set(FileDescriptorSet {
+            file: vec![timestamp, order],
+        })
+        .unwrap()
+        .get_message_by_name("shop.Order")
+        .unwrap()
+    }
+
+    #[test]
+    fn schema_from_message_descriptor() {
+        let schema = schema_from_descriptor(&descriptor());
+        let fields = schema
+            .fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.typ, field.nullable))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            vec![
+                ("id", FieldType::UInt, false),
+                ("note", FieldType::String, true),
+                ("status", FieldType::String, false),
+                ("created", FieldType::Timestamp, true),
+                ("tags", FieldType::Json, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn record_message_roundtrip() {
+        let descriptor = descriptor();
+        let schema = schema_from_descriptor(&descriptor);
+        let record = Record::new(vec![
+            Field::UInt(7),
+            Field::Null,
+            Field::String("SHIPPED".to_string()),
+            Field::Timestamp(Utc.timestamp_opt(86400, 5000).unwrap().fixed_offset()),
+            Field::Json(json!(["a", "b"])),
+        ]);
+
+        let message = record_to_message(&record, &schema, &descriptor).unwrap();
+        let bytes = message.encode_to_vec();
+        assert_eq!(decode_record(&descriptor, &bytes, &schema).unwrap(), record);
+
+        let empty = DynamicMessage::new(descriptor.clone());
+        assert_eq!(
+            message_to_record(&empty, &schema).unwrap(),
+            Record::new(vec![
+                Field::UInt(0),
+                Field::Null,
+                Field::String("PENDING".to_string()),
+                Field::Null,
+                Field::Json(json!([])),
+            ])
+        );
+    }
+
+    #[test]
+    fn conversion_errors() {
+        let descriptor = descriptor();
+        let mut schema = schema_from_descriptor(&descriptor);
+        let mut record = Record::new(vec![
+            Field::Int(-1),
+            Field::Null,
+            Field::String("PENDING".to_string()),
+            Field::Null,
+            Field::Null,
+        ]);
+        assert!(matches!(
+            record_to_message(&record, &schema, &descriptor),
+            Err(ProtoRecordError::ToProto { field, .. }) if field == "id"
+        ));
+        record.values[0] = Field::UInt(1);
+        record.values[2] = Field::String("LOST".to_string());
+        assert!(matches!(
+            record_to_message(&record, &schema, &descriptor),
+            Err(ProtoRecordError::ToProto { field, .. }) if field == "status"
+        ));
+
+        schema.fields[0].name = "missing".to_string();
+        assert!(matches!(
+            message_to_record(&DynamicMessage::new(descriptor), &schema),
+            Err(ProtoRecordError::FieldNotFound(field, message)) if field == "missing" && message == "shop.Order"
+        ));
+    }
+
+    #[test]
+    fn grpc_value_roundtrip() {
+        let fields = [
+            (Field::UInt(1), FieldType::UInt),
+            (Field::I128(-2), FieldType::I128),
+            (Field::Text("text".to_string()), FieldType::Text),
+            (Field::Decimal(Decimal::new(314, 2)), FieldType::Decimal),
+            (
+                Field::Timestamp(Utc.timestamp_opt(1, 2).unwrap().fixed_offset()),
+                FieldType::Timestamp,
+            ),
+            (
+                Field::Date(chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()),
+                FieldType::Date,
+            ),
+            (
+                Field::Duration(DozerDuration(
+                    Duration::from_millis(3),
+                    TimeUnit::Milliseconds,
+                )),
+                FieldType::Duration,
+            ),
+            (Field::Json(json!({"a": [1]})), FieldType::Json),
+            (Field::Null, FieldType::Int),
+        ];
+        for (field, typ) in fields {
+            assert_eq!(
+                grpc_value_to_field(field_to_grpc(field.clone()), typ),
+                Some(field)
+            );
+        }
+        assert_eq!(
+            grpc_value_to_field(field_to_grpc(Field::Int(1)), FieldType::String),
+            None
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use std::time::Duration;
+
+use rand::Rng;
+
+use crate::models::connection::{
+    default_retry_initial_interval_ms, default_retry_max_interval_ms, default_retry_max_retries,
+    RetryConfig,
+};
+
+/// How to retry an operation that failed because of a transient error, such as a dropped connection.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// Delay before the first retry.
+    pub initial_interval: Duration,
+    /// Upper bound of the delay between retries.
+    pub max_interval: Duration,
+    /// Factor the delay is multiplied by after each retry.
+    pub multiplier: f64,
+    /// Fraction of the delay that is randomized, so clients failing together don't retry together.
+    pub jitter: f64,
+    /// Number of retries before giving up, or `None` to retry forever.
+    pub max_retries: Option<u32>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::from(&RetryConfig::default())
+    }
+}
+
+impl From<&RetryConfig> for RetryPolicy {
+    fn from(config: &RetryConfig) -> Self {
+        Self {
+            initial_interval: Duration::from_millis(
+                config
+                    .initial_interval_ms
+                    .unwrap_or_else(default_retry_initial_interval_ms),
+            ),
+            max_interval: Duration::from_millis(
+                config
+                    .max_interval_ms
+                    .unwrap_or_else(default_retry_max_interval_ms),
+            ),
+            multiplier: 2.0,
+            jitter: 0.2,
+            max_retries: Some(config.max_retries.unwrap_or_else(default_retry_max_retries)),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// The policy of a connection, or the default one if it doesn't configure retries.
+    pub fn of_connection(config: Option<&RetryConfig>) -> Self {
+        config.map(Self::from).unwrap_or_default()
+    }
+
+    pub fn backoff(&self) -> Backoff {
+        Backoff {
+            policy: *self,
+            retries: 0,
+            interval: self.initial_interval,
+        }
+    }
+}
+
+/// The delays between successive retries of an operation, following a [`RetryPolicy`].
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    policy: RetryPolicy,
+    retries: u32,
+    interval: Duration,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        RetryPolicy::default().backoff()
+    }
+}
+
+impl Backoff {
+    /// Returns the delay before the next retry, or `None` if the policy allows no more retries.
+    pub fn next_delay(&mut self) -> Option<Duration> {
+        if self
+            .policy
+            .max_retries
+            .map_or(false, |max_retries| self.retries >= max_retries)
+        {
+            return None;
+        }
+        self.retries += 1;
+
+        let interval = self.interval;
+        self.interval = interval
+            .mul_f64(self.policy.multiplier.max(1.0))
+            .min(self.policy.max_interval);
+
+        let jitter = self.policy.jitter.clamp(0.0, 1.0);
+        if jitter == 0.0 {
+            return Some(interval);
+        }
+        let factor = rand::thread_rng().gen_range(1.0 - jitter..=1.0 + jitter);
+        Some(interval.mul_f64(factor))
+    }
+
+    /// Number of retries so far.
+    pub fn retries(&self) -> u32 {
+        self.retries
+    }
+
+    /// Starts over from the initial interval, after the operation succeeded.
+    pub fn reset(&mut self) {
+        self.retries = 0;
+        self.interval = self.policy.initial_interval;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            initial_interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(5),
+            multiplier: 2.0,
+            jitter: 0.0,
+            max_retries: Some(5),
+        }
+    }
+
+    #[test]
+    fn test_backoff_grows_until_max_interval() {
+        let mut backoff = policy().backoff();
+        let delays = std::iter::from_fn(|| backoff.next_delay()).collect::<Vec<_>>();
+        assert_eq!(
+            delays,
+            [1, 2, 4, 5, 5].map(Duration::from_secs).to_vec(),
+            "gives up after max_retries"
+        );
+
+        backoff.reset();
+        assert_eq!(backoff.next_delay(), Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_default_policy_gives_up() {
+        let mut backoff = RetryPolicy::default().backoff();
+        assert_eq!(
+            std::iter::from_fn(|| backoff.next_delay()).count(),
+            default_retry_max_retries() as usize
+        );
+
+        let policy = RetryPolicy::of_connection(Some(&RetryConfig {
+            initial_interval_ms: Some(10),
+            max_interval_ms: None,
+            max_retries: Some(2),
+        }));
+        assert_eq!(policy.initial_interval, Duration::from_millis(10));
+        assert_eq!(
+            policy.max_interval,
+            Duration::from_millis(default_retry_max_interval_ms())
+        );
+        assert_eq!(policy.max_retries, Some(2));
+    }
+
+    #[test]
+    fn test_backoff_jitter() {
+        let mut backoff = RetryPolicy {
+            jitter: 0.5,
+            ..policy()
+        }
+        .backoff();
+        let delay = backoff.next_delay().unwrap();
+        assert!(delay >= Duration::from_millis(500) && delay <= Duration::from_millis(1500));
+    }
+}
@@ -10,6 +10,7 @@ use std::str::FromStr;
 use crate::errors::types::TypeError;
 use crate::node::OpIdentifier;
 use prettytable::{Cell, Row, Table};
+use schemars::JsonSchema;
 use serde::{self, Deserialize, Serialize};
 
 pub mod field;
@@ -53,6 +54,8 @@ pub struct FieldDefinition {
     pub nullable: bool,
     #[serde(default)]
     pub source: SourceDefinition,
+    #[serde(default)]
+    pub annotations: FieldAnnotations,
 }
 
 impl FieldDefinition {
@@ -62,6 +65,7 @@ impl FieldDefinition {
             typ,
             nullable,
             source,
+            annotations: Default::default(),
         }
     }
 
@@ -74,6 +78,57 @@ impl FieldDefinition {
     }
 }
 
+/// Metadata describing a field, used to document generated APIs and to apply data policies.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct FieldAnnotations {
+    pub description: Option<String>,
+    pub pii: Option<PiiCategory>,
+    /// Unit of the values, e.g. `ms` or `USD`.
+    pub unit: Option<String>,
+}
+
+impl FieldAnnotations {
+    pub fn is_empty(&self) -> bool {
+        self.description.is_none() && self.pii.is_none() && self.unit.is_none()
+    }
+
+    /// Overrides these annotations with the ones set in `other`.
+    pub fn merge(&mut self, other: &FieldAnnotations) {
+        if let Some(description) = &other.description {
+            self.description = Some(description.clone());
+        }
+        if let Some(pii) = other.pii {
+            self.pii = Some(pii);
+        }
+        if let Some(unit) = &other.unit {
+            self.unit = Some(unit.clone());
+        }
+    }
+}
+
+/// How a field holding personal data identifies a person.
+#[derive(
+    Clone, Copy, Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum PiiCategory {
+    /// Identifies a person on its own, like an email address or a national id.
+    Identifier,
+    /// Identifies a person when combined with other data, like a birth date or a zip code.
+    QuasiIdentifier,
+    /// Doesn't identify a person, but must be protected, like health or financial data.
+    Sensitive,
+}
+
+impl Display for PiiCategory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PiiCategory::Identifier => f.write_str("Identifier"),
+            PiiCategory::QuasiIdentifier => f.write_str("QuasiIdentifier"),
+            PiiCategory::Sensitive => f.write_str("Sensitive"),
+        }
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Default)]
 pub struct Schema {
     /// fields contains a list of FieldDefinition for all the fields that appear in a record.
@@ -270,6 +325,68 @@ impl Record {
     pub fn get_lifetime(&self) -> Option<Lifetime> {
         self.lifetime.clone()
     }
+
+    /// The changes that turn this record into `new`.
+    pub fn diff(&self, new: &Record) -> FieldDiff {
+        if self.values.len() != new.values.len() {
+            return FieldDiff::Full(new.values.clone());
+        }
+        FieldDiff::Changed(
+            self.values
+                .iter()
+                .zip(&new.values)
+                .enumerate()
+                .filter(|(_, (old, new))| old != new)
+                .map(|(index, (_, new))| (index, new.clone()))
+                .collect(),
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// The difference between two versions of a record, computed by [`Record::diff`].
+pub enum FieldDiff {
+    /// The records have different numbers of fields, so the new values are kept whole.
+    Full(Vec<Field>),
+    /// Indexes and new values of the fields that changed, in field order.
+    Changed(Vec<(usize, Field)>),
+}
+
+impl FieldDiff {
+    pub fn is_empty(&self) -> bool {
+        matches!(self, FieldDiff::Changed(changes) if changes.is_empty())
+    }
+
+    /// Whether carrying the diff is worth it, that is, whether it changes at most half the fields of a record of `len` fields.
+    /// Otherwise the full record should be sent instead.
+    pub fn is_minimal(&self, len: usize) -> bool {
+        match self {
+            FieldDiff::Full(_) => false,
+            FieldDiff::Changed(changes) => changes.len() * 2 <= len,
+        }
+    }
+
+    /// Applies the changes to the old version of the record.
+    ///
+    /// Fails without changing `record` if a change is to a field it doesn't have, as when the diff was computed
+    /// against another version of the record.
+    pub fn apply(self, record: &mut Record) -> Result<(), TypeError> {
+        match self {
+            FieldDiff::Full(values) => record.values = values,
+            FieldDiff::Changed(changes) => {
+                if let Some((index, _)) = changes
+                    .iter()
+                    .find(|(index, _)| *index >= record.values.len())
+                {
+                    return Err(TypeError::InvalidFieldIndex(*index));
+                }
+                for (index, value) in changes {
+                    record.values[index] = value;
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Display for Record {
@@ -1,4 +1,7 @@
-use crate::types::{field_test_cases, DozerDuration, DozerPoint, Field, TimeUnit};
+use crate::errors::types::TypeError;
+use crate::types::{
+    field_test_cases, DozerDuration, DozerPoint, Field, FieldDiff, Record, TimeUnit,
+};
 use chrono::{DateTime, NaiveDate, TimeZone, Utc};
 use ordered_float::OrderedFloat;
 use rust_decimal::Decimal;
@@ -565,3 +568,51 @@ fn test_to_conversion() {
     assert!(field.to_duration().is_some());
     assert!(field.to_null().is_some());
 }
+
+#[test]
+fn test_record_diff() {
+    let old = Record::new(vec![
+        Field::Int(1),
+        Field::String("a".to_string()),
+        Field::Null,
+        Field::Boolean(true),
+    ]);
+    let new = Record::new(vec![
+        Field::Int(1),
+        Field::String("b".to_string()),
+        Field::Null,
+        Field::Boolean(true),
+    ]);
+
+    let diff = old.diff(&new);
+    assert_eq!(
+        diff,
+        FieldDiff::Changed(vec![(1, Field::String("b".to_string()))])
+    );
+    assert!(diff.is_minimal(new.values.len()));
+    let mut applied = old.clone();
+    diff.clone().apply(&mut applied).unwrap();
+    assert_eq!(applied, new);
+
+    let mut narrower = Record::new(vec![Field::Int(1)]);
+    assert!(matches!(
+        diff.apply(&mut narrower),
+        Err(TypeError::InvalidFieldIndex(1))
+    ));
+    assert_eq!(narrower, Record::new(vec![Field::Int(1)]));
+
+    assert!(old.diff(&old).is_empty());
+
+    let wider = Record::new(vec![
+        Field::Int(1),
+        Field::Int(2),
+        Field::Int(3),
+        Field::Int(4),
+        Field::Int(5),
+    ]);
+    let diff = old.diff(&wider);
+    assert!(!diff.is_minimal(wider.values.len()));
+    let mut applied = old;
+    diff.apply(&mut applied).unwrap();
+    assert_eq!(applied, wider);
+}